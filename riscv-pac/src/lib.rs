@@ -4,16 +4,42 @@ pub mod result;
 
 use result::Result;
 
-/// Trait for enums of target-specific external interrupt numbers.
+/// Trait for enums of target-specific exception numbers.
 ///
 /// This trait should be implemented by a peripheral access crate (PAC)
-/// on its enum of available external interrupts for a specific device.
+/// on its enum of available exceptions for a specific device.
+/// Each variant must convert to a `u16` of its exception number.
+///
+/// # Safety
+///
+/// * This trait must only be implemented on a PAC of a RISC-V target.
+/// * This trait must only be implemented on enums of exceptions.
+/// * Each enum variant must represent a distinct value (no duplicates are permitted),
+/// * Each enum variant must always return the same value (do not change at runtime).
+/// * All the exception numbers must be less than or equal to `MAX_EXCEPTION_NUMBER`.
+/// * `MAX_EXCEPTION_NUMBER` must coincide with the highest allowed exception number.
+pub unsafe trait ExceptionNumber: Copy {
+    /// Highest number assigned to an exception.
+    const MAX_EXCEPTION_NUMBER: u16;
+
+    /// Converts an exception to its corresponding number.
+    fn number(self) -> u16;
+
+    /// Tries to convert a number to a valid exception.
+    /// If the conversion fails, it returns an error with the number back.
+    fn from_number(value: u16) -> Result<Self>;
+}
+
+/// Trait for enums of target-specific interrupt numbers.
+///
+/// This trait should be implemented by a peripheral access crate (PAC)
+/// on its enum of available interrupts for a specific device.
 /// Each variant must convert to a `u16` of its interrupt number.
 ///
 /// # Safety
 ///
 /// * This trait must only be implemented on a PAC of a RISC-V target.
-/// * This trait must only be implemented on enums of external interrupts.
+/// * This trait must only be implemented on enums of interrupts.
 /// * Each enum variant must represent a distinct value (no duplicates are permitted),
 /// * Each enum variant must always return the same value (do not change at runtime).
 /// * All the interrupt numbers must be less than or equal to `MAX_INTERRUPT_NUMBER`.
@@ -30,6 +56,32 @@ pub unsafe trait InterruptNumber: Copy {
     fn from_number(value: u16) -> Result<Self>;
 }
 
+/// Marker trait for enums of target-specific core interrupt numbers.
+///
+/// Core interrupts are interrupts directly handled by the hart (e.g., software,
+/// timer, and external interrupts). Their numbers coincide with the interrupt
+/// codes reported in the `mcause` and `scause` CSRs.
+///
+/// # Safety
+///
+/// * This trait must only be implemented on a PAC of a RISC-V target.
+/// * This trait must only be implemented on enums of core interrupts.
+/// * Each interrupt number must coincide with the code reported in the `mcause` and `scause` CSRs.
+pub unsafe trait CoreInterruptNumber: InterruptNumber {}
+
+/// Marker trait for enums of target-specific external interrupt numbers.
+///
+/// External interrupts are signaled to the hart by an interrupt controller (e.g., PLIC or IMSIC).
+/// Their numbers coincide with the interrupt source or identity numbers of the interrupt controller.
+///
+/// # Safety
+///
+/// * This trait must only be implemented on a PAC of a RISC-V target.
+/// * This trait must only be implemented on enums of external interrupts.
+/// * Each interrupt number must coincide with the number of the interrupt source in the interrupt controller.
+/// * Interrupt number 0 is reserved and must not be used.
+pub unsafe trait ExternalInterruptNumber: InterruptNumber {}
+
 /// Trait for enums of priority levels.
 ///
 /// This trait should be implemented by a peripheral access crate (PAC)
@@ -81,3 +133,30 @@ pub unsafe trait HartIdNumber: Copy {
     /// If the conversion fails, it returns an error with the number back.
     fn from_number(value: u16) -> Result<Self>;
 }
+
+/// Trait for enums of target-specific hardware performance-monitoring counters.
+///
+/// This trait should be implemented by a peripheral access crate (PAC)
+/// on its enum of available `hpmcounter`s for a specific device.
+/// Each variant must convert to a `u16` of its counter index (i.e., `X` in `mhpmcounterX`).
+///
+/// # Safety
+///
+/// * This trait must only be implemented on a PAC of a RISC-V target.
+/// * This trait must only be implemented on enums of implemented hardware performance-monitoring counters.
+/// * Each enum variant must represent a distinct value (no duplicates are permitted),
+/// * Each enum variant must always return the same value (do not change at runtime).
+/// * All the counter numbers must be in the range 3 to 31.
+/// * All the counter numbers must be less than or equal to `MAX_COUNTER_NUMBER`.
+/// * `MAX_COUNTER_NUMBER` must coincide with the highest allowed counter number.
+pub unsafe trait CounterNumber: Copy {
+    /// Highest number assigned to a counter.
+    const MAX_COUNTER_NUMBER: u16;
+
+    /// Converts a counter to its corresponding number.
+    fn number(self) -> u16;
+
+    /// Tries to convert a number to a valid counter.
+    /// If the conversion fails, it returns an error with the number back.
+    fn from_number(value: u16) -> Result<Self>;
+}
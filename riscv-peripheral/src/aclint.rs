@@ -58,6 +58,40 @@ impl<C: Clint> CLINT<C> {
             )
         }
     }
+
+    /// Returns the current value of the `MTIME` register.
+    ///
+    /// # Note
+    ///
+    /// On 32-bit targets, the value is consistent even if the lower half of the register
+    /// overflows while reading it (see [`mtimer::MTIME::read_u64`]).
+    #[inline]
+    pub fn mtime() -> u64 {
+        Self::mtimer().mtime.read_u64()
+    }
+
+    /// Sets the `MTIMECMP` register of the HART which ID is `hart_id`.
+    ///
+    /// # Note
+    ///
+    /// On 32-bit targets, no spurious timer interrupt is triggered while writing the register
+    /// (see [`mtimer::MTIMECMP::write_u64`]).
+    #[inline]
+    pub fn set_mtimecmp<H: HartIdNumber>(hart_id: H, val: u64) {
+        Self::mtimer().mtimecmp(hart_id).write_u64(val);
+    }
+
+    /// Triggers a machine software interrupt in the HART which ID is `hart_id`.
+    #[inline]
+    pub fn set_msip<H: HartIdNumber>(hart_id: H) {
+        Self::mswi().msip(hart_id).pend();
+    }
+
+    /// Clears the machine software interrupt of the HART which ID is `hart_id`.
+    #[inline]
+    pub fn clear_msip<H: HartIdNumber>(hart_id: H) {
+        Self::mswi().msip(hart_id).unpend();
+    }
 }
 
 #[cfg(test)]
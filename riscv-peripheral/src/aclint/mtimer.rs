@@ -55,9 +55,81 @@ impl MTIMER {
 // MTIMECMP register.
 safe_peripheral!(MTIMECMP, u64, RW);
 
+impl MTIMECMP {
+    /// Writes the whole `MTIMECMP` register.
+    ///
+    /// On 32-bit targets, the register is written as two halves. The lower half is first set to
+    /// `u32::MAX`, so no spurious timer interrupt is triggered between both writes.
+    #[inline]
+    pub fn write_u64(self, val: u64) {
+        match () {
+            #[cfg(target_pointer_width = "32")]
+            () => {
+                // SAFETY: valid register address
+                unsafe { write_split(self.get_ptr(), val) }
+            }
+            #[cfg(not(target_pointer_width = "32"))]
+            () => self.write(val),
+        }
+    }
+}
+
 // MTIME register.
 safe_peripheral!(MTIME, u64, RW);
 
+impl MTIME {
+    /// Reads the whole `MTIME` register.
+    ///
+    /// On 32-bit targets, the register is read as two halves. The upper half is read before and
+    /// after the lower half, and the read is repeated if the lower half overflowed in between.
+    #[inline]
+    pub fn read_u64(self) -> u64 {
+        match () {
+            #[cfg(target_pointer_width = "32")]
+            () => {
+                // SAFETY: valid register address
+                unsafe { read_split(self.get_ptr()) }
+            }
+            #[cfg(not(target_pointer_width = "32"))]
+            () => self.read(),
+        }
+    }
+}
+
+/// Reads a 64-bit register as two 32-bit halves.
+///
+/// # Safety
+///
+/// `ptr` must point to a valid 64-bit register.
+#[cfg_attr(not(target_pointer_width = "32"), allow(dead_code))]
+#[inline]
+unsafe fn read_split(ptr: *const u64) -> u64 {
+    let lo = ptr as *const u32;
+    let hi = lo.add(1);
+    loop {
+        let h = hi.read_volatile();
+        let l = lo.read_volatile();
+        if h == hi.read_volatile() {
+            return ((h as u64) << 32) | l as u64;
+        }
+    }
+}
+
+/// Writes a 64-bit register as two 32-bit halves.
+///
+/// # Safety
+///
+/// `ptr` must point to a valid 64-bit register.
+#[cfg_attr(not(target_pointer_width = "32"), allow(dead_code))]
+#[inline]
+unsafe fn write_split(ptr: *mut u64, val: u64) {
+    let lo = ptr as *mut u32;
+    let hi = lo.add(1);
+    lo.write_volatile(u32::MAX);
+    hi.write_volatile((val >> 32) as u32);
+    lo.write_volatile(val as u32);
+}
+
 #[cfg(test)]
 mod test {
     use super::super::test::HartId;
@@ -89,4 +161,20 @@ mod test {
             &raw_mtime as *const u64 as _
         );
     }
+
+    #[test]
+    fn check_split() {
+        let mut raw_reg = 0u64;
+        let ptr = &mut raw_reg as *mut u64;
+
+        unsafe { write_split(ptr, 0x1234_5678_9ABC_DEF0) };
+        assert_eq!(unsafe { read_split(ptr) }, 0x1234_5678_9ABC_DEF0);
+        unsafe { write_split(ptr, u64::MAX) };
+        assert_eq!(unsafe { read_split(ptr) }, u64::MAX);
+
+        // SAFETY: valid memory address
+        let (mtimecmp, mtime) = unsafe { (MTIMECMP::new(ptr as _), MTIME::new(ptr as _)) };
+        mtimecmp.write_u64(0x1_0000_0000);
+        assert_eq!(mtime.read_u64(), 0x1_0000_0000);
+    }
 }
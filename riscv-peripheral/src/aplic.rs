@@ -0,0 +1,272 @@
+//! Advanced Platform-Level Interrupt Controller (APLIC) peripheral.
+//!
+//! Specification: <https://github.com/riscv/riscv-aia>
+//!
+//! An APLIC is organized as a tree of interrupt domains. Each interrupt domain has its own
+//! memory-mapped control region, so each interrupt domain is a different [`APLIC`] peripheral.
+//! Interrupt sources are delegated from a parent domain to its child domains with [`sourcecfg`].
+//!
+//! Each interrupt domain delivers its interrupts in one of two modes (see [`domaincfg`]):
+//!
+//! - Direct mode: the interrupts are signaled to the HARTs through the interrupt delivery control
+//!   structures ([`idc`]), where they are claimed.
+//! - MSI mode: the interrupts are forwarded as MSIs to the IMSIC interrupt files of the HARTs
+//!   (see [`crate::imsic`]), where they are claimed.
+//!
+//! In both modes, [`target`] routes the interrupts of each source to a HART.
+
+pub mod domaincfg;
+pub mod idc;
+pub mod sourcecfg;
+pub mod target;
+
+use crate::common::{Reg, RO, WO};
+use crate::imsic::Imsic;
+
+// re-export useful riscv-pac traits
+pub use riscv_pac::{ExternalInterruptNumber, HartIdNumber, PriorityNumber};
+
+/// Trait for an APLIC interrupt domain.
+///
+/// # Safety
+///
+/// * This trait must only be implemented on a PAC of a target with an APLIC peripheral.
+/// * The interrupt domain base address `BASE` must be valid for the target device.
+pub unsafe trait Aplic: Copy {
+    /// Base address of the control region of the interrupt domain.
+    const BASE: usize;
+}
+
+/// Advanced Platform-Level Interrupt Controller (APLIC) interrupt domain.
+///
+/// The RISC-V standard does not specify a fixed location for the APLIC.
+/// Thus, each platform must specify the base address of each interrupt domain on the platform.
+/// The base address, as well as all the associated types, are defined in the [`Aplic`] trait.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct APLIC<A: Aplic> {
+    _marker: core::marker::PhantomData<A>,
+}
+
+impl<A: Aplic> APLIC<A> {
+    const DOMAINCFG_OFFSET: usize = 0x0000;
+    const SOURCECFG_OFFSET: usize = 0x0004;
+
+    const MMSIADDRCFG_OFFSET: usize = 0x1BC0;
+    const MMSIADDRCFGH_OFFSET: usize = 0x1BC4;
+    const SMSIADDRCFG_OFFSET: usize = 0x1BC8;
+    const SMSIADDRCFGH_OFFSET: usize = 0x1BCC;
+
+    const SETIP_OFFSET: usize = 0x1C00;
+    const SETIPNUM_OFFSET: usize = 0x1CDC;
+    const CLRIPNUM_OFFSET: usize = 0x1DDC;
+    const SETIE_OFFSET: usize = 0x1E00;
+    const SETIENUM_OFFSET: usize = 0x1EDC;
+    const CLRIENUM_OFFSET: usize = 0x1FDC;
+
+    const TARGET_OFFSET: usize = 0x3004;
+
+    const IDC_OFFSET: usize = 0x4000;
+    const IDC_SEPARATION: usize = 0x20;
+
+    /// Returns the configuration register of the interrupt domain.
+    #[inline]
+    pub const fn domaincfg() -> domaincfg::DOMAINCFG {
+        // SAFETY: valid address
+        unsafe { domaincfg::DOMAINCFG::new(A::BASE + Self::DOMAINCFG_OFFSET) }
+    }
+
+    /// Returns the configuration register of a given interrupt source.
+    #[inline]
+    pub fn sourcecfg<E: ExternalInterruptNumber>(source: E) -> sourcecfg::SOURCECFG {
+        let addr = A::BASE + Self::SOURCECFG_OFFSET + Self::source_offset(source);
+        // SAFETY: valid address
+        unsafe { sourcecfg::SOURCECFG::new(addr) }
+    }
+
+    /// Returns the target register of a given interrupt source.
+    #[inline]
+    pub fn target<E: ExternalInterruptNumber>(source: E) -> target::TARGET {
+        let addr = A::BASE + Self::TARGET_OFFSET + Self::source_offset(source);
+        // SAFETY: valid address
+        unsafe { target::TARGET::new(addr) }
+    }
+
+    /// Returns the interrupt delivery control structure of a given HART (direct delivery mode).
+    #[inline]
+    pub fn idc<H: HartIdNumber>(hart_id: H) -> idc::IDC {
+        let addr = A::BASE + Self::IDC_OFFSET + hart_id.number() as usize * Self::IDC_SEPARATION;
+        // SAFETY: valid address
+        unsafe { idc::IDC::new(addr) }
+    }
+
+    /// Checks if an interrupt source is pending.
+    #[inline]
+    pub fn is_pending<E: ExternalInterruptNumber>(source: E) -> bool {
+        let source = source.number() as usize;
+        let addr = A::BASE + Self::SETIP_OFFSET + (source / u32::BITS as usize) * 4;
+        // SAFETY: valid address
+        let reg: Reg<u32, RO> = unsafe { Reg::new(addr as _) };
+        reg.read_bit(source % u32::BITS as usize)
+    }
+
+    /// Sets the pending bit of an interrupt source.
+    ///
+    /// # Note
+    ///
+    /// The write is ignored if the source is inactive or, for level-sensitive sources, not detached.
+    #[inline]
+    pub fn pend<E: ExternalInterruptNumber>(source: E) {
+        Self::write_num(Self::SETIPNUM_OFFSET, source);
+    }
+
+    /// Clears the pending bit of an interrupt source.
+    #[inline]
+    pub fn unpend<E: ExternalInterruptNumber>(source: E) {
+        Self::write_num(Self::CLRIPNUM_OFFSET, source);
+    }
+
+    /// Checks if an interrupt source is enabled.
+    #[inline]
+    pub fn is_enabled<E: ExternalInterruptNumber>(source: E) -> bool {
+        let source = source.number() as usize;
+        let addr = A::BASE + Self::SETIE_OFFSET + (source / u32::BITS as usize) * 4;
+        // SAFETY: valid address
+        let reg: Reg<u32, RO> = unsafe { Reg::new(addr as _) };
+        reg.read_bit(source % u32::BITS as usize)
+    }
+
+    /// Enables an interrupt source.
+    ///
+    /// # Note
+    ///
+    /// Unlike the PLIC, the write is atomic, as it uses the `setienum` register.
+    ///
+    /// # Safety
+    ///
+    /// Enabling an interrupt source can break mask-based critical sections.
+    #[inline]
+    pub unsafe fn enable<E: ExternalInterruptNumber>(source: E) {
+        Self::write_num(Self::SETIENUM_OFFSET, source);
+    }
+
+    /// Disables an interrupt source.
+    #[inline]
+    pub fn disable<E: ExternalInterruptNumber>(source: E) {
+        Self::write_num(Self::CLRIENUM_OFFSET, source);
+    }
+
+    /// Configures the MSI target addresses of the interrupt domain to the interrupt files of the
+    /// IMSIC `I`, for the HARTs `H` (MSI delivery mode).
+    ///
+    /// The MSIs of machine-level domains are sent to the machine-level interrupt files, and the MSIs
+    /// of supervisor-level domains are sent to the supervisor-level or guest interrupt files.
+    ///
+    /// # Note
+    ///
+    /// Only the root interrupt domain has these registers, and they may be locked or hardwired.
+    /// The base addresses of `I` must be aligned to the size of their regions.
+    ///
+    /// # Safety
+    ///
+    /// Changing the MSI target addresses can break mask-based critical sections.
+    #[inline]
+    pub unsafe fn configure_msi<I: Imsic, H: HartIdNumber>() {
+        let (mcfg, mcfgh) = Self::msi_address_cfg(I::MACHINE_BASE, 0);
+        let (scfg, scfgh) = Self::msi_address_cfg(I::SUPERVISOR_BASE, I::GUEST_INDEX_BITS);
+        // hart index width (LHXW) of the single group
+        let lhxw = u16::BITS - H::MAX_HART_ID_NUMBER.leading_zeros();
+
+        Self::write(Self::MMSIADDRCFG_OFFSET, mcfg);
+        Self::write(Self::MMSIADDRCFGH_OFFSET, mcfgh | (lhxw << 12));
+        Self::write(Self::SMSIADDRCFG_OFFSET, scfg);
+        Self::write(Self::SMSIADDRCFGH_OFFSET, scfgh);
+    }
+
+    /// Returns the values of the `xmsiaddrcfg` and `xmsiaddrcfgh` registers for a base address
+    /// and the log2 of the number of pages per HART (LHXS).
+    #[inline]
+    const fn msi_address_cfg(base: usize, lhxs: usize) -> (u32, u32) {
+        let ppn = base as u64 >> 12;
+        (
+            ppn as u32,
+            ((ppn >> 32) as u32 & 0xFFF) | ((lhxs as u32 & 0x7) << 20),
+        )
+    }
+
+    /// Returns the offset of the register of a given interrupt source in a source array.
+    #[inline]
+    fn source_offset<E: ExternalInterruptNumber>(source: E) -> usize {
+        (source.number() as usize - 1) * 4
+    }
+
+    #[inline]
+    fn write_num<E: ExternalInterruptNumber>(offset: usize, source: E) {
+        Self::write(offset, source.number() as _);
+    }
+
+    #[inline]
+    fn write(offset: usize, value: u32) {
+        // SAFETY: valid address
+        let reg: Reg<u32, WO> = unsafe { Reg::new((A::BASE + offset) as _) };
+        reg.write(value);
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    pub(crate) use crate::plic::test::{Context, Interrupt, Priority};
+    use riscv_pac::InterruptNumber;
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    struct Root;
+
+    unsafe impl Aplic for Root {
+        const BASE: usize = 0x0C00_0000;
+    }
+
+    #[test]
+    fn check_aplic() {
+        type A = APLIC<Root>;
+
+        assert_eq!(A::domaincfg().get_ptr() as usize, 0x0C00_0000);
+
+        for i in 1..=Interrupt::MAX_INTERRUPT_NUMBER {
+            let source = Interrupt::from_number(i).unwrap();
+            let i = i as usize;
+
+            assert_eq!(
+                A::sourcecfg(source).get_ptr() as usize,
+                0x0C00_0000 + 0x0004 + (i - 1) * 4
+            );
+            assert_eq!(
+                A::target(source).get_ptr() as usize,
+                0x0C00_0000 + 0x3004 + (i - 1) * 4
+            );
+        }
+
+        for i in 0..=Context::MAX_HART_ID_NUMBER {
+            let hart_id = Context::from_number(i).unwrap();
+            let i = i as usize;
+            assert_eq!(A::idc(hart_id).address(), 0x0C00_0000 + 0x4000 + i * 0x20);
+        }
+    }
+
+    #[test]
+    fn check_msi_address_cfg() {
+        assert_eq!(
+            APLIC::<Root>::msi_address_cfg(0x2400_0000, 0),
+            (0x2_4000, 0)
+        );
+        assert_eq!(
+            APLIC::<Root>::msi_address_cfg(0x2800_0000, 2),
+            (0x2_8000, 2 << 20)
+        );
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(
+            APLIC::<Root>::msi_address_cfg(0x1000_2800_0000, 2),
+            (0x2_8000, 1 | (2 << 20))
+        );
+    }
+}
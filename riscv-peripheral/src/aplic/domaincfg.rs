@@ -0,0 +1,98 @@
+//! Domain configuration register.
+
+use crate::common::unsafe_peripheral;
+
+unsafe_peripheral!(DOMAINCFG, u32, RW);
+
+/// Delivery mode of an interrupt domain.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DeliveryMode {
+    /// Interrupts are delivered directly to the HARTs through the interrupt delivery controls.
+    Direct = 0,
+    /// Interrupts are forwarded as MSIs to the IMSIC interrupt files of the HARTs.
+    Msi = 1,
+}
+
+impl DOMAINCFG {
+    const IE: usize = 8;
+    const DM: usize = 2;
+    const BE: usize = 0;
+
+    /// Returns `true` if the interrupt domain signals interrupts to the HARTs.
+    #[inline]
+    pub fn is_enabled(self) -> bool {
+        self.register.read_bit(Self::IE)
+    }
+
+    /// Enables the signaling of interrupts from the interrupt domain to the HARTs.
+    ///
+    /// # Safety
+    ///
+    /// Enabling the interrupt domain can break mask-based critical sections.
+    #[inline]
+    pub unsafe fn enable(self) {
+        self.register.set_bit(Self::IE);
+    }
+
+    /// Disables the signaling of interrupts from the interrupt domain to the HARTs.
+    #[inline]
+    pub fn disable(self) {
+        self.register.clear_bit(Self::IE);
+    }
+
+    /// Returns the delivery mode of the interrupt domain.
+    #[inline]
+    pub fn delivery_mode(self) -> DeliveryMode {
+        match self.register.read_bit(Self::DM) {
+            false => DeliveryMode::Direct,
+            true => DeliveryMode::Msi,
+        }
+    }
+
+    /// Sets the delivery mode of the interrupt domain.
+    ///
+    /// # Note
+    ///
+    /// Implementations that only support one delivery mode ignore the write.
+    /// Check the delivery mode with [`DOMAINCFG::delivery_mode`] afterwards.
+    #[inline]
+    pub fn set_delivery_mode(self, mode: DeliveryMode) {
+        match mode {
+            DeliveryMode::Direct => self.register.clear_bit(Self::DM),
+            DeliveryMode::Msi => self.register.set_bit(Self::DM),
+        }
+    }
+
+    /// Returns `true` if the memory-mapped registers of the interrupt domain are big-endian.
+    #[inline]
+    pub fn is_big_endian(self) -> bool {
+        self.register.read_bit(Self::BE)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_domaincfg() {
+        let mut raw_reg = 0x8000_0000u32;
+        // SAFETY: valid memory address
+        let domaincfg = unsafe { DOMAINCFG::new(&mut raw_reg as *mut _ as _) };
+
+        assert!(!domaincfg.is_enabled());
+        unsafe { domaincfg.enable() };
+        assert!(domaincfg.is_enabled());
+        domaincfg.disable();
+        assert!(!domaincfg.is_enabled());
+
+        assert_eq!(domaincfg.delivery_mode(), DeliveryMode::Direct);
+        domaincfg.set_delivery_mode(DeliveryMode::Msi);
+        assert_eq!(domaincfg.delivery_mode(), DeliveryMode::Msi);
+        domaincfg.set_delivery_mode(DeliveryMode::Direct);
+        assert_eq!(domaincfg.delivery_mode(), DeliveryMode::Direct);
+
+        assert!(!domaincfg.is_big_endian());
+        assert_eq!(raw_reg, 0x8000_0000);
+    }
+}
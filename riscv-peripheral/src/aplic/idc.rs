@@ -0,0 +1,171 @@
+//! Interrupt delivery control (IDC) structure of a HART (direct delivery mode).
+
+use crate::{
+    aplic::{ExternalInterruptNumber, PriorityNumber},
+    common::{Reg, RO, RW},
+};
+
+/// Interrupt delivery control structure of a HART.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(transparent)]
+pub struct IDC {
+    ptr: *mut u32,
+}
+
+impl IDC {
+    const IDELIVERY_OFFSET: usize = 0;
+    const IFORCE_OFFSET: usize = 1;
+    const ITHRESHOLD_OFFSET: usize = 2;
+    const TOPI_OFFSET: usize = 6;
+    const CLAIMI_OFFSET: usize = 7;
+
+    /// Creates a new interrupt delivery control structure from a base address.
+    ///
+    /// # Safety
+    ///
+    /// The base address must point to a valid interrupt delivery control structure.
+    #[inline]
+    pub(crate) const unsafe fn new(address: usize) -> Self {
+        Self { ptr: address as _ }
+    }
+
+    #[cfg(test)]
+    #[inline]
+    pub(crate) fn address(self) -> usize {
+        self.ptr as _
+    }
+
+    #[inline]
+    fn reg(self, offset: usize) -> Reg<u32, RW> {
+        // SAFETY: valid register offset
+        unsafe { Reg::new(self.ptr.add(offset)) }
+    }
+
+    /// Returns `true` if the interrupts are delivered to the HART.
+    #[inline]
+    pub fn is_delivery_enabled(self) -> bool {
+        self.reg(Self::IDELIVERY_OFFSET).read() != 0
+    }
+
+    /// Enables the delivery of interrupts to the HART.
+    ///
+    /// # Safety
+    ///
+    /// Enabling the delivery of interrupts can break mask-based critical sections.
+    #[inline]
+    pub unsafe fn enable_delivery(self) {
+        self.reg(Self::IDELIVERY_OFFSET).write(1);
+    }
+
+    /// Disables the delivery of interrupts to the HART.
+    #[inline]
+    pub fn disable_delivery(self) {
+        self.reg(Self::IDELIVERY_OFFSET).write(0);
+    }
+
+    /// Forces a spurious external interrupt to the HART, e.g., for testing.
+    ///
+    /// When claimed, a forced interrupt has identity 0 (i.e., [`IDC::claim`] returns [`None`]).
+    #[inline]
+    pub fn force(self) {
+        self.reg(Self::IFORCE_OFFSET).write(1);
+    }
+
+    /// Returns the priority threshold level.
+    /// Only interrupts with a priority number lower than the threshold are delivered.
+    #[inline]
+    pub fn get_threshold<P: PriorityNumber>(self) -> P {
+        P::from_number(self.reg(Self::ITHRESHOLD_OFFSET).read() as _).unwrap()
+    }
+
+    /// Sets the priority threshold level.
+    ///
+    /// # Safety
+    ///
+    /// Changing the priority threshold can break priority-based critical sections.
+    #[inline]
+    pub unsafe fn set_threshold<P: PriorityNumber>(self, threshold: P) {
+        self.reg(Self::ITHRESHOLD_OFFSET)
+            .write(threshold.number() as _);
+    }
+
+    /// Resets the priority threshold level to 0.
+    ///
+    /// # Note
+    ///
+    /// Threshold 0 implies that all interrupts are accepted.
+    #[inline]
+    pub fn reset_threshold(self) {
+        self.reg(Self::ITHRESHOLD_OFFSET).write(0);
+    }
+
+    /// Returns the highest-priority pending and enabled interrupt for the HART, without claiming it.
+    /// If no interrupt is pending, it returns [`None`].
+    #[inline]
+    pub fn top<E: ExternalInterruptNumber>(self) -> Option<E> {
+        // SAFETY: valid register offset
+        let topi: Reg<u32, RO> = unsafe { Reg::new(self.ptr.add(Self::TOPI_OFFSET)) };
+        Self::identity(topi.read())
+    }
+
+    /// Claims the highest-priority pending and enabled interrupt for the HART.
+    /// If no interrupt is pending, it returns [`None`].
+    ///
+    /// # Note
+    ///
+    /// Claiming an interrupt clears its pending bit, except for level-sensitive sources that are
+    /// still asserted. Unlike the PLIC, there is no completion step: a claimed interrupt can be
+    /// signaled again as soon as its source is asserted again.
+    #[inline]
+    pub fn claim<E: ExternalInterruptNumber>(self) -> Option<E> {
+        // SAFETY: valid register offset
+        let claimi: Reg<u32, RO> = unsafe { Reg::new(self.ptr.add(Self::CLAIMI_OFFSET)) };
+        Self::identity(claimi.read())
+    }
+
+    #[inline]
+    fn identity<E: ExternalInterruptNumber>(bits: u32) -> Option<E> {
+        match (bits >> 16) & 0x3FF {
+            0 => None,
+            i => Some(E::from_number(i as _).unwrap()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test::{Interrupt, Priority};
+    use super::*;
+
+    #[test]
+    fn test_idc() {
+        let mut raw_reg = [0u32; 8];
+        // SAFETY: valid memory address
+        let idc = unsafe { IDC::new(raw_reg.as_mut_ptr() as _) };
+
+        assert!(!idc.is_delivery_enabled());
+        unsafe { idc.enable_delivery() };
+        assert!(idc.is_delivery_enabled());
+        idc.disable_delivery();
+        assert!(!idc.is_delivery_enabled());
+
+        idc.force();
+        assert_eq!(raw_reg[1], 1);
+
+        unsafe { idc.set_threshold(Priority::P2) };
+        assert_eq!(idc.get_threshold::<Priority>(), Priority::P2);
+        idc.reset_threshold();
+        assert_eq!(idc.get_threshold::<Priority>(), Priority::P0);
+
+        assert_eq!(idc.top::<Interrupt>(), None);
+        assert_eq!(idc.claim::<Interrupt>(), None);
+        // SAFETY: valid memory addresses
+        unsafe {
+            idc.ptr.add(6).write_volatile((3 << 16) | 1);
+            idc.ptr.add(7).write_volatile((3 << 16) | 1);
+        }
+        assert_eq!(idc.top(), Some(Interrupt::I3));
+        assert_eq!(idc.claim(), Some(Interrupt::I3));
+    }
+}
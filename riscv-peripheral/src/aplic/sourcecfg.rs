@@ -0,0 +1,134 @@
+//! Source configuration register.
+
+use crate::common::unsafe_peripheral;
+use riscv_pac::result::{Error, Result};
+
+unsafe_peripheral!(SOURCECFG, u32, RW);
+
+/// Source mode of an interrupt source that is not delegated.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SourceMode {
+    /// The source is inactive in this interrupt domain.
+    Inactive = 0,
+    /// The source is active, but its wire input is ignored. It can only be pended by software.
+    Detached = 1,
+    /// The source is active and asserted on rising edges.
+    Edge1 = 4,
+    /// The source is active and asserted on falling edges.
+    Edge0 = 5,
+    /// The source is active and asserted when high.
+    Level1 = 6,
+    /// The source is active and asserted when low.
+    Level0 = 7,
+}
+
+impl TryFrom<u32> for SourceMode {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(value: u32) -> Result<Self> {
+        match value {
+            0 => Ok(Self::Inactive),
+            1 => Ok(Self::Detached),
+            4 => Ok(Self::Edge1),
+            5 => Ok(Self::Edge0),
+            6 => Ok(Self::Level1),
+            7 => Ok(Self::Level0),
+            _ => Err(Error::InvalidVariant(value as _)),
+        }
+    }
+}
+
+impl SOURCECFG {
+    const D: usize = 10;
+    const CHILD_INDEX_MASK: u32 = 0x3FF;
+    const SM_MASK: u32 = 0x7;
+
+    /// Returns `true` if the source is delegated to a child interrupt domain.
+    #[inline]
+    pub fn is_delegated(self) -> bool {
+        self.register.read_bit(Self::D)
+    }
+
+    /// Returns the index of the child interrupt domain to which the source is delegated.
+    /// If the source is not delegated, it returns [`None`].
+    #[inline]
+    pub fn child_index(self) -> Option<u16> {
+        let bits = self.register.read();
+        match bits & (1 << Self::D) {
+            0 => None,
+            _ => Some((bits & Self::CHILD_INDEX_MASK) as _),
+        }
+    }
+
+    /// Delegates the source to the child interrupt domain `child_index`.
+    ///
+    /// # Note
+    ///
+    /// The source becomes inactive in this interrupt domain.
+    /// If the child interrupt domain does not exist, the write is ignored.
+    #[inline]
+    pub fn delegate(self, child_index: u16) {
+        let child_index = child_index as u32 & Self::CHILD_INDEX_MASK;
+        self.register.write((1 << Self::D) | child_index);
+    }
+
+    /// Returns the source mode of the source. If the source is delegated, it returns [`None`].
+    #[inline]
+    pub fn source_mode(self) -> Option<SourceMode> {
+        let bits = self.register.read();
+        match bits & (1 << Self::D) {
+            0 => Some(SourceMode::try_from(bits & Self::SM_MASK).unwrap()),
+            _ => None,
+        }
+    }
+
+    /// Sets the source mode of the source. If the source was delegated, it is undelegated.
+    ///
+    /// # Note
+    ///
+    /// Implementations may not support all the source modes for all the sources.
+    /// Unsupported source modes are written as [`SourceMode::Inactive`].
+    #[inline]
+    pub fn set_source_mode(self, mode: SourceMode) {
+        self.register.write(mode as _);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sourcecfg() {
+        let mut raw_reg = 0u32;
+        // SAFETY: valid memory address
+        let sourcecfg = unsafe { SOURCECFG::new(&mut raw_reg as *mut _ as _) };
+
+        assert!(!sourcecfg.is_delegated());
+        assert_eq!(sourcecfg.child_index(), None);
+        assert_eq!(sourcecfg.source_mode(), Some(SourceMode::Inactive));
+
+        for mode in [
+            SourceMode::Detached,
+            SourceMode::Edge1,
+            SourceMode::Edge0,
+            SourceMode::Level1,
+            SourceMode::Level0,
+        ] {
+            sourcecfg.set_source_mode(mode);
+            assert_eq!(sourcecfg.source_mode(), Some(mode));
+        }
+
+        sourcecfg.delegate(3);
+        assert!(sourcecfg.is_delegated());
+        assert_eq!(sourcecfg.child_index(), Some(3));
+        assert_eq!(sourcecfg.source_mode(), None);
+        assert_eq!(raw_reg, 0x403);
+
+        sourcecfg.set_source_mode(SourceMode::Level1);
+        assert!(!sourcecfg.is_delegated());
+
+        assert_eq!(SourceMode::try_from(2), Err(Error::InvalidVariant(2)));
+    }
+}
@@ -0,0 +1,114 @@
+//! Target register of an interrupt source.
+
+use crate::{
+    aplic::{ExternalInterruptNumber, HartIdNumber, PriorityNumber},
+    common::unsafe_peripheral,
+};
+
+unsafe_peripheral!(TARGET, u32, RW);
+
+impl TARGET {
+    const HART_INDEX: usize = 18;
+    const GUEST_INDEX: usize = 12;
+    const GUEST_INDEX_MASK: u32 = 0x3F;
+    const IPRIO_MASK: u32 = 0xFF;
+    const EIID_MASK: u32 = 0x7FF;
+
+    /// Returns the HART to which the interrupts of the source are routed.
+    #[inline]
+    pub fn hart<H: HartIdNumber>(self) -> H {
+        H::from_number((self.register.read() >> Self::HART_INDEX) as _).unwrap()
+    }
+
+    /// Returns the priority of the source (direct delivery mode).
+    ///
+    /// # Note
+    ///
+    /// The value of this field is only meaningful in direct delivery mode.
+    #[inline]
+    pub fn priority<P: PriorityNumber>(self) -> P {
+        P::from_number((self.register.read() & Self::IPRIO_MASK) as _).unwrap()
+    }
+
+    /// Routes the interrupts of the source to the HART `hart_id` with the priority `priority`
+    /// (direct delivery mode).
+    ///
+    /// # Note
+    ///
+    /// Priority 0 is not valid in the APLIC, and it is written as priority 1.
+    ///
+    /// # Safety
+    ///
+    /// Changing the target and priority of a source can break priority-based critical sections.
+    #[inline]
+    pub unsafe fn set_direct<H: HartIdNumber, P: PriorityNumber>(self, hart_id: H, priority: P) {
+        let priority = (priority.number() as u32).max(1);
+        self.register
+            .write(((hart_id.number() as u32) << Self::HART_INDEX) | priority);
+    }
+
+    /// Returns the guest index of the interrupt file to which the MSIs of the source are sent
+    /// (MSI delivery mode). Guest index 0 selects the machine-level or supervisor-level interrupt file.
+    #[inline]
+    pub fn guest_index(self) -> usize {
+        ((self.register.read() >> Self::GUEST_INDEX) & Self::GUEST_INDEX_MASK) as _
+    }
+
+    /// Returns the external interrupt identity of the MSIs of the source (MSI delivery mode).
+    /// If the identity is 0, it returns [`None`].
+    #[inline]
+    pub fn eiid<E: ExternalInterruptNumber>(self) -> Option<E> {
+        match self.register.read() & Self::EIID_MASK {
+            0 => None,
+            i => Some(E::from_number(i as _).unwrap()),
+        }
+    }
+
+    /// Routes the interrupts of the source as MSIs with the identity `eiid` to the interrupt file
+    /// `guest_index` of the HART `hart_id` (MSI delivery mode).
+    ///
+    /// # Safety
+    ///
+    /// Changing the target of a source can break mask-based critical sections.
+    #[inline]
+    pub unsafe fn set_msi<H: HartIdNumber, E: ExternalInterruptNumber>(
+        self,
+        hart_id: H,
+        guest_index: usize,
+        eiid: E,
+    ) {
+        let guest_index = guest_index as u32 & Self::GUEST_INDEX_MASK;
+        self.register.write(
+            ((hart_id.number() as u32) << Self::HART_INDEX)
+                | (guest_index << Self::GUEST_INDEX)
+                | eiid.number() as u32,
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test::{Context, Interrupt, Priority};
+    use super::*;
+
+    #[test]
+    fn test_target() {
+        let mut raw_reg = 0u32;
+        // SAFETY: valid memory address
+        let target = unsafe { TARGET::new(&mut raw_reg as *mut _ as _) };
+
+        unsafe { target.set_direct(Context::C2, Priority::P3) };
+        assert_eq!(target.hart::<Context>(), Context::C2);
+        assert_eq!(target.priority::<Priority>(), Priority::P3);
+        assert_eq!(raw_reg, (2 << 18) | 3);
+
+        unsafe { target.set_direct(Context::C1, Priority::P0) };
+        assert_eq!(target.priority::<Priority>(), Priority::P1);
+
+        unsafe { target.set_msi(Context::C1, 5, Interrupt::I4) };
+        assert_eq!(target.hart::<Context>(), Context::C1);
+        assert_eq!(target.guest_index(), 5);
+        assert_eq!(target.eiid::<Interrupt>(), Some(Interrupt::I4));
+        assert_eq!(raw_reg, (1 << 18) | (5 << 12) | 4);
+    }
+}
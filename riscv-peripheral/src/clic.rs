@@ -0,0 +1,159 @@
+//! Core-Local Interrupt Controller (CLIC) peripheral.
+//!
+//! Specification: <https://github.com/riscv/riscv-fast-interrupt>
+//!
+//! The CLIC replaces the CLINT interrupt scheme of a HART (see [`TrapMode::Clic`]).
+//! Each interrupt has its own control registers (see [`clicint`]) to set its trigger type,
+//! its level and priority, and whether it uses hardware vectoring.
+//! The number of bits of `clicintctl` used to encode the level is configured in [`cliccfg`].
+//!
+//! Hardware-vectored interrupts jump directly to their handler in the vector table pointed by
+//! `mtvt` (see [`vector`]). Use [`CLIC::set_dispatch`] to update the vector table entry and the
+//! selective hardware vectoring bit of an interrupt together.
+//!
+//! [`TrapMode::Clic`]: riscv::register::mtvec::TrapMode::Clic
+
+pub mod cliccfg;
+pub mod clicint;
+pub mod vector;
+
+use vector::{Dispatch, VectorTable};
+
+// re-export useful riscv-pac traits
+pub use riscv_pac::{CoreInterruptNumber, PriorityNumber};
+
+/// Trait for a CLIC peripheral.
+///
+/// # Safety
+///
+/// * This trait must only be implemented on a PAC of a target with a CLIC peripheral.
+/// * The CLIC peripheral base address `BASE` must be valid for the target device.
+pub unsafe trait Clic: Copy {
+    /// Base address of the CLIC peripheral.
+    const BASE: usize;
+}
+
+/// Core-Local Interrupt Controller (CLIC) peripheral.
+///
+/// The RISC-V standard does not specify a fixed location for the CLIC.
+/// Thus, each platform must specify the base address of the CLIC on the platform.
+/// The base address, as well as all the associated types, are defined in the [`Clic`] trait.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CLIC<C: Clic> {
+    _marker: core::marker::PhantomData<C>,
+}
+
+impl<C: Clic> CLIC<C> {
+    const CLICCFG_OFFSET: usize = 0x0000;
+    const CLICINFO_OFFSET: usize = 0x0004;
+    const CLICINT_OFFSET: usize = 0x1000;
+    const CLICINT_SEPARATION: usize = 0x4;
+
+    /// Returns the configuration register of the CLIC.
+    #[inline]
+    pub const fn cliccfg() -> cliccfg::CLICCFG {
+        // SAFETY: valid address
+        unsafe { cliccfg::CLICCFG::new(C::BASE + Self::CLICCFG_OFFSET) }
+    }
+
+    /// Returns the information register of the CLIC.
+    #[inline]
+    pub const fn clicinfo() -> cliccfg::CLICINFO {
+        // SAFETY: valid address
+        unsafe { cliccfg::CLICINFO::new(C::BASE + Self::CLICINFO_OFFSET) }
+    }
+
+    /// Returns the control registers of a given interrupt.
+    ///
+    /// # Note
+    ///
+    /// The level/priority split is read from `cliccfg` and `clicinfo` when the proxy is created.
+    /// Create a new proxy after changing the number of level bits with [`cliccfg::CLICCFG::set_nlbits`].
+    #[inline]
+    pub fn clicint<I: CoreInterruptNumber>(interrupt: I) -> clicint::CLICINT {
+        let addr = Self::clicint_address(interrupt);
+        let nlbits = Self::cliccfg().nlbits();
+        let ctlbits = Self::clicinfo().ctlbits();
+        // SAFETY: valid address
+        unsafe { clicint::CLICINT::new(addr, nlbits, ctlbits) }
+    }
+
+    /// Returns the dispatch mode of a given interrupt, as described by its selective hardware
+    /// vectoring bit and its entry in `table`.
+    ///
+    /// Returns `None` if the interrupt is hardware-vectored but has no handler in `table`,
+    /// e.g., if `table` is not the vector table of the CLIC.
+    #[inline]
+    pub fn dispatch<I: CoreInterruptNumber, const N: usize>(
+        interrupt: I,
+        table: &VectorTable<N>,
+    ) -> Option<Dispatch> {
+        table.dispatch(Self::clicint(interrupt), interrupt.number() as _)
+    }
+
+    /// Sets the dispatch mode of a given interrupt.
+    ///
+    /// For [`Dispatch::Vectored`], the handler is written to the entry of the interrupt in `table`
+    /// before selecting hardware vectoring. For [`Dispatch::Trampoline`], hardware vectoring is
+    /// deselected before clearing the entry. Thus, a hardware-vectored interrupt never jumps to
+    /// an empty entry.
+    ///
+    /// # Note
+    ///
+    /// Implementations may hardwire the selective hardware vectoring bit of some interrupts.
+    /// Check the dispatch mode with [`CLIC::dispatch`] afterwards.
+    ///
+    /// **WARNING**: panics if the interrupt number is out of the bounds of `table`.
+    ///
+    /// # Safety
+    ///
+    /// `table` must be the vector table pointed by `mtvt` (see [`VectorTable::mtvt`]), and
+    /// the handler must be a valid interrupt handler, i.e., it must save and restore the context.
+    #[inline]
+    pub unsafe fn set_dispatch<I: CoreInterruptNumber, const N: usize>(
+        interrupt: I,
+        table: &mut VectorTable<N>,
+        dispatch: Dispatch,
+    ) {
+        table.set_dispatch(Self::clicint(interrupt), interrupt.number() as _, dispatch);
+    }
+
+    /// Returns the address of the control registers of a given interrupt.
+    #[inline]
+    fn clicint_address<I: CoreInterruptNumber>(interrupt: I) -> usize {
+        C::BASE + Self::CLICINT_OFFSET + interrupt.number() as usize * Self::CLICINT_SEPARATION
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    pub(crate) use crate::plic::test::{Interrupt, Priority};
+    use riscv_pac::InterruptNumber;
+
+    unsafe impl CoreInterruptNumber for Interrupt {}
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    struct Clic;
+
+    unsafe impl super::Clic for Clic {
+        const BASE: usize = 0x0080_0000;
+    }
+
+    #[test]
+    fn check_clic() {
+        type C = CLIC<Clic>;
+
+        assert_eq!(C::cliccfg().get_ptr() as usize, 0x0080_0000);
+        assert_eq!(C::clicinfo().get_ptr() as usize, 0x0080_0004);
+
+        for i in 1..=Interrupt::MAX_INTERRUPT_NUMBER {
+            let interrupt = Interrupt::from_number(i).unwrap();
+            assert_eq!(
+                C::clicint_address(interrupt),
+                0x0080_0000 + 0x1000 + i as usize * 4
+            );
+        }
+    }
+}
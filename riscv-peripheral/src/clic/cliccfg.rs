@@ -0,0 +1,89 @@
+//! CLIC configuration and information registers.
+
+use crate::common::unsafe_peripheral;
+
+unsafe_peripheral!(CLICCFG, u32, RW);
+
+impl CLICCFG {
+    /// Returns the number of bits of `clicintctl` that encode the interrupt level.
+    /// The remaining implemented bits encode the interrupt priority.
+    #[inline]
+    pub fn nlbits(self) -> u8 {
+        self.register.read_bits(0, 3) as _
+    }
+
+    /// Sets the number of bits of `clicintctl` that encode the interrupt level (`0..=8`).
+    ///
+    /// # Note
+    ///
+    /// Values greater than 8 are written as 8.
+    ///
+    /// # Safety
+    ///
+    /// Changing the split between levels and priorities changes the level of all the interrupts,
+    /// which can break level-based critical sections.
+    #[inline]
+    pub unsafe fn set_nlbits(self, nlbits: u8) {
+        self.register.write_bits(0, 3, nlbits.min(8) as _);
+    }
+}
+
+unsafe_peripheral!(CLICINFO, u32, RO);
+
+impl CLICINFO {
+    /// Returns the number of interrupts supported by the CLIC.
+    #[inline]
+    pub fn num_interrupt(self) -> u16 {
+        self.register.read_bits(0, 12) as _
+    }
+
+    /// Returns the implementation version of the CLIC.
+    #[inline]
+    pub fn version(self) -> u8 {
+        self.register.read_bits(13, 20) as _
+    }
+
+    /// Returns the number of implemented bits of the `clicintctl` registers (`CLICINTCTLBITS`).
+    #[inline]
+    pub fn ctlbits(self) -> u8 {
+        self.register.read_bits(21, 24) as _
+    }
+
+    /// Returns the number of interrupt triggers supported by the CLIC.
+    #[inline]
+    pub fn num_trigger(self) -> u8 {
+        self.register.read_bits(25, 30) as _
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cliccfg() {
+        let mut raw_reg = 0u32;
+        // SAFETY: valid memory address
+        let cliccfg = unsafe { CLICCFG::new(&mut raw_reg as *mut _ as _) };
+
+        assert_eq!(cliccfg.nlbits(), 0);
+        for nlbits in 0..=8 {
+            unsafe { cliccfg.set_nlbits(nlbits) };
+            assert_eq!(cliccfg.nlbits(), nlbits);
+        }
+        unsafe { cliccfg.set_nlbits(15) };
+        assert_eq!(cliccfg.nlbits(), 8);
+    }
+
+    #[test]
+    fn test_clicinfo() {
+        let mut raw_reg = (2 << 25) | (4 << 21) | (0x11 << 13) | 64u32;
+        // SAFETY: valid memory address
+        let clicinfo = unsafe { CLICINFO::new(&mut raw_reg as *mut _ as _) };
+
+        assert_eq!(clicinfo.num_interrupt(), 64);
+        assert_eq!(clicinfo.version(), 0x11);
+        assert_eq!(clicinfo.ctlbits(), 4);
+        assert_eq!(clicinfo.num_trigger(), 2);
+    }
+}
@@ -0,0 +1,321 @@
+//! Control registers of a CLIC interrupt.
+
+use crate::{
+    clic::PriorityNumber,
+    common::{Reg, RW},
+};
+
+/// Trigger type of an interrupt.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Trigger {
+    /// Level-triggered, active high
+    LevelHigh = 0b00,
+    /// Edge-triggered, on rising edges
+    EdgeRising = 0b01,
+    /// Level-triggered, active low
+    LevelLow = 0b10,
+    /// Edge-triggered, on falling edges
+    EdgeFalling = 0b11,
+}
+
+/// Control registers (`clicintip`, `clicintie`, `clicintattr`, and `clicintctl`) of an interrupt.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CLICINT {
+    ptr: *mut u8,
+    nlbits: u8,
+    ctlbits: u8,
+}
+
+impl CLICINT {
+    const IP_OFFSET: usize = 0;
+    const IE_OFFSET: usize = 1;
+    const ATTR_OFFSET: usize = 2;
+    const CTL_OFFSET: usize = 3;
+
+    const ATTR_SHV: usize = 0;
+    const ATTR_TRIG: usize = 1;
+
+    /// Creates a new interrupt proxy from the address of its `clicintip` register.
+    ///
+    /// `nlbits` is the number of level bits of `cliccfg`, and `ctlbits` is the number of
+    /// implemented bits of `clicintctl` (`CLICINTCTLBITS`).
+    ///
+    /// # Safety
+    ///
+    /// The address must point to the control registers of a valid interrupt.
+    #[inline]
+    pub(crate) const unsafe fn new(address: usize, nlbits: u8, ctlbits: u8) -> Self {
+        Self {
+            ptr: address as _,
+            nlbits,
+            ctlbits,
+        }
+    }
+
+    #[inline]
+    fn reg(self, offset: usize) -> Reg<u8, RW> {
+        // SAFETY: valid register offset
+        unsafe { Reg::new(self.ptr.add(offset)) }
+    }
+
+    /// Checks if the interrupt is pending.
+    #[inline]
+    pub fn is_pending(self) -> bool {
+        self.reg(Self::IP_OFFSET).read_bit(0)
+    }
+
+    /// Sets the pending bit of the interrupt.
+    ///
+    /// # Note
+    ///
+    /// The write is ignored for level-triggered interrupts.
+    #[inline]
+    pub fn pend(self) {
+        self.reg(Self::IP_OFFSET).write(1);
+    }
+
+    /// Clears the pending bit of the interrupt.
+    ///
+    /// # Note
+    ///
+    /// The write is ignored for level-triggered interrupts.
+    #[inline]
+    pub fn unpend(self) {
+        self.reg(Self::IP_OFFSET).write(0);
+    }
+
+    /// Checks if the interrupt is enabled.
+    #[inline]
+    pub fn is_enabled(self) -> bool {
+        self.reg(Self::IE_OFFSET).read_bit(0)
+    }
+
+    /// Enables the interrupt.
+    ///
+    /// # Safety
+    ///
+    /// Enabling an interrupt can break mask-based critical sections.
+    #[inline]
+    pub unsafe fn enable(self) {
+        self.reg(Self::IE_OFFSET).write(1);
+    }
+
+    /// Disables the interrupt.
+    #[inline]
+    pub fn disable(self) {
+        self.reg(Self::IE_OFFSET).write(0);
+    }
+
+    /// Returns the trigger type of the interrupt.
+    #[inline]
+    pub fn trigger(self) -> Trigger {
+        match self
+            .reg(Self::ATTR_OFFSET)
+            .read_bits(Self::ATTR_TRIG, Self::ATTR_TRIG + 1)
+        {
+            0b00 => Trigger::LevelHigh,
+            0b01 => Trigger::EdgeRising,
+            0b10 => Trigger::LevelLow,
+            _ => Trigger::EdgeFalling,
+        }
+    }
+
+    /// Sets the trigger type of the interrupt.
+    ///
+    /// # Note
+    ///
+    /// Implementations may hardwire the trigger type of some interrupts.
+    /// Check the trigger type with [`CLICINT::trigger`] afterwards.
+    #[inline]
+    pub fn set_trigger(self, trigger: Trigger) {
+        self.reg(Self::ATTR_OFFSET)
+            .write_bits(Self::ATTR_TRIG, Self::ATTR_TRIG + 1, trigger as _);
+    }
+
+    /// Checks if the interrupt uses selective hardware vectoring, i.e., if the hart jumps
+    /// directly to the handler address of the interrupt in the `mtvt` table.
+    #[inline]
+    pub fn is_vectored(self) -> bool {
+        self.reg(Self::ATTR_OFFSET).read_bit(Self::ATTR_SHV)
+    }
+
+    /// Selects hardware vectoring (`true`) or the common trap handler of `mtvec` (`false`)
+    /// for the interrupt.
+    ///
+    /// # Safety
+    ///
+    /// If hardware vectoring is selected, the entry of the interrupt in the `mtvt` table must hold
+    /// the address of a valid interrupt handler.
+    #[inline]
+    pub unsafe fn set_vectored(self, vectored: bool) {
+        let attr = self.reg(Self::ATTR_OFFSET);
+        match vectored {
+            true => attr.set_bit(Self::ATTR_SHV),
+            false => attr.clear_bit(Self::ATTR_SHV),
+        }
+    }
+
+    /// Returns the raw value of the `clicintctl` register of the interrupt.
+    #[inline]
+    pub fn ctl(self) -> u8 {
+        self.reg(Self::CTL_OFFSET).read()
+    }
+
+    /// Returns the interrupt level of the interrupt.
+    ///
+    /// Levels are left-justified, i.e., the level bits are followed by 1s to form an 8-bit level.
+    #[inline]
+    pub fn level(self) -> u8 {
+        decode_level(self.ctl(), self.nlbits)
+    }
+
+    /// Sets the interrupt level of the interrupt. Only the upper bits of `level` are used.
+    ///
+    /// # Safety
+    ///
+    /// Changing the level of an interrupt can break level-based critical sections.
+    #[inline]
+    pub unsafe fn set_level(self, level: u8) {
+        let ctl = self.ctl();
+        let priority = decode_priority(ctl, self.nlbits, self.ctlbits);
+        self.reg(Self::CTL_OFFSET)
+            .write(encode_ctl(level, priority, self.nlbits, self.ctlbits));
+    }
+
+    /// Returns the priority of the interrupt within its level.
+    #[inline]
+    pub fn priority<P: PriorityNumber>(self) -> P {
+        P::from_number(decode_priority(self.ctl(), self.nlbits, self.ctlbits)).unwrap()
+    }
+
+    /// Sets the priority of the interrupt within its level.
+    ///
+    /// # Note
+    ///
+    /// Only the priority bits implemented in `clicintctl` are written.
+    ///
+    /// # Safety
+    ///
+    /// Changing the priority of an interrupt can break priority-based critical sections.
+    #[inline]
+    pub unsafe fn set_priority<P: PriorityNumber>(self, priority: P) {
+        let level = self.level();
+        self.reg(Self::CTL_OFFSET).write(encode_ctl(
+            level,
+            priority.number(),
+            self.nlbits,
+            self.ctlbits,
+        ));
+    }
+}
+
+/// Returns the mask of the upper `bits` bits of a `clicintctl` value.
+#[inline]
+const fn upper_mask(bits: u8) -> u8 {
+    match bits {
+        0 => 0,
+        1..=7 => !(0xFF >> bits),
+        _ => 0xFF,
+    }
+}
+
+/// Returns the left-justified level encoded in `ctl`.
+#[inline]
+const fn decode_level(ctl: u8, nlbits: u8) -> u8 {
+    (ctl & upper_mask(nlbits)) | !upper_mask(nlbits)
+}
+
+/// Returns the priority encoded in `ctl`.
+#[inline]
+const fn decode_priority(ctl: u8, nlbits: u8, ctlbits: u8) -> u8 {
+    if ctlbits <= nlbits {
+        return 0;
+    }
+    let ctlbits = if ctlbits > 8 { 8 } else { ctlbits };
+    (ctl & upper_mask(ctlbits) & !upper_mask(nlbits)) >> (8 - ctlbits)
+}
+
+/// Returns the `clicintctl` value for a level and a priority.
+/// Unimplemented bits are set to 1.
+#[inline]
+const fn encode_ctl(level: u8, priority: u8, nlbits: u8, ctlbits: u8) -> u8 {
+    let ctlbits = if ctlbits > 8 { 8 } else { ctlbits };
+    let priority = if ctlbits <= nlbits {
+        0
+    } else {
+        (priority << (8 - ctlbits)) & upper_mask(ctlbits) & !upper_mask(nlbits)
+    };
+    (level & upper_mask(nlbits)) | priority | !upper_mask(ctlbits)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test::Priority;
+    use super::*;
+    use riscv_pac::PriorityNumber;
+
+    #[test]
+    fn test_ctl() {
+        // 4 implemented bits: 2 level bits and 2 priority bits
+        assert_eq!(encode_ctl(0xC0, 2, 2, 4), 0b1110_1111);
+        assert_eq!(decode_level(0b1110_1111, 2), 0xFF);
+        assert_eq!(decode_level(0b0110_1111, 2), 0x7F);
+        assert_eq!(decode_priority(0b1110_1111, 2, 4), 2);
+
+        // all the implemented bits are level bits
+        assert_eq!(encode_ctl(0x80, 3, 8, 4), 0b1000_0000 | 0x0F);
+        assert_eq!(decode_priority(0x8F, 8, 4), 0);
+        assert_eq!(decode_level(0x8F, 8), 0x8F);
+
+        // no level bits
+        assert_eq!(decode_level(0x00, 0), 0xFF);
+        assert_eq!(encode_ctl(0x00, 3, 0, 2), 0b1111_1111);
+        assert_eq!(decode_priority(0b1111_1111, 0, 2), 3);
+    }
+
+    #[test]
+    fn test_clicint() {
+        let mut raw_reg = [0u8; 4];
+        // SAFETY: valid memory address
+        let clicint = unsafe { CLICINT::new(raw_reg.as_mut_ptr() as _, 2, 4) };
+
+        assert!(!clicint.is_pending());
+        clicint.pend();
+        assert!(clicint.is_pending());
+        clicint.unpend();
+        assert!(!clicint.is_pending());
+
+        assert!(!clicint.is_enabled());
+        unsafe { clicint.enable() };
+        assert!(clicint.is_enabled());
+        clicint.disable();
+        assert!(!clicint.is_enabled());
+
+        for trigger in [
+            Trigger::EdgeRising,
+            Trigger::LevelLow,
+            Trigger::EdgeFalling,
+            Trigger::LevelHigh,
+        ] {
+            clicint.set_trigger(trigger);
+            assert_eq!(clicint.trigger(), trigger);
+        }
+
+        assert!(!clicint.is_vectored());
+        unsafe { clicint.set_vectored(true) };
+        assert!(clicint.is_vectored());
+        assert_eq!(clicint.trigger(), Trigger::LevelHigh);
+        unsafe { clicint.set_vectored(false) };
+        assert!(!clicint.is_vectored());
+
+        unsafe { clicint.set_level(0x40) };
+        assert_eq!(clicint.level(), 0x7F);
+        for i in 0..=Priority::MAX_PRIORITY_NUMBER {
+            let priority = Priority::from_number(i).unwrap();
+            unsafe { clicint.set_priority(priority) };
+            assert_eq!(clicint.priority::<Priority>(), priority);
+            assert_eq!(clicint.level(), 0x7F);
+        }
+    }
+}
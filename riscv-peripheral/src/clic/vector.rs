@@ -0,0 +1,173 @@
+//! Interrupt vector table of the CLIC (`mtvt`).
+
+use super::clicint::CLICINT;
+use riscv::register::mtvt::Mtvt;
+
+/// Interrupt handler of a hardware-vectored interrupt.
+pub type Handler = unsafe extern "C" fn();
+
+/// Dispatch mode of an interrupt.
+#[derive(Clone, Copy, Debug)]
+pub enum Dispatch {
+    /// The HART jumps directly to the handler of the interrupt in the vector table.
+    Vectored(Handler),
+    /// The HART jumps to the common trap handler of `mtvec`, which dispatches the interrupt.
+    Trampoline,
+}
+
+/// Interrupt vector table pointed by `mtvt`, with `N` entries.
+///
+/// The entries are only updated through [`CLIC::set_dispatch`](super::CLIC::set_dispatch),
+/// which also updates the selective hardware vectoring bit of the interrupt.
+/// Thus, the entry of every hardware-vectored interrupt always holds its handler.
+///
+/// # Note
+///
+/// The table is 64-byte aligned. Some implementations require a greater alignment,
+/// depending on the number of interrupts.
+#[derive(Clone, Copy, Debug)]
+#[repr(C, align(64))]
+pub struct VectorTable<const N: usize> {
+    handlers: [Option<Handler>; N],
+}
+
+impl<const N: usize> VectorTable<N> {
+    /// Creates a new vector table without handlers.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            handlers: [None; N],
+        }
+    }
+
+    /// Returns the handler of the `n`th entry of the table.
+    #[inline]
+    pub fn handler(&self, n: usize) -> Option<Handler> {
+        self.handlers.get(n).copied().flatten()
+    }
+
+    /// Returns the [`Mtvt`] value that points to the table.
+    #[inline]
+    pub fn mtvt(&self) -> Mtvt {
+        // the table is 64-byte aligned
+        Mtvt::new(self.handlers.as_ptr() as usize).unwrap()
+    }
+
+    /// Returns the dispatch mode of the `n`th interrupt, with control registers `clicint`.
+    #[inline]
+    pub(crate) fn dispatch(&self, clicint: CLICINT, n: usize) -> Option<Dispatch> {
+        match clicint.is_vectored() {
+            true => self.handler(n).map(Dispatch::Vectored),
+            false => Some(Dispatch::Trampoline),
+        }
+    }
+
+    /// Sets the dispatch mode of the `n`th interrupt, with control registers `clicint`.
+    ///
+    /// **WARNING**: panics if `n` is out of bounds.
+    ///
+    /// # Safety
+    ///
+    /// See [`CLIC::set_dispatch`](super::CLIC::set_dispatch).
+    #[inline]
+    pub(crate) unsafe fn set_dispatch(&mut self, clicint: CLICINT, n: usize, dispatch: Dispatch) {
+        // the entry must be valid while the interrupt is hardware-vectored
+        match dispatch {
+            Dispatch::Vectored(handler) => {
+                self.set_handler(n, Some(handler));
+                clicint.set_vectored(true);
+            }
+            Dispatch::Trampoline => {
+                assert!(n < N);
+                clicint.set_vectored(false);
+                self.set_handler(n, None);
+            }
+        }
+    }
+
+    /// Sets the handler of the `n`th entry of the table.
+    ///
+    /// **WARNING**: panics if `n` is out of bounds.
+    #[inline]
+    fn set_handler(&mut self, n: usize, handler: Option<Handler>) {
+        // SAFETY: valid pointer. The HART may read the entry at any time
+        unsafe { core::ptr::write_volatile(&mut self.handlers[n], handler) };
+    }
+}
+
+impl<const N: usize> Default for VectorTable<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    unsafe extern "C" fn handler() {}
+
+    fn address(handler: Option<Handler>) -> Option<usize> {
+        handler.map(|h| h as usize)
+    }
+
+    #[test]
+    fn test_vector_table() {
+        let mut table = VectorTable::<4>::new();
+
+        assert_eq!(table.mtvt().address(), &table as *const _ as usize);
+        assert_eq!(table.mtvt().address() % 64, 0);
+
+        for n in 0..4 {
+            assert!(table.handler(n).is_none());
+            table.set_handler(n, Some(handler));
+            assert_eq!(address(table.handler(n)), Some(handler as Handler as usize));
+            table.set_handler(n, None);
+            assert!(table.handler(n).is_none());
+        }
+        assert!(table.handler(4).is_none());
+    }
+
+    #[test]
+    fn test_dispatch() {
+        let mut table = VectorTable::<4>::new();
+        let mut raw_reg = [0u8; 4];
+        // SAFETY: valid memory address
+        let clicint = unsafe { CLICINT::new(raw_reg.as_mut_ptr() as _, 0, 0) };
+
+        assert!(matches!(
+            table.dispatch(clicint, 2),
+            Some(Dispatch::Trampoline)
+        ));
+
+        unsafe { table.set_dispatch(clicint, 2, Dispatch::Vectored(handler)) };
+        assert!(clicint.is_vectored());
+        assert_eq!(address(table.handler(2)), Some(handler as Handler as usize));
+        assert!(matches!(
+            table.dispatch(clicint, 2),
+            Some(Dispatch::Vectored(h)) if h as usize == handler as Handler as usize
+        ));
+        // the entry of another interrupt is empty
+        assert!(table.dispatch(clicint, 1).is_none());
+
+        unsafe { table.set_dispatch(clicint, 2, Dispatch::Trampoline) };
+        assert!(!clicint.is_vectored());
+        assert!(table.handler(2).is_none());
+        assert!(matches!(
+            table.dispatch(clicint, 2),
+            Some(Dispatch::Trampoline)
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dispatch_out_of_bounds() {
+        let mut table = VectorTable::<4>::new();
+        let mut raw_reg = [0u8; 4];
+        // SAFETY: valid memory address
+        let clicint = unsafe { CLICINT::new(raw_reg.as_mut_ptr() as _, 0, 0) };
+
+        unsafe { table.set_dispatch(clicint, 4, Dispatch::Vectored(handler)) };
+    }
+}
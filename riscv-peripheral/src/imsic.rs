@@ -0,0 +1,409 @@
+//! Incoming Message-Signaled Interrupt Controller (IMSIC) peripheral.
+//!
+//! Specification: <https://github.com/riscv/riscv-aia>
+//!
+//! Each HART has a machine-level interrupt file, a supervisor-level interrupt file, and optionally
+//! a number of guest interrupt files for virtual machines. Each interrupt file has:
+//!
+//! - A memory-mapped page ([`FILE`]) to which devices and other HARTs write MSIs.
+//! - A set of registers accessed by its own HART through the AIA indirect CSRs ([`LOCAL`]),
+//!   to enable the delivery of interrupts, enable interrupt identities, and claim interrupts.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use riscv_peripheral::imsic::{Level, LOCAL};
+//! # use riscv_pac::{result::{Error, Result}, ExternalInterruptNumber, InterruptNumber};
+//! # #[derive(Clone, Copy)]
+//! # enum Interrupt { Uart = 1 }
+//! # unsafe impl InterruptNumber for Interrupt {
+//! #     const MAX_INTERRUPT_NUMBER: u16 = 1;
+//! #     fn number(self) -> u16 { self as _ }
+//! #     fn from_number(n: u16) -> Result<Self> {
+//! #         if n == 1 { Ok(Self::Uart) } else { Err(Error::InvalidVariant(n as _)) }
+//! #     }
+//! # }
+//! # unsafe impl ExternalInterruptNumber for Interrupt {}
+//!
+//! let local = LOCAL::new(Level::Machine);
+//! unsafe {
+//!     local.enable(Interrupt::Uart);
+//!     local.enable_delivery();
+//! }
+//!
+//! // in the machine external interrupt handler
+//! while let Some(interrupt) = local.claim::<Interrupt>() {
+//!     match interrupt {
+//!         Interrupt::Uart => { /* ... */ }
+//!     }
+//! }
+//! ```
+
+use crate::common::unsafe_peripheral;
+use riscv::aia::{self, machine, supervisor};
+use riscv::register::{mtopei, stopei};
+
+// re-export useful riscv-pac traits
+pub use riscv_pac::{ExternalInterruptNumber, HartIdNumber};
+
+/// Trait for an IMSIC peripheral.
+///
+/// # Safety
+///
+/// * This trait must only be implemented on a PAC of a target with an IMSIC peripheral.
+/// * The base addresses and the number of guest index bits must be valid for the target device.
+pub unsafe trait Imsic: Copy {
+    /// Base address of the machine-level interrupt files.
+    const MACHINE_BASE: usize;
+    /// Base address of the supervisor-level interrupt files.
+    const SUPERVISOR_BASE: usize;
+    /// Number of guest index bits.
+    ///
+    /// Each HART has `2^GUEST_INDEX_BITS` pages in the supervisor-level region:
+    /// its supervisor-level interrupt file followed by its guest interrupt files.
+    const GUEST_INDEX_BITS: usize = 0;
+}
+
+/// Incoming Message-Signaled Interrupt Controller (IMSIC) peripheral.
+///
+/// The RISC-V standard does not specify a fixed location for the IMSIC interrupt files.
+/// Thus, each platform must specify the base addresses of the interrupt files on the platform.
+/// The base addresses, as well as all the associated types, are defined in the [`Imsic`] trait.
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct IMSIC<I: Imsic> {
+    _marker: core::marker::PhantomData<I>,
+}
+
+impl<I: Imsic> IMSIC<I> {
+    const PAGE_SIZE: usize = 0x1000;
+
+    /// Returns the machine-level interrupt file of a given HART.
+    #[inline]
+    pub fn machine<H: HartIdNumber>(hart_id: H) -> FILE {
+        let addr = I::MACHINE_BASE + hart_id.number() as usize * Self::PAGE_SIZE;
+        // SAFETY: valid address
+        unsafe { FILE::new(addr) }
+    }
+
+    /// Returns the supervisor-level interrupt file of a given HART.
+    #[inline]
+    pub fn supervisor<H: HartIdNumber>(hart_id: H) -> FILE {
+        // SAFETY: valid address
+        unsafe { FILE::new(Self::supervisor_page(hart_id, 0)) }
+    }
+
+    /// Returns the guest interrupt file `guest` of a given HART.
+    ///
+    /// Guest interrupt files are numbered from 1. If the guest interrupt file does not exist,
+    /// it returns [`None`].
+    #[inline]
+    pub fn guest<H: HartIdNumber>(hart_id: H, guest: usize) -> Option<FILE> {
+        if guest == 0 || guest >= 1 << I::GUEST_INDEX_BITS {
+            return None;
+        }
+        // SAFETY: valid address
+        Some(unsafe { FILE::new(Self::supervisor_page(hart_id, guest)) })
+    }
+
+    /// Returns the interrupt file of the current HART for the given privilege level.
+    #[inline]
+    pub const fn local(level: Level) -> LOCAL {
+        LOCAL::new(level)
+    }
+
+    /// Returns the address of the page `page` of a HART in the supervisor-level region.
+    #[inline]
+    fn supervisor_page<H: HartIdNumber>(hart_id: H, page: usize) -> usize {
+        let pages = (hart_id.number() as usize) << I::GUEST_INDEX_BITS;
+        I::SUPERVISOR_BASE + (pages + page) * Self::PAGE_SIZE
+    }
+}
+
+unsafe_peripheral!(FILE, u32, WO);
+
+impl FILE {
+    /// Returns the address to which MSIs must be written to signal this interrupt file.
+    ///
+    /// Devices (or an APLIC in MSI delivery mode) must be configured with this address.
+    #[inline]
+    pub fn msi_address(self) -> usize {
+        self.get_ptr() as _
+    }
+
+    /// Sends an MSI to the interrupt file, i.e., sets the pending bit of the interrupt identity `source`.
+    #[inline]
+    pub fn send<E: ExternalInterruptNumber>(self, source: E) {
+        self.register.write(source.number() as _)
+    }
+}
+
+/// Privilege level of an interrupt file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Level {
+    /// Machine-level interrupt file (accessed through `miselect` and `mireg`)
+    Machine,
+    /// Supervisor-level interrupt file (accessed through `siselect` and `sireg`)
+    Supervisor,
+}
+
+/// Interrupt file of the current HART, accessed through the AIA indirect CSRs.
+///
+/// The accesses do not interfere with trap handlers (see [`riscv::aia`]).
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LOCAL {
+    level: Level,
+}
+
+impl LOCAL {
+    /// Creates a new proxy to the interrupt file of the current HART for a given privilege level.
+    #[inline]
+    pub const fn new(level: Level) -> Self {
+        Self { level }
+    }
+
+    /// Returns the privilege level of the interrupt file.
+    #[inline]
+    pub const fn level(self) -> Level {
+        self.level
+    }
+
+    /// Returns `true` if the interrupt file delivers interrupts to the HART.
+    #[inline]
+    pub fn is_delivery_enabled(self) -> bool {
+        self.read(aia::EIDELIVERY) & 1 != 0
+    }
+
+    /// Enables the delivery of interrupts from the interrupt file to the HART.
+    ///
+    /// # Safety
+    ///
+    /// Enabling the delivery of interrupts can break mask-based critical sections.
+    #[inline]
+    pub unsafe fn enable_delivery(self) {
+        self.write(aia::EIDELIVERY, 1);
+    }
+
+    /// Disables the delivery of interrupts from the interrupt file to the HART.
+    #[inline]
+    pub fn disable_delivery(self) {
+        // SAFETY: it is safe to disable interrupts
+        unsafe { self.write(aia::EIDELIVERY, 0) };
+    }
+
+    /// Returns the interrupt threshold. Interrupts with an identity greater than or equal to the
+    /// threshold are not delivered. If no threshold is set, it returns [`None`].
+    ///
+    /// # Note
+    ///
+    /// The threshold is returned as a raw identity, as it may not be a valid interrupt
+    /// (e.g., one more than the maximum identity of the interrupt file).
+    #[inline]
+    pub fn threshold(self) -> Option<u16> {
+        match self.read(aia::EITHRESHOLD) {
+            0 => None,
+            t => Some(t as _),
+        }
+    }
+
+    /// Sets the interrupt threshold. Interrupts with an identity greater than or equal to
+    /// `threshold` are not delivered.
+    ///
+    /// # Safety
+    ///
+    /// Changing the interrupt threshold can break priority-based critical sections.
+    #[inline]
+    pub unsafe fn set_threshold<E: ExternalInterruptNumber>(self, threshold: E) {
+        self.write(aia::EITHRESHOLD, threshold.number() as _);
+    }
+
+    /// Resets the interrupt threshold to 0.
+    ///
+    /// # Note
+    ///
+    /// Threshold 0 implies that all the enabled interrupts are delivered.
+    #[inline]
+    pub fn reset_threshold(self) {
+        // SAFETY: resetting the threshold only accepts enabled interrupts
+        unsafe { self.write(aia::EITHRESHOLD, 0) };
+    }
+
+    /// Checks if an interrupt identity is enabled.
+    #[inline]
+    pub fn is_enabled<E: ExternalInterruptNumber>(self, source: E) -> bool {
+        let (offset, bit) = bit_of(source.number());
+        self.read(aia::EIE0 + offset) & (1 << bit) != 0
+    }
+
+    /// Enables an interrupt identity.
+    ///
+    /// # Safety
+    ///
+    /// Enabling an interrupt identity can break mask-based critical sections.
+    #[inline]
+    pub unsafe fn enable<E: ExternalInterruptNumber>(self, source: E) {
+        let (offset, bit) = bit_of(source.number());
+        self.modify(aia::EIE0 + offset, |eie| eie | (1 << bit));
+    }
+
+    /// Disables an interrupt identity.
+    #[inline]
+    pub fn disable<E: ExternalInterruptNumber>(self, source: E) {
+        let (offset, bit) = bit_of(source.number());
+        // SAFETY: it is safe to disable interrupts
+        unsafe { self.modify(aia::EIE0 + offset, |eie| eie & !(1 << bit)) };
+    }
+
+    /// Checks if an interrupt identity is pending.
+    #[inline]
+    pub fn is_pending<E: ExternalInterruptNumber>(self, source: E) -> bool {
+        let (offset, bit) = bit_of(source.number());
+        self.read(aia::EIP0 + offset) & (1 << bit) != 0
+    }
+
+    /// Sets the pending bit of an interrupt identity.
+    ///
+    /// # Note
+    ///
+    /// Other HARTs and devices must use [`FILE::send`] instead.
+    #[inline]
+    pub fn pend<E: ExternalInterruptNumber>(self, source: E) {
+        let (offset, bit) = bit_of(source.number());
+        // SAFETY: pending an interrupt does not enable it
+        unsafe { self.modify(aia::EIP0 + offset, |eip| eip | (1 << bit)) };
+    }
+
+    /// Clears the pending bit of an interrupt identity.
+    #[inline]
+    pub fn unpend<E: ExternalInterruptNumber>(self, source: E) {
+        let (offset, bit) = bit_of(source.number());
+        // SAFETY: it is safe to clear pending interrupts
+        unsafe { self.modify(aia::EIP0 + offset, |eip| eip & !(1 << bit)) };
+    }
+
+    /// Returns the highest-priority interrupt that is pending, enabled, and below the threshold,
+    /// without claiming it. If no interrupt is pending, it returns [`None`].
+    #[inline]
+    pub fn top<E: ExternalInterruptNumber>(self) -> Option<E> {
+        let identity = match self.level {
+            Level::Machine => mtopei::read().identity(),
+            Level::Supervisor => stopei::read().identity(),
+        };
+        match identity {
+            0 => None,
+            i => Some(E::from_number(i as _).unwrap()),
+        }
+    }
+
+    /// Claims the highest-priority interrupt that is pending, enabled, and below the threshold,
+    /// i.e., clears its pending bit. If no interrupt is pending, it returns [`None`].
+    #[inline]
+    pub fn claim<E: ExternalInterruptNumber>(self) -> Option<E> {
+        let identity = match self.level {
+            Level::Machine => mtopei::claim().map(|mtopei| mtopei.identity()),
+            Level::Supervisor => stopei::claim().map(|stopei| stopei.identity()),
+        };
+        identity.map(|i| E::from_number(i as _).unwrap())
+    }
+
+    #[inline]
+    fn read(self, select: usize) -> usize {
+        match self.level {
+            Level::Machine => machine::read_indirect(select),
+            Level::Supervisor => supervisor::read_indirect(select),
+        }
+    }
+
+    #[inline]
+    unsafe fn write(self, select: usize, value: usize) {
+        match self.level {
+            Level::Machine => machine::write_indirect(select, value),
+            Level::Supervisor => supervisor::write_indirect(select, value),
+        }
+    }
+
+    #[inline]
+    unsafe fn modify(self, select: usize, f: impl FnOnce(usize) -> usize) {
+        match self.level {
+            Level::Machine => machine::modify_indirect(select, f),
+            Level::Supervisor => supervisor::modify_indirect(select, f),
+        }
+    }
+}
+
+/// Returns the select offset and the bit of an interrupt identity in the `eip` and `eie` arrays.
+///
+/// On RV64, each register holds 64 identities, and only the even-numbered registers exist.
+#[inline]
+const fn bit_of(source: u16) -> (usize, usize) {
+    let source = source as usize;
+    let bits = usize::BITS as usize;
+    ((source / bits) * (bits / 32), source % bits)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::plic::test::{Context, Interrupt};
+    use super::*;
+    use riscv_pac::InterruptNumber;
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    struct Target;
+
+    unsafe impl Imsic for Target {
+        const MACHINE_BASE: usize = 0x2400_0000;
+        const SUPERVISOR_BASE: usize = 0x2800_0000;
+        const GUEST_INDEX_BITS: usize = 2;
+    }
+
+    #[test]
+    fn test_imsic() {
+        for i in 0..=Context::MAX_HART_ID_NUMBER {
+            let hart_id = Context::from_number(i).unwrap();
+            let i = i as usize;
+
+            let machine = IMSIC::<Target>::machine(hart_id);
+            assert_eq!(machine.msi_address(), 0x2400_0000 + i * 0x1000);
+
+            let supervisor = IMSIC::<Target>::supervisor(hart_id);
+            assert_eq!(supervisor.msi_address(), 0x2800_0000 + i * 0x4000);
+
+            assert_eq!(IMSIC::<Target>::guest(hart_id, 0), None);
+            for guest in 1..4 {
+                let file = IMSIC::<Target>::guest(hart_id, guest).unwrap();
+                assert_eq!(
+                    file.msi_address(),
+                    supervisor.msi_address() + guest * 0x1000
+                );
+            }
+            assert_eq!(IMSIC::<Target>::guest(hart_id, 4), None);
+        }
+
+        assert_eq!(
+            IMSIC::<Target>::local(Level::Supervisor).level(),
+            Level::Supervisor
+        );
+    }
+
+    #[test]
+    fn test_file() {
+        let mut raw_reg = 0u32;
+        // SAFETY: valid memory address
+        let file = unsafe { FILE::new(&mut raw_reg as *mut _ as _) };
+
+        for i in 1..=Interrupt::MAX_INTERRUPT_NUMBER {
+            file.send(Interrupt::from_number(i).unwrap());
+            assert_eq!(raw_reg, i as u32);
+        }
+    }
+
+    #[test]
+    fn test_bit_of() {
+        assert_eq!(bit_of(1), (0, 1));
+        #[cfg(target_pointer_width = "32")]
+        assert_eq!(bit_of(100), (3, 4));
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(bit_of(100), (2, 36));
+    }
+}
@@ -17,4 +17,7 @@ pub mod hal_async; // async trait implementations for embedded-hal
 pub mod macros; // macros for easing the definition of peripherals in PACs
 
 pub mod aclint; // ACLINT and CLINT peripherals
+pub mod aplic; // APLIC peripheral
+pub mod clic; // CLIC peripheral
+pub mod imsic; // IMSIC peripheral
 pub mod plic; // PLIC peripheral
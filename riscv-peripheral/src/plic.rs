@@ -1,6 +1,16 @@
 //! Platform-Level Interrupt Controller (PLIC) peripheral.
 //!
 //! Specification: <https://github.com/riscv/riscv-plic-spec/blob/master/riscv-plic.adoc>
+//!
+//! The driver is generic over the `riscv-pac` traits of the target:
+//!
+//! - Interrupt sources implement [`InterruptNumber`] (usually, [`ExternalInterruptNumber`]).
+//!   Their priorities are shared among all the contexts (see [`priorities`]), and their pending
+//!   bits are in [`pendings`].
+//! - Priorities implement [`PriorityNumber`].
+//! - HART contexts implement [`HartIdNumber`]. Each context has its own interrupt enables
+//!   ([`enables`]), priority threshold ([`threshold`]), and claim/complete register ([`claim`]),
+//!   which are accessed through a [`CTX`] proxy.
 
 pub mod claim;
 pub mod enables;
@@ -9,7 +19,7 @@ pub mod priorities;
 pub mod threshold;
 
 // re-export useful riscv-pac traits
-pub use riscv_pac::{HartIdNumber, InterruptNumber, PriorityNumber};
+pub use riscv_pac::{ExternalInterruptNumber, HartIdNumber, InterruptNumber, PriorityNumber};
 
 /// Trait for a PLIC peripheral.
 ///
@@ -145,8 +155,9 @@ impl<P: Plic> CTX<P> {
 
 #[cfg(test)]
 pub(crate) mod test {
-    use super::{HartIdNumber, InterruptNumber, PriorityNumber};
+    use super::{ExternalInterruptNumber, HartIdNumber, PriorityNumber};
     use riscv_pac::result::{Error, Result};
+    use riscv_pac::InterruptNumber;
 
     #[derive(Clone, Copy, Debug, Eq, PartialEq)]
     #[repr(u16)]
@@ -193,6 +204,8 @@ pub(crate) mod test {
         }
     }
 
+    unsafe impl ExternalInterruptNumber for Interrupt {}
+
     unsafe impl PriorityNumber for Priority {
         const MAX_PRIORITY_NUMBER: u8 = 3;
 
@@ -25,6 +25,74 @@ impl CLAIM {
     pub fn complete<I: InterruptNumber>(self, source: I) {
         self.register.write(source.number() as _)
     }
+
+    /// Claims the number of a pending interrupt for the PLIC context, and returns a guard
+    /// that marks the interrupt as complete when dropped.
+    /// If no interrupt is pending for this context, it returns [`None`].
+    #[inline]
+    pub fn claim_guard<I: InterruptNumber>(self) -> Option<ClaimGuard<I>> {
+        self.claim().map(|source| ClaimGuard {
+            claim: self,
+            source,
+        })
+    }
+
+    /// Returns an iterator that claims the pending interrupts of the PLIC context
+    /// until no interrupt is pending.
+    ///
+    /// Each interrupt is yielded as a [`ClaimGuard`], so it is marked as complete
+    /// when the guard is dropped (e.g., at the end of each loop iteration).
+    #[inline]
+    pub const fn pending<I: InterruptNumber>(self) -> Pending<I> {
+        Pending {
+            claim: self,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+/// Iterator over the pending interrupts of a PLIC context.
+///
+/// See [`CLAIM::pending`].
+#[derive(Debug)]
+pub struct Pending<I: InterruptNumber> {
+    claim: CLAIM,
+    _marker: core::marker::PhantomData<I>,
+}
+
+impl<I: InterruptNumber> Iterator for Pending<I> {
+    type Item = ClaimGuard<I>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.claim.claim_guard()
+    }
+}
+
+/// Claimed interrupt of a PLIC context.
+///
+/// The interrupt is marked as complete when the guard is dropped,
+/// so the PLIC can signal the interrupt source again.
+#[derive(Debug)]
+#[must_use = "the interrupt is completed when the guard is dropped"]
+pub struct ClaimGuard<I: InterruptNumber> {
+    claim: CLAIM,
+    source: I,
+}
+
+impl<I: InterruptNumber> ClaimGuard<I> {
+    /// Returns the claimed interrupt source.
+    #[inline]
+    pub fn source(&self) -> I {
+        self.source
+    }
+}
+
+impl<I: InterruptNumber> Drop for ClaimGuard<I> {
+    #[inline]
+    fn drop(&mut self) {
+        self.claim.complete(self.source);
+    }
 }
 
 #[cfg(test)]
@@ -46,4 +114,48 @@ mod test {
             assert_eq!(claim.claim(), Some(interrupt));
         }
     }
+
+    #[test]
+    fn test_claim_guard() {
+        let mut raw_reg = 0u32;
+        // SAFETY: valid memory address
+        let claim = unsafe { CLAIM::new(&mut raw_reg as *mut _ as _) };
+
+        assert!(claim.claim_guard::<Interrupt>().is_none());
+
+        for i in 1..=Interrupt::MAX_INTERRUPT_NUMBER {
+            let interrupt = Interrupt::from_number(i).unwrap();
+            claim.complete(interrupt);
+            let guard = claim.claim_guard::<Interrupt>().unwrap();
+            assert_eq!(guard.source(), interrupt);
+            claim.register.write(0);
+            drop(guard);
+            assert_eq!(claim.claim(), Some(interrupt));
+        }
+    }
+
+    #[test]
+    fn test_pending() {
+        let mut raw_reg = 0u32;
+        // SAFETY: valid memory address
+        let claim = unsafe { CLAIM::new(&mut raw_reg as *mut _ as _) };
+
+        assert!(claim.pending::<Interrupt>().next().is_none());
+
+        // the mock register returns the last completed interrupt until it is cleared
+        claim.complete(Interrupt::I2);
+        let mut claimed = 0;
+        for guard in claim.pending::<Interrupt>() {
+            assert_eq!(guard.source(), Interrupt::I2);
+            claimed += 1;
+            drop(guard);
+            assert_eq!(claim.register.read(), Interrupt::I2.number() as u32);
+            if claimed == 2 {
+                // the source stops being pending after it is serviced twice
+                claim.register.write(0);
+            }
+        }
+        assert_eq!(claimed, 2);
+        assert_eq!(claim.claim::<Interrupt>(), None);
+    }
 }
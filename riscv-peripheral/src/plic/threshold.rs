@@ -31,6 +31,64 @@ impl THRESHOLD {
     pub fn reset(self) {
         self.register.write(0)
     }
+
+    /// Executes `f` with the priority threshold raised to at least `ceiling`,
+    /// and restores the previous threshold afterwards.
+    ///
+    /// Interrupts with a priority lower than or equal to `ceiling` are masked while `f` executes.
+    /// Thus, `f` is a critical section for all the interrupt handlers with those priorities,
+    /// while higher-priority interrupts are still accepted.
+    ///
+    /// # Note
+    ///
+    /// If the current threshold is already greater than `ceiling`, it is not modified.
+    #[inline]
+    pub fn with_threshold<P: PriorityNumber, F: FnOnce() -> R, R>(self, ceiling: P, f: F) -> R {
+        // SAFETY: the guard is dropped before returning
+        let _guard = unsafe { self.raise(ceiling) };
+        f()
+    }
+
+    /// Raises the priority threshold to at least `ceiling`, and returns a guard
+    /// that restores the previous threshold when dropped.
+    ///
+    /// # Note
+    ///
+    /// If the current threshold is already greater than `ceiling`, it is not modified.
+    ///
+    /// # Safety
+    ///
+    /// Guards of the same context must be dropped in the reverse order of their creation.
+    /// Otherwise, the threshold is restored to a level lower than the ceiling of the remaining
+    /// guards, which can break priority-based critical sections.
+    #[inline]
+    pub unsafe fn raise<P: PriorityNumber>(self, ceiling: P) -> ThresholdGuard {
+        let previous = self.register.read();
+        if previous < ceiling.number() as _ {
+            self.register.write(ceiling.number() as _);
+        }
+        ThresholdGuard {
+            threshold: self,
+            previous,
+        }
+    }
+}
+
+/// Raised priority threshold of a PLIC context.
+///
+/// The previous threshold is restored when the guard is dropped.
+#[derive(Debug)]
+#[must_use = "the previous threshold is restored when the guard is dropped"]
+pub struct ThresholdGuard {
+    threshold: THRESHOLD,
+    previous: u32,
+}
+
+impl Drop for ThresholdGuard {
+    #[inline]
+    fn drop(&mut self) {
+        self.threshold.register.write(self.previous);
+    }
 }
 
 #[cfg(test)]
@@ -52,4 +110,31 @@ mod test {
         threshold.reset();
         assert_eq!(threshold.get_threshold::<Priority>(), Priority::P0);
     }
+
+    #[test]
+    fn test_with_threshold() {
+        let mut raw_reg = 0u32;
+        // SAFETY: valid memory address
+        let threshold = unsafe { THRESHOLD::new(&mut raw_reg as *mut _ as _) };
+
+        let r = threshold.with_threshold(Priority::P2, || {
+            assert_eq!(threshold.get_threshold::<Priority>(), Priority::P2);
+            // a lower ceiling does not lower the threshold
+            threshold.with_threshold(Priority::P1, || {
+                assert_eq!(threshold.get_threshold::<Priority>(), Priority::P2);
+            });
+            threshold.with_threshold(Priority::P3, || {
+                assert_eq!(threshold.get_threshold::<Priority>(), Priority::P3);
+            });
+            assert_eq!(threshold.get_threshold::<Priority>(), Priority::P2);
+            42
+        });
+        assert_eq!(r, 42);
+        assert_eq!(threshold.get_threshold::<Priority>(), Priority::P0);
+
+        let guard = unsafe { threshold.raise(Priority::P1) };
+        assert_eq!(threshold.get_threshold::<Priority>(), Priority::P1);
+        drop(guard);
+        assert_eq!(threshold.get_threshold::<Priority>(), Priority::P0);
+    }
 }
@@ -0,0 +1,145 @@
+//! Advanced Interrupt Architecture (AIA)
+//!
+//! Helpers for the CSRs of the AIA (Smaia and Ssaia extensions).
+//!
+//! The major interrupt priorities (e.g., `iprio0`) and the registers of the IMSIC interrupt files
+//! (e.g., `eie0`) are accessed through a window of two CSRs: `xiselect` selects a register,
+//! and `xireg` accesses the selected register. A trap handler that uses the window between both
+//! accesses changes the selection, so the helpers of [`machine`] (`miselect` and `mireg`) and
+//! [`supervisor`] (`siselect` and `sireg`) perform the selection and the access with interrupts
+//! disabled, and restore the previous selection afterwards.
+//!
+//! The select values are the same for both privilege levels.
+//!
+//! External interrupts of the IMSIC interrupt file are claimed through `mtopei` (or `stopei` if
+//! the `s-mode` feature is enabled). [`claim_loop`] claims and dispatches them until none remain.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use riscv::aia::{self, machine};
+//!
+//! // enable the interrupt file and the external interrupt identities 1 to 31
+//! unsafe {
+//!     machine::write_indirect(aia::EIDELIVERY, 1);
+//!     machine::write_indirect(aia::EITHRESHOLD, 0);
+//!     machine::modify_indirect(aia::EIE0, |eie| eie | 0xFFFF_FFFE);
+//! }
+//! ```
+
+pub mod machine;
+pub mod supervisor;
+
+#[cfg(not(feature = "s-mode"))]
+use crate::register::mtopei as topei;
+#[cfg(feature = "s-mode")]
+use crate::register::stopei as topei;
+use crate::result::Result;
+use crate::ExternalInterruptNumber;
+
+/// Select value of `iprio0`, the priorities of the major interrupts 0 to 3 (RV32) or 0 to 7 (RV64).
+///
+/// The priority arrays `iprio0` to `iprio15` have consecutive select values.
+/// On RV64, only the even-numbered registers exist.
+pub const IPRIO0: usize = 0x30;
+
+/// Select value of `eidelivery`, which enables the interrupt delivery of the IMSIC interrupt file
+pub const EIDELIVERY: usize = 0x70;
+
+/// Select value of `eithreshold`, the priority threshold of the IMSIC interrupt file
+pub const EITHRESHOLD: usize = 0x72;
+
+/// Select value of `eip0`, the pending bits of the external interrupt identities 0 to 31 (RV32) or 0 to 63 (RV64).
+///
+/// The pending arrays `eip0` to `eip63` have consecutive select values.
+/// On RV64, only the even-numbered registers exist.
+pub const EIP0: usize = 0x80;
+
+/// Select value of `eie0`, the enable bits of the external interrupt identities 0 to 31 (RV32) or 0 to 63 (RV64).
+///
+/// The enable arrays `eie0` to `eie63` have consecutive select values.
+/// On RV64, only the even-numbered registers exist.
+pub const EIE0: usize = 0xC0;
+
+/// Claims the top external interrupt of the IMSIC interrupt file and calls `f` with it,
+/// until no interrupt is pending.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - claimed identities that are not valid for `E`
+///
+/// # Example
+///
+/// ```no_run
+/// # use riscv::{result::{Error, Result}, ExternalInterruptNumber, InterruptNumber};
+/// # #[derive(Clone, Copy)]
+/// # enum Interrupt { Uart = 1 }
+/// # unsafe impl InterruptNumber for Interrupt {
+/// #     const MAX_INTERRUPT_NUMBER: u16 = 1;
+/// #     fn number(self) -> u16 { self as _ }
+/// #     fn from_number(value: u16) -> Result<Self> {
+/// #         match value { 1 => Ok(Self::Uart), _ => Err(Error::InvalidVariant(value as _)) }
+/// #     }
+/// # }
+/// # unsafe impl ExternalInterruptNumber for Interrupt {}
+/// // in the external interrupt handler
+/// riscv::aia::claim_loop(|irq: Interrupt| match irq {
+///     Interrupt::Uart => { /* ... */ }
+/// });
+/// ```
+#[inline]
+pub fn claim_loop<E: ExternalInterruptNumber, F: FnMut(E)>(f: F) {
+    try_claim_loop(f).unwrap();
+}
+
+/// Attempts to claim the top external interrupt of the IMSIC interrupt file and call `f` with it,
+/// until no interrupt is pending.
+///
+/// If a claimed identity is not valid for `E`, it returns the error of
+/// [`ExternalInterruptNumber::from_number`](crate::InterruptNumber::from_number).
+/// The interrupt is already claimed, so `f` is not called for it.
+#[inline]
+pub fn try_claim_loop<E: ExternalInterruptNumber, F: FnMut(E)>(mut f: F) -> Result<()> {
+    while let Some(topei) = topei::try_claim()? {
+        f(E::from_number(topei.identity() as _)?);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::result::Error;
+    use crate::InterruptNumber;
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    struct Interrupt;
+
+    unsafe impl InterruptNumber for Interrupt {
+        const MAX_INTERRUPT_NUMBER: u16 = 1;
+
+        fn number(self) -> u16 {
+            1
+        }
+
+        fn from_number(value: u16) -> Result<Self> {
+            match value {
+                1 => Ok(Self),
+                _ => Err(Error::InvalidVariant(value as _)),
+            }
+        }
+    }
+
+    unsafe impl ExternalInterruptNumber for Interrupt {}
+
+    #[test]
+    fn test_claim_loop() {
+        let mut claimed = 0;
+        assert_eq!(
+            try_claim_loop(|_: Interrupt| claimed += 1),
+            Err(Error::Unimplemented)
+        );
+        assert_eq!(claimed, 0);
+    }
+}
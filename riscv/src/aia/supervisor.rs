@@ -0,0 +1,105 @@
+//! Supervisor-level indirect CSR access
+//!
+//! Accesses the registers selected with [`siselect`] through [`sireg`], with supervisor interrupts disabled.
+
+use crate::register::{sireg, siselect, sstatus};
+use crate::result::Result;
+
+/// Reads the register selected by `select`.
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn read_indirect(select: usize) -> usize {
+    try_read_indirect(select).unwrap()
+}
+
+/// Attempts to read the register selected by `select`.
+#[inline]
+pub fn try_read_indirect(select: usize) -> Result<usize> {
+    with_select(select, sireg::try_read)
+}
+
+/// Writes `value` to the register selected by `select`.
+///
+/// **WARNING**: panics on non-`riscv` targets.
+///
+/// # Safety
+///
+/// Writing the selected register may change the interrupt configuration of the hart.
+#[inline]
+pub unsafe fn write_indirect(select: usize, value: usize) {
+    try_write_indirect(select, value).unwrap();
+}
+
+/// Attempts to write `value` to the register selected by `select`.
+///
+/// # Safety
+///
+/// See [`write_indirect`].
+#[inline]
+pub unsafe fn try_write_indirect(select: usize, value: usize) -> Result<()> {
+    with_select(select, || sireg::try_write(value))
+}
+
+/// Updates the register selected by `select` with the value returned by `f`.
+///
+/// The read and the write are not interleaved with trap handlers.
+///
+/// **WARNING**: panics on non-`riscv` targets.
+///
+/// # Safety
+///
+/// See [`write_indirect`].
+#[inline]
+pub unsafe fn modify_indirect<F: FnOnce(usize) -> usize>(select: usize, f: F) {
+    try_modify_indirect(select, f).unwrap();
+}
+
+/// Attempts to update the register selected by `select` with the value returned by `f`.
+///
+/// # Safety
+///
+/// See [`write_indirect`].
+#[inline]
+pub unsafe fn try_modify_indirect<F: FnOnce(usize) -> usize>(select: usize, f: F) -> Result<()> {
+    with_select(select, || sireg::try_write(f(sireg::try_read()?)))
+}
+
+/// Executes `f` with `select` selected and supervisor interrupts disabled.
+///
+/// The previous selection is restored afterwards, so interrupted code that accesses the window
+/// directly is not affected either.
+#[inline]
+fn with_select<F: FnOnce() -> Result<R>, R>(select: usize, f: F) -> Result<R> {
+    let sstatus = sstatus::try_read()?;
+    // SAFETY: It is safe to disable interrupts
+    unsafe { sstatus::clear_sie() };
+
+    let r = siselect::try_swap(select).and_then(|previous| {
+        let r = f();
+        siselect::try_write(previous)?;
+        r
+    });
+
+    if sstatus.sie() {
+        // SAFETY: interrupts were enabled before the access
+        unsafe { sstatus::set_sie() };
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aia::EIDELIVERY;
+    use crate::result::Error;
+
+    #[test]
+    fn test_indirect() {
+        assert_eq!(try_read_indirect(EIDELIVERY), Err(Error::Unimplemented));
+        assert_eq!(
+            unsafe { try_write_indirect(EIDELIVERY, 1) },
+            Err(Error::Unimplemented)
+        );
+    }
+}
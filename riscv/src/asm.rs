@@ -91,6 +91,23 @@ instruction!(
     /// implementations shall ignore these fields, and standard software shall zero these fields.
     , fence_i, "fence.i");
 
+/// `MNRET` instruction wrapper (Smrnmi)
+///
+/// Returns from a resumable non-maskable interrupt handler: it restores the privilege mode
+/// from `mnstatus`, sets `mnstatus.NMIE`, and resumes execution at the address in `mnepc`.
+///
+/// Note that the NMI handler must restore the interrupted context before calling this function.
+#[inline]
+pub unsafe fn mnret() -> ! {
+    match () {
+        #[cfg(riscv)]
+        () => core::arch::asm!(".insn i 0x73, 0, x0, x0, 0x702", options(noreturn)),
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
 /// `SFENCE.VMA` instruction wrapper
 ///
 /// Synchronizes updates to in-memory memory-management data structures with current execution.
@@ -110,6 +127,398 @@ pub unsafe fn sfence_vma(asid: usize, addr: usize) {
     }
 }
 
+/// `SFENCE.VMA` instruction wrapper (all page table levels of a single address space)
+///
+/// Orders the stores prior to the fence before the implicit references to the page table
+/// of the address space `asid`, and invalidates its address-translation cache entries.
+/// Global mappings are not affected.
+#[inline]
+#[allow(unused_variables)]
+pub unsafe fn sfence_vma_asid(asid: usize) {
+    match () {
+        #[cfg(riscv)]
+        () => core::arch::asm!("sfence.vma x0, {0}", in(reg) asid),
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+/// `SFENCE.VMA` instruction wrapper (leaf page table entries of a virtual address in all address spaces)
+///
+/// Orders the stores prior to the fence before the implicit references to the leaf page table
+/// entries that translate `addr`, and invalidates their address-translation cache entries
+/// in all address spaces.
+#[inline]
+#[allow(unused_variables)]
+pub unsafe fn sfence_vma_addr(addr: usize) {
+    match () {
+        #[cfg(riscv)]
+        () => core::arch::asm!("sfence.vma {0}, x0", in(reg) addr),
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+// The hypervisor fences are emitted with `.insn`, as assemblers may not enable the extension.
+
+/// `HFENCE.VVMA` instruction wrapper (hypervisor extension)
+///
+/// Orders the stores prior to the fence before the subsequent implicit references to the VS-stage
+/// page tables of the current virtual machine (selected by `hgatp.VMID`), and invalidates the
+/// matching VS-stage address-translation cache entries of the guest address space `asid`
+/// and guest virtual address `addr`.
+///
+/// A `None` `asid` selects all the guest address spaces, and a `None` `addr` selects all the guest virtual addresses.
+#[cfg(feature = "h-extension")]
+#[inline]
+#[allow(unused_variables)]
+pub unsafe fn hfence_vvma(asid: Option<usize>, addr: Option<usize>) {
+    match () {
+        #[cfg(riscv)]
+        () => match (asid, addr) {
+            (None, None) => core::arch::asm!(".insn r 0x73, 0, 0x11, x0, x0, x0"),
+            (Some(asid), None) => {
+                core::arch::asm!(".insn r 0x73, 0, 0x11, x0, x0, {0}", in(reg) asid)
+            }
+            (None, Some(addr)) => {
+                core::arch::asm!(".insn r 0x73, 0, 0x11, x0, {0}, x0", in(reg) addr)
+            }
+            (Some(asid), Some(addr)) => core::arch::asm!(
+                ".insn r 0x73, 0, 0x11, x0, {0}, {1}",
+                in(reg) addr,
+                in(reg) asid,
+            ),
+        },
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+/// `HFENCE.GVMA` instruction wrapper (hypervisor extension)
+///
+/// Orders the stores prior to the fence before the subsequent implicit references to the G-stage
+/// page tables, and invalidates the matching G-stage address-translation cache entries of the
+/// virtual machine `vmid` and guest physical address `gaddr`.
+///
+/// Note that `gaddr` is the guest physical address shifted right by 2 bits.
+///
+/// A `None` `vmid` selects all the virtual machines, and a `None` `gaddr` selects all the guest physical addresses.
+#[cfg(feature = "h-extension")]
+#[inline]
+#[allow(unused_variables)]
+pub unsafe fn hfence_gvma(vmid: Option<usize>, gaddr: Option<usize>) {
+    match () {
+        #[cfg(riscv)]
+        () => match (vmid, gaddr) {
+            (None, None) => core::arch::asm!(".insn r 0x73, 0, 0x31, x0, x0, x0"),
+            (Some(vmid), None) => {
+                core::arch::asm!(".insn r 0x73, 0, 0x31, x0, x0, {0}", in(reg) vmid)
+            }
+            (None, Some(gaddr)) => {
+                core::arch::asm!(".insn r 0x73, 0, 0x31, x0, {0}, x0", in(reg) gaddr)
+            }
+            (Some(vmid), Some(gaddr)) => core::arch::asm!(
+                ".insn r 0x73, 0, 0x31, x0, {0}, {1}",
+                in(reg) gaddr,
+                in(reg) vmid,
+            ),
+        },
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+// The Svinval instructions are emitted with `.insn`, as assemblers may not enable the extension.
+
+/// `SINVAL.VMA` instruction wrapper (Svinval)
+///
+/// Invalidates the address-translation cache entries of `addr` in the address space `asid`,
+/// like [`sfence_vma`], but it is only ordered against other `SINVAL.VMA`, `SFENCE.W.INVAL`,
+/// and `SFENCE.INVAL.IR` instructions. A batch of invalidations must be preceded by
+/// [`sfence_w_inval`] and followed by [`sfence_inval_ir`].
+#[cfg(feature = "svinval")]
+#[inline]
+#[allow(unused_variables)]
+pub unsafe fn sinval_vma(asid: usize, addr: usize) {
+    match () {
+        #[cfg(riscv)]
+        () => core::arch::asm!(".insn r 0x73, 0, 0x0B, x0, {0}, {1}", in(reg) addr, in(reg) asid),
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+/// `SINVAL.VMA` instruction wrapper (Svinval, all address spaces and page table levels)
+///
+/// See [`sinval_vma`] for the ordering requirements.
+#[cfg(feature = "svinval")]
+#[inline]
+pub unsafe fn sinval_vma_all() {
+    match () {
+        #[cfg(riscv)]
+        () => core::arch::asm!(".insn r 0x73, 0, 0x0B, x0, x0, x0"),
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+/// `SFENCE.W.INVAL` instruction wrapper (Svinval)
+///
+/// Orders the stores prior to the fence before the subsequent invalidation instructions
+/// (e.g., [`sinval_vma`]).
+#[cfg(feature = "svinval")]
+#[inline]
+pub unsafe fn sfence_w_inval() {
+    match () {
+        #[cfg(riscv)]
+        () => core::arch::asm!(".insn r 0x73, 0, 0x0C, x0, x0, x0"),
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+/// `SFENCE.INVAL.IR` instruction wrapper (Svinval)
+///
+/// Orders the prior invalidation instructions (e.g., [`sinval_vma`]) before the subsequent
+/// implicit references to the memory-management data structures.
+#[cfg(feature = "svinval")]
+#[inline]
+pub unsafe fn sfence_inval_ir() {
+    match () {
+        #[cfg(riscv)]
+        () => core::arch::asm!(".insn r 0x73, 0, 0x0C, x0, x0, x1"),
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+/// `HINVAL.VVMA` instruction wrapper (Svinval and hypervisor extension)
+///
+/// Invalidates the VS-stage address-translation cache entries of the guest virtual address `addr`
+/// in the guest address space `asid` of the current virtual machine.
+/// See [`sinval_vma`] for the ordering requirements.
+#[cfg(all(feature = "svinval", feature = "h-extension"))]
+#[inline]
+#[allow(unused_variables)]
+pub unsafe fn hinval_vvma(asid: usize, addr: usize) {
+    match () {
+        #[cfg(riscv)]
+        () => core::arch::asm!(".insn r 0x73, 0, 0x13, x0, {0}, {1}", in(reg) addr, in(reg) asid),
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+/// `HINVAL.GVMA` instruction wrapper (Svinval and hypervisor extension)
+///
+/// Invalidates the G-stage address-translation cache entries of the guest physical address `gaddr`
+/// in the virtual machine `vmid`. See [`sinval_vma`] for the ordering requirements.
+///
+/// Note that `gaddr` is the guest physical address shifted right by 2 bits.
+#[cfg(all(feature = "svinval", feature = "h-extension"))]
+#[inline]
+#[allow(unused_variables)]
+pub unsafe fn hinval_gvma(vmid: usize, gaddr: usize) {
+    match () {
+        #[cfg(riscv)]
+        () => core::arch::asm!(".insn r 0x73, 0, 0x33, x0, {0}, {1}", in(reg) gaddr, in(reg) vmid),
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+// The hypervisor virtual-machine load/store instructions are emitted with `.insn`,
+// as assemblers may not enable the extension.
+
+/// Generates a wrapper of a hypervisor virtual-machine load instruction
+macro_rules! hlv {
+    ($(#[$attr:meta])* $fnname:ident, $ty:ty, $funct7:literal, $rs2:ident) => {
+        $(#[$attr])*
+        ///
+        /// # Safety
+        ///
+        /// See [`hlv_b`].
+        #[cfg(feature = "h-extension")]
+        #[inline]
+        #[allow(unused_variables)]
+        pub unsafe fn $fnname(addr: usize) -> $ty {
+            match () {
+                #[cfg(riscv)]
+                () => {
+                    let value: usize;
+                    core::arch::asm!(
+                        concat!(".insn r 0x73, 4, ", stringify!($funct7), ", {0}, {1}, ", stringify!($rs2)),
+                        out(reg) value,
+                        in(reg) addr,
+                        options(nostack, readonly),
+                    );
+                    value as $ty
+                }
+
+                #[cfg(not(riscv))]
+                () => unimplemented!(),
+            }
+        }
+    };
+}
+
+/// Generates a wrapper of a hypervisor virtual-machine store instruction
+macro_rules! hsv {
+    ($(#[$attr:meta])* $fnname:ident, $ty:ty, $funct7:literal) => {
+        $(#[$attr])*
+        ///
+        /// # Safety
+        ///
+        /// See [`hlv_b`]. Besides, the store must not break the memory of the virtual machine.
+        #[cfg(feature = "h-extension")]
+        #[inline]
+        #[allow(unused_variables)]
+        pub unsafe fn $fnname(addr: usize, value: $ty) {
+            match () {
+                #[cfg(riscv)]
+                () => core::arch::asm!(
+                    concat!(".insn r 0x73, 4, ", stringify!($funct7), ", x0, {0}, {1}"),
+                    in(reg) addr,
+                    in(reg) value as usize,
+                    options(nostack),
+                ),
+
+                #[cfg(not(riscv))]
+                () => unimplemented!(),
+            }
+        }
+    };
+}
+
+/// `HLV.B` instruction wrapper (hypervisor extension)
+///
+/// Loads a sign-extended byte from the guest virtual address `addr`, with the address translation
+/// and protection of the virtual machine, as if executed in the virtualization mode and privilege
+/// level held by `hstatus.SPVP` (i.e., using `vsatp` and `hgatp`). The load is performed even if
+/// the hart is not currently running in a virtualization mode.
+///
+/// # Safety
+///
+/// The instruction can only be executed in M-mode, HS-mode, or U-mode with `hstatus.HU` set, and
+/// `vsatp`, `hgatp`, and `hstatus.SPVP` must hold the configuration of the target virtual machine.
+/// Accesses to invalid guest addresses raise guest-page-fault (or access-fault) exceptions, which
+/// must be handled by the trap handler (e.g., to report the error to the caller). Note that the
+/// accessed memory is not managed by Rust, so the virtual machine can modify it at any time.
+#[cfg(feature = "h-extension")]
+#[inline]
+#[allow(unused_variables)]
+pub unsafe fn hlv_b(addr: usize) -> i8 {
+    match () {
+        #[cfg(riscv)]
+        () => {
+            let value: usize;
+            core::arch::asm!(
+                ".insn r 0x73, 4, 0x30, {0}, {1}, x0",
+                out(reg) value,
+                in(reg) addr,
+                options(nostack, readonly),
+            );
+            value as i8
+        }
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+hlv!(
+    /// `HLV.BU` instruction wrapper (hypervisor extension)
+    ///
+    /// Loads an unsigned byte from the guest virtual address `addr` (see [`hlv_b`]).
+    hlv_bu, u8, 0x30, x1
+);
+hlv!(
+    /// `HLV.H` instruction wrapper (hypervisor extension)
+    ///
+    /// Loads a sign-extended halfword from the guest virtual address `addr` (see [`hlv_b`]).
+    hlv_h, i16, 0x32, x0
+);
+hlv!(
+    /// `HLV.HU` instruction wrapper (hypervisor extension)
+    ///
+    /// Loads an unsigned halfword from the guest virtual address `addr` (see [`hlv_b`]).
+    hlv_hu, u16, 0x32, x1
+);
+hlv!(
+    /// `HLVX.HU` instruction wrapper (hypervisor extension)
+    ///
+    /// Loads an unsigned halfword from the guest virtual address `addr` (see [`hlv_b`]), checking
+    /// execute permission instead of read permission (e.g., to read a guest instruction).
+    hlvx_hu, u16, 0x32, x3
+);
+hlv!(
+    /// `HLV.W` instruction wrapper (hypervisor extension)
+    ///
+    /// Loads a sign-extended word from the guest virtual address `addr` (see [`hlv_b`]).
+    hlv_w, i32, 0x34, x0
+);
+hlv!(
+    /// `HLVX.WU` instruction wrapper (hypervisor extension)
+    ///
+    /// Loads an unsigned word from the guest virtual address `addr` (see [`hlv_b`]), checking
+    /// execute permission instead of read permission (e.g., to read a guest instruction).
+    hlvx_wu, u32, 0x34, x3
+);
+hlv!(
+    /// `HLV.WU` instruction wrapper (hypervisor extension, RV64 only)
+    ///
+    /// Loads an unsigned word from the guest virtual address `addr` (see [`hlv_b`]).
+    #[cfg(target_pointer_width = "64")]
+    hlv_wu, u32, 0x34, x1
+);
+hlv!(
+    /// `HLV.D` instruction wrapper (hypervisor extension, RV64 only)
+    ///
+    /// Loads a doubleword from the guest virtual address `addr` (see [`hlv_b`]).
+    #[cfg(target_pointer_width = "64")]
+    hlv_d, u64, 0x36, x0
+);
+
+hsv!(
+    /// `HSV.B` instruction wrapper (hypervisor extension)
+    ///
+    /// Stores a byte to the guest virtual address `addr`, with the address translation and
+    /// protection of the virtual machine (see [`hlv_b`]).
+    hsv_b, u8, 0x31
+);
+hsv!(
+    /// `HSV.H` instruction wrapper (hypervisor extension)
+    ///
+    /// Stores a halfword to the guest virtual address `addr`, with the address translation and
+    /// protection of the virtual machine (see [`hlv_b`]).
+    hsv_h, u16, 0x33
+);
+hsv!(
+    /// `HSV.W` instruction wrapper (hypervisor extension)
+    ///
+    /// Stores a word to the guest virtual address `addr`, with the address translation and
+    /// protection of the virtual machine (see [`hlv_b`]).
+    hsv_w, u32, 0x35
+);
+hsv!(
+    /// `HSV.D` instruction wrapper (hypervisor extension, RV64 only)
+    ///
+    /// Stores a doubleword to the guest virtual address `addr`, with the address translation and
+    /// protection of the virtual machine (see [`hlv_b`]).
+    #[cfg(target_pointer_width = "64")]
+    hsv_d, u64, 0x37
+);
+
 /// `ECALL` instruction wrapper
 ///
 /// Generates an exception for a service request to the execution environment.
@@ -132,6 +541,193 @@ pub unsafe fn ecall() {
     }
 }
 
+// The vector configuration instructions are emitted with `.insn`, as assemblers may not enable
+// the extension. `vsetvli` takes `vtype` as an immediate, so the wrappers use `vsetvl` instead,
+// with a `Vtype` value built at runtime.
+
+/// `VSETVL` instruction wrapper (V extension)
+///
+/// Requests an application vector length of `avl` elements with the configuration `vtype`,
+/// and returns the granted vector length (i.e., the number of elements that vector instructions
+/// process). If the configuration is not supported, `vtype.vill` is set and it returns 0.
+///
+/// # Example
+///
+/// ```no_run
+/// use riscv::asm::vsetvl;
+/// use riscv::register::vtype::{Lmul, Sew, Vtype};
+///
+/// let mut remaining = 1000;
+/// while remaining > 0 {
+///     let vl = unsafe { vsetvl(remaining, Vtype::new(Sew::E32, Lmul::M1, true, true)) };
+///     // process `vl` elements
+///     remaining -= vl;
+/// }
+/// ```
+///
+/// # Safety
+///
+/// The vector unit must be enabled (i.e., the `VS` field of `mstatus` or `sstatus` must not be off),
+/// and the new configuration must not break vector code that expects the previous one.
+#[cfg(feature = "v-ext")]
+#[inline]
+#[allow(unused_variables)]
+pub unsafe fn vsetvl(avl: usize, vtype: crate::register::vtype::Vtype) -> usize {
+    match () {
+        #[cfg(riscv)]
+        () => {
+            let vl: usize;
+            core::arch::asm!(
+                ".insn r 0x57, 7, 0x40, {0}, {1}, {2}",
+                out(reg) vl,
+                in(reg) avl,
+                in(reg) vtype.bits(),
+            );
+            vl
+        }
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+/// `VSETVL` instruction wrapper (V extension, maximum vector length)
+///
+/// Sets the configuration `vtype` with the maximum vector length (i.e., `VLMAX`), and returns it.
+///
+/// # Safety
+///
+/// See [`vsetvl`].
+#[cfg(feature = "v-ext")]
+#[inline]
+#[allow(unused_variables)]
+pub unsafe fn vsetvlmax(vtype: crate::register::vtype::Vtype) -> usize {
+    match () {
+        #[cfg(riscv)]
+        () => {
+            let vl: usize;
+            core::arch::asm!(
+                ".insn r 0x57, 7, 0x40, {0}, x0, {1}",
+                out(reg) vl,
+                in(reg) vtype.bits(),
+            );
+            vl
+        }
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+// The shadow stack instructions are emitted with `.insn`, as assemblers may not enable the extension.
+// `sspush`, `sspopchk`, and `ssrdp` are encoded as may-be-operations, so they behave as no-ops
+// (and `ssrdp` returns 0) on harts without the Zicfiss extension or with shadow stacks disabled.
+// `ssamoswap` is an AMO instruction instead, and it always requires active shadow stacks.
+
+/// `SSPUSH x1` instruction wrapper (Zicfiss)
+///
+/// Pushes `value` to the shadow stack, and decrements `ssp`.
+///
+/// # Safety
+///
+/// The shadow stack must have room for the new entry, and the pushed value must be popped
+/// (e.g., with [`sspopchk`]) before the current function returns.
+#[cfg(feature = "zicfiss")]
+#[inline]
+#[allow(unused_variables)]
+pub unsafe fn sspush(value: usize) {
+    match () {
+        #[cfg(riscv)]
+        () => {
+            core::arch::asm!(".insn r 0x73, 4, 0x67, x0, x0, x1", in("x1") value, options(nostack))
+        }
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+/// `SSPOPCHK x1` instruction wrapper (Zicfiss)
+///
+/// Pops an entry from the shadow stack, and increments `ssp`.
+/// If the entry does not equal `expected`, it raises a software-check exception.
+///
+/// # Safety
+///
+/// The popped entry must have been pushed by the caller (e.g., with [`sspush`]).
+#[cfg(feature = "zicfiss")]
+#[inline]
+#[allow(unused_variables)]
+pub unsafe fn sspopchk(expected: usize) {
+    match () {
+        // the immediate is 0xCDC, sign-extended
+        #[cfg(riscv)]
+        () => {
+            core::arch::asm!(".insn i 0x73, 4, x0, x1, -0x324", in("x1") expected, options(nostack))
+        }
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+/// `SSRDP` instruction wrapper (Zicfiss)
+///
+/// Returns the shadow stack pointer, or 0 if shadow stacks are disabled.
+/// Unlike reading the [`ssp`](crate::register::ssp) CSR, it never raises an exception.
+#[cfg(feature = "zicfiss")]
+#[inline]
+pub fn ssrdp() -> usize {
+    match () {
+        #[cfg(riscv)]
+        () => {
+            let ssp: usize;
+            // the immediate is 0xCDC, sign-extended
+            unsafe {
+                core::arch::asm!(".insn i 0x73, 4, {0}, x0, -0x324", out(reg) ssp, options(nomem, nostack))
+            };
+            ssp
+        }
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+/// `SSAMOSWAP` instruction wrapper (Zicfiss)
+///
+/// Atomically swaps the shadow stack entry at `addr` with `value`, and returns the previous entry.
+/// It is used to switch shadow stacks with restore tokens.
+///
+/// Unlike the other shadow stack instructions, it is not a may-be-operation: it raises an
+/// illegal-instruction exception on harts without the Zicfiss extension or with shadow stacks
+/// disabled for the current privilege mode.
+///
+/// # Safety
+///
+/// `addr` must point to a shadow stack page, and the swap must not corrupt the shadow stack of
+/// any context.
+#[cfg(feature = "zicfiss")]
+#[inline]
+#[allow(unused_variables)]
+pub unsafe fn ssamoswap(addr: *mut usize, value: usize) -> usize {
+    match () {
+        #[cfg(riscv)]
+        () => {
+            let prev: usize;
+            // ssamoswap.{w,d}.aqrl
+            #[cfg(target_pointer_width = "32")]
+            core::arch::asm!(".insn r 0x2F, 2, 0x27, {0}, {1}, {2}", out(reg) prev, in(reg) addr, in(reg) value, options(nostack));
+            #[cfg(target_pointer_width = "64")]
+            core::arch::asm!(".insn r 0x2F, 3, 0x27, {0}, {1}, {2}", out(reg) prev, in(reg) addr, in(reg) value, options(nostack));
+            prev
+        }
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
 /// Blocks the program for *at least* `cycles` CPU cycles.
 ///
 /// This is implemented in assembly so its execution time is independent of the optimization
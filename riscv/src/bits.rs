@@ -1,3 +1,5 @@
+use crate::result::{Error, Result};
+
 /// Insert a new value into a bitfield
 ///
 /// `value` is masked to `width` bits and inserted into `orig`.`
@@ -15,3 +17,34 @@ pub fn bf_extract(orig: usize, bit: usize, width: usize) -> usize {
     let mask = (1 << width) - 1;
     (orig >> bit) & mask
 }
+
+/// Returns the mask of bit `index` of a register, if `index` is in the range `min` to `max`
+///
+/// The mask is 64 bits wide, so it also covers the 64-bit registers of RV32 targets
+/// (e.g., the `hvien`/`hvienh` pair). `max` must be less than 64.
+///
+/// Returns an error if `index` is not in the range.
+#[inline]
+pub fn range_bit_mask(index: usize, min: usize, max: usize) -> Result<u64> {
+    if (min..=max).contains(&index) {
+        Ok(1 << index)
+    } else {
+        Err(Error::IndexOutOfBounds { index, min, max })
+    }
+}
+
+/// Returns the mask of a single bit of an XLEN-wide register
+///
+/// Returns an error if `index` does not fit in the register.
+#[inline]
+pub fn bit_mask(index: usize) -> Result<usize> {
+    range_bit_mask(index, 0, usize::BITS as usize - 1).map(|mask| mask as usize)
+}
+
+/// Returns the mask of a platform-custom interrupt bit (16 to XLEN-1) of an interrupt register
+///
+/// Returns an error if `index` is not in the platform-custom range.
+#[inline]
+pub fn custom_bit_mask(index: usize) -> Result<usize> {
+    range_bit_mask(index, 16, usize::BITS as usize - 1).map(|mask| mask as usize)
+}
@@ -0,0 +1,345 @@
+//! Scalar cryptography instructions
+//!
+//! Wrappers around the instructions of the scalar cryptography extensions:
+//!
+//! - `zkne` feature: AES encryption (Zkne), e.g., `aes64es`.
+//! - `zknd` feature: AES decryption (Zknd), e.g., `aes64ds`.
+//! - `zknh` feature: SHA-2 hash functions (Zknh), e.g., `sha256sig0`.
+//!
+//! The AES instructions are only available on RV64 targets. The SHA-512 instructions have
+//! different forms on RV32 (e.g., `sha512sig0l` and `sha512sig0h`) and RV64 (e.g., `sha512sig0`) targets.
+//!
+//! These wrappers use the `.insn` directive, so the assembler does not need to support the extensions.
+//! Executing them on a hart without the corresponding extension raises an illegal-instruction exception.
+
+#[cfg(all(any(feature = "zkne", feature = "zknd"), target_pointer_width = "64"))]
+use crate::result::{Error, Result};
+
+/// Generates a wrapper of an instruction with one source register
+macro_rules! unary {
+    ($(#[$attr:meta])* $fnname:ident($ty:ty), $imm:literal) => {
+        $(#[$attr])*
+        #[inline]
+        #[allow(unused_variables)]
+        pub fn $fnname(rs1: $ty) -> $ty {
+            match () {
+                #[cfg(riscv)]
+                () => {
+                    let rd: usize;
+                    unsafe {
+                        core::arch::asm!(
+                            concat!(".insn i 0x13, 1, {0}, {1}, ", stringify!($imm)),
+                            out(reg) rd,
+                            in(reg) rs1 as usize,
+                            options(pure, nomem, nostack),
+                        )
+                    };
+                    rd as $ty
+                }
+
+                #[cfg(not(riscv))]
+                () => unimplemented!(),
+            }
+        }
+    };
+}
+
+/// Generates a wrapper of an instruction with two source registers
+macro_rules! binary {
+    ($(#[$attr:meta])* $fnname:ident($ty:ty), $funct7:literal) => {
+        $(#[$attr])*
+        #[inline]
+        #[allow(unused_variables)]
+        pub fn $fnname(rs1: $ty, rs2: $ty) -> $ty {
+            match () {
+                #[cfg(riscv)]
+                () => {
+                    let rd: usize;
+                    unsafe {
+                        core::arch::asm!(
+                            concat!(".insn r 0x33, 0, ", stringify!($funct7), ", {0}, {1}, {2}"),
+                            out(reg) rd,
+                            in(reg) rs1 as usize,
+                            in(reg) rs2 as usize,
+                            options(pure, nomem, nostack),
+                        )
+                    };
+                    rd as $ty
+                }
+
+                #[cfg(not(riscv))]
+                () => unimplemented!(),
+            }
+        }
+    };
+}
+
+binary!(
+    /// `AES64ES` instruction wrapper (Zkne)
+    ///
+    /// Performs the `ShiftRows` and `SubBytes` steps of an AES final encryption round on the
+    /// state held by `rs1` (low half) and `rs2` (high half), and returns the low half of the result.
+    #[cfg(all(feature = "zkne", target_pointer_width = "64"))]
+    aes64es(u64),
+    0x19
+);
+
+binary!(
+    /// `AES64ESM` instruction wrapper (Zkne)
+    ///
+    /// Like [`aes64es`], followed by the `MixColumns` step of a middle encryption round.
+    #[cfg(all(feature = "zkne", target_pointer_width = "64"))]
+    aes64esm(u64),
+    0x1B
+);
+
+binary!(
+    /// `AES64DS` instruction wrapper (Zknd)
+    ///
+    /// Performs the `InvShiftRows` and `InvSubBytes` steps of an AES final decryption round on the
+    /// state held by `rs1` (low half) and `rs2` (high half), and returns the low half of the result.
+    #[cfg(all(feature = "zknd", target_pointer_width = "64"))]
+    aes64ds(u64),
+    0x1D
+);
+
+binary!(
+    /// `AES64DSM` instruction wrapper (Zknd)
+    ///
+    /// Like [`aes64ds`], followed by the `InvMixColumns` step of a middle decryption round.
+    #[cfg(all(feature = "zknd", target_pointer_width = "64"))]
+    aes64dsm(u64),
+    0x1F
+);
+
+unary!(
+    /// `AES64IM` instruction wrapper (Zknd)
+    ///
+    /// Applies the `InvMixColumns` step to two columns of a round key, for the equivalent inverse cipher.
+    #[cfg(all(feature = "zknd", target_pointer_width = "64"))]
+    aes64im(u64),
+    0x300
+);
+
+binary!(
+    /// `AES64KS2` instruction wrapper (Zkne or Zknd)
+    ///
+    /// Second step of the AES key schedule (see [`aes64ks1i`]).
+    #[cfg(all(any(feature = "zkne", feature = "zknd"), target_pointer_width = "64"))]
+    aes64ks2(u64),
+    0x3F
+);
+
+/// `AES64KS1I` instruction wrapper (Zkne or Zknd)
+///
+/// First step of the AES key schedule, for the round number `rnum` (`0..=10`).
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `rnum` out of bounds
+#[cfg(all(any(feature = "zkne", feature = "zknd"), target_pointer_width = "64"))]
+#[inline]
+pub fn aes64ks1i(rs1: u64, rnum: u8) -> u64 {
+    try_aes64ks1i(rs1, rnum).unwrap()
+}
+
+/// Attempts to execute the `AES64KS1I` instruction (Zkne or Zknd)
+///
+/// Returns [`Error::IndexOutOfBounds`] if `rnum` is greater than 10,
+/// or [`Error::Unimplemented`] on non-`riscv` targets.
+#[cfg(all(any(feature = "zkne", feature = "zknd"), target_pointer_width = "64"))]
+#[inline]
+#[allow(unused_variables)]
+pub fn try_aes64ks1i(rs1: u64, rnum: u8) -> Result<u64> {
+    if rnum > 10 {
+        return Err(Error::IndexOutOfBounds {
+            index: rnum as usize,
+            min: 0,
+            max: 10,
+        });
+    }
+    match () {
+        #[cfg(riscv)]
+        () => {
+            // the round number is an immediate, so each value needs its own instruction
+            macro_rules! ks1i {
+                ($($rnum:literal => $imm:literal),*) => {
+                    match rnum {
+                        $($rnum => {
+                            let rd: u64;
+                            unsafe {
+                                core::arch::asm!(
+                                    concat!(".insn i 0x13, 1, {0}, {1}, ", stringify!($imm)),
+                                    out(reg) rd,
+                                    in(reg) rs1,
+                                    options(pure, nomem, nostack),
+                                )
+                            };
+                            Ok(rd)
+                        })*
+                        _ => unreachable!(),
+                    }
+                };
+            }
+            ks1i!(
+                0 => 0x310, 1 => 0x311, 2 => 0x312, 3 => 0x313, 4 => 0x314, 5 => 0x315,
+                6 => 0x316, 7 => 0x317, 8 => 0x318, 9 => 0x319, 10 => 0x31A
+            )
+        }
+
+        #[cfg(not(riscv))]
+        () => Err(Error::Unimplemented),
+    }
+}
+
+unary!(
+    /// `SHA256SIG0` instruction wrapper (Zknh)
+    ///
+    /// SHA-256 message schedule function σ0.
+    #[cfg(feature = "zknh")]
+    sha256sig0(u32),
+    0x102
+);
+
+unary!(
+    /// `SHA256SIG1` instruction wrapper (Zknh)
+    ///
+    /// SHA-256 message schedule function σ1.
+    #[cfg(feature = "zknh")]
+    sha256sig1(u32),
+    0x103
+);
+
+unary!(
+    /// `SHA256SUM0` instruction wrapper (Zknh)
+    ///
+    /// SHA-256 compression function Σ0.
+    #[cfg(feature = "zknh")]
+    sha256sum0(u32),
+    0x100
+);
+
+unary!(
+    /// `SHA256SUM1` instruction wrapper (Zknh)
+    ///
+    /// SHA-256 compression function Σ1.
+    #[cfg(feature = "zknh")]
+    sha256sum1(u32),
+    0x101
+);
+
+unary!(
+    /// `SHA512SIG0` instruction wrapper (Zknh, RV64)
+    ///
+    /// SHA-512 message schedule function σ0.
+    #[cfg(all(feature = "zknh", target_pointer_width = "64"))]
+    sha512sig0(u64),
+    0x106
+);
+
+unary!(
+    /// `SHA512SIG1` instruction wrapper (Zknh, RV64)
+    ///
+    /// SHA-512 message schedule function σ1.
+    #[cfg(all(feature = "zknh", target_pointer_width = "64"))]
+    sha512sig1(u64),
+    0x107
+);
+
+unary!(
+    /// `SHA512SUM0` instruction wrapper (Zknh, RV64)
+    ///
+    /// SHA-512 compression function Σ0.
+    #[cfg(all(feature = "zknh", target_pointer_width = "64"))]
+    sha512sum0(u64),
+    0x104
+);
+
+unary!(
+    /// `SHA512SUM1` instruction wrapper (Zknh, RV64)
+    ///
+    /// SHA-512 compression function Σ1.
+    #[cfg(all(feature = "zknh", target_pointer_width = "64"))]
+    sha512sum1(u64),
+    0x105
+);
+
+binary!(
+    /// `SHA512SIG0L` instruction wrapper (Zknh, RV32)
+    ///
+    /// Low half of the SHA-512 message schedule function σ0, for the value `rs2:rs1`.
+    #[cfg(all(feature = "zknh", target_pointer_width = "32"))]
+    sha512sig0l(u32),
+    0x2A
+);
+
+binary!(
+    /// `SHA512SIG0H` instruction wrapper (Zknh, RV32)
+    ///
+    /// High half of the SHA-512 message schedule function σ0, for the value `rs1:rs2`.
+    #[cfg(all(feature = "zknh", target_pointer_width = "32"))]
+    sha512sig0h(u32),
+    0x2E
+);
+
+binary!(
+    /// `SHA512SIG1L` instruction wrapper (Zknh, RV32)
+    ///
+    /// Low half of the SHA-512 message schedule function σ1, for the value `rs2:rs1`.
+    #[cfg(all(feature = "zknh", target_pointer_width = "32"))]
+    sha512sig1l(u32),
+    0x2B
+);
+
+binary!(
+    /// `SHA512SIG1H` instruction wrapper (Zknh, RV32)
+    ///
+    /// High half of the SHA-512 message schedule function σ1, for the value `rs1:rs2`.
+    #[cfg(all(feature = "zknh", target_pointer_width = "32"))]
+    sha512sig1h(u32),
+    0x2F
+);
+
+binary!(
+    /// `SHA512SUM0R` instruction wrapper (Zknh, RV32)
+    ///
+    /// Half of the SHA-512 compression function Σ0: returns the low half for the value `rs2:rs1`,
+    /// or the high half for the value `rs1:rs2`.
+    #[cfg(all(feature = "zknh", target_pointer_width = "32"))]
+    sha512sum0r(u32),
+    0x28
+);
+
+binary!(
+    /// `SHA512SUM1R` instruction wrapper (Zknh, RV32)
+    ///
+    /// Half of the SHA-512 compression function Σ1: returns the low half for the value `rs2:rs1`,
+    /// or the high half for the value `rs1:rs2`.
+    #[cfg(all(feature = "zknh", target_pointer_width = "32"))]
+    sha512sum1r(u32),
+    0x29
+);
+
+#[cfg(all(
+    test,
+    any(feature = "zkne", feature = "zknd"),
+    target_pointer_width = "64"
+))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aes64ks1i() {
+        assert_eq!(
+            try_aes64ks1i(0, 11),
+            Err(Error::IndexOutOfBounds {
+                index: 11,
+                min: 0,
+                max: 10,
+            })
+        );
+        #[cfg(not(riscv))]
+        assert_eq!(try_aes64ks1i(0, 10), Err(Error::Unimplemented));
+    }
+}
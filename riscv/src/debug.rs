@@ -0,0 +1,217 @@
+//! Hardware breakpoints and watchpoints
+//!
+//! Convenience functions on top of the Sdtrig trigger CSRs (see [`tdata1`]) for debug monitors.
+//! Each breakpoint or watchpoint uses a free address match trigger, which raises a breakpoint
+//! exception when it fires.
+//!
+//! A trigger is considered free if it is disabled or if it is a match trigger that does not match
+//! any access. Triggers reserved for the external debugger (i.e., with `dmode` set) are never used.
+//!
+//! Note that, in M-mode, match triggers only raise breakpoint exceptions if enabled in `tcontrol`.
+
+use crate::register::tdata1::{self, AccessKind, Match, MatchTrigger, Tdata1, TriggerType};
+use crate::register::{tdata2, tselect};
+use crate::result::{Error, Result};
+
+/// Maximum number of triggers that are looked up
+const MAX_TRIGGERS: usize = 64;
+
+/// Sets a breakpoint at `addr`.
+///
+/// Returns the index of the trigger that holds the breakpoint.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - no trigger available
+#[inline]
+pub unsafe fn set_breakpoint(addr: usize) -> usize {
+    try_set_breakpoint(addr).unwrap()
+}
+
+/// Attempts to set a breakpoint at `addr`.
+///
+/// Returns the index of the trigger that holds the breakpoint, or
+/// [`Error::IndexOutOfBounds`] if no trigger is available.
+#[inline]
+pub unsafe fn try_set_breakpoint(addr: usize) -> Result<usize> {
+    try_set_trigger(MatchTrigger::new(AccessKind::Execute), addr)
+}
+
+/// Sets a watchpoint on the `len` bytes from `addr`.
+///
+/// Returns the index of the trigger that holds the watchpoint.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - invalid watchpoint range (see [`try_set_watchpoint`])
+/// - no trigger available
+#[inline]
+pub unsafe fn set_watchpoint(addr: usize, len: usize, access: AccessKind) -> usize {
+    try_set_watchpoint(addr, len, access).unwrap()
+}
+
+/// Attempts to set a watchpoint on the `len` bytes from `addr`.
+///
+/// Returns the index of the trigger that holds the watchpoint, or an error if:
+///
+/// - `len` is not a power of two, or `addr` is not aligned to `len`.
+/// - no trigger is available ([`Error::IndexOutOfBounds`]).
+///
+/// Note that the triggers may limit the size of the watched range.
+#[inline]
+pub unsafe fn try_set_watchpoint(addr: usize, len: usize, access: AccessKind) -> Result<usize> {
+    let (condition, tdata2) = watch_range(addr, len)?;
+    let mut trigger = MatchTrigger::new(access);
+    trigger.condition = condition;
+    try_set_trigger(trigger, tdata2)
+}
+
+/// Clears the breakpoint or watchpoint held by the trigger `index`.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` out of bounds
+#[inline]
+pub unsafe fn clear(index: usize) {
+    try_clear(index).unwrap();
+}
+
+/// Attempts to clear the breakpoint or watchpoint held by the trigger `index`.
+///
+/// Returns [`Error::IndexOutOfBounds`] if the trigger does not exist.
+#[inline]
+pub unsafe fn try_clear(index: usize) -> Result<()> {
+    if !try_select(index)? {
+        return Err(Error::IndexOutOfBounds {
+            index,
+            min: 0,
+            max: try_count()?.saturating_sub(1),
+        });
+    }
+    tdata1::try_write(Tdata1::from(0))
+}
+
+/// Returns the match condition and `tdata2` value that watch the `len` bytes from `addr`.
+fn watch_range(addr: usize, len: usize) -> Result<(Match, usize)> {
+    if !len.is_power_of_two() {
+        Err(Error::InvalidFieldValue {
+            field: "len",
+            value: len,
+            bitmask: !0,
+        })
+    } else if addr & (len - 1) != 0 {
+        Err(Error::InvalidFieldValue {
+            field: "addr",
+            value: addr,
+            bitmask: !(len - 1),
+        })
+    } else if len == 1 {
+        Ok((Match::Equal, addr))
+    } else {
+        Ok((Match::Napot, addr | ((len >> 1) - 1)))
+    }
+}
+
+/// Selects the trigger `index`, and returns false if it does not exist.
+fn try_select(index: usize) -> Result<bool> {
+    tselect::try_write(index)?;
+    Ok(tselect::try_read()? == index
+        && tdata1::try_read()?.try_trigger_type() != Ok(TriggerType::None))
+}
+
+/// Returns the number of triggers.
+fn try_count() -> Result<usize> {
+    for index in 0..MAX_TRIGGERS {
+        if !try_select(index)? {
+            return Ok(index);
+        }
+    }
+    Ok(MAX_TRIGGERS)
+}
+
+/// Returns true if the selected trigger is free.
+fn is_free(tdata1: Tdata1) -> bool {
+    match tdata1.try_trigger_type() {
+        _ if tdata1.dmode() => false,
+        Ok(TriggerType::Disabled) => true,
+        Ok(TriggerType::Mcontrol | TriggerType::Mcontrol6) => tdata1.bits() & 0b111 == 0,
+        _ => false,
+    }
+}
+
+/// Programs `trigger` with `tdata2` in the first free trigger.
+fn try_set_trigger(trigger: MatchTrigger, tdata2: usize) -> Result<usize> {
+    for index in 0..MAX_TRIGGERS {
+        if !try_select(index)? {
+            return Err(Error::IndexOutOfBounds {
+                index,
+                min: 0,
+                max: index.saturating_sub(1),
+            });
+        }
+        if !is_free(tdata1::try_read()?) {
+            continue;
+        }
+        // disable the trigger while it is being configured
+        tdata1::try_write(Tdata1::from(0))?;
+        tdata2::try_write(tdata2)?;
+        // unsupported trigger types are not accepted, so check which one the trigger took
+        for trigger_type in [TriggerType::Mcontrol6, TriggerType::Mcontrol] {
+            let tdata1 = trigger.encode(trigger_type)?;
+            tdata1::try_write(tdata1)?;
+            if tdata1::try_read()?.try_trigger_type() == Ok(trigger_type) {
+                return Ok(index);
+            }
+        }
+        tdata1::try_write(Tdata1::from(0))?;
+    }
+    Err(Error::IndexOutOfBounds {
+        index: MAX_TRIGGERS,
+        min: 0,
+        max: MAX_TRIGGERS - 1,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_watch_range() {
+        assert_eq!(watch_range(0x8000_0001, 1), Ok((Match::Equal, 0x8000_0001)));
+        assert_eq!(watch_range(0x8000_0000, 2), Ok((Match::Napot, 0x8000_0000)));
+        assert_eq!(
+            watch_range(0x8000_0100, 0x100),
+            Ok((Match::Napot, 0x8000_017F))
+        );
+        assert_eq!(
+            watch_range(0x8000_0000, 12),
+            Err(Error::InvalidFieldValue {
+                field: "len",
+                value: 12,
+                bitmask: !0,
+            })
+        );
+        assert_eq!(
+            watch_range(0x8000_0004, 8),
+            Err(Error::InvalidFieldValue {
+                field: "addr",
+                value: 0x8000_0004,
+                bitmask: !7,
+            })
+        );
+
+        assert!(is_free(Tdata1::from(15 << (usize::BITS - 4))));
+        assert!(is_free(Tdata1::from(6 << (usize::BITS - 4))));
+        assert!(!is_free(MatchTrigger::new(AccessKind::Load).mcontrol6()));
+        assert!(!is_free(Tdata1::from(0b11111 << (usize::BITS - 5))));
+
+        assert_eq!(
+            unsafe { try_set_breakpoint(0x8000_0000) },
+            Err(Error::Unimplemented)
+        );
+    }
+}
@@ -0,0 +1,197 @@
+//! Floating-point state save and restore
+//!
+//! Helpers to save and restore the floating-point registers (`f0`-`f31`) and `fcsr`,
+//! e.g., to switch the floating-point context of a thread in a trap handler.
+//!
+//! The registers are saved with their full width: 64 bits on targets with the D extension,
+//! and 32 bits on targets with only the F extension (see [`Freg`]).
+//!
+//! These helpers cooperate with the `FS` field of `mstatus` (or `sstatus` if the `s-mode` feature
+//! is enabled) for lazy context switching: [`save_if_dirty`] only saves the state if it has been
+//! modified since it was last saved or restored.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use riscv::fpu::{self, FpuState};
+//!
+//! let mut current = FpuState::default();
+//! let next = FpuState::default();
+//! // in the trap handler, before any floating-point code
+//! unsafe {
+//!     fpu::save_if_dirty(&mut current);
+//!     // ...
+//!     fpu::restore(&next);
+//! }
+//! ```
+
+use crate::register::fcsr::{self, Fcsr};
+#[cfg(not(feature = "s-mode"))]
+use crate::register::mstatus as status;
+use crate::register::mstatus::FS;
+#[cfg(feature = "s-mode")]
+use crate::register::sstatus as status;
+
+/// Contents of a floating-point register
+#[cfg(any(target_feature = "d", not(riscv)))]
+pub type Freg = u64;
+
+/// Contents of a floating-point register
+#[cfg(all(riscv, not(target_feature = "d")))]
+pub type Freg = u32;
+
+/// Floating-point state of a context
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(C)]
+pub struct FpuState {
+    /// Floating-point registers `f0`-`f31`
+    pub f: [Freg; 32],
+    /// Floating-point control and status register
+    pub fcsr: Fcsr,
+}
+
+impl Default for FpuState {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            f: [0; 32],
+            fcsr: Fcsr::from(0),
+        }
+    }
+}
+
+/// Saves the floating-point state to `state`, and marks it as [`FS::Clean`].
+///
+/// **WARNING**: panics on non-`riscv` targets.
+///
+/// # Safety
+///
+/// The floating-point unit must be enabled (i.e., the `FS` field must not be off), and
+/// floating-point code must not run between the trap entry and this call.
+#[inline]
+pub unsafe fn save(state: &mut FpuState) {
+    store_registers(state.f.as_mut_ptr());
+    state.fcsr = fcsr::read();
+    status::set_fs(FS::Clean);
+}
+
+/// Saves the floating-point state to `state` if it is [`FS::Dirty`].
+///
+/// Returns `true` if the state was saved.
+///
+/// **WARNING**: panics on non-`riscv` targets.
+///
+/// # Safety
+///
+/// Floating-point code must not run between the trap entry and this call.
+#[inline]
+pub unsafe fn save_if_dirty(state: &mut FpuState) -> bool {
+    let dirty = status::read().fs() == FS::Dirty;
+    if dirty {
+        save(state);
+    }
+    dirty
+}
+
+/// Restores the floating-point state from `state`, and marks it as [`FS::Clean`].
+///
+/// It enables the floating-point unit if it was off.
+///
+/// **WARNING**: panics on non-`riscv` targets.
+///
+/// # Safety
+///
+/// It overwrites the floating-point state of the current context. The restored state is only
+/// preserved until floating-point code runs, so this must be called right before returning from
+/// the trap handler.
+#[inline]
+pub unsafe fn restore(state: &FpuState) {
+    status::set_fs(FS::Clean);
+    load_registers(state.f.as_ptr());
+    fcsr::write(state.fcsr);
+    status::set_fs(FS::Clean);
+}
+
+/// Expands to the instructions that store or load `f0`-`f31` at offsets of `size` bytes from `{0}`
+#[cfg(riscv)]
+macro_rules! fregs {
+    ($insn:literal, $size:literal) => {
+        fregs!(
+            @ $insn, $size,
+            0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31
+        )
+    };
+    (@ $insn:literal, $size:literal, $($n:literal)*) => {
+        concat!($($insn, " f", $n, ", ", $n, "*", $size, "({0})\n",)*)
+    };
+}
+
+/// Stores `f0`-`f31` to `ptr`
+#[inline]
+#[allow(unused_variables)]
+unsafe fn store_registers(ptr: *mut Freg) {
+    match () {
+        #[cfg(all(riscv, target_feature = "d"))]
+        () => core::arch::asm!(fregs!("fsd", "8"), in(reg) ptr, options(nostack)),
+
+        #[cfg(all(riscv, not(target_feature = "d")))]
+        () => core::arch::asm!(fregs!("fsw", "4"), in(reg) ptr, options(nostack)),
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+/// Loads `f0`-`f31` from `ptr`
+#[inline]
+#[allow(unused_variables)]
+unsafe fn load_registers(ptr: *const Freg) {
+    // the callee-saved registers are not covered by `clobber_abi`
+    #[cfg(riscv)]
+    macro_rules! load {
+        ($insn:literal, $size:literal) => {
+            core::arch::asm!(
+                fregs!($insn, $size),
+                in(reg) ptr,
+                clobber_abi("C"),
+                out("f8") _,
+                out("f9") _,
+                out("f18") _,
+                out("f19") _,
+                out("f20") _,
+                out("f21") _,
+                out("f22") _,
+                out("f23") _,
+                out("f24") _,
+                out("f25") _,
+                out("f26") _,
+                out("f27") _,
+                options(nostack, readonly),
+            )
+        };
+    }
+
+    match () {
+        #[cfg(all(riscv, target_feature = "d"))]
+        () => load!("fld", "8"),
+
+        #[cfg(all(riscv, not(target_feature = "d")))]
+        () => load!("flw", "4"),
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fpu_state() {
+        let state = FpuState::default();
+        assert_eq!(state.f, [0; 32]);
+        assert_eq!(state.fcsr.bits(), 0);
+        assert_eq!(core::mem::size_of::<FpuState>(), 33 * 8);
+    }
+}
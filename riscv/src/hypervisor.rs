@@ -0,0 +1,162 @@
+//! Hypervisor helpers
+//!
+//! Helpers to flush the address-translation caches of a guest after its G-stage page tables are
+//! modified, e.g., when a virtual machine monitor unmaps guest physical memory.
+//!
+//! After updating the G-stage page table entries of a guest physical page, [`flush_guest`] with the
+//! guest physical address invalidates the G-stage translations of the page. Implementations that
+//! cache combined VS-stage and G-stage translations flush them on `HFENCE.GVMA`, so no VS-stage fence
+//! is needed. When tearing down a whole virtual machine (or before reusing its VMID), [`flush_guest`]
+//! without address also invalidates the VS-stage translations of the virtual machine.
+//!
+//! If the `svinval` feature is enabled, batches of pages are invalidated with the Svinval
+//! instructions (see [`flush_guest_pages`]).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use riscv::hypervisor;
+//!
+//! let (vmid, gpa) = (1, 0x8020_0000);
+//! // ... clear the G-stage page table entry of gpa ...
+//! unsafe { hypervisor::flush_guest(vmid, Some(gpa)) };
+//! ```
+
+use crate::asm::{hfence_gvma, hfence_vvma};
+#[cfg(feature = "svinval")]
+use crate::asm::{hinval_gvma, sfence_inval_ir, sfence_w_inval};
+use crate::register::hgatp::{self, Hgatp};
+use crate::result::{Error, Result};
+
+/// Flushes the address-translation caches of the guest physical address `gpa` of the virtual machine `vmid`.
+///
+/// If `gpa` is `None`, it flushes all the G-stage and VS-stage translations of the virtual machine.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `vmid` out of bounds
+/// - `vmid` not held by `hgatp` (e.g., in Bare mode)
+///
+/// # Safety
+///
+/// It must be executed in HS-mode. The G-stage page tables must be updated before this call.
+#[inline]
+pub unsafe fn flush_guest(vmid: usize, gpa: Option<usize>) {
+    try_flush_guest(vmid, gpa).unwrap();
+}
+
+/// Attempts to flush the address-translation caches of the guest physical address `gpa` of the virtual machine `vmid`.
+///
+/// If `gpa` is `None`, it flushes all the G-stage and VS-stage translations of the virtual machine.
+/// `HFENCE.VVMA` only applies to the virtual machine selected by `hgatp`, so `hgatp` is temporarily
+/// switched to `vmid` if needed. Returns [`Error::InvalidFieldValue`] if `vmid` does not fit in
+/// `hgatp`, or [`Error::InvalidFieldVariant`] if `hgatp` does not hold `vmid` after the switch
+/// (e.g., if the current `hgatp` mode is Bare).
+/// In this case, the G-stage translations are flushed, but the VS-stage translations are not.
+///
+/// # Safety
+///
+/// See [`flush_guest`].
+#[inline]
+pub unsafe fn try_flush_guest(vmid: usize, gpa: Option<usize>) -> Result<()> {
+    match gpa {
+        Some(gpa) => {
+            hfence_gvma(Some(vmid), Some(gpa >> 2));
+            Ok(())
+        }
+        None => {
+            let current = hgatp::try_read()?;
+            let guest = guest_hgatp(current, vmid)?;
+            hfence_gvma(Some(vmid), None);
+            if guest.bits() == current.bits() {
+                hfence_vvma(None, None);
+            } else {
+                // hgatp only takes effect with V=1, so it can be switched in HS-mode
+                hgatp::try_write(guest.bits())?;
+                // the VMID may not stick (e.g., in Bare mode or with fewer VMID bits)
+                let flushed = check_vmid(hgatp::try_read()?, vmid).map(|_| hfence_vvma(None, None));
+                hgatp::try_write(current.bits())?;
+                flushed?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Flushes the G-stage translations of the guest physical addresses `gpas` of the virtual machine `vmid`.
+///
+/// If the `svinval` feature is enabled, all the pages are invalidated in a single batch
+/// with `HINVAL.GVMA`, enclosed by `SFENCE.W.INVAL` and `SFENCE.INVAL.IR`.
+/// Otherwise, it executes an `HFENCE.GVMA` per page.
+///
+/// **WARNING**: panics on non-`riscv` targets.
+///
+/// # Safety
+///
+/// See [`flush_guest`].
+#[inline]
+pub unsafe fn flush_guest_pages<I: IntoIterator<Item = usize>>(vmid: usize, gpas: I) {
+    #[cfg(feature = "svinval")]
+    {
+        sfence_w_inval();
+        for gpa in gpas {
+            hinval_gvma(vmid, gpa >> 2);
+        }
+        sfence_inval_ir();
+    }
+    #[cfg(not(feature = "svinval"))]
+    for gpa in gpas {
+        hfence_gvma(Some(vmid), Some(gpa >> 2));
+    }
+}
+
+/// Returns the `hgatp` value that selects the virtual machine `vmid`, based on `hgatp`.
+#[inline]
+fn guest_hgatp(hgatp: Hgatp, vmid: usize) -> Result<Hgatp> {
+    Hgatp::new(hgatp.try_mode()?, vmid, hgatp.ppn())
+}
+
+/// Checks that `hgatp` selects the virtual machine `vmid`.
+#[inline]
+fn check_vmid(hgatp: Hgatp, vmid: usize) -> Result<()> {
+    if hgatp.vmid() == vmid {
+        Ok(())
+    } else {
+        Err(Error::InvalidFieldVariant {
+            field: "vmid",
+            value: vmid,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::register::hgatp::Mode;
+
+    #[test]
+    fn test_flush_guest() {
+        let current = Hgatp::new(Mode::Bare, 1, 0).unwrap();
+        let guest = guest_hgatp(current, 2).unwrap();
+        assert_eq!(guest.mode(), Mode::Bare);
+        assert_eq!(guest.vmid(), 2);
+        assert_eq!(guest.ppn(), 0);
+        assert!(guest_hgatp(current, usize::MAX).is_err());
+        assert_eq!(check_vmid(guest, 2), Ok(()));
+        // Bare mode may hardwire the VMID to zero
+        let bare = Hgatp::new(Mode::Bare, 0, 0).unwrap();
+        assert_eq!(
+            check_vmid(bare, 2),
+            Err(Error::InvalidFieldVariant {
+                field: "vmid",
+                value: 2
+            })
+        );
+
+        assert_eq!(
+            unsafe { try_flush_guest(1, None) },
+            Err(Error::Unimplemented)
+        );
+    }
+}
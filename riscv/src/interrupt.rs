@@ -2,6 +2,230 @@
 
 // NOTE: Adapted from cortex-m/src/interrupt.rs
 
+use crate::result::{Error, Result};
+use crate::{CoreInterruptNumber, ExceptionNumber, InterruptNumber};
+
+/// Trap Cause
+///
+/// Generic over the target-specific interrupt (`I`) and exception (`E`) types.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Trap<I, E> {
+    Interrupt(I),
+    Exception(E),
+}
+
+impl<I, E> Trap<I, E> {
+    /// Is trap cause an interrupt.
+    #[inline]
+    pub const fn is_interrupt(&self) -> bool {
+        matches!(self, Self::Interrupt(_))
+    }
+
+    /// Is trap cause an exception.
+    #[inline]
+    pub const fn is_exception(&self) -> bool {
+        matches!(self, Self::Exception(_))
+    }
+}
+
+impl<I: CoreInterruptNumber, E: ExceptionNumber> Trap<I, E> {
+    /// Attempts to decode a trap cause from the raw bits of an `xcause` register.
+    ///
+    /// Returns an error if the code field does not match any variant of `I` or `E`.
+    #[inline]
+    pub fn try_from_bits(bits: usize) -> Result<Self> {
+        let code = bits & !INTERRUPT_BIT;
+        let number = u16::try_from(code).map_err(|_| Error::InvalidVariant(code))?;
+        if bits & INTERRUPT_BIT != 0 {
+            Ok(Self::Interrupt(I::from_number(number)?))
+        } else {
+            Ok(Self::Exception(E::from_number(number)?))
+        }
+    }
+
+    /// Encodes the trap cause as the raw bits of an `xcause` register.
+    #[inline]
+    pub fn into_bits(self) -> usize {
+        match self {
+            Self::Interrupt(i) => i.number() as usize | INTERRUPT_BIT,
+            Self::Exception(e) => e.number() as usize,
+        }
+    }
+}
+
+impl<I: core::fmt::Display, E: core::fmt::Display> core::fmt::Display for Trap<I, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Interrupt(i) => i.fmt(f),
+            Self::Exception(e) => e.fmt(f),
+        }
+    }
+}
+
+/// Interrupt flag of the `xcause` registers (most significant bit)
+const INTERRUPT_BIT: usize = 1 << (usize::BITS as usize - 1);
+
+/// Standard core interrupts defined in the RISC-V privileged specification
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u16)]
+pub enum Interrupt {
+    SupervisorSoft = 1,
+    MachineSoft = 3,
+    SupervisorTimer = 5,
+    MachineTimer = 7,
+    SupervisorExternal = 9,
+    MachineExternal = 11,
+    /// Local counter overflow interrupt (Sscofpmf extension)
+    LocalCounterOverflow = 13,
+}
+
+unsafe impl InterruptNumber for Interrupt {
+    const MAX_INTERRUPT_NUMBER: u16 = Self::LocalCounterOverflow as u16;
+
+    #[inline]
+    fn number(self) -> u16 {
+        self as u16
+    }
+
+    #[inline]
+    fn from_number(value: u16) -> Result<Self> {
+        match value {
+            1 => Ok(Self::SupervisorSoft),
+            3 => Ok(Self::MachineSoft),
+            5 => Ok(Self::SupervisorTimer),
+            7 => Ok(Self::MachineTimer),
+            9 => Ok(Self::SupervisorExternal),
+            11 => Ok(Self::MachineExternal),
+            13 => Ok(Self::LocalCounterOverflow),
+            _ => Err(Error::InvalidVariant(value as usize)),
+        }
+    }
+}
+
+unsafe impl CoreInterruptNumber for Interrupt {}
+
+impl Interrupt {
+    /// Returns a human-readable description of the interrupt
+    #[inline]
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::SupervisorSoft => "Supervisor software interrupt",
+            Self::MachineSoft => "Machine software interrupt",
+            Self::SupervisorTimer => "Supervisor timer interrupt",
+            Self::MachineTimer => "Machine timer interrupt",
+            Self::SupervisorExternal => "Supervisor external interrupt",
+            Self::MachineExternal => "Machine external interrupt",
+            Self::LocalCounterOverflow => "Local counter overflow interrupt",
+        }
+    }
+}
+
+impl core::fmt::Display for Interrupt {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+/// Standard exceptions defined in the RISC-V privileged specification
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u16)]
+pub enum Exception {
+    InstructionMisaligned = 0,
+    InstructionFault = 1,
+    IllegalInstruction = 2,
+    Breakpoint = 3,
+    LoadMisaligned = 4,
+    LoadFault = 5,
+    StoreMisaligned = 6,
+    StoreFault = 7,
+    UserEnvCall = 8,
+    SupervisorEnvCall = 9,
+    VirtualSupervisorEnvCall = 10,
+    MachineEnvCall = 11,
+    InstructionPageFault = 12,
+    LoadPageFault = 13,
+    StorePageFault = 15,
+    SoftwareCheck = 18,
+    HardwareError = 19,
+    InstructionGuestPageFault = 20,
+    LoadGuestPageFault = 21,
+    VirtualInstruction = 22,
+    StoreGuestPageFault = 23,
+}
+
+unsafe impl ExceptionNumber for Exception {
+    const MAX_EXCEPTION_NUMBER: u16 = Self::StoreGuestPageFault as u16;
+
+    #[inline]
+    fn number(self) -> u16 {
+        self as u16
+    }
+
+    #[inline]
+    fn from_number(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(Self::InstructionMisaligned),
+            1 => Ok(Self::InstructionFault),
+            2 => Ok(Self::IllegalInstruction),
+            3 => Ok(Self::Breakpoint),
+            4 => Ok(Self::LoadMisaligned),
+            5 => Ok(Self::LoadFault),
+            6 => Ok(Self::StoreMisaligned),
+            7 => Ok(Self::StoreFault),
+            8 => Ok(Self::UserEnvCall),
+            9 => Ok(Self::SupervisorEnvCall),
+            10 => Ok(Self::VirtualSupervisorEnvCall),
+            11 => Ok(Self::MachineEnvCall),
+            12 => Ok(Self::InstructionPageFault),
+            13 => Ok(Self::LoadPageFault),
+            15 => Ok(Self::StorePageFault),
+            18 => Ok(Self::SoftwareCheck),
+            19 => Ok(Self::HardwareError),
+            20 => Ok(Self::InstructionGuestPageFault),
+            21 => Ok(Self::LoadGuestPageFault),
+            22 => Ok(Self::VirtualInstruction),
+            23 => Ok(Self::StoreGuestPageFault),
+            _ => Err(Error::InvalidVariant(value as usize)),
+        }
+    }
+}
+
+impl Exception {
+    /// Returns a human-readable description of the exception
+    #[inline]
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::InstructionMisaligned => "Instruction address misaligned",
+            Self::InstructionFault => "Instruction access fault",
+            Self::IllegalInstruction => "Illegal instruction",
+            Self::Breakpoint => "Breakpoint",
+            Self::LoadMisaligned => "Load address misaligned",
+            Self::LoadFault => "Load access fault",
+            Self::StoreMisaligned => "Store/AMO address misaligned",
+            Self::StoreFault => "Store/AMO access fault",
+            Self::UserEnvCall => "Environment call from U-mode",
+            Self::SupervisorEnvCall => "Environment call from S-mode",
+            Self::VirtualSupervisorEnvCall => "Environment call from VS-mode",
+            Self::MachineEnvCall => "Environment call from M-mode",
+            Self::InstructionPageFault => "Instruction page fault",
+            Self::LoadPageFault => "Load page fault",
+            Self::StorePageFault => "Store/AMO page fault",
+            Self::SoftwareCheck => "Software check",
+            Self::HardwareError => "Hardware error",
+            Self::InstructionGuestPageFault => "Instruction guest-page fault",
+            Self::LoadGuestPageFault => "Load guest-page fault",
+            Self::VirtualInstruction => "Virtual instruction",
+            Self::StoreGuestPageFault => "Store/AMO guest-page fault",
+        }
+    }
+}
+
+impl core::fmt::Display for Exception {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
 pub mod machine {
     use crate::register::{mepc, mstatus};
 
@@ -0,0 +1,489 @@
+//! ISA string parsing
+//!
+//! Platforms usually describe the extensions of a hart with an ISA string
+//! (e.g., `rv64imafdc_zicsr_zifencei_zba_zbb`), as found in the `riscv,isa` property of
+//! a device tree. This module parses ISA strings into an [`IsaExtensions`] set.
+//!
+//! Single-letter extensions are queried with the same API as [`Misa`], and an
+//! [`IsaExtensions`] set can also be obtained from a [`Misa`] value.
+
+use crate::register::misa::{Misa, XLEN};
+use core::{fmt, str::FromStr};
+
+/// Mask of the single-letter extensions (`A` to `Z`)
+const LETTERS_MASK: u32 = (1 << 26) - 1;
+
+/// Returns the bit of a single-letter extension, or `None` if `letter` is not an ASCII letter.
+#[inline]
+pub(crate) fn letter_bit(letter: char) -> Option<u32> {
+    let letter = letter.to_ascii_uppercase();
+    letter
+        .is_ascii_uppercase()
+        .then(|| 1 << (letter as u8 - b'A'))
+}
+
+/// Returns an iterator over the single-letter extensions of `bits`, in alphabetical order.
+#[inline]
+pub(crate) fn letters(bits: u32) -> impl Iterator<Item = char> {
+    (b'A'..=b'Z')
+        .filter(move |letter| bits & (1 << (letter - b'A')) != 0)
+        .map(char::from)
+}
+
+macro_rules! extensions {
+    ($($(#[$doc:meta])* $variant:ident => $name:literal,)*) => {
+        /// Multi-letter extensions
+        #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+        pub enum Extension {
+            $($(#[$doc])* $variant,)*
+        }
+
+        impl Extension {
+            /// All the known multi-letter extensions
+            pub const ALL: &'static [Self] = &[$(Self::$variant,)*];
+
+            /// Returns the name of the extension as it appears in an ISA string (e.g., `"zicsr"`)
+            #[inline]
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)*
+                }
+            }
+        }
+    };
+}
+
+extensions! {
+    /// Control and status register instructions
+    Zicsr => "zicsr",
+    /// Instruction-fetch fence
+    Zifencei => "zifencei",
+    /// Base counters and timers
+    Zicntr => "zicntr",
+    /// Hardware performance counters
+    Zihpm => "zihpm",
+    /// Cache-block management instructions
+    Zicbom => "zicbom",
+    /// Cache-block prefetch instructions
+    Zicbop => "zicbop",
+    /// Cache-block zero instructions
+    Zicboz => "zicboz",
+    /// Integer conditional operations
+    Zicond => "zicond",
+    /// Pause hint
+    Zihintpause => "zihintpause",
+    /// Non-temporal locality hints
+    Zihintntl => "zihintntl",
+    /// Landing pads (control-flow integrity)
+    Zicfilp => "zicfilp",
+    /// Shadow stacks (control-flow integrity)
+    Zicfiss => "zicfiss",
+    /// May-be-operations
+    Zimop => "zimop",
+    /// Integer multiplication
+    Zmmul => "zmmul",
+    /// Atomic memory operations
+    Zaamo => "zaamo",
+    /// Load-reserved/store-conditional
+    Zalrsc => "zalrsc",
+    /// Compare-and-swap
+    Zacas => "zacas",
+    /// Wait-on-reservation-set instructions
+    Zawrs => "zawrs",
+    /// Half-precision floating-point
+    Zfh => "zfh",
+    /// Minimal half-precision floating-point
+    Zfhmin => "zfhmin",
+    /// Additional floating-point instructions
+    Zfa => "zfa",
+    /// Single-precision floating-point in integer registers
+    Zfinx => "zfinx",
+    /// Double-precision floating-point in integer registers
+    Zdinx => "zdinx",
+    /// Address generation
+    Zba => "zba",
+    /// Basic bit-manipulation
+    Zbb => "zbb",
+    /// Carry-less multiplication
+    Zbc => "zbc",
+    /// Single-bit instructions
+    Zbs => "zbs",
+    /// Bit-manipulation for cryptography
+    Zbkb => "zbkb",
+    /// Carry-less multiplication for cryptography
+    Zbkc => "zbkc",
+    /// Crossbar permutations
+    Zbkx => "zbkx",
+    /// AES decryption
+    Zknd => "zknd",
+    /// AES encryption
+    Zkne => "zkne",
+    /// SHA-2 hash functions
+    Zknh => "zknh",
+    /// Entropy source
+    Zkr => "zkr",
+    /// SM4 block cipher
+    Zksed => "zksed",
+    /// SM3 hash function
+    Zksh => "zksh",
+    /// Data-independent execution latency
+    Zkt => "zkt",
+    /// Compressed integer instructions
+    Zca => "zca",
+    /// Additional compressed instructions
+    Zcb => "zcb",
+    /// Compressed double-precision loads and stores
+    Zcd => "zcd",
+    /// Compressed single-precision loads and stores
+    Zcf => "zcf",
+    /// Compressed push/pop and move instructions
+    Zcmp => "zcmp",
+    /// Compressed table jumps
+    Zcmt => "zcmt",
+    /// Vector for embedded processors (integer, 32-bit elements)
+    Zve32x => "zve32x",
+    /// Vector for embedded processors (single-precision, 32-bit elements)
+    Zve32f => "zve32f",
+    /// Vector for embedded processors (integer, 64-bit elements)
+    Zve64x => "zve64x",
+    /// Vector for embedded processors (single-precision, 64-bit elements)
+    Zve64f => "zve64f",
+    /// Vector for embedded processors (double-precision, 64-bit elements)
+    Zve64d => "zve64d",
+    /// Vector half-precision floating-point
+    Zvfh => "zvfh",
+    /// Vector basic bit-manipulation
+    Zvbb => "zvbb",
+    /// Vector carry-less multiplication
+    Zvbc => "zvbc",
+    /// Vector cryptography bit-manipulation
+    Zvkb => "zvkb",
+    /// Vector GCM/GMAC
+    Zvkg => "zvkg",
+    /// Vector AES block cipher
+    Zvkned => "zvkned",
+    /// Vector SHA-2 secure hash (SHA-256)
+    Zvknha => "zvknha",
+    /// Vector SHA-2 secure hash (SHA-256 and SHA-512)
+    Zvknhb => "zvknhb",
+    /// Vector SM4 block cipher
+    Zvksed => "zvksed",
+    /// Vector SM3 secure hash
+    Zvksh => "zvksh",
+    /// Vector data-independent execution latency
+    Zvkt => "zvkt",
+    /// Advanced interrupt architecture (machine level)
+    Smaia => "smaia",
+    /// Enhanced PMP
+    Smepmp => "smepmp",
+    /// Pointer masking for M-mode
+    Smmpm => "smmpm",
+    /// Pointer masking for the next-lower privilege mode of M-mode
+    Smnpm => "smnpm",
+    /// Resumable non-maskable interrupts
+    Smrnmi => "smrnmi",
+    /// State-enable (machine level)
+    Smstateen => "smstateen",
+    /// Advanced interrupt architecture (supervisor level)
+    Ssaia => "ssaia",
+    /// Count overflow and mode-based filtering
+    Sscofpmf => "sscofpmf",
+    /// Pointer masking for the next-lower privilege mode of S-mode
+    Ssnpm => "ssnpm",
+    /// State-enable (supervisor level)
+    Ssstateen => "ssstateen",
+    /// Supervisor-mode timer interrupts
+    Sstc => "sstc",
+    /// Hardware updating of `A`/`D` bits
+    Svadu => "svadu",
+    /// Fine-grained address-translation cache invalidation
+    Svinval => "svinval",
+    /// NAPOT translation contiguity
+    Svnapot => "svnapot",
+    /// Page-based memory types
+    Svpbmt => "svpbmt",
+    /// Debug triggers
+    Sdtrig => "sdtrig",
+    /// External debug
+    Sdext => "sdext",
+}
+
+const _: () = assert!(Extension::ALL.len() <= u128::BITS as usize);
+
+impl Extension {
+    /// Looks up a multi-letter extension by its (case-insensitive) name
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|extension| extension.name().eq_ignore_ascii_case(name))
+    }
+
+    #[inline]
+    const fn mask(self) -> u128 {
+        1 << self as u32
+    }
+}
+
+impl fmt::Display for Extension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// ISA string parsing error
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IsaError {
+    /// Missing or invalid `rv32`, `rv64` or `rv128` prefix
+    InvalidXlen,
+    /// Missing or invalid base ISA (i.e., not `i`, `e` or `g`)
+    InvalidBase,
+    /// Invalid character in the single-letter extensions
+    InvalidLetter(char),
+    /// Malformed multi-letter extension
+    InvalidExtension,
+}
+
+impl fmt::Display for IsaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidXlen => f.write_str("invalid base integer ISA width"),
+            Self::InvalidBase => f.write_str("invalid base integer ISA"),
+            Self::InvalidLetter(letter) => write!(f, "invalid single-letter extension: {letter:?}"),
+            Self::InvalidExtension => f.write_str("malformed multi-letter extension"),
+        }
+    }
+}
+
+/// Set of ISA extensions
+///
+/// Unknown multi-letter extensions are ignored, so that new extensions in ISA strings
+/// do not break parsing. Implied extensions are not added, except for the `G` shorthand,
+/// which expands to `IMAFD_Zicsr_Zifencei`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IsaExtensions {
+    xlen: XLEN,
+    letters: u32,
+    extensions: u128,
+}
+
+impl IsaExtensions {
+    /// Creates an empty set of extensions for the base integer ISA width `xlen`
+    #[inline]
+    pub const fn new(xlen: XLEN) -> Self {
+        Self {
+            xlen,
+            letters: 0,
+            extensions: 0,
+        }
+    }
+
+    /// Base integer ISA width
+    #[inline]
+    pub fn xlen(&self) -> XLEN {
+        self.xlen
+    }
+
+    /// Returns true when a given single-letter extension is present.
+    ///
+    /// This is equivalent to [`Misa::has_extension`].
+    #[inline]
+    pub fn has_extension(&self, extension: char) -> bool {
+        letter_bit(extension).map_or(false, |bit| self.letters & bit != 0)
+    }
+
+    /// Returns an iterator over the single-letter extensions, in alphabetical order.
+    ///
+    /// This is equivalent to [`Misa::extensions`].
+    #[inline]
+    pub fn extensions(&self) -> impl Iterator<Item = char> {
+        letters(self.letters)
+    }
+
+    /// Returns true when a given multi-letter extension is present.
+    #[inline]
+    pub fn has(&self, extension: Extension) -> bool {
+        self.extensions & extension.mask() != 0
+    }
+
+    /// Adds the multi-letter extension `extension` to the set.
+    #[inline]
+    pub fn insert(&mut self, extension: Extension) {
+        self.extensions |= extension.mask();
+    }
+
+    /// Returns an iterator over the known multi-letter extensions that are present.
+    #[inline]
+    pub fn multi_letter_extensions(&self) -> impl Iterator<Item = Extension> + '_ {
+        Extension::ALL
+            .iter()
+            .copied()
+            .filter(move |extension| self.has(*extension))
+    }
+
+    /// Returns true when the extension `name` is present.
+    ///
+    /// `name` is either a single letter (e.g., `"m"`) or the name of a multi-letter extension
+    /// (e.g., `"zba"`), and is case-insensitive. It returns false for unknown extensions.
+    #[inline]
+    pub fn contains(&self, name: &str) -> bool {
+        let mut chars = name.chars();
+        match (chars.next(), chars.next()) {
+            (Some(letter), None) => self.has_extension(letter),
+            _ => Extension::from_name(name).map_or(false, |extension| self.has(extension)),
+        }
+    }
+
+    /// Adds the single-letter extension `letter`, expanding the `G` shorthand.
+    fn insert_letter(&mut self, letter: char) -> Result<(), IsaError> {
+        match letter {
+            'g' => {
+                for letter in ['i', 'm', 'a', 'f', 'd'] {
+                    self.insert_letter(letter)?;
+                }
+                self.insert(Extension::Zicsr);
+                self.insert(Extension::Zifencei);
+            }
+            _ => self.letters |= letter_bit(letter).ok_or(IsaError::InvalidLetter(letter))?,
+        }
+        Ok(())
+    }
+}
+
+impl From<Misa> for IsaExtensions {
+    #[inline]
+    fn from(misa: Misa) -> Self {
+        Self {
+            xlen: misa.mxl(),
+            letters: misa.bits() as u32 & LETTERS_MASK,
+            extensions: 0,
+        }
+    }
+}
+
+/// Checks if `c` starts a multi-letter extension (`z`, `s`, or `x`).
+fn is_multi_letter_prefix(c: char) -> bool {
+    matches!(c.to_ascii_lowercase(), 'z' | 's' | 'x')
+}
+
+/// Strips a trailing version number (e.g., `2p0` or `2`) from an extension.
+fn strip_version(s: &str) -> &str {
+    let s = s.trim_end_matches(|c: char| c.is_ascii_digit());
+    match s.strip_suffix('p') {
+        Some(major) if major.ends_with(|c: char| c.is_ascii_digit()) => {
+            major.trim_end_matches(|c: char| c.is_ascii_digit())
+        }
+        _ => s,
+    }
+}
+
+impl FromStr for IsaExtensions {
+    type Err = IsaError;
+
+    fn from_str(isa: &str) -> Result<Self, Self::Err> {
+        let prefix = isa.get(..2).ok_or(IsaError::InvalidXlen)?;
+        if !prefix.eq_ignore_ascii_case("rv") {
+            return Err(IsaError::InvalidXlen);
+        }
+        let isa = &isa[2..];
+        let (xlen, isa) = if let Some(isa) = isa.strip_prefix("32") {
+            (XLEN::XLEN32, isa)
+        } else if let Some(isa) = isa.strip_prefix("64") {
+            (XLEN::XLEN64, isa)
+        } else if let Some(isa) = isa.strip_prefix("128") {
+            (XLEN::XLEN128, isa)
+        } else {
+            return Err(IsaError::InvalidXlen);
+        };
+
+        let mut res = Self::new(xlen);
+        match isa.chars().next().map(|c| c.to_ascii_lowercase()) {
+            Some(base @ ('i' | 'e' | 'g')) => res.insert_letter(base)?,
+            _ => return Err(IsaError::InvalidBase),
+        }
+
+        // single-letter extensions (optionally versioned and separated by underscores)
+        // come before the multi-letter extensions, which start with `z`, `s` or `x`
+        let isa = &isa[1..];
+        let (single, multi) = isa.split_at(isa.find(is_multi_letter_prefix).unwrap_or(isa.len()));
+        let mut chars = single.chars().map(|c| c.to_ascii_lowercase()).peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '_' => {}
+                '0'..='9' => {
+                    // version number, possibly of the form `<major>p<minor>`
+                    if chars.peek() == Some(&'p') {
+                        chars.next();
+                    }
+                }
+                _ => res.insert_letter(c)?,
+            }
+        }
+
+        // multi-letter extensions, separated by underscores
+        for extension in multi.split('_').filter(|_| !multi.is_empty()) {
+            let name = strip_version(extension);
+            if !name.starts_with(is_multi_letter_prefix)
+                || !name.chars().all(|c| c.is_ascii_alphanumeric())
+            {
+                return Err(IsaError::InvalidExtension);
+            }
+            if let Some(extension) = Extension::from_name(name) {
+                res.insert(extension);
+            }
+        }
+
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let isa: IsaExtensions = "rv64imafdc_zicsr_zifencei_zba_zbb".parse().unwrap();
+        assert_eq!(isa.xlen(), XLEN::XLEN64);
+        assert!(isa.extensions().eq("ACDFIM".chars()));
+        assert!(isa.has_extension('c') && !isa.has_extension('v'));
+        assert!(isa.has(Extension::Zba) && !isa.has(Extension::Zbs));
+        assert!(isa.contains("zicsr") && isa.contains("F") && !isa.contains("zbc"));
+        assert!(isa.multi_letter_extensions().eq([
+            Extension::Zicsr,
+            Extension::Zifencei,
+            Extension::Zba,
+            Extension::Zbb
+        ]));
+
+        let isa: IsaExtensions = "RV32GC_Xfoo_Zve32x1p0_Svnapot2".parse().unwrap();
+        assert_eq!(isa.xlen(), XLEN::XLEN32);
+        assert!(isa.extensions().eq("ACDFIM".chars()));
+        assert!(isa.has(Extension::Zicsr) && isa.has(Extension::Zifencei));
+        assert!(isa.has(Extension::Zve32x) && isa.has(Extension::Svnapot));
+
+        let isa: IsaExtensions = "rv32e2p0_m2p0_c".parse().unwrap();
+        assert!(isa.extensions().eq("CEM".chars()));
+        assert_eq!(isa.multi_letter_extensions().count(), 0);
+
+        assert_eq!("rv16i".parse::<IsaExtensions>(), Err(IsaError::InvalidXlen));
+        assert_eq!("i".parse::<IsaExtensions>(), Err(IsaError::InvalidXlen));
+        assert_eq!(
+            "rv64ma".parse::<IsaExtensions>(),
+            Err(IsaError::InvalidBase)
+        );
+        assert_eq!(
+            "rv64im-a".parse::<IsaExtensions>(),
+            Err(IsaError::InvalidLetter('-'))
+        );
+        assert_eq!(
+            "rv64i_zba__zbb".parse::<IsaExtensions>(),
+            Err(IsaError::InvalidExtension)
+        );
+        assert_eq!(
+            "rv64i_zba_m".parse::<IsaExtensions>(),
+            Err(IsaError::InvalidExtension)
+        );
+    }
+}
@@ -11,7 +11,15 @@
 //!
 //! - Access to core registers like `mstatus` or `mcause`.
 //! - Interrupt manipulation mechanisms.
+//! - Indirect access to the Advanced Interrupt Architecture (AIA) registers.
 //! - Wrappers around assembly instructions like `WFI`.
+//! - Helpers to save and restore the floating-point and vector states (e.g., for context switches).
+//! - Hardware breakpoints and watchpoints based on the Sdtrig triggers.
+//! - Typed physical memory protection (PMP) configuration, including a PMP-based stack guard.
+//! - A high-level facade over the hardware performance counters.
+//! - Page-table entry layouts and a page-table walker for page-based virtual memory.
+//! - A parser of ISA strings (e.g., `rv64imac_zicsr`) and runtime probing to query the available extensions.
+//! - Bindings to the Supervisor Binary Interface (SBI).
 //!
 //! # Optional features
 //!
@@ -21,6 +29,43 @@
 //! By default, the crate assumes that the target is running in M-mode.
 //! Thus, `interrupt` re-exports the M-mode functions defined in `interrupt::machine`.
 //!
+//! ## `h-extension`
+//!
+//! This feature enables the CSRs of the hypervisor extension (e.g., `vstvec`), and the `hypervisor`
+//! module with helpers to flush the address-translation caches of a guest.
+//!
+//! ## `n-ext`
+//!
+//! This feature enables the user-level trap CSRs of the N extension (e.g., `ustatus` or `utvec`).
+//!
+//! ## `svinval`
+//!
+//! This feature enables the fine-grained address-translation cache invalidation instructions
+//! of the Svinval extension in `asm` (e.g., `sinval_vma`). Together with the `h-extension` feature,
+//! it also enables the hypervisor variants (e.g., `hinval_vvma`).
+//!
+//! ## `v-ext`
+//!
+//! This feature enables the CSRs of the vector extension (e.g., `vtype` or `vlenb`), the vector
+//! configuration instructions in `asm` (e.g., `vsetvl`), and the `vector` module to save and restore
+//! the vector state.
+//!
+//! ## `debug-mode`
+//!
+//! This feature enables the debug mode CSRs (e.g., `dcsr` or `dpc`). These CSRs are only accessible
+//! in debug mode (e.g., from a debug monitor running in debug mode), and accessing them in any other
+//! mode raises an illegal-instruction exception.
+//!
+//! ## `zkne`, `zknd`, and `zknh`
+//!
+//! These features enable the wrappers in `crypto` of the scalar cryptography instructions of the
+//! Zkne (AES encryption), Zknd (AES decryption), and Zknh (SHA-2 hash functions) extensions, respectively.
+//!
+//! ## `zicfiss`
+//!
+//! This feature enables the shadow stack pointer CSR (`ssp`) and the shadow stack instructions
+//! in `asm` (e.g., `sspush`) of the Zicfiss extension.
+//!
 //! ## `critical-section-single-hart`
 //!
 //! This feature enables a [`critical-section`](https://github.com/rust-embedded/critical-section)
@@ -35,11 +80,28 @@
 #![no_std]
 #![allow(clippy::missing_safety_doc)]
 
+pub mod aia;
 pub mod asm;
 pub(crate) mod bits;
+#[cfg(any(feature = "zkne", feature = "zknd", feature = "zknh"))]
+pub mod crypto;
+pub mod debug;
 pub mod delay;
+#[cfg(any(target_feature = "f", not(riscv)))]
+pub mod fpu;
+#[cfg(feature = "h-extension")]
+pub mod hypervisor;
 pub mod interrupt;
+pub mod isa;
+pub mod paging;
+pub mod perf;
+pub mod pmp;
+pub mod probe;
 pub mod register;
+pub mod sbi;
+pub mod stack_guard;
+#[cfg(feature = "v-ext")]
+pub mod vector;
 pub use riscv_pac::*;
 
 #[macro_use]
@@ -0,0 +1,621 @@
+//! Page-based virtual memory
+//!
+//! This module provides the page-table entry (PTE) layout shared by the Sv39, Sv48, and Sv57
+//! address-translation schemes, including the bits of the Svnapot and Svpbmt extensions,
+//! and a software page-table walker (see [`walk`]) for all the Sv schemes.
+
+use crate::register::satp;
+use crate::result::{Error, Result};
+use core::fmt;
+
+/// Size of a base page, in bytes
+pub const PAGE_SIZE: usize = 4096;
+
+/// Page-based memory type (Svpbmt)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Pbmt {
+    /// None: use the memory type of the underlying physical memory attributes
+    Pma = 0,
+    /// Non-cacheable, idempotent, weakly-ordered main memory
+    Nc = 1,
+    /// Non-cacheable, non-idempotent, strongly-ordered I/O memory
+    Io = 2,
+}
+
+impl TryFrom<u8> for Pbmt {
+    type Error = Error;
+
+    fn try_from(val: u8) -> Result<Self> {
+        match val {
+            0 => Ok(Self::Pma),
+            1 => Ok(Self::Nc),
+            2 => Ok(Self::Io),
+            _ => Err(Error::InvalidFieldVariant {
+                field: "pbmt",
+                value: val as usize,
+            }),
+        }
+    }
+}
+
+/// Page-table entry of the Sv39, Sv48, and Sv57 schemes
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Pte {
+    bits: u64,
+}
+
+impl From<u64> for Pte {
+    #[inline]
+    fn from(bits: u64) -> Self {
+        Self { bits }
+    }
+}
+
+impl Pte {
+    const PPN_SHIFT: u32 = 10;
+    /// Mask of the physical page number field
+    const PPN_MASK: u64 = (1 << 44) - 1;
+    const PBMT_SHIFT: u32 = 61;
+    /// Mask of the bits reserved for future standard use (bits 54 to 60)
+    const RESERVED_MASK: u64 = 0x7F << 54;
+
+    /// Creates a new, invalid entry pointing to the physical page number `ppn`.
+    ///
+    /// Returns an error if `ppn` does not fit in 44 bits.
+    #[inline]
+    pub const fn new(ppn: u64) -> Result<Self> {
+        if ppn & !Self::PPN_MASK != 0 {
+            Err(Error::InvalidFieldValue {
+                field: "ppn",
+                value: ppn as usize,
+                bitmask: Self::PPN_MASK as usize,
+            })
+        } else {
+            Ok(Self {
+                bits: ppn << Self::PPN_SHIFT,
+            })
+        }
+    }
+
+    /// Returns the contents of the entry as raw bits
+    #[inline]
+    pub const fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    #[inline]
+    const fn bit(&self, index: u32) -> bool {
+        self.bits & (1 << index) != 0
+    }
+
+    #[inline]
+    fn set_bit(&mut self, index: u32, value: bool) {
+        self.bits = (self.bits & !(1 << index)) | ((value as u64) << index);
+    }
+
+    /// Valid (`V`)
+    #[inline]
+    pub const fn v(&self) -> bool {
+        self.bit(0)
+    }
+
+    /// Updates valid (`V`)
+    #[inline]
+    pub fn set_v(&mut self, v: bool) {
+        self.set_bit(0, v);
+    }
+
+    /// Readable (`R`)
+    #[inline]
+    pub const fn r(&self) -> bool {
+        self.bit(1)
+    }
+
+    /// Updates readable (`R`)
+    #[inline]
+    pub fn set_r(&mut self, r: bool) {
+        self.set_bit(1, r);
+    }
+
+    /// Writable (`W`)
+    #[inline]
+    pub const fn w(&self) -> bool {
+        self.bit(2)
+    }
+
+    /// Updates writable (`W`)
+    #[inline]
+    pub fn set_w(&mut self, w: bool) {
+        self.set_bit(2, w);
+    }
+
+    /// Executable (`X`)
+    #[inline]
+    pub const fn x(&self) -> bool {
+        self.bit(3)
+    }
+
+    /// Updates executable (`X`)
+    #[inline]
+    pub fn set_x(&mut self, x: bool) {
+        self.set_bit(3, x);
+    }
+
+    /// User-mode accessible (`U`)
+    #[inline]
+    pub const fn u(&self) -> bool {
+        self.bit(4)
+    }
+
+    /// Updates user-mode accessible (`U`)
+    #[inline]
+    pub fn set_u(&mut self, u: bool) {
+        self.set_bit(4, u);
+    }
+
+    /// Global mapping (`G`)
+    #[inline]
+    pub const fn g(&self) -> bool {
+        self.bit(5)
+    }
+
+    /// Updates global mapping (`G`)
+    #[inline]
+    pub fn set_g(&mut self, g: bool) {
+        self.set_bit(5, g);
+    }
+
+    /// Accessed (`A`)
+    #[inline]
+    pub const fn a(&self) -> bool {
+        self.bit(6)
+    }
+
+    /// Updates accessed (`A`)
+    #[inline]
+    pub fn set_a(&mut self, a: bool) {
+        self.set_bit(6, a);
+    }
+
+    /// Dirty (`D`)
+    #[inline]
+    pub const fn d(&self) -> bool {
+        self.bit(7)
+    }
+
+    /// Updates dirty (`D`)
+    #[inline]
+    pub fn set_d(&mut self, d: bool) {
+        self.set_bit(7, d);
+    }
+
+    /// Returns `true` if the entry is a leaf (i.e., any of `R` or `X` is set).
+    ///
+    /// Otherwise, a valid entry points to the next level of the page table.
+    #[inline]
+    pub const fn is_leaf(&self) -> bool {
+        self.r() || self.x()
+    }
+
+    /// Reserved for supervisor software (`RSW`)
+    #[inline]
+    pub const fn rsw(&self) -> u8 {
+        ((self.bits >> 8) & 0b11) as u8
+    }
+
+    /// Updates the bits reserved for supervisor software (`RSW`)
+    ///
+    /// Only the two least significant bits of `rsw` are used.
+    #[inline]
+    pub fn set_rsw(&mut self, rsw: u8) {
+        self.bits = (self.bits & !(0b11 << 8)) | (((rsw & 0b11) as u64) << 8);
+    }
+
+    /// Physical page number
+    #[inline]
+    pub const fn ppn(&self) -> u64 {
+        (self.bits >> Self::PPN_SHIFT) & Self::PPN_MASK
+    }
+
+    /// Updates the physical page number
+    ///
+    /// Returns an error if `ppn` does not fit in 44 bits.
+    #[inline]
+    pub fn set_ppn(&mut self, ppn: u64) -> Result<()> {
+        let pte = Self::new(ppn)?;
+        self.bits = (self.bits & !(Self::PPN_MASK << Self::PPN_SHIFT)) | pte.bits;
+        Ok(())
+    }
+
+    /// Physical address of the page (or next-level page table) pointed to by the entry
+    #[inline]
+    pub const fn address(&self) -> u64 {
+        self.ppn() * PAGE_SIZE as u64
+    }
+
+    /// Page-based memory type (Svpbmt)
+    ///
+    /// **WARNING**: panics if the field has an invalid variant.
+    #[inline]
+    pub fn pbmt(&self) -> Pbmt {
+        self.try_pbmt().unwrap()
+    }
+
+    /// Attempts to get the page-based memory type (Svpbmt)
+    #[inline]
+    pub fn try_pbmt(&self) -> Result<Pbmt> {
+        (((self.bits >> Self::PBMT_SHIFT) & 0b11) as u8).try_into()
+    }
+
+    /// Updates the page-based memory type (Svpbmt)
+    #[inline]
+    pub fn set_pbmt(&mut self, pbmt: Pbmt) {
+        self.bits = (self.bits & !(0b11 << Self::PBMT_SHIFT)) | ((pbmt as u64) << Self::PBMT_SHIFT);
+    }
+
+    /// NAPOT translation contiguity (`N`, Svnapot)
+    #[inline]
+    pub const fn n(&self) -> bool {
+        self.bit(63)
+    }
+
+    /// Updates NAPOT translation contiguity (`N`, Svnapot)
+    #[inline]
+    pub fn set_n(&mut self, n: bool) {
+        self.set_bit(63, n);
+    }
+}
+
+/// Page-based virtual-memory scheme
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Scheme {
+    /// Page-based 32-bit virtual addressing
+    Sv32,
+    /// Page-based 39-bit virtual addressing
+    Sv39,
+    /// Page-based 48-bit virtual addressing
+    Sv48,
+    /// Page-based 57-bit virtual addressing
+    Sv57,
+}
+
+impl Scheme {
+    /// Number of levels of the page table
+    #[inline]
+    pub const fn levels(self) -> usize {
+        match self {
+            Self::Sv32 => 2,
+            Self::Sv39 => 3,
+            Self::Sv48 => 4,
+            Self::Sv57 => 5,
+        }
+    }
+
+    /// Width of the virtual addresses, in bits
+    #[inline]
+    pub const fn va_bits(self) -> u32 {
+        match self {
+            Self::Sv32 => 32,
+            Self::Sv39 => 39,
+            Self::Sv48 => 48,
+            Self::Sv57 => 57,
+        }
+    }
+
+    /// Size of a page mapped by a leaf entry at `level`, in bytes
+    #[inline]
+    pub const fn page_size(self, level: usize) -> u64 {
+        (PAGE_SIZE as u64) << (self.vpn_bits() * level as u32)
+    }
+
+    /// Width of each virtual page number field, in bits
+    #[inline]
+    const fn vpn_bits(self) -> u32 {
+        match self {
+            Self::Sv32 => 10,
+            _ => 9,
+        }
+    }
+
+    /// Size of a page-table entry, in bytes
+    #[inline]
+    const fn pte_size(self) -> u64 {
+        match self {
+            Self::Sv32 => 4,
+            _ => 8,
+        }
+    }
+}
+
+impl TryFrom<satp::Mode> for Scheme {
+    type Error = Error;
+
+    fn try_from(mode: satp::Mode) -> Result<Self> {
+        match mode {
+            #[cfg(target_pointer_width = "32")]
+            satp::Mode::Sv32 => Ok(Self::Sv32),
+            #[cfg(target_pointer_width = "64")]
+            satp::Mode::Sv39 => Ok(Self::Sv39),
+            #[cfg(target_pointer_width = "64")]
+            satp::Mode::Sv48 => Ok(Self::Sv48),
+            #[cfg(target_pointer_width = "64")]
+            satp::Mode::Sv57 => Ok(Self::Sv57),
+            mode => Err(Error::InvalidVariant(mode as usize)),
+        }
+    }
+}
+
+/// Result of a successful page-table walk
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Translation {
+    /// Leaf page-table entry that maps the virtual address
+    pub pte: Pte,
+    /// Level of the leaf entry (0 for base pages, greater for superpages)
+    pub level: usize,
+    /// Translated physical address
+    pub address: u64,
+}
+
+/// Page-table walk errors
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WalkError {
+    /// The virtual address is not a valid address of the scheme (i.e., not sign-extended)
+    NonCanonical(u64),
+    /// The physical address of the page table rooted at this physical page number overflows
+    InvalidRoot(u64),
+    /// The entry at `level` is invalid, sets reserved bits or encodings, or is a pointer at the last level
+    InvalidEntry {
+        /// Level of the entry
+        level: usize,
+        /// Invalid entry
+        pte: Pte,
+    },
+    /// The leaf entry at `level` maps a misaligned superpage
+    MisalignedSuperpage {
+        /// Level of the entry
+        level: usize,
+        /// Leaf entry
+        pte: Pte,
+    },
+}
+
+impl fmt::Display for WalkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonCanonical(va) => write!(f, "non-canonical virtual address: {va:#x}"),
+            Self::InvalidRoot(ppn) => write!(f, "invalid root page number: {ppn:#x}"),
+            Self::InvalidEntry { level, pte } => {
+                write!(f, "invalid entry at level {level}: {:#x}", pte.bits())
+            }
+            Self::MisalignedSuperpage { level, pte } => {
+                write!(
+                    f,
+                    "misaligned superpage at level {level}: {:#x}",
+                    pte.bits()
+                )
+            }
+        }
+    }
+}
+
+/// Walks the page table of `scheme` rooted at the physical page number `root_ppn`
+/// to translate the virtual address `va`.
+///
+/// The page-table entries are read with `read`, which receives the physical address of an entry
+/// and returns its contents. For Sv32, `read` must return the (zero-extended) 32-bit entry.
+///
+/// This function does not check the access permissions of the leaf entry, nor updates its `A`/`D` bits.
+#[inline]
+pub fn walk<F: FnMut(u64) -> u64>(
+    scheme: Scheme,
+    root_ppn: u64,
+    va: u64,
+    mut read: F,
+) -> core::result::Result<Translation, WalkError> {
+    let shift = 64 - scheme.va_bits();
+    let canonical = match scheme {
+        Scheme::Sv32 => va >> 32 == 0,
+        _ => (((va << shift) as i64) >> shift) as u64 == va,
+    };
+    if !canonical {
+        return Err(WalkError::NonCanonical(va));
+    }
+
+    let vpn_bits = scheme.vpn_bits();
+    let mut table = root_ppn
+        .checked_mul(PAGE_SIZE as u64)
+        .ok_or(WalkError::InvalidRoot(root_ppn))?;
+    let mut level = scheme.levels() - 1;
+    loop {
+        let vpn = (va >> (12 + vpn_bits * level as u32)) & ((1 << vpn_bits) - 1);
+        let address = table
+            .checked_add(vpn * scheme.pte_size())
+            .ok_or(WalkError::InvalidRoot(root_ppn))?;
+        let pte = Pte::from(read(address));
+        if !pte.v()
+            || (!pte.r() && pte.w())
+            || pte.bits() & Pte::RESERVED_MASK != 0
+            || pte.try_pbmt().is_err()
+        {
+            return Err(WalkError::InvalidEntry { level, pte });
+        }
+        if pte.is_leaf() {
+            let ppn = pte.ppn();
+            if ppn & ((1 << (vpn_bits * level as u32)) - 1) != 0 {
+                return Err(WalkError::MisalignedSuperpage { level, pte });
+            }
+            let offset_mask = match pte.n() {
+                // Svnapot only defines 64 KiB contiguous base pages
+                true if level == 0 && ppn & 0xF == 0b1000 => 0xFFFF,
+                true => return Err(WalkError::InvalidEntry { level, pte }),
+                false => scheme.page_size(level) - 1,
+            };
+            let address = (pte.address() & !offset_mask) | (va & offset_mask);
+            return Ok(Translation {
+                pte,
+                level,
+                address,
+            });
+        }
+        // `N` and `PBMT` are reserved in non-leaf entries
+        if level == 0 || pte.n() || pte.pbmt() != Pbmt::Pma {
+            return Err(WalkError::InvalidEntry { level, pte });
+        }
+        table = pte.address();
+        level -= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pte() {
+        let mut pte = Pte::new(0x8_0200).unwrap();
+        assert!(!pte.v());
+        assert_eq!(pte.ppn(), 0x8_0200);
+        assert_eq!(pte.address(), 0x8020_0000);
+
+        pte.set_v(true);
+        assert!(!pte.is_leaf());
+        pte.set_r(true);
+        pte.set_x(true);
+        assert!(pte.is_leaf());
+        pte.set_a(true);
+        pte.set_d(true);
+        assert_eq!(pte.bits(), (0x8_0200 << 10) | 0b1100_1011);
+
+        pte.set_rsw(0b10);
+        assert_eq!(pte.rsw(), 0b10);
+        pte.set_pbmt(Pbmt::Io);
+        assert_eq!(pte.pbmt(), Pbmt::Io);
+        pte.set_n(true);
+        assert!(pte.n());
+        assert_eq!(pte.ppn(), 0x8_0200);
+
+        assert!(pte.set_ppn(0x1234).is_ok());
+        assert_eq!(pte.ppn(), 0x1234);
+        assert_eq!(
+            pte.set_ppn(1 << 44),
+            Err(Error::InvalidFieldValue {
+                field: "ppn",
+                value: 1 << 44,
+                bitmask: (1 << 44) - 1,
+            })
+        );
+
+        assert_eq!(
+            Pte::from(3 << 61).try_pbmt(),
+            Err(Error::InvalidFieldVariant {
+                field: "pbmt",
+                value: 3,
+            })
+        );
+    }
+
+    fn table(entries: &[(u64, u64)]) -> impl FnMut(u64) -> u64 + '_ {
+        |address| {
+            entries
+                .iter()
+                .find(|(a, _)| *a == address)
+                .map_or(0, |(_, pte)| *pte)
+        }
+    }
+
+    #[test]
+    fn test_walk() {
+        const V: u64 = 0b1;
+        const RWX: u64 = 0b1110;
+        let root = 0x8_0000;
+        let entries = [
+            // root[1] -> 0x8_1000, root[2] -> 1 GiB page at 0x8000_0000
+            (0x8000_0008, (0x8_1000 << 10) | V),
+            (0x8000_0010, (0x8_0000 << 10) | RWX | V),
+            // root[3] -> misaligned 1 GiB page, root[4] -> reserved (W without R)
+            (0x8000_0018, (0x8_1000 << 10) | RWX | V),
+            (0x8000_0020, (0x8_1000 << 10) | 0b0100 | V),
+            // 0x8_1000[0] -> 0x8_2000, 0x8_1000[1] -> 2 MiB page at 0x8020_0000
+            (0x8100_0000, (0x8_2000 << 10) | V),
+            (0x8100_0008, (0x8_0200 << 10) | RWX | V),
+            // 0x8_2000[0] -> 4 KiB page, 0x8_2000[21] -> 64 KiB NAPOT page
+            (0x8200_0000, (0x8_3000 << 10) | RWX | V),
+            (0x8200_00A8, (1 << 63) | (0x8_3018 << 10) | RWX | V),
+            // 0x8_2000[2] -> reserved bit 54, 0x8_2000[3] -> reserved PBMT encoding
+            (0x8200_0010, (1 << 54) | (0x8_3002 << 10) | RWX | V),
+            (0x8200_0018, (3 << 61) | (0x8_3003 << 10) | RWX | V),
+            // 0x8_1000[2] -> pointer with PBMT set
+            (0x8100_0010, (1 << 61) | (0x8_2000 << 10) | V),
+        ];
+
+        let walk = |va| walk(Scheme::Sv39, root, va, table(&entries));
+
+        let t = walk(0x8012_3456).unwrap();
+        assert_eq!((t.level, t.address), (2, 0x8012_3456));
+        let t = walk(0x4030_1234).unwrap();
+        assert_eq!((t.level, t.address), (1, 0x8030_1234));
+        let t = walk(0x4000_0ABC).unwrap();
+        assert_eq!((t.level, t.address), (0, 0x8300_0ABC));
+        let t = walk(0x4001_5678).unwrap();
+        assert_eq!((t.level, t.address), (0, 0x8301_5678));
+        assert!(t.pte.n());
+
+        assert_eq!(
+            walk(0xC000_0000),
+            Err(WalkError::MisalignedSuperpage {
+                level: 2,
+                pte: Pte::from(entries[2].1),
+            })
+        );
+        assert_eq!(
+            walk(0x1_0000_0000),
+            Err(WalkError::InvalidEntry {
+                level: 2,
+                pte: Pte::from(entries[3].1),
+            })
+        );
+        assert_eq!(
+            walk(0x1_4000_0000),
+            Err(WalkError::InvalidEntry {
+                level: 2,
+                pte: Pte::default(),
+            })
+        );
+        assert_eq!(
+            walk(0x4000_2000),
+            Err(WalkError::InvalidEntry {
+                level: 0,
+                pte: Pte::from(entries[8].1),
+            })
+        );
+        assert_eq!(
+            walk(0x4000_3000),
+            Err(WalkError::InvalidEntry {
+                level: 0,
+                pte: Pte::from(entries[9].1),
+            })
+        );
+        assert_eq!(
+            walk(0x4040_0000),
+            Err(WalkError::InvalidEntry {
+                level: 1,
+                pte: Pte::from(entries[10].1),
+            })
+        );
+        assert_eq!(
+            walk(0x40_0000_0000),
+            Err(WalkError::NonCanonical(0x40_0000_0000))
+        );
+        assert_eq!(
+            super::walk(Scheme::Sv39, u64::MAX, 0, table(&entries)),
+            Err(WalkError::InvalidRoot(u64::MAX))
+        );
+        assert_eq!(
+            walk(0xFFFF_FFC0_0000_0000),
+            Err(WalkError::InvalidEntry {
+                level: 2,
+                pte: Pte::default(),
+            })
+        );
+    }
+}
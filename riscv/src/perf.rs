@@ -0,0 +1,285 @@
+//! High-level performance-counter facade (M-mode)
+//!
+//! This module builds on the [`mcycle`], [`minstret`], [`mhpmcounter`], [`mhpmevent`],
+//! and [`mcountinhibit`] registers to select a counter, program its event, control
+//! whether it counts, and measure the number of events within a code region:
+//!
+//! ```no_run
+//! use riscv::perf::{self, Counter};
+//!
+//! let m = unsafe { perf::measure(Counter::Cycle) };
+//! // code to measure
+//! let cycles = m.finish();
+//! ```
+
+use crate::register::mcountinhibit::{self, Mcountinhibit};
+pub use crate::register::mhpmevent::Mhpmevent;
+use crate::register::{mcycle, mhpmcounter, mhpmevent, minstret};
+use crate::result::{Error, Result};
+use crate::CounterNumber;
+
+/// Machine-level hardware performance counter
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Counter {
+    /// Cycle counter (`mcycle`)
+    Cycle,
+    /// Retired instructions counter (`minstret`)
+    Instret,
+    /// Programmable hardware performance-monitoring counter (`mhpmcounter3`-`mhpmcounter31`)
+    Hpm(usize),
+}
+
+impl Counter {
+    /// Returns the programmable counter of a target-specific [`CounterNumber`]
+    #[inline]
+    pub fn hpm<C: CounterNumber>(counter: C) -> Self {
+        Self::Hpm(counter.number() as usize)
+    }
+
+    /// Returns the bit of the counter in the `mcountinhibit` and `xcounteren` registers
+    #[inline]
+    fn mask(self) -> Result<usize> {
+        match self {
+            Self::Cycle => Ok(1 << 0),
+            Self::Instret => Ok(1 << 2),
+            Self::Hpm(index) if (3..32).contains(&index) => Ok(1 << index),
+            Self::Hpm(index) => Err(Error::IndexOutOfBounds {
+                index,
+                min: 3,
+                max: 31,
+            }),
+        }
+    }
+
+    /// Reads the current value of the counter.
+    ///
+    /// **WARNING**: panics on:
+    ///
+    /// - non-`riscv` targets
+    /// - `Hpm` index out-of-bounds
+    #[inline]
+    pub fn read(self) -> u64 {
+        self.try_read().unwrap()
+    }
+
+    /// Attempts to read the current value of the counter.
+    ///
+    /// Returns an error if the `Hpm` index is not in the range 3 to 31.
+    #[inline]
+    pub fn try_read(self) -> Result<u64> {
+        match self {
+            Self::Cycle => mcycle::try_read64(),
+            Self::Instret => minstret::try_read64(),
+            Self::Hpm(index) => mhpmcounter::try_read64(index),
+        }
+    }
+
+    /// Programs the event counted by a hardware performance-monitoring counter.
+    ///
+    /// **WARNING**: panics on:
+    ///
+    /// - non-`riscv` targets
+    /// - fixed-function counters (i.e., [`Counter::Cycle`] and [`Counter::Instret`])
+    /// - `Hpm` index out-of-bounds
+    #[inline]
+    pub unsafe fn set_event(self, event: Mhpmevent) {
+        self.try_set_event(event).unwrap();
+    }
+
+    /// Attempts to program the event counted by a hardware performance-monitoring counter.
+    ///
+    /// Returns an error if the counter is a fixed-function counter,
+    /// or if the `Hpm` index is not in the range 3 to 31.
+    #[inline]
+    pub unsafe fn try_set_event(self, event: Mhpmevent) -> Result<()> {
+        match self {
+            Self::Hpm(index) => mhpmevent::try_write64(index, event.bits()),
+            _ => Err(Error::Unimplemented),
+        }
+    }
+
+    /// Stops the counter.
+    ///
+    /// **WARNING**: panics on:
+    ///
+    /// - non-`riscv` targets
+    /// - `Hpm` index out-of-bounds
+    #[inline]
+    pub unsafe fn inhibit(self) {
+        self.try_inhibit().unwrap();
+    }
+
+    /// Attempts to stop the counter.
+    ///
+    /// Returns an error if the `Hpm` index is not in the range 3 to 31.
+    #[inline]
+    pub unsafe fn try_inhibit(self) -> Result<()> {
+        mcountinhibit::try_set_counters(Mcountinhibit::from(self.mask()?))
+    }
+
+    /// Resumes the counter.
+    ///
+    /// **WARNING**: panics on:
+    ///
+    /// - non-`riscv` targets
+    /// - `Hpm` index out-of-bounds
+    #[inline]
+    pub unsafe fn uninhibit(self) {
+        self.try_uninhibit().unwrap();
+    }
+
+    /// Attempts to resume the counter.
+    ///
+    /// Returns an error if the `Hpm` index is not in the range 3 to 31.
+    #[inline]
+    pub unsafe fn try_uninhibit(self) -> Result<()> {
+        mcountinhibit::try_clear_counters(Mcountinhibit::from(self.mask()?))
+    }
+}
+
+/// Measurement scope over a performance counter
+///
+/// Created by [`measure`]. Call [`Measurement::finish`] at the end of the measured region.
+///
+/// If the counter was inhibited when the measurement started, it is resumed during the measurement
+/// and inhibited again when the measurement is finished or dropped.
+#[derive(Debug)]
+#[must_use = "a measurement does nothing unless it is finished"]
+pub struct Measurement {
+    counter: Counter,
+    start: u64,
+    inhibited: bool,
+}
+
+impl Measurement {
+    /// Returns the counter used by this measurement
+    #[inline]
+    pub const fn counter(&self) -> Counter {
+        self.counter
+    }
+
+    /// Returns the number of events counted since the start of the measurement
+    ///
+    /// **WARNING**: panics on non-`riscv` targets.
+    #[inline]
+    pub fn elapsed(&self) -> u64 {
+        self.counter.read().wrapping_sub(self.start)
+    }
+
+    /// Finishes the measurement and returns the number of events counted since its start
+    ///
+    /// **WARNING**: panics on non-`riscv` targets.
+    #[inline]
+    pub fn finish(self) -> u64 {
+        self.elapsed()
+    }
+}
+
+impl Drop for Measurement {
+    #[inline]
+    fn drop(&mut self) {
+        if self.inhibited {
+            // SAFETY: it restores the inhibit state of the counter before the measurement,
+            // as required by `measure`. The counter index was checked when the measurement started.
+            let _ = unsafe { self.counter.try_inhibit() };
+        }
+    }
+}
+
+/// Starts measuring the events counted by `counter`.
+///
+/// If the counter is inhibited, it is resumed until the measurement is finished or dropped.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `Hpm` index out-of-bounds
+///
+/// # Safety
+///
+/// It writes `mcountinhibit` (see [`Counter::uninhibit`]). Other code must not change
+/// the inhibit state of the counter until the measurement is finished or dropped.
+#[inline]
+pub unsafe fn measure(counter: Counter) -> Measurement {
+    try_measure(counter).unwrap()
+}
+
+/// Attempts to start measuring the events counted by `counter`.
+///
+/// If the counter is inhibited, it is resumed until the measurement is finished or dropped.
+///
+/// Returns an error if the `Hpm` index is not in the range 3 to 31.
+///
+/// # Safety
+///
+/// See [`measure`].
+#[inline]
+pub unsafe fn try_measure(counter: Counter) -> Result<Measurement> {
+    let mask = counter.mask()?;
+    let inhibited = mcountinhibit::try_read()?.bits() & mask != 0;
+    // an inhibited counter does not change, so it can be read before resuming it
+    let start = counter.try_read()?;
+    if inhibited {
+        counter.try_uninhibit()?;
+    }
+    Ok(Measurement {
+        counter,
+        start,
+        inhibited,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    enum Hpm {
+        Hpm3 = 3,
+        Hpm4 = 4,
+    }
+
+    unsafe impl CounterNumber for Hpm {
+        const MAX_COUNTER_NUMBER: u16 = 4;
+
+        fn number(self) -> u16 {
+            self as u16
+        }
+
+        fn from_number(value: u16) -> Result<Self> {
+            match value {
+                3 => Ok(Self::Hpm3),
+                4 => Ok(Self::Hpm4),
+                _ => Err(Error::InvalidVariant(value as usize)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_counter() {
+        assert_eq!(Counter::Cycle.mask(), Ok(1 << 0));
+        assert_eq!(Counter::Instret.mask(), Ok(1 << 2));
+        assert_eq!(Counter::Hpm(31).mask(), Ok(1 << 31));
+        assert_eq!(
+            Counter::Hpm(2).mask(),
+            Err(Error::IndexOutOfBounds {
+                index: 2,
+                min: 3,
+                max: 31,
+            })
+        );
+        assert!(matches!(
+            unsafe { try_measure(Counter::Hpm(32)) },
+            Err(Error::IndexOutOfBounds { index: 32, .. })
+        ));
+        assert!(matches!(
+            unsafe { try_measure(Counter::Cycle) },
+            Err(Error::Unimplemented)
+        ));
+        assert_eq!(Counter::hpm(Hpm::Hpm4), Counter::Hpm(4));
+        assert_eq!(
+            unsafe { Counter::Cycle.try_set_event(Mhpmevent::new(1)) },
+            Err(Error::Unimplemented)
+        );
+    }
+}
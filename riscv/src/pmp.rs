@@ -0,0 +1,542 @@
+//! Physical memory protection (PMP)
+//!
+//! This module provides a typed view of the PMP entries on top of the `pmpcfgx` and
+//! `pmpaddrx` registers. It takes care of packing the configuration bytes in the
+//! `pmpcfgx` registers (4 entries per register on RV32, 8 entries per even register on RV64)
+//! and of encoding the region addresses in the `pmpaddrx` registers.
+//!
+//! On RV32, only the lower 4 GiB of the physical address space can be described by a [`Region`].
+
+use crate::register::{
+    pmpaddr0, pmpaddr1, pmpaddr10, pmpaddr11, pmpaddr12, pmpaddr13, pmpaddr14, pmpaddr15, pmpaddr2,
+    pmpaddr3, pmpaddr4, pmpaddr5, pmpaddr6, pmpaddr7, pmpaddr8, pmpaddr9, pmpcfg0, pmpcfg2, Pmp,
+};
+#[cfg(riscv32)]
+use crate::register::{pmpcfg1, pmpcfg3};
+pub use crate::register::{Permission, Range};
+use crate::result::{Error, Result};
+
+/// Number of PMP entries supported by this module
+pub const ENTRIES: usize = 16;
+
+/// Memory region protected by a PMP entry
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Region {
+    /// Base address of the region
+    ///
+    /// For [`Range::TOR`] regions, [`set_entry`] programs `base + size` as the top of the region,
+    /// and the bottom of the region is given by the `pmpaddrx` register of the previous PMP entry.
+    /// Thus, `base` must match the top address of the previous entry to describe the actual region.
+    pub base: usize,
+    /// Size of the region, in bytes
+    pub size: usize,
+    /// Access permissions
+    pub permission: Permission,
+    /// Address-matching mode
+    pub mode: Range,
+    /// Whether the entry is locked (it also applies to M-mode until the next reset)
+    ///
+    /// With machine mode lockdown (`mseccfg.MML`) enabled, this bit selects between M-mode
+    /// and S/U-mode rules instead. See [`MmlPermission`] for the resulting access permissions.
+    pub locked: bool,
+}
+
+impl Region {
+    /// Encodes the address of the region as the value of its `pmpaddrx` register.
+    ///
+    /// Returns an error if the base address or size of the region are not valid for its mode.
+    #[inline]
+    pub fn pmpaddr(&self) -> Result<usize> {
+        match self.mode {
+            Range::OFF => Ok(self.base >> 2),
+            Range::TOR => match self.base.checked_add(self.size) {
+                Some(top) if top & 0b11 == 0 => Ok(top >> 2),
+                _ => Err(Error::InvalidFieldValue {
+                    field: "size",
+                    value: self.size,
+                    bitmask: !0b11,
+                }),
+            },
+            Range::NA4 => {
+                if self.size != 4 {
+                    Err(Error::InvalidFieldValue {
+                        field: "size",
+                        value: self.size,
+                        bitmask: 0b100,
+                    })
+                } else if self.base & 0b11 != 0 {
+                    Err(Error::InvalidFieldValue {
+                        field: "base",
+                        value: self.base,
+                        bitmask: !0b11,
+                    })
+                } else {
+                    Ok(self.base >> 2)
+                }
+            }
+            Range::NAPOT => napot_encode(self.base, self.size),
+        }
+    }
+
+    /// Decodes a region from its configuration and its `pmpaddrx` register value.
+    ///
+    /// `previous` must hold the value of the previous `pmpaddrx` register
+    /// (0 for the first entry), as it is the base address of [`Range::TOR`] regions.
+    #[inline]
+    pub fn decode(config: Pmp, pmpaddr: usize, previous: usize) -> Self {
+        let (base, size) = match config.range {
+            Range::OFF => (pmpaddr << 2, 0),
+            Range::TOR => {
+                let base = previous << 2;
+                (base, (pmpaddr << 2).saturating_sub(base))
+            }
+            Range::NA4 => (pmpaddr << 2, 4),
+            Range::NAPOT => napot_decode(pmpaddr),
+        };
+        Self {
+            base,
+            size,
+            permission: config.permission,
+            mode: config.range,
+            locked: config.locked,
+        }
+    }
+
+    /// Returns the access permissions of the region with machine mode lockdown (`mseccfg.MML`) enabled.
+    #[inline]
+    pub const fn mml_permission(&self) -> MmlPermission {
+        MmlPermission::decode(self.permission, self.locked)
+    }
+
+    /// Updates the access permissions of the region for machine mode lockdown (`mseccfg.MML`).
+    ///
+    /// This updates both the [`Region::permission`] and [`Region::locked`] fields.
+    ///
+    /// Returns an error if the permissions cannot be encoded in a PMP entry.
+    #[inline]
+    pub fn set_mml_permission(&mut self, mml_permission: MmlPermission) -> Result<()> {
+        let (permission, locked) = mml_permission.try_encode()?;
+        self.permission = permission;
+        self.locked = locked;
+        Ok(())
+    }
+}
+
+/// Access permissions of a PMP entry with machine mode lockdown (`mseccfg.MML`) enabled
+///
+/// With MML, the `L`, `R`, `W`, and `X` bits of an entry jointly encode the permissions
+/// of M-mode and S/U-modes. The encodings with `R=0` and `W=1` describe shared regions,
+/// accessible from both M-mode and S/U-modes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MmlPermission {
+    /// Access permissions in M-mode
+    pub m_mode: Permission,
+    /// Access permissions in S-mode and U-mode
+    pub su_mode: Permission,
+}
+
+impl MmlPermission {
+    /// Decodes the access permissions of a PMP entry with machine mode lockdown enabled.
+    #[inline]
+    pub const fn decode(permission: Permission, locked: bool) -> Self {
+        let (m_mode, su_mode) = match (locked, permission) {
+            (false, Permission::W) => (Permission::RW, Permission::R),
+            (false, Permission::WX) => (Permission::RW, Permission::RW),
+            (false, permission) => (Permission::NONE, permission),
+            (true, Permission::W) => (Permission::X, Permission::X),
+            (true, Permission::WX) => (Permission::RX, Permission::X),
+            (true, Permission::RWX) => (Permission::R, Permission::R),
+            (true, permission) => (permission, Permission::NONE),
+        };
+        Self { m_mode, su_mode }
+    }
+
+    /// Encodes the access permissions as the permission and lock bits of a PMP entry.
+    ///
+    /// Returns an error if no PMP entry encoding grants these permissions.
+    /// The error value holds the M-mode and S/U-mode permission bits as `(m_mode << 3) | su_mode`.
+    #[inline]
+    pub const fn try_encode(&self) -> Result<(Permission, bool)> {
+        match (self.m_mode, self.su_mode) {
+            (Permission::RW, Permission::R) => Ok((Permission::W, false)),
+            (Permission::RW, Permission::RW) => Ok((Permission::WX, false)),
+            (Permission::X, Permission::X) => Ok((Permission::W, true)),
+            (Permission::RX, Permission::X) => Ok((Permission::WX, true)),
+            (Permission::R, Permission::R) => Ok((Permission::RWX, true)),
+            (Permission::NONE, Permission::W | Permission::WX) => Err(self.invalid()),
+            (Permission::NONE, su_mode) => Ok((su_mode, false)),
+            (Permission::W | Permission::WX | Permission::RWX, Permission::NONE) => {
+                Err(self.invalid())
+            }
+            (m_mode, Permission::NONE) => Ok((m_mode, true)),
+            _ => Err(self.invalid()),
+        }
+    }
+
+    /// Returns `true` if the region is accessible from both M-mode and S/U-modes.
+    #[inline]
+    pub const fn is_shared(&self) -> bool {
+        !matches!(self.m_mode, Permission::NONE) && !matches!(self.su_mode, Permission::NONE)
+    }
+
+    #[inline]
+    const fn invalid(&self) -> Error {
+        Error::InvalidVariant(((self.m_mode as usize) << 3) | self.su_mode as usize)
+    }
+}
+
+/// Encodes a naturally aligned power-of-two (NAPOT) region as a `pmpaddrx` register value.
+///
+/// The region starts at `base` and spans `size` bytes.
+///
+/// Returns an error if `size` is not a power of two of at least 8 bytes,
+/// or if `base` is not aligned to `size`.
+#[inline]
+pub const fn napot_encode(base: usize, size: usize) -> Result<usize> {
+    if size < 8 || !size.is_power_of_two() {
+        Err(Error::InvalidFieldValue {
+            field: "size",
+            value: size,
+            bitmask: !0b111,
+        })
+    } else if base & (size - 1) != 0 {
+        Err(Error::InvalidFieldValue {
+            field: "base",
+            value: base,
+            bitmask: !(size - 1),
+        })
+    } else {
+        Ok((base >> 2) | ((size >> 3) - 1))
+    }
+}
+
+/// Decodes a `pmpaddrx` register value of a naturally aligned power-of-two (NAPOT) region.
+///
+/// Returns the base address and the size (in bytes) of the region.
+/// If the region spans the whole address space, the size saturates to `usize::MAX`.
+#[inline]
+pub const fn napot_decode(pmpaddr: usize) -> (usize, usize) {
+    let ones = pmpaddr.trailing_ones();
+    match 1usize.checked_shl(ones + 3) {
+        Some(size) => ((pmpaddr & !((1 << ones) - 1)) << 2, size),
+        None => (0, usize::MAX),
+    }
+}
+
+/// Returns the `pmpcfgx` register and the byte within it that hold the configuration of an entry.
+#[inline]
+const fn cfg_location(index: usize) -> (usize, usize) {
+    match () {
+        #[cfg(riscv32)]
+        () => (index / 4, index % 4),
+        #[cfg(not(riscv32))]
+        () => ((index / 8) * 2, index % 8),
+    }
+}
+
+#[inline]
+fn check_index(index: usize) -> Result<()> {
+    if index < ENTRIES {
+        Ok(())
+    } else {
+        Err(Error::IndexOutOfBounds {
+            index,
+            min: 0,
+            max: ENTRIES - 1,
+        })
+    }
+}
+
+#[inline]
+fn try_read_cfg(index: usize) -> Result<Pmp> {
+    check_index(index)?;
+    let (csr, byte) = cfg_location(index);
+    match csr {
+        0 => pmpcfg0::try_read()?.try_into_config(byte),
+        #[cfg(riscv32)]
+        1 => pmpcfg1::try_read()?.try_into_config(byte),
+        2 => pmpcfg2::try_read()?.try_into_config(byte),
+        #[cfg(riscv32)]
+        3 => pmpcfg3::try_read()?.try_into_config(byte),
+        _ => unreachable!(),
+    }
+}
+
+#[inline]
+unsafe fn try_write_cfg(
+    index: usize,
+    range: Range,
+    permission: Permission,
+    locked: bool,
+) -> Result<()> {
+    check_index(index)?;
+    let (csr, byte) = cfg_location(index);
+    match csr {
+        0 => pmpcfg0::try_set_pmp(byte, range, permission, locked),
+        #[cfg(riscv32)]
+        1 => pmpcfg1::try_set_pmp(byte, range, permission, locked),
+        2 => pmpcfg2::try_set_pmp(byte, range, permission, locked),
+        #[cfg(riscv32)]
+        3 => pmpcfg3::try_set_pmp(byte, range, permission, locked),
+        _ => unreachable!(),
+    }
+}
+
+/// Dispatches a function call to the `pmpaddrx` module selected by `index`.
+macro_rules! dispatch_addr {
+    ($index:expr, $f:ident($($arg:expr),*)) => {
+        match $index {
+            0 => pmpaddr0::$f($($arg),*),
+            1 => pmpaddr1::$f($($arg),*),
+            2 => pmpaddr2::$f($($arg),*),
+            3 => pmpaddr3::$f($($arg),*),
+            4 => pmpaddr4::$f($($arg),*),
+            5 => pmpaddr5::$f($($arg),*),
+            6 => pmpaddr6::$f($($arg),*),
+            7 => pmpaddr7::$f($($arg),*),
+            8 => pmpaddr8::$f($($arg),*),
+            9 => pmpaddr9::$f($($arg),*),
+            10 => pmpaddr10::$f($($arg),*),
+            11 => pmpaddr11::$f($($arg),*),
+            12 => pmpaddr12::$f($($arg),*),
+            13 => pmpaddr13::$f($($arg),*),
+            14 => pmpaddr14::$f($($arg),*),
+            15 => pmpaddr15::$f($($arg),*),
+            index => Err(Error::IndexOutOfBounds {
+                index,
+                min: 0,
+                max: ENTRIES - 1,
+            }),
+        }
+    };
+}
+
+/// Programs the PMP entry `index` to protect `region`.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` out-of-bounds
+/// - base address or size not valid for the addressing mode of the region
+#[inline]
+pub unsafe fn set_entry(index: usize, region: Region) {
+    try_set_entry(index, region).unwrap();
+}
+
+/// Attempts to program the PMP entry `index` to protect `region`.
+///
+/// The `pmpaddrx` register is written before the configuration byte,
+/// so the entry is never enabled with a stale address.
+/// Note that writes to locked entries are silently ignored by the hardware.
+///
+/// Returns an error if `index` is out-of-bounds, or if the base address or size
+/// of the region are not valid for its addressing mode.
+#[inline]
+pub unsafe fn try_set_entry(index: usize, region: Region) -> Result<()> {
+    check_index(index)?;
+    let pmpaddr = region.pmpaddr()?;
+    dispatch_addr!(index, try_write(pmpaddr))?;
+    try_write_cfg(index, region.mode, region.permission, region.locked)
+}
+
+/// Reads the region protected by the PMP entry `index`.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` out-of-bounds
+#[inline]
+pub fn get_entry(index: usize) -> Region {
+    try_get_entry(index).unwrap()
+}
+
+/// Attempts to read the region protected by the PMP entry `index`.
+///
+/// Returns an error if `index` is out-of-bounds.
+#[inline]
+pub fn try_get_entry(index: usize) -> Result<Region> {
+    let config = try_read_cfg(index)?;
+    let pmpaddr = dispatch_addr!(index, try_read())?;
+    let previous = match (config.range, index) {
+        (Range::TOR, 1..) => dispatch_addr!(index - 1, try_read())?,
+        _ => 0,
+    };
+    Ok(Region::decode(config, pmpaddr, previous))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(range: Range) -> Pmp {
+        Pmp {
+            byte: 0,
+            permission: Permission::RX,
+            range,
+            locked: false,
+        }
+    }
+
+    #[test]
+    fn test_region() {
+        let region = Region {
+            base: 0x8000_0000,
+            size: 0x1_0000,
+            permission: Permission::RX,
+            mode: Range::NAPOT,
+            locked: false,
+        };
+        assert_eq!(region.pmpaddr(), Ok(0x2000_0000 | 0x1FFF));
+        assert_eq!(Region::decode(config(Range::NAPOT), 0x2000_1FFF, 0), region);
+
+        let region = Region {
+            mode: Range::TOR,
+            ..region
+        };
+        assert_eq!(region.pmpaddr(), Ok(0x2000_4000));
+        assert_eq!(
+            Region::decode(config(Range::TOR), 0x2000_4000, 0x2000_0000),
+            region
+        );
+
+        let region = Region {
+            base: 0x1000,
+            size: 4,
+            mode: Range::NA4,
+            ..region
+        };
+        assert_eq!(region.pmpaddr(), Ok(0x400));
+        assert_eq!(Region::decode(config(Range::NA4), 0x400, 0), region);
+
+        let region = Region {
+            base: 0x8000_1000,
+            size: 0x1_0000,
+            mode: Range::NAPOT,
+            ..region
+        };
+        assert!(region.pmpaddr().is_err());
+        let region = Region {
+            base: 0x8000_0000,
+            size: 0x1_1000,
+            ..region
+        };
+        assert!(region.pmpaddr().is_err());
+    }
+
+    #[test]
+    fn test_mml_permission() {
+        for locked in [false, true] {
+            for bits in 0..8 {
+                let permission = Permission::try_from(bits).unwrap();
+                let mml = MmlPermission::decode(permission, locked);
+                match (locked, permission) {
+                    (_, Permission::NONE) => assert_eq!(mml.try_encode(), Ok((permission, false))),
+                    _ => assert_eq!(mml.try_encode(), Ok((permission, locked))),
+                }
+            }
+        }
+
+        // every encodable pair decodes back to itself, and no entry decodes to the others
+        for m_bits in 0..8 {
+            for su_bits in 0..8 {
+                let mml = MmlPermission {
+                    m_mode: Permission::try_from(m_bits).unwrap(),
+                    su_mode: Permission::try_from(su_bits).unwrap(),
+                };
+                match mml.try_encode() {
+                    Ok((permission, locked)) => {
+                        assert_eq!(MmlPermission::decode(permission, locked), mml)
+                    }
+                    Err(e) => {
+                        assert_eq!(e, Error::InvalidVariant(((m_bits << 3) | su_bits) as _));
+                        for locked in [false, true] {
+                            for bits in 0..8 {
+                                let permission = Permission::try_from(bits).unwrap();
+                                assert_ne!(MmlPermission::decode(permission, locked), mml);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let shared = MmlPermission {
+            m_mode: Permission::RX,
+            su_mode: Permission::X,
+        };
+        assert!(shared.is_shared());
+        assert_eq!(shared.try_encode(), Ok((Permission::WX, true)));
+        assert!(!MmlPermission::decode(Permission::RWX, false).is_shared());
+
+        let invalid = MmlPermission {
+            m_mode: Permission::RWX,
+            su_mode: Permission::R,
+        };
+        assert_eq!(invalid.try_encode(), Err(Error::InvalidVariant(0o71)));
+
+        let mut region = Region {
+            base: 0x8000_0000,
+            size: 0x1000,
+            permission: Permission::NONE,
+            mode: Range::NAPOT,
+            locked: false,
+        };
+        assert!(region.set_mml_permission(shared).is_ok());
+        assert_eq!(region.permission, Permission::WX);
+        assert!(region.locked);
+        assert_eq!(region.mml_permission(), shared);
+    }
+
+    #[test]
+    fn test_napot() {
+        assert_eq!(napot_encode(0x8000_0000, 8), Ok(0x2000_0000));
+        assert_eq!(napot_encode(0x8000_0000, 0x1000), Ok(0x2000_01FF));
+        assert_eq!(napot_decode(0x2000_0000), (0x8000_0000, 8));
+        assert_eq!(napot_decode(0x2000_01FF), (0x8000_0000, 0x1000));
+        assert_eq!(napot_decode(usize::MAX), (0, usize::MAX));
+
+        for shift in 3..usize::BITS - 1 {
+            let size = 1 << shift;
+            let pmpaddr = napot_encode(size, size).unwrap();
+            assert_eq!(napot_decode(pmpaddr), (size, size));
+        }
+
+        assert_eq!(
+            napot_encode(0x8000_0000, 4),
+            Err(Error::InvalidFieldValue {
+                field: "size",
+                value: 4,
+                bitmask: !0b111,
+            })
+        );
+        assert_eq!(
+            napot_encode(0x8000_0000, 0x1800),
+            Err(Error::InvalidFieldValue {
+                field: "size",
+                value: 0x1800,
+                bitmask: !0b111,
+            })
+        );
+        assert_eq!(
+            napot_encode(0x8000_0800, 0x1000),
+            Err(Error::InvalidFieldValue {
+                field: "base",
+                value: 0x8000_0800,
+                bitmask: !0xFFF,
+            })
+        );
+    }
+
+    #[test]
+    fn test_entry() {
+        assert_eq!(try_get_entry(0), Err(Error::Unimplemented));
+        assert_eq!(
+            try_get_entry(ENTRIES),
+            Err(Error::IndexOutOfBounds {
+                index: ENTRIES,
+                min: 0,
+                max: ENTRIES - 1,
+            })
+        );
+    }
+}
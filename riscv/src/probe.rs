@@ -0,0 +1,208 @@
+//! Runtime extension probing
+//!
+//! Many extensions (e.g., `Zba` or `Sstc`) are not reported in `misa`. This module detects
+//! them by executing a representative instruction and checking whether it raises an
+//! illegal-instruction exception.
+//!
+//! Probing requires the cooperation of the trap handler: on an illegal-instruction exception,
+//! it must call [`on_illegal_instruction`] and, if it returns a new exception program counter,
+//! write it to `mepc` (or `sepc` in S-mode) and return from the trap.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use riscv::{isa::Extension, probe, register::mepc};
+//!
+//! // in the illegal-instruction exception handler
+//! if let Some(epc) = probe::on_illegal_instruction(mepc::read()) {
+//!     unsafe { mepc::write(epc) };
+//!     return;
+//! }
+//!
+//! // during initialization
+//! if unsafe { probe::probe(Extension::Zba) } {
+//!     // use the address generation instructions
+//! }
+//! ```
+
+use crate::isa::{Extension, IsaExtensions};
+use crate::register::misa::XLEN;
+use crate::result::{Error, Result};
+use core::sync::atomic::{compiler_fence, AtomicBool, Ordering};
+
+/// Extensions that can be probed
+pub const EXTENSIONS: &[Extension] = &[
+    Extension::Zicntr,
+    Extension::Zicond,
+    Extension::Zba,
+    Extension::Zbb,
+    Extension::Zbc,
+    Extension::Zbs,
+    Extension::Zbkb,
+    Extension::Zve32x,
+    Extension::Sstc,
+    Extension::Svinval,
+];
+
+/// Set while a probe instruction is being executed
+static PROBING: AtomicBool = AtomicBool::new(false);
+/// Set by [`on_illegal_instruction`] if the probe instruction trapped
+static TRAPPED: AtomicBool = AtomicBool::new(false);
+
+/// Trap hook to be called by the illegal-instruction exception handler.
+///
+/// `epc` is the address of the faulting instruction (i.e., the value of `mepc` or `sepc`).
+///
+/// Returns the address at which execution must resume if the exception was raised
+/// by a probe instruction, or `None` if the exception must be handled as usual.
+#[inline]
+pub fn on_illegal_instruction(epc: usize) -> Option<usize> {
+    if PROBING.load(Ordering::Relaxed) {
+        PROBING.store(false, Ordering::Relaxed);
+        TRAPPED.store(true, Ordering::Relaxed);
+        // probe instructions are never compressed
+        Some(epc.wrapping_add(4))
+    } else {
+        None
+    }
+}
+
+/// Returns true if `extension` is available.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - extensions that cannot be probed (see [`EXTENSIONS`])
+///
+/// # Safety
+///
+/// The illegal-instruction exception handler must call [`on_illegal_instruction`] (see the module
+/// documentation). Probing must not run concurrently on several harts.
+#[inline]
+pub unsafe fn probe(extension: Extension) -> bool {
+    try_probe(extension).unwrap()
+}
+
+/// Attempts to probe whether `extension` is available.
+///
+/// Returns [`Error::InvalidVariant`] for extensions that cannot be probed (see [`EXTENSIONS`]).
+///
+/// Note that `Zve32x` is probed with a `vsetivli` instruction, which also covers the `V` extension.
+/// The vector unit must be enabled (i.e., the `VS` field of `mstatus` or `sstatus` must not be off),
+/// or the extension is reported as unavailable.
+///
+/// # Safety
+///
+/// See [`probe`].
+#[inline]
+pub unsafe fn try_probe(extension: Extension) -> Result<bool> {
+    if !EXTENSIONS.contains(&extension) {
+        return Err(Error::InvalidVariant(extension as usize));
+    }
+    if cfg!(not(riscv)) {
+        return Err(Error::Unimplemented);
+    }
+
+    TRAPPED.store(false, Ordering::Relaxed);
+    PROBING.store(true, Ordering::Relaxed);
+    compiler_fence(Ordering::SeqCst);
+    execute(extension);
+    compiler_fence(Ordering::SeqCst);
+    PROBING.store(false, Ordering::Relaxed);
+    Ok(!TRAPPED.load(Ordering::Relaxed))
+}
+
+/// Probes all the [`EXTENSIONS`] and returns the available ones.
+///
+/// **WARNING**: panics on non-`riscv` targets.
+///
+/// # Safety
+///
+/// See [`probe`].
+#[inline]
+pub unsafe fn probe_all() -> IsaExtensions {
+    try_probe_all().unwrap()
+}
+
+/// Attempts to probe all the [`EXTENSIONS`] and returns the available ones.
+///
+/// The returned set does not contain single-letter extensions,
+/// which can be obtained from `misa` or an ISA string instead.
+///
+/// # Safety
+///
+/// See [`probe`].
+#[inline]
+pub unsafe fn try_probe_all() -> Result<IsaExtensions> {
+    let mut extensions = IsaExtensions::new(match () {
+        #[cfg(target_pointer_width = "32")]
+        () => XLEN::XLEN32,
+        #[cfg(not(target_pointer_width = "32"))]
+        () => XLEN::XLEN64,
+    });
+    for &extension in EXTENSIONS {
+        if try_probe(extension)? {
+            extensions.insert(extension);
+        }
+    }
+    Ok(extensions)
+}
+
+/// Executes the probe instruction of `extension`.
+///
+/// Each probe is a single, uncompressed instruction without side effects.
+#[inline(never)]
+#[allow(unused_variables)]
+unsafe fn execute(extension: Extension) {
+    match () {
+        #[cfg(riscv)]
+        () => {
+            let _r: usize;
+            match extension {
+                // rdcycle
+                Extension::Zicntr => core::arch::asm!("csrrs {0}, 0xC00, x0", out(reg) _r),
+                // czero.eqz x0, x0, x0
+                Extension::Zicond => core::arch::asm!(".insn r 0x33, 5, 0x07, x0, x0, x0"),
+                // sh1add x0, x0, x0
+                Extension::Zba => core::arch::asm!(".insn r 0x33, 2, 0x10, x0, x0, x0"),
+                // clz x0, x0
+                Extension::Zbb => core::arch::asm!(".insn i 0x13, 1, x0, x0, 0x600"),
+                // clmul x0, x0, x0
+                Extension::Zbc => core::arch::asm!(".insn r 0x33, 1, 0x05, x0, x0, x0"),
+                // bset x0, x0, x0
+                Extension::Zbs => core::arch::asm!(".insn r 0x33, 1, 0x14, x0, x0, x0"),
+                // pack x0, x0, x1 (`pack` with `rs2 = x0` is `zext.h` of Zbb)
+                Extension::Zbkb => core::arch::asm!(".insn r 0x33, 4, 0x04, x0, x0, x1"),
+                // vsetivli x0, 0, e8, m1, ta, ma
+                Extension::Zve32x => core::arch::asm!(".word 0xCC007057"),
+                // csrr stimecmp
+                Extension::Sstc => core::arch::asm!("csrrs {0}, 0x14D, x0", out(reg) _r),
+                // sfence.w.inval
+                Extension::Svinval => core::arch::asm!(".insn r 0x73, 0, 0x0C, x0, x0, x0"),
+                _ => {}
+            }
+        }
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_probe() {
+        assert_eq!(on_illegal_instruction(0x8000_0000), None);
+        assert_eq!(
+            unsafe { try_probe(Extension::Zicsr) },
+            Err(Error::InvalidVariant(Extension::Zicsr as usize))
+        );
+        assert_eq!(
+            unsafe { try_probe(Extension::Zba) },
+            Err(Error::Unimplemented)
+        );
+        assert_eq!(unsafe { try_probe_all() }, Err(Error::Unimplemented));
+    }
+}
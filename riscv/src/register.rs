@@ -10,12 +10,14 @@
 //! - minstreth
 //! - mhpmcounter<3-31>h
 //! - mstatush
+//! - menvcfgh
+//! - mseccfgh
+//! - mstateen<0-3>h
 //!
 //! # On Floating-Point CSRs
 //!
-//! We are deliberately *not* providing instructions that could change the floating-point rounding
-//! mode or exception behavior or read the accrued exceptions flags: `frcsr`, `fscsr`, `fsrm`,
-//! `frflags`, `fsflags`.
+//! The `fcsr` module provides access to the floating-point rounding mode and accrued exceptions
+//! flags (e.g., to switch the floating-point context of a thread), but be aware of the following.
 //!
 //! Rust makes no guarantees whatsoever about the contents of the accrued exceptions register: Rust
 //! floating-point operations may or may not result in this register getting updated with exception
@@ -39,9 +41,49 @@
 #[macro_use]
 mod macros;
 
+mod trap_vector;
+pub use self::trap_vector::TrapVector;
+
+// User Trap Setup
+#[cfg(feature = "n-ext")]
+pub mod ustatus;
+#[cfg(feature = "n-ext")]
+pub mod utvec;
+
+// User Trap Handling
+#[cfg(feature = "n-ext")]
+pub mod ucause;
+#[cfg(feature = "n-ext")]
+pub mod uepc;
+#[cfg(feature = "n-ext")]
+pub mod uscratch;
+#[cfg(feature = "n-ext")]
+pub mod utval;
+
+// User Floating-Point CSRs
+#[cfg(any(target_feature = "f", not(riscv)))]
+pub mod fcsr;
+
+// User Vector CSRs
+#[cfg(feature = "v-ext")]
+pub mod vcsr;
+#[cfg(feature = "v-ext")]
+pub mod vl;
+#[cfg(feature = "v-ext")]
+pub mod vlenb;
+#[cfg(feature = "v-ext")]
+pub mod vstart;
+#[cfg(feature = "v-ext")]
+pub mod vtype;
+#[cfg(feature = "v-ext")]
+pub mod vxrm;
+#[cfg(feature = "v-ext")]
+pub mod vxsat;
+
 // User Counter/Timers
 pub mod cycle;
 pub mod cycleh;
+pub mod hpmcounter;
 mod hpmcounterx;
 pub use self::hpmcounterx::*;
 pub mod instret;
@@ -49,6 +91,16 @@ pub mod instreth;
 pub mod time;
 pub mod timeh;
 
+// User Table Jump
+pub mod jvt;
+
+// User Entropy Source
+pub mod seed;
+
+// User Shadow Stack
+#[cfg(feature = "zicfiss")]
+pub mod ssp;
+
 // Supervisor Trap Setup
 pub mod scounteren;
 pub mod sie;
@@ -62,11 +114,26 @@ pub mod sip;
 pub mod sscratch;
 pub mod stval;
 
+// Supervisor Counter/Timers
+pub mod scountovf;
+
 // Supervisor Protection and Translation
 pub mod satp;
 
+// Supervisor Configuration
+pub mod senvcfg;
+pub mod sstateen;
+
+// Supervisor Advanced Interrupt Architecture (AIA)
+pub mod sireg;
+pub mod siselect;
+pub mod stopei;
+pub mod stopi;
+
 // Machine Information Registers
+pub mod machine_id;
 pub mod marchid;
+pub mod mconfigptr;
 pub mod mhartid;
 pub mod mimpid;
 pub mod mvendorid;
@@ -80,33 +147,166 @@ pub mod misa;
 pub mod mstatus;
 pub mod mstatush;
 pub mod mtvec;
+pub mod mtvt;
 
 // Machine Trap Handling
 pub mod mcause;
 pub mod mepc;
 pub mod mip;
 pub mod mscratch;
+#[cfg(feature = "h-extension")]
+pub mod mtinst;
 pub mod mtval;
+#[cfg(feature = "h-extension")]
+pub mod mtval2;
+
+// Machine Advanced Interrupt Architecture (AIA)
+pub mod mireg;
+pub mod miselect;
+pub mod mtopei;
+pub mod mtopi;
+
+// Machine Core-Local Interrupt Controller (CLIC)
+pub mod mintstatus;
+pub mod mintthresh;
+pub mod mnxti;
+pub mod mscratchcsw;
+pub mod mscratchcswl;
+
+// Machine Resumable NMI Handling
+pub mod mncause;
+pub mod mnepc;
+pub mod mnscratch;
+pub mod mnstatus;
 
 // Machine Protection and Translation
 mod pmpcfgx;
 pub use self::pmpcfgx::*;
 mod pmpaddrx;
 pub use self::pmpaddrx::*;
+pub mod mseccfg;
+pub mod mseccfgh;
+
+// Machine Configuration
+pub mod menvcfg;
+pub mod menvcfgh;
+pub mod mstateen;
+mod stateenx;
+pub use self::stateenx::*;
 
 // Machine Counter/Timers
 pub mod mcountinhibit;
 pub mod mcycle;
 pub mod mcycleh;
+pub mod mhpmcounter;
 mod mhpmcounterx;
 pub use self::mhpmcounterx::*;
 pub mod minstret;
 pub mod minstreth;
 
 // Machine Counter Setup
+pub mod mcyclecfg;
+pub mod mcyclecfgh;
+pub mod mhpmevent;
 mod mhpmeventx;
 pub use self::mhpmeventx::*;
+pub mod minstretcfg;
+pub mod minstretcfgh;
+
+// Hypervisor Trap Setup
+#[cfg(feature = "h-extension")]
+pub mod hcounteren;
+#[cfg(feature = "h-extension")]
+pub mod hedeleg;
+#[cfg(feature = "h-extension")]
+pub mod hgeie;
+#[cfg(feature = "h-extension")]
+pub mod hideleg;
+#[cfg(feature = "h-extension")]
+pub mod hie;
+#[cfg(feature = "h-extension")]
+pub mod hstatus;
+
+// Hypervisor Trap Handling
+#[cfg(feature = "h-extension")]
+pub mod hgeip;
+#[cfg(feature = "h-extension")]
+pub mod hip;
+#[cfg(feature = "h-extension")]
+pub mod hvip;
+
+// Hypervisor Interrupt Virtualization (AIA)
+#[cfg(feature = "h-extension")]
+pub mod hvictl;
+#[cfg(feature = "h-extension")]
+pub mod hvien;
+#[cfg(feature = "h-extension")]
+pub mod hvienh;
+#[cfg(feature = "h-extension")]
+pub mod hviprio1;
+#[cfg(feature = "h-extension")]
+pub mod hviprio1h;
+#[cfg(feature = "h-extension")]
+pub mod hviprio2;
+#[cfg(feature = "h-extension")]
+pub mod hviprio2h;
+
+// Hypervisor Configuration
+#[cfg(feature = "h-extension")]
+pub mod henvcfg;
+#[cfg(feature = "h-extension")]
+pub mod henvcfgh;
+
+// Hypervisor Counter/Timer Virtualization Registers
+#[cfg(feature = "h-extension")]
+pub mod htimedelta;
+#[cfg(feature = "h-extension")]
+pub mod htimedeltah;
+
+// Hypervisor Protection and Translation
+#[cfg(feature = "h-extension")]
+pub mod hgatp;
+
+// Virtual Supervisor Registers
+#[cfg(feature = "h-extension")]
+pub mod vsatp;
+#[cfg(feature = "h-extension")]
+pub mod vscause;
+#[cfg(feature = "h-extension")]
+pub mod vsepc;
+#[cfg(feature = "h-extension")]
+pub mod vsie;
+#[cfg(feature = "h-extension")]
+pub mod vsip;
+#[cfg(feature = "h-extension")]
+pub mod vsscratch;
+#[cfg(feature = "h-extension")]
+pub mod vsstatus;
+#[cfg(feature = "h-extension")]
+pub mod vstval;
+#[cfg(feature = "h-extension")]
+pub mod vstvec;
+
+// Snapshots
+pub mod snapshot;
 
-// TODO: Debug/Trace Registers (shared with Debug Mode)
+// Debug/Trace Registers (shared with Debug Mode)
+#[cfg(feature = "h-extension")]
+pub mod hcontext;
+pub mod mcontext;
+pub mod scontext;
+pub mod tdata1;
+pub mod tdata2;
+pub mod tdata3;
+pub mod tinfo;
+pub mod tselect;
 
-// TODO: Debug Mode Registers
+// Debug Mode Registers
+#[cfg(feature = "debug-mode")]
+pub mod dcsr;
+#[cfg(feature = "debug-mode")]
+pub mod dpc;
+#[cfg(feature = "debug-mode")]
+pub mod dscratch0;
+#[cfg(feature = "debug-mode")]
+pub mod dscratch1;
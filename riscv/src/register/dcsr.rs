@@ -0,0 +1,398 @@
+//! dcsr register (Sdext)
+//!
+//! Debug control and status register. It is only accessible in debug mode.
+
+use super::mstatus::PrivilegeLevel;
+use crate::bits::{bf_extract, bf_insert};
+use crate::result::{Error, Result};
+
+/// dcsr register
+#[derive(Clone, Copy, Debug)]
+pub struct Dcsr {
+    bits: usize,
+}
+
+impl From<usize> for Dcsr {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Dcsr {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Privilege level the hart was operating in when debug mode was entered
+    ///
+    /// The hart resumes in this privilege level when leaving debug mode.
+    ///
+    /// **WARNING**: panics if the field has an invalid variant.
+    #[inline]
+    pub fn prv(&self) -> PrivilegeLevel {
+        self.try_prv().unwrap()
+    }
+
+    /// Attempts to get the privilege level the hart was operating in when debug mode was entered
+    #[inline]
+    pub fn try_prv(&self) -> Result<PrivilegeLevel> {
+        match bf_extract(self.bits, 0, 2) {
+            0 => Ok(PrivilegeLevel::User),
+            1 => Ok(PrivilegeLevel::Supervisor),
+            3 => Ok(PrivilegeLevel::Machine),
+            value => Err(Error::InvalidFieldVariant {
+                field: "prv",
+                value,
+            }),
+        }
+    }
+
+    /// Update the privilege level the hart resumes in when leaving debug mode
+    ///
+    /// Note this updates a previously read [`Dcsr`] value, but does not
+    /// affect the dcsr CSR itself. See [`set_prv`] to directly update the CSR.
+    #[inline]
+    pub fn set_prv(&mut self, prv: PrivilegeLevel) {
+        self.bits = bf_insert(self.bits, 0, 2, prv as usize);
+    }
+
+    /// Single step
+    ///
+    /// When set, the hart enters debug mode again after executing a single instruction.
+    #[inline]
+    pub fn step(&self) -> bool {
+        self.bits & (1 << 2) != 0
+    }
+
+    /// Update single step
+    ///
+    /// Note this updates a previously read [`Dcsr`] value, but does not
+    /// affect the dcsr CSR itself. See [`set_step`]/[`clear_step`] to directly update the CSR.
+    #[inline]
+    pub fn set_step(&mut self, step: bool) {
+        self.bits = bf_insert(self.bits, 2, 1, step as usize);
+    }
+
+    /// Non-maskable interrupt pending
+    #[inline]
+    pub fn nmip(&self) -> bool {
+        self.bits & (1 << 3) != 0
+    }
+
+    /// Enable `mstatus.MPRV` in debug mode
+    #[inline]
+    pub fn mprven(&self) -> bool {
+        self.bits & (1 << 4) != 0
+    }
+
+    /// Update enable `mstatus.MPRV` in debug mode
+    ///
+    /// Note this updates a previously read [`Dcsr`] value, but does not
+    /// affect the dcsr CSR itself. See [`set_mprven`]/[`clear_mprven`] to directly update the CSR.
+    #[inline]
+    pub fn set_mprven(&mut self, mprven: bool) {
+        self.bits = bf_insert(self.bits, 4, 1, mprven as usize);
+    }
+
+    /// Virtualization mode the hart was operating in when debug mode was entered
+    ///
+    /// Together with [`Dcsr::prv`], the hart resumes in this mode.
+    #[inline]
+    pub fn v(&self) -> bool {
+        self.bits & (1 << 5) != 0
+    }
+
+    /// Update virtualization mode the hart was operating in when debug mode was entered
+    ///
+    /// Note this updates a previously read [`Dcsr`] value, but does not
+    /// affect the dcsr CSR itself. See [`set_v`]/[`clear_v`] to directly update the CSR.
+    #[inline]
+    pub fn set_v(&mut self, v: bool) {
+        self.bits = bf_insert(self.bits, 5, 1, v as usize);
+    }
+
+    /// Stop the timer while in debug mode
+    #[inline]
+    pub fn stoptime(&self) -> bool {
+        self.bits & (1 << 9) != 0
+    }
+
+    /// Update stop the timer while in debug mode
+    ///
+    /// Note this updates a previously read [`Dcsr`] value, but does not
+    /// affect the dcsr CSR itself. See [`set_stoptime`]/[`clear_stoptime`] to directly update the CSR.
+    #[inline]
+    pub fn set_stoptime(&mut self, stoptime: bool) {
+        self.bits = bf_insert(self.bits, 9, 1, stoptime as usize);
+    }
+
+    /// Stop the counters while in debug mode
+    #[inline]
+    pub fn stopcount(&self) -> bool {
+        self.bits & (1 << 10) != 0
+    }
+
+    /// Update stop the counters while in debug mode
+    ///
+    /// Note this updates a previously read [`Dcsr`] value, but does not
+    /// affect the dcsr CSR itself. See [`set_stopcount`]/[`clear_stopcount`] to directly update the CSR.
+    #[inline]
+    pub fn set_stopcount(&mut self, stopcount: bool) {
+        self.bits = bf_insert(self.bits, 10, 1, stopcount as usize);
+    }
+
+    /// Enable interrupts during single stepping
+    #[inline]
+    pub fn stepie(&self) -> bool {
+        self.bits & (1 << 11) != 0
+    }
+
+    /// Update enable interrupts during single stepping
+    ///
+    /// Note this updates a previously read [`Dcsr`] value, but does not
+    /// affect the dcsr CSR itself. See [`set_stepie`]/[`clear_stepie`] to directly update the CSR.
+    #[inline]
+    pub fn set_stepie(&mut self, stepie: bool) {
+        self.bits = bf_insert(self.bits, 11, 1, stepie as usize);
+    }
+
+    /// Enter debug mode on `ebreak` in U-mode
+    #[inline]
+    pub fn ebreaku(&self) -> bool {
+        self.bits & (1 << 12) != 0
+    }
+
+    /// Update enter debug mode on `ebreak` in U-mode
+    ///
+    /// Note this updates a previously read [`Dcsr`] value, but does not
+    /// affect the dcsr CSR itself. See [`set_ebreaku`]/[`clear_ebreaku`] to directly update the CSR.
+    #[inline]
+    pub fn set_ebreaku(&mut self, ebreaku: bool) {
+        self.bits = bf_insert(self.bits, 12, 1, ebreaku as usize);
+    }
+
+    /// Enter debug mode on `ebreak` in S-mode
+    #[inline]
+    pub fn ebreaks(&self) -> bool {
+        self.bits & (1 << 13) != 0
+    }
+
+    /// Update enter debug mode on `ebreak` in S-mode
+    ///
+    /// Note this updates a previously read [`Dcsr`] value, but does not
+    /// affect the dcsr CSR itself. See [`set_ebreaks`]/[`clear_ebreaks`] to directly update the CSR.
+    #[inline]
+    pub fn set_ebreaks(&mut self, ebreaks: bool) {
+        self.bits = bf_insert(self.bits, 13, 1, ebreaks as usize);
+    }
+
+    /// Enter debug mode on `ebreak` in M-mode
+    #[inline]
+    pub fn ebreakm(&self) -> bool {
+        self.bits & (1 << 15) != 0
+    }
+
+    /// Update enter debug mode on `ebreak` in M-mode
+    ///
+    /// Note this updates a previously read [`Dcsr`] value, but does not
+    /// affect the dcsr CSR itself. See [`set_ebreakm`]/[`clear_ebreakm`] to directly update the CSR.
+    #[inline]
+    pub fn set_ebreakm(&mut self, ebreakm: bool) {
+        self.bits = bf_insert(self.bits, 15, 1, ebreakm as usize);
+    }
+
+    /// Enter debug mode on `ebreak` in VU-mode
+    #[inline]
+    pub fn ebreakvu(&self) -> bool {
+        self.bits & (1 << 16) != 0
+    }
+
+    /// Update enter debug mode on `ebreak` in VU-mode
+    ///
+    /// Note this updates a previously read [`Dcsr`] value, but does not
+    /// affect the dcsr CSR itself. See [`set_ebreakvu`]/[`clear_ebreakvu`] to directly update the CSR.
+    #[inline]
+    pub fn set_ebreakvu(&mut self, ebreakvu: bool) {
+        self.bits = bf_insert(self.bits, 16, 1, ebreakvu as usize);
+    }
+
+    /// Enter debug mode on `ebreak` in VS-mode
+    #[inline]
+    pub fn ebreakvs(&self) -> bool {
+        self.bits & (1 << 17) != 0
+    }
+
+    /// Update enter debug mode on `ebreak` in VS-mode
+    ///
+    /// Note this updates a previously read [`Dcsr`] value, but does not
+    /// affect the dcsr CSR itself. See [`set_ebreakvs`]/[`clear_ebreakvs`] to directly update the CSR.
+    #[inline]
+    pub fn set_ebreakvs(&mut self, ebreakvs: bool) {
+        self.bits = bf_insert(self.bits, 17, 1, ebreakvs as usize);
+    }
+
+    /// Cause of entering debug mode
+    ///
+    /// **WARNING**: panics if the field has an invalid variant.
+    #[inline]
+    pub fn cause(&self) -> Cause {
+        self.try_cause().unwrap()
+    }
+
+    /// Attempts to get the cause of entering debug mode
+    #[inline]
+    pub fn try_cause(&self) -> Result<Cause> {
+        (bf_extract(self.bits, 6, 3) as u8).try_into()
+    }
+
+    /// Version of the external debug support
+    #[inline]
+    pub fn debugver(&self) -> DebugVer {
+        match bf_extract(self.bits, 28, 4) {
+            0 => DebugVer::None,
+            4 => DebugVer::Standard,
+            15 => DebugVer::Custom,
+            value => DebugVer::Unknown(value as u8),
+        }
+    }
+}
+
+/// Cause of entering debug mode
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Cause {
+    /// An `ebreak` instruction was executed
+    Ebreak = 1,
+    /// A trigger module fired with a debug mode action
+    Trigger = 2,
+    /// The debugger requested a halt
+    HaltRequest = 3,
+    /// The hart single stepped
+    Step = 4,
+    /// The hart halted out of reset due to a debugger request
+    ResetHaltRequest = 5,
+    /// The hart halted because it is part of a halt group
+    Group = 6,
+    /// Other cause (see the `extcause` field)
+    Other = 7,
+}
+
+impl TryFrom<u8> for Cause {
+    type Error = Error;
+
+    fn try_from(val: u8) -> Result<Self> {
+        match val {
+            1 => Ok(Cause::Ebreak),
+            2 => Ok(Cause::Trigger),
+            3 => Ok(Cause::HaltRequest),
+            4 => Ok(Cause::Step),
+            5 => Ok(Cause::ResetHaltRequest),
+            6 => Ok(Cause::Group),
+            7 => Ok(Cause::Other),
+            _ => Err(Error::InvalidFieldVariant {
+                field: "cause",
+                value: val as usize,
+            }),
+        }
+    }
+}
+
+/// External debug support version
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DebugVer {
+    /// No external debug support
+    None,
+    /// External debug support as described in the RISC-V debug specification
+    Standard,
+    /// Non-standard external debug support
+    Custom,
+    /// Reserved version
+    Unknown(u8),
+}
+
+read_csr_as!(Dcsr, 0x7B0);
+write_csr_as!(Dcsr, 0x7B0);
+set!(0x7B0);
+clear!(0x7B0);
+
+set_clear_csr!(
+    /// Single step
+    , set_step, clear_step, 1 << 2);
+set_clear_csr!(
+    /// Enable `mstatus.MPRV` in debug mode
+    , set_mprven, clear_mprven, 1 << 4);
+set_clear_csr!(
+    /// Virtualization mode the hart resumes in when leaving debug mode
+    , set_v, clear_v, 1 << 5);
+set_clear_csr!(
+    /// Stop the timer while in debug mode
+    , set_stoptime, clear_stoptime, 1 << 9);
+set_clear_csr!(
+    /// Stop the counters while in debug mode
+    , set_stopcount, clear_stopcount, 1 << 10);
+set_clear_csr!(
+    /// Enable interrupts during single stepping
+    , set_stepie, clear_stepie, 1 << 11);
+set_clear_csr!(
+    /// Enter debug mode on `ebreak` in U-mode
+    , set_ebreaku, clear_ebreaku, 1 << 12);
+set_clear_csr!(
+    /// Enter debug mode on `ebreak` in S-mode
+    , set_ebreaks, clear_ebreaks, 1 << 13);
+set_clear_csr!(
+    /// Enter debug mode on `ebreak` in M-mode
+    , set_ebreakm, clear_ebreakm, 1 << 15);
+set_clear_csr!(
+    /// Enter debug mode on `ebreak` in VU-mode
+    , set_ebreakvu, clear_ebreakvu, 1 << 16);
+set_clear_csr!(
+    /// Enter debug mode on `ebreak` in VS-mode
+    , set_ebreakvs, clear_ebreakvs, 1 << 17);
+
+/// Set the privilege level the hart resumes in when leaving debug mode
+#[inline]
+pub unsafe fn set_prv(prv: PrivilegeLevel) {
+    let mut value = _read();
+    value &= !0x3; // clear previous value
+    value |= prv as usize;
+    _write(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dcsr() {
+        // debug support version 4, halted by an ebreak in M-mode
+        let mut dcsr = Dcsr::from((4 << 28) | (1 << 15) | (1 << 6) | 0b11);
+        assert_eq!(dcsr.debugver(), DebugVer::Standard);
+        assert_eq!(dcsr.cause(), Cause::Ebreak);
+        assert_eq!(dcsr.prv(), PrivilegeLevel::Machine);
+        assert!(dcsr.ebreakm() && !dcsr.ebreaks() && !dcsr.step());
+
+        dcsr.set_prv(PrivilegeLevel::User);
+        assert_eq!(dcsr.prv(), PrivilegeLevel::User);
+        dcsr.set_step(true);
+        assert!(dcsr.step());
+        dcsr.set_ebreakm(false);
+        assert!(!dcsr.ebreakm());
+
+        assert_eq!(
+            Dcsr::from(0b10).try_prv(),
+            Err(Error::InvalidFieldVariant {
+                field: "prv",
+                value: 2,
+            })
+        );
+        assert_eq!(
+            Dcsr::from(0).try_cause(),
+            Err(Error::InvalidFieldVariant {
+                field: "cause",
+                value: 0,
+            })
+        );
+    }
+}
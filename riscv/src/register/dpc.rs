@@ -0,0 +1,7 @@
+//! dpc register (Sdext)
+//!
+//! Debug program counter register. It holds the address of the instruction to resume at
+//! when leaving debug mode. It is only accessible in debug mode.
+
+read_csr_as_usize!(0x7B1);
+write_csr_as_usize!(0x7B1);
@@ -0,0 +1,6 @@
+//! dscratch0 register (Sdext)
+//!
+//! Debug scratch register 0. It is only accessible in debug mode.
+
+read_csr_as_usize!(0x7B2);
+write_csr_as_usize!(0x7B2);
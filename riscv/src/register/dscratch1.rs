@@ -0,0 +1,6 @@
+//! dscratch1 register (Sdext)
+//!
+//! Debug scratch register 1. It is only accessible in debug mode.
+
+read_csr_as_usize!(0x7B3);
+write_csr_as_usize!(0x7B3);
@@ -0,0 +1,230 @@
+//! fcsr register (F extension)
+//!
+//! Floating-point control and status register. It holds the accrued exception flags (`fflags`)
+//! and the dynamic rounding mode (`frm`).
+//!
+//! This module is only available on targets with the F extension.
+//! Read the [floating-point CSRs section](super#on-floating-point-csrs) before using it:
+//! Rust assumes the default rounding mode, and gives no guarantees on the accrued flags.
+
+use crate::result::{Error, Result};
+
+/// Mask of the accrued exception flags
+const FFLAGS_MASK: usize = 0x1F;
+
+/// fcsr register
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Fcsr {
+    bits: usize,
+}
+
+impl From<usize> for Fcsr {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Fcsr {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Accrued exception flags
+    #[inline]
+    pub fn fflags(&self) -> Fflags {
+        Fflags {
+            bits: self.bits & FFLAGS_MASK,
+        }
+    }
+
+    /// Dynamic rounding mode
+    ///
+    /// **WARNING**: panics if the field has an invalid variant.
+    #[inline]
+    pub fn frm(&self) -> RoundingMode {
+        self.try_frm().unwrap()
+    }
+
+    /// Attempts to get the dynamic rounding mode
+    #[inline]
+    pub fn try_frm(&self) -> Result<RoundingMode> {
+        (((self.bits >> 5) & 0b111) as u8).try_into()
+    }
+}
+
+/// Accrued floating-point exception flags
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Fflags {
+    bits: usize,
+}
+
+impl Fflags {
+    /// Returns the flags as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns true if no flag is set
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Inexact (`NX`)
+    #[inline]
+    pub fn nx(&self) -> bool {
+        self.bits & (1 << 0) != 0
+    }
+
+    /// Underflow (`UF`)
+    #[inline]
+    pub fn uf(&self) -> bool {
+        self.bits & (1 << 1) != 0
+    }
+
+    /// Overflow (`OF`)
+    #[inline]
+    pub fn of(&self) -> bool {
+        self.bits & (1 << 2) != 0
+    }
+
+    /// Divide by zero (`DZ`)
+    #[inline]
+    pub fn dz(&self) -> bool {
+        self.bits & (1 << 3) != 0
+    }
+
+    /// Invalid operation (`NV`)
+    #[inline]
+    pub fn nv(&self) -> bool {
+        self.bits & (1 << 4) != 0
+    }
+}
+
+/// Floating-point rounding mode
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoundingMode {
+    /// Round to nearest, ties to even (the default)
+    RoundToNearestEven = 0,
+    /// Round towards zero
+    RoundTowardsZero = 1,
+    /// Round down (towards negative infinity)
+    RoundDown = 2,
+    /// Round up (towards positive infinity)
+    RoundUp = 3,
+    /// Round to nearest, ties to max magnitude
+    RoundToNearestMaxMagnitude = 4,
+}
+
+impl TryFrom<u8> for RoundingMode {
+    type Error = Error;
+
+    fn try_from(val: u8) -> Result<Self> {
+        match val {
+            0 => Ok(Self::RoundToNearestEven),
+            1 => Ok(Self::RoundTowardsZero),
+            2 => Ok(Self::RoundDown),
+            3 => Ok(Self::RoundUp),
+            4 => Ok(Self::RoundToNearestMaxMagnitude),
+            _ => Err(Error::InvalidFieldVariant {
+                field: "frm",
+                value: val as usize,
+            }),
+        }
+    }
+}
+
+read_csr_as!(Fcsr, 0x003);
+write_csr!(0x003);
+set!(0x003);
+clear!(0x003);
+
+/// Writes the CSR
+///
+/// **WARNING**: panics on non-`riscv` targets.
+///
+/// # Safety
+///
+/// Modifying the rounding mode is immediate undefined behavior for the Rust code running under it
+/// (see the [floating-point CSRs section](super#on-floating-point-csrs)). It is only sound to
+/// restore the floating-point state of another context (e.g., when switching threads).
+#[inline]
+pub unsafe fn write(fcsr: Fcsr) {
+    try_write(fcsr).unwrap();
+}
+
+/// Attempts to write the CSR
+///
+/// # Safety
+///
+/// See [`write()`].
+#[inline]
+pub unsafe fn try_write(fcsr: Fcsr) -> Result<()> {
+    _try_write(fcsr.bits)
+}
+
+/// Reads the accrued exception flags
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn read_flags() -> Fflags {
+    read().fflags()
+}
+
+clear_csr!(
+    /// Clears the accrued exception flags
+    , clear_flags, FFLAGS_MASK);
+
+/// Sets the dynamic rounding mode
+///
+/// **WARNING**: panics on non-`riscv` targets.
+///
+/// # Safety
+///
+/// Modifying the rounding mode is immediate undefined behavior for the Rust code running under it
+/// (see the [floating-point CSRs section](super#on-floating-point-csrs)). It is only sound to
+/// restore the floating-point state of another context (e.g., when switching threads).
+#[inline]
+pub unsafe fn set_rounding_mode(frm: RoundingMode) {
+    try_set_rounding_mode(frm).unwrap();
+}
+
+/// Attempts to set the dynamic rounding mode
+///
+/// # Safety
+///
+/// See [`set_rounding_mode`].
+#[inline]
+pub unsafe fn try_set_rounding_mode(frm: RoundingMode) -> Result<()> {
+    // clear and set the field without affecting the accrued flags
+    _try_clear(0b111 << 5)?;
+    _try_set((frm as usize) << 5)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fcsr() {
+        let fcsr = Fcsr::from((3 << 5) | 0b1_0001);
+        assert_eq!(fcsr.frm(), RoundingMode::RoundUp);
+        let fflags = fcsr.fflags();
+        assert!(fflags.nx() && fflags.nv());
+        assert!(!fflags.uf() && !fflags.of() && !fflags.dz());
+        assert!(!fflags.is_empty());
+        assert!(Fcsr::from(0).fflags().is_empty());
+
+        assert_eq!(
+            Fcsr::from(7 << 5).try_frm(),
+            Err(Error::InvalidFieldVariant {
+                field: "frm",
+                value: 7,
+            })
+        );
+    }
+}
@@ -0,0 +1,29 @@
+//! hcontext register (Sdtrig, hypervisor extension)
+//!
+//! Hypervisor context register. It is an alias of the `mcontext` register
+//! accessible from HS-mode, and shares the [`Mcontext`] type with it.
+
+pub use super::mcontext::{Mcontext, HCONTEXT_MASK};
+use crate::result::Result;
+
+read_csr_as!(Mcontext, 0x6A8);
+write_csr_as!(Mcontext, 0x6A8);
+
+/// Sets the context value
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `hcontext` does not fit in the field
+#[inline]
+pub unsafe fn set(hcontext: usize) {
+    try_set(hcontext).unwrap();
+}
+
+/// Attempts to set the context value
+///
+/// Returns an error if `hcontext` does not fit in the field (see [`HCONTEXT_MASK`]).
+#[inline]
+pub unsafe fn try_set(hcontext: usize) -> Result<()> {
+    try_write(Mcontext::new(hcontext)?)
+}
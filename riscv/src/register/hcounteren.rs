@@ -0,0 +1,197 @@
+//! hcounteren register (hypervisor extension)
+//!
+//! Controls the availability of the counters to VS-mode and VU-mode.
+
+use crate::bits::{bf_extract, bf_insert};
+use crate::result::{Error, Result};
+
+/// hcounteren register
+#[derive(Clone, Copy, Debug)]
+pub struct Hcounteren {
+    bits: usize,
+}
+
+impl From<usize> for Hcounteren {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Hcounteren {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Virtual supervisor "cycle\[h\]" Enable
+    #[inline]
+    pub fn cy(&self) -> bool {
+        bf_extract(self.bits, 0, 1) != 0
+    }
+
+    /// Sets whether to enable the "cycle\[h\]" counter.
+    ///
+    /// Only updates the in-memory value, does not modify the `hcounteren` register.
+    #[inline]
+    pub fn set_cy(&mut self, cy: bool) {
+        self.bits = bf_insert(self.bits, 0, 1, cy as usize);
+    }
+
+    /// Virtual supervisor "time\[h\]" Enable
+    #[inline]
+    pub fn tm(&self) -> bool {
+        bf_extract(self.bits, 1, 1) != 0
+    }
+
+    /// Sets whether to enable "time\[h\]".
+    ///
+    /// Only updates the in-memory value, does not modify the `hcounteren` register.
+    #[inline]
+    pub fn set_tm(&mut self, tm: bool) {
+        self.bits = bf_insert(self.bits, 1, 1, tm as usize);
+    }
+
+    /// Virtual supervisor "instret\[h\]" Enable
+    #[inline]
+    pub fn ir(&self) -> bool {
+        bf_extract(self.bits, 2, 1) != 0
+    }
+
+    /// Sets whether to enable the "instret\[h\]" counter.
+    ///
+    /// Only updates the in-memory value, does not modify the `hcounteren` register.
+    #[inline]
+    pub fn set_ir(&mut self, ir: bool) {
+        self.bits = bf_insert(self.bits, 2, 1, ir as usize);
+    }
+
+    /// Virtual supervisor "hpm\[x\]" Enable (bits 3-31)
+    ///
+    /// **WARNING**: panics on `index` out-of-bounds
+    #[inline]
+    pub fn hpm(&self, index: usize) -> bool {
+        self.try_hpm(index).unwrap()
+    }
+
+    /// Fallible Supervisor "hpm\[x\]" Enable (bits 3-31).
+    ///
+    /// Attempts to read the "hpm\[x\]" value, and returns an error if the `index` is invalid.
+    #[inline]
+    pub fn try_hpm(&self, index: usize) -> Result<bool> {
+        if (3..32).contains(&index) {
+            Ok(bf_extract(self.bits, index, 1) != 0)
+        } else {
+            Err(Error::IndexOutOfBounds {
+                index,
+                min: 3,
+                max: 31,
+            })
+        }
+    }
+
+    /// Sets whether to enable the "hpm\[X\]" counter.
+    ///
+    /// Only updates the in-memory value, does not modify the `hcounteren` register.
+    ///
+    /// **WARNING**: panics on `index` out-of-bounds
+    #[inline]
+    pub fn set_hpm(&mut self, index: usize, hpm: bool) {
+        self.try_set_hpm(index, hpm).unwrap()
+    }
+
+    /// Sets whether to enable the "hpm\[X\]" counter.
+    ///
+    /// Only updates the in-memory value, does not modify the `hcounteren` register.
+    ///
+    /// Attempts to update the "hpm\[x\]" value, and returns an error if the `index` is invalid.
+    #[inline]
+    pub fn try_set_hpm(&mut self, index: usize, hpm: bool) -> Result<()> {
+        if (3..32).contains(&index) {
+            self.bits = bf_insert(self.bits, index, 1, hpm as usize);
+            Ok(())
+        } else {
+            Err(Error::IndexOutOfBounds {
+                index,
+                min: 3,
+                max: 31,
+            })
+        }
+    }
+}
+
+read_csr_as!(Hcounteren, 0x606);
+write_csr_as!(Hcounteren, 0x606);
+set!(0x606);
+clear!(0x606);
+
+set_clear_csr!(
+/// Virtual supervisor cycle Enable
+    , set_cy, clear_cy, 1 << 0);
+
+set_clear_csr!(
+/// Virtual supervisor time Enable
+    , set_tm, clear_tm, 1 << 1);
+
+set_clear_csr!(
+/// Virtual supervisor instret Enable
+    , set_ir, clear_ir, 1 << 2);
+
+/// Enables the "hpm\[X\]" counter.
+///
+/// Updates the `hcounteren` register.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` out-of-bounds
+#[inline]
+pub unsafe fn set_hpm(index: usize) {
+    try_set_hpm(index).unwrap();
+}
+
+/// Attempts to enable the "hpm\[X\]" counter.
+///
+/// Updates the `hcounteren` register.
+#[inline]
+pub unsafe fn try_set_hpm(index: usize) -> Result<()> {
+    if (3..32).contains(&index) {
+        _try_set(1 << index)
+    } else {
+        Err(Error::IndexOutOfBounds {
+            index,
+            min: 3,
+            max: 31,
+        })
+    }
+}
+
+/// Disables the "hpm\[X\]" counter.
+///
+/// Updates the `hcounteren` register.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` out-of-bounds
+#[inline]
+pub unsafe fn clear_hpm(index: usize) {
+    try_clear_hpm(index).unwrap();
+}
+
+/// Attempts to disable the "hpm\[X\]" counter.
+///
+/// Updates the `hcounteren` register.
+#[inline]
+pub unsafe fn try_clear_hpm(index: usize) -> Result<()> {
+    if (3..32).contains(&index) {
+        _try_clear(1 << index)
+    } else {
+        Err(Error::IndexOutOfBounds {
+            index,
+            min: 3,
+            max: 31,
+        })
+    }
+}
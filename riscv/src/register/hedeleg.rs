@@ -0,0 +1,290 @@
+//! hedeleg register (hypervisor extension)
+//!
+//! Hypervisor exception delegation register. Exceptions delegated to HS-mode (see
+//! [`medeleg`](super::medeleg)) that are raised in VS-mode or VU-mode are further delegated
+//! to VS-mode if their bit is set.
+//!
+//! Only some exceptions can be delegated to VS-mode (see [`DELEGABLE`]). The bits of the
+//! environment calls from HS-mode, VS-mode, and M-mode, the guest-page faults, and the
+//! virtual-instruction exception are read-only zero.
+
+use crate::bits::{bf_extract, bit_mask};
+use crate::result::{Error, Result};
+use crate::ExceptionNumber;
+
+/// Bits of the exceptions that can be delegated to VS-mode
+///
+/// The bits of the software-check (18) and hardware-error (19) exceptions
+/// are only writable if the corresponding extensions are implemented.
+pub const DELEGABLE: usize = 0x1FF | (1 << 12) | (1 << 13) | (1 << 15) | (1 << 18) | (1 << 19);
+
+/// hedeleg register
+#[derive(Clone, Copy, Debug)]
+pub struct Hedeleg {
+    bits: usize,
+}
+
+impl From<usize> for Hedeleg {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Hedeleg {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Instruction Address Misaligned Delegate
+    #[inline]
+    pub fn instruction_misaligned(&self) -> bool {
+        self.bits & (1 << 0) != 0
+    }
+
+    /// Instruction Access Fault Delegate
+    #[inline]
+    pub fn instruction_fault(&self) -> bool {
+        self.bits & (1 << 1) != 0
+    }
+
+    /// Illegal Instruction Delegate
+    #[inline]
+    pub fn illegal_instruction(&self) -> bool {
+        self.bits & (1 << 2) != 0
+    }
+
+    /// Breakpoint Delegate
+    #[inline]
+    pub fn breakpoint(&self) -> bool {
+        self.bits & (1 << 3) != 0
+    }
+
+    /// Load Address Misaligned Delegate
+    #[inline]
+    pub fn load_misaligned(&self) -> bool {
+        self.bits & (1 << 4) != 0
+    }
+
+    /// Load Access Fault Delegate
+    #[inline]
+    pub fn load_fault(&self) -> bool {
+        self.bits & (1 << 5) != 0
+    }
+
+    /// Store/AMO Address Misaligned Delegate
+    #[inline]
+    pub fn store_misaligned(&self) -> bool {
+        self.bits & (1 << 6) != 0
+    }
+
+    /// Store/AMO Access Fault Delegate
+    #[inline]
+    pub fn store_fault(&self) -> bool {
+        self.bits & (1 << 7) != 0
+    }
+
+    /// Environment Call from VU-mode Delegate
+    #[inline]
+    pub fn user_env_call(&self) -> bool {
+        self.bits & (1 << 8) != 0
+    }
+
+    /// Instruction Page Fault Delegate
+    #[inline]
+    pub fn instruction_page_fault(&self) -> bool {
+        self.bits & (1 << 12) != 0
+    }
+
+    /// Load Page Fault Delegate
+    #[inline]
+    pub fn load_page_fault(&self) -> bool {
+        self.bits & (1 << 13) != 0
+    }
+
+    /// Store/AMO Page Fault Delegate
+    #[inline]
+    pub fn store_page_fault(&self) -> bool {
+        self.bits & (1 << 15) != 0
+    }
+
+    /// Returns `true` if the given exception is delegated to VS-mode
+    #[inline]
+    pub fn is_delegated<E: ExceptionNumber>(&self, e: E) -> bool {
+        let index = e.number() as usize;
+        index < usize::BITS as usize && bf_extract(self.bits, index, 1) != 0
+    }
+}
+
+/// Returns the bit of exception number `index`
+///
+/// Returns an error if the exception cannot be delegated to VS-mode.
+#[inline]
+fn delegable_mask(index: usize) -> Result<usize> {
+    let mask = bit_mask(index)?;
+    if mask & DELEGABLE != 0 {
+        Ok(mask)
+    } else {
+        Err(Error::InvalidValue {
+            value: mask,
+            bitmask: DELEGABLE,
+        })
+    }
+}
+
+read_csr_as!(Hedeleg, 0x602);
+write_csr!(0x602);
+set!(0x602);
+clear!(0x602);
+
+set_clear_csr!(
+    /// Instruction Address Misaligned Delegate
+    , set_instruction_misaligned, clear_instruction_misaligned, 1 << 0);
+set_clear_csr!(
+    /// Instruction Access Fault Delegate
+    , set_instruction_fault, clear_instruction_fault, 1 << 1);
+set_clear_csr!(
+    /// Illegal Instruction Delegate
+    , set_illegal_instruction, clear_illegal_instruction, 1 << 2);
+set_clear_csr!(
+    /// Breakpoint Delegate
+    , set_breakpoint, clear_breakpoint, 1 << 3);
+set_clear_csr!(
+    /// Load Address Misaligned Delegate
+    , set_load_misaligned, clear_load_misaligned, 1 << 4);
+set_clear_csr!(
+    /// Load Access Fault Delegate
+    , set_load_fault, clear_load_fault, 1 << 5);
+set_clear_csr!(
+    /// Store/AMO Address Misaligned Delegate
+    , set_store_misaligned, clear_store_misaligned, 1 << 6);
+set_clear_csr!(
+    /// Store/AMO Access Fault Delegate
+    , set_store_fault, clear_store_fault, 1 << 7);
+set_clear_csr!(
+    /// Environment Call from VU-mode Delegate
+    , set_user_env_call, clear_user_env_call, 1 << 8);
+set_clear_csr!(
+    /// Instruction Page Fault Delegate
+    , set_instruction_page_fault, clear_instruction_page_fault, 1 << 12);
+set_clear_csr!(
+    /// Load Page Fault Delegate
+    , set_load_page_fault, clear_load_page_fault, 1 << 13);
+set_clear_csr!(
+    /// Store/AMO Page Fault Delegate
+    , set_store_page_fault, clear_store_page_fault, 1 << 15);
+
+/// Writes the CSR
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `hedeleg` delegating exceptions that cannot be delegated to VS-mode
+#[inline]
+pub unsafe fn write(hedeleg: Hedeleg) {
+    try_write(hedeleg).unwrap();
+}
+
+/// Attempts to write the CSR
+///
+/// Returns an error if `hedeleg` delegates exceptions that cannot be delegated to VS-mode.
+#[inline]
+pub unsafe fn try_write(hedeleg: Hedeleg) -> Result<()> {
+    if hedeleg.bits & !DELEGABLE != 0 {
+        Err(Error::InvalidValue {
+            value: hedeleg.bits,
+            bitmask: DELEGABLE,
+        })
+    } else {
+        _try_write(hedeleg.bits)
+    }
+}
+
+/// Delegates the given exception to VS-mode
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - exceptions that cannot be delegated to VS-mode
+#[inline]
+pub unsafe fn delegate<E: ExceptionNumber>(e: E) {
+    try_delegate(e).unwrap();
+}
+
+/// Attempts to delegate the given exception to VS-mode
+///
+/// Returns an error if the exception cannot be delegated to VS-mode.
+#[inline]
+pub unsafe fn try_delegate<E: ExceptionNumber>(e: E) -> Result<()> {
+    _try_set(delegable_mask(e.number() as usize)?)
+}
+
+/// Stops delegating the given exception to VS-mode
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - exceptions that cannot be delegated to VS-mode
+#[inline]
+pub unsafe fn undelegate<E: ExceptionNumber>(e: E) {
+    try_undelegate(e).unwrap();
+}
+
+/// Attempts to stop delegating the given exception to VS-mode
+///
+/// Returns an error if the exception cannot be delegated to VS-mode.
+#[inline]
+pub unsafe fn try_undelegate<E: ExceptionNumber>(e: E) -> Result<()> {
+    _try_clear(delegable_mask(e.number() as usize)?)
+}
+
+/// Returns `true` if the given exception is delegated to VS-mode
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn is_delegated<E: ExceptionNumber>(e: E) -> bool {
+    read().is_delegated(e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interrupt::Exception;
+
+    #[test]
+    fn test_hedeleg() {
+        let hedeleg = Hedeleg::from((1 << 8) | (1 << 15));
+        assert!(hedeleg.is_delegated(Exception::UserEnvCall));
+        assert!(hedeleg.is_delegated(Exception::StorePageFault));
+        assert!(!hedeleg.is_delegated(Exception::Breakpoint));
+
+        assert_eq!(
+            delegable_mask(Exception::IllegalInstruction as usize),
+            Ok(1 << 2)
+        );
+        for e in [
+            Exception::SupervisorEnvCall,
+            Exception::VirtualSupervisorEnvCall,
+            Exception::MachineEnvCall,
+            Exception::LoadGuestPageFault,
+            Exception::VirtualInstruction,
+        ] {
+            assert_eq!(
+                delegable_mask(e as usize),
+                Err(Error::InvalidValue {
+                    value: 1 << (e as usize),
+                    bitmask: DELEGABLE,
+                })
+            );
+        }
+        assert_eq!(
+            unsafe { try_write(Hedeleg::from(1 << 9)) },
+            Err(Error::InvalidValue {
+                value: 1 << 9,
+                bitmask: DELEGABLE,
+            })
+        );
+    }
+}
@@ -0,0 +1,350 @@
+//! henvcfg register (hypervisor extension)
+//!
+//! Hypervisor environment configuration register.
+//! It controls the execution environment of VS-mode.
+//!
+//! The register is 64 bits wide on all targets. On RV32, the upper half lives in the
+//! [`henvcfgh`](super::henvcfgh) register, and [`read`] and [`write()`] access both halves.
+
+pub use super::menvcfg::{Cbie, Pmm};
+use crate::result::Result;
+
+/// henvcfg register
+#[derive(Clone, Copy, Debug)]
+pub struct Henvcfg {
+    bits: u64,
+}
+
+impl From<u64> for Henvcfg {
+    #[inline]
+    fn from(bits: u64) -> Self {
+        Self { bits }
+    }
+}
+
+impl Henvcfg {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    #[inline]
+    fn bit(&self, index: usize) -> bool {
+        self.bits & (1 << index) != 0
+    }
+
+    #[inline]
+    fn set_bit(&mut self, index: usize, value: bool) {
+        self.bits = (self.bits & !(1 << index)) | ((value as u64) << index);
+    }
+
+    /// Fence of I/O implies Memory (`FIOM`)
+    ///
+    /// When set, `FENCE` instructions ordering I/O accesses of the less privileged modes also order memory accesses.
+    #[inline]
+    pub fn fiom(&self) -> bool {
+        self.bit(0)
+    }
+
+    /// Update Fence of I/O implies Memory (`FIOM`)
+    ///
+    /// Note this updates a previously read [`Henvcfg`] value, but does not
+    /// affect the henvcfg CSR itself. See [`set_fiom`]/[`clear_fiom`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_fiom(&mut self, fiom: bool) {
+        self.set_bit(0, fiom);
+    }
+
+    /// Landing Pad Enable (`LPE`, Zicfilp)
+    #[inline]
+    pub fn lpe(&self) -> bool {
+        self.bit(2)
+    }
+
+    /// Update Landing Pad Enable (`LPE`, Zicfilp)
+    ///
+    /// Note this updates a previously read [`Henvcfg`] value, but does not
+    /// affect the henvcfg CSR itself. See [`set_lpe`]/[`clear_lpe`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_lpe(&mut self, lpe: bool) {
+        self.set_bit(2, lpe);
+    }
+
+    /// Shadow Stack Enable (`SSE`, Zicfiss)
+    #[inline]
+    pub fn sse(&self) -> bool {
+        self.bit(3)
+    }
+
+    /// Update Shadow Stack Enable (`SSE`, Zicfiss)
+    ///
+    /// Note this updates a previously read [`Henvcfg`] value, but does not
+    /// affect the henvcfg CSR itself. See [`set_sse`]/[`clear_sse`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_sse(&mut self, sse: bool) {
+        self.set_bit(3, sse);
+    }
+
+    /// Cache Block Invalidate instruction Enable (`CBIE`, Zicbom)
+    ///
+    /// **WARNING**: panics if the field has an invalid variant.
+    #[inline]
+    pub fn cbie(&self) -> Cbie {
+        self.try_cbie().unwrap()
+    }
+
+    /// Attempts to get the cache block invalidate instruction enable (`CBIE`, Zicbom)
+    #[inline]
+    pub fn try_cbie(&self) -> Result<Cbie> {
+        (((self.bits >> 4) & 0b11) as u8).try_into()
+    }
+
+    /// Update Cache Block Invalidate instruction Enable (`CBIE`, Zicbom)
+    ///
+    /// Note this updates a previously read [`Henvcfg`] value, but does not
+    /// affect the henvcfg CSR itself. See [`set_cbie`] to directly update the CSR.
+    #[inline]
+    pub fn set_cbie(&mut self, cbie: Cbie) {
+        self.bits = (self.bits & !(0b11 << 4)) | ((cbie as u64) << 4);
+    }
+
+    /// Cache Block Clean and Flush Enable (`CBCFE`, Zicbom)
+    #[inline]
+    pub fn cbcfe(&self) -> bool {
+        self.bit(6)
+    }
+
+    /// Update Cache Block Clean and Flush Enable (`CBCFE`, Zicbom)
+    ///
+    /// Note this updates a previously read [`Henvcfg`] value, but does not
+    /// affect the henvcfg CSR itself. See [`set_cbcfe`]/[`clear_cbcfe`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_cbcfe(&mut self, cbcfe: bool) {
+        self.set_bit(6, cbcfe);
+    }
+
+    /// Cache Block Zero Enable (`CBZE`, Zicboz)
+    #[inline]
+    pub fn cbze(&self) -> bool {
+        self.bit(7)
+    }
+
+    /// Update Cache Block Zero Enable (`CBZE`, Zicboz)
+    ///
+    /// Note this updates a previously read [`Henvcfg`] value, but does not
+    /// affect the henvcfg CSR itself. See [`set_cbze`]/[`clear_cbze`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_cbze(&mut self, cbze: bool) {
+        self.set_bit(7, cbze);
+    }
+
+    /// Pointer masking mode of VS-mode (Ssnpm)
+    ///
+    /// **WARNING**: panics if the field has an invalid variant.
+    #[inline]
+    pub fn pmm(&self) -> Pmm {
+        self.try_pmm().unwrap()
+    }
+
+    /// Attempts to get the pointer masking mode of VS-mode (Ssnpm)
+    #[inline]
+    pub fn try_pmm(&self) -> Result<Pmm> {
+        (((self.bits >> 32) & 0b11) as u8).try_into()
+    }
+
+    /// Updates the pointer masking mode of VS-mode (Ssnpm)
+    ///
+    /// Note this updates a previously read [`Henvcfg`] value, but does not
+    /// affect the henvcfg CSR itself.
+    #[cfg_attr(
+        target_pointer_width = "64",
+        doc = "See [`set_pmm`] to directly update the CSR."
+    )]
+    #[inline]
+    pub fn set_pmm(&mut self, pmm: Pmm) {
+        self.bits = (self.bits & !(0b11 << 32)) | ((pmm as u64) << 32);
+    }
+
+    /// Double Trap Enable (`DTE`, Ssdbltrp)
+    #[inline]
+    pub fn dte(&self) -> bool {
+        self.bit(59)
+    }
+
+    /// Update Double Trap Enable (`DTE`, Ssdbltrp)
+    ///
+    /// Note this updates a previously read [`Henvcfg`] value, but does not
+    /// affect the henvcfg CSR itself. See [`set_dte`]/[`clear_dte`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_dte(&mut self, dte: bool) {
+        self.set_bit(59, dte);
+    }
+
+    /// Hardware A/D bit Update Enable (`ADUE`, Svadu)
+    ///
+    /// When set, the hardware updates the `A` and `D` bits of the page-table entries.
+    #[inline]
+    pub fn adue(&self) -> bool {
+        self.bit(61)
+    }
+
+    /// Update Hardware A/D bit Update Enable (`ADUE`, Svadu)
+    ///
+    /// Note this updates a previously read [`Henvcfg`] value, but does not
+    /// affect the henvcfg CSR itself. See [`set_adue`]/[`clear_adue`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_adue(&mut self, adue: bool) {
+        self.set_bit(61, adue);
+    }
+
+    /// Page-Based Memory Types Enable (`PBMTE`, Svpbmt)
+    #[inline]
+    pub fn pbmte(&self) -> bool {
+        self.bit(62)
+    }
+
+    /// Update Page-Based Memory Types Enable (`PBMTE`, Svpbmt)
+    ///
+    /// Note this updates a previously read [`Henvcfg`] value, but does not
+    /// affect the henvcfg CSR itself. See [`set_pbmte`]/[`clear_pbmte`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_pbmte(&mut self, pbmte: bool) {
+        self.set_bit(62, pbmte);
+    }
+
+    /// STimecmp Enable (`STCE`, Sstc)
+    ///
+    /// When set, the `stimecmp` CSR and the supervisor timer interrupt are available.
+    #[inline]
+    pub fn stce(&self) -> bool {
+        self.bit(63)
+    }
+
+    /// Update STimecmp Enable (`STCE`, Sstc)
+    ///
+    /// Note this updates a previously read [`Henvcfg`] value, but does not
+    /// affect the henvcfg CSR itself. See [`set_stce`]/[`clear_stce`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_stce(&mut self, stce: bool) {
+        self.set_bit(63, stce);
+    }
+}
+
+read_csr!(0x60A);
+write_csr!(0x60A);
+set!(0x60A);
+clear!(0x60A);
+
+/// Reads the CSR
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn read() -> Henvcfg {
+    try_read().unwrap()
+}
+
+/// Attempts to read the CSR
+///
+/// On RV32, the upper half is read from the `henvcfgh` CSR.
+#[inline]
+pub fn try_read() -> Result<Henvcfg> {
+    let lo = unsafe { _try_read()? } as u64;
+    match () {
+        #[cfg(target_pointer_width = "32")]
+        () => Ok(Henvcfg::from(
+            ((super::henvcfgh::try_read()? as u64) << 32) | lo,
+        )),
+        #[cfg(not(target_pointer_width = "32"))]
+        () => Ok(Henvcfg::from(lo)),
+    }
+}
+
+/// Writes the CSR
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub unsafe fn write(henvcfg: Henvcfg) {
+    try_write(henvcfg).unwrap();
+}
+
+/// Attempts to write the CSR
+///
+/// On RV32, the upper half is written to the `henvcfgh` CSR first.
+#[inline]
+pub unsafe fn try_write(henvcfg: Henvcfg) -> Result<()> {
+    #[cfg(target_pointer_width = "32")]
+    super::henvcfgh::try_write((henvcfg.bits >> 32) as usize)?;
+    _try_write(henvcfg.bits as usize)
+}
+
+set_clear_csr!(
+    /// Fence of I/O implies Memory (`FIOM`)
+    , set_fiom, clear_fiom, 1 << 0);
+set_clear_csr!(
+    /// Landing Pad Enable (`LPE`, Zicfilp)
+    , set_lpe, clear_lpe, 1 << 2);
+set_clear_csr!(
+    /// Shadow Stack Enable (`SSE`, Zicfiss)
+    , set_sse, clear_sse, 1 << 3);
+set_clear_csr!(
+    /// Cache Block Clean and Flush Enable (`CBCFE`, Zicbom)
+    , set_cbcfe, clear_cbcfe, 1 << 6);
+set_clear_csr!(
+    /// Cache Block Zero Enable (`CBZE`, Zicboz)
+    , set_cbze, clear_cbze, 1 << 7);
+set_clear_csr64!(
+    /// Double Trap Enable (`DTE`, Ssdbltrp)
+    , set_dte, clear_dte, henvcfgh, 59);
+set_clear_csr64!(
+    /// Hardware A/D bit Update Enable (`ADUE`, Svadu)
+    , set_adue, clear_adue, henvcfgh, 61);
+set_clear_csr64!(
+    /// Page-Based Memory Types Enable (`PBMTE`, Svpbmt)
+    , set_pbmte, clear_pbmte, henvcfgh, 62);
+set_clear_csr64!(
+    /// STimecmp Enable (`STCE`, Sstc)
+    , set_stce, clear_stce, henvcfgh, 63);
+
+/// Sets the cache block invalidate instruction enable (`CBIE`, Zicbom)
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub unsafe fn set_cbie(cbie: Cbie) {
+    try_set_cbie(cbie).unwrap();
+}
+
+/// Attempts to set the cache block invalidate instruction enable (`CBIE`, Zicbom)
+#[inline]
+pub unsafe fn try_set_cbie(cbie: Cbie) -> Result<()> {
+    let mut henvcfg = try_read()?;
+    henvcfg.set_cbie(cbie);
+    try_write(henvcfg)
+}
+
+/// Sets the pointer masking mode of VS-mode (Ssnpm, RV64 only)
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[cfg(target_pointer_width = "64")]
+#[inline]
+pub unsafe fn set_pmm(pmm: Pmm) {
+    try_set_pmm(pmm).unwrap();
+}
+
+/// Attempts to set the pointer masking mode of VS-mode (Ssnpm, RV64 only)
+#[cfg(target_pointer_width = "64")]
+#[inline]
+pub unsafe fn try_set_pmm(pmm: Pmm) -> Result<()> {
+    let mut henvcfg = try_read()?;
+    henvcfg.set_pmm(pmm);
+    try_write(henvcfg)
+}
@@ -0,0 +1,23 @@
+//! henvcfgh register (hypervisor extension, RV32 only)
+//!
+//! Upper 32 bits of the hypervisor environment configuration register.
+//! Use [`henvcfg::read`](super::henvcfg::read) and [`henvcfg::write`](super::henvcfg::write)
+//! to access the full 64-bit register.
+
+read_csr_as_usize_rv32!(0x61A);
+write_csr_as_usize_rv32!(0x61A);
+set_rv32!(0x61A);
+clear_rv32!(0x61A);
+
+set_clear_csr!(
+    /// Double Trap Enable (`DTE`, Ssdbltrp)
+    , set_dte, clear_dte, 1 << 27);
+set_clear_csr!(
+    /// Hardware A/D bit Update Enable (`ADUE`, Svadu)
+    , set_adue, clear_adue, 1 << 29);
+set_clear_csr!(
+    /// Page-Based Memory Types Enable (`PBMTE`, Svpbmt)
+    , set_pbmte, clear_pbmte, 1 << 30);
+set_clear_csr!(
+    /// STimecmp Enable (`STCE`, Sstc)
+    , set_stce, clear_stce, 1 << 31);
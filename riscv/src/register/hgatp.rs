@@ -0,0 +1,218 @@
+//! hgatp register (hypervisor extension)
+//!
+//! Hypervisor guest address translation and protection register.
+//! It controls the G-stage address translation of guest physical addresses.
+
+use crate::result::{Error, Result};
+
+#[cfg(target_pointer_width = "32")]
+const MODE_SHIFT: usize = 31;
+#[cfg(target_pointer_width = "32")]
+const VMID_SHIFT: usize = 22;
+#[cfg(target_pointer_width = "32")]
+const VMID_MASK: usize = 0x7F;
+#[cfg(target_pointer_width = "32")]
+const PPN_MASK: usize = 0x3F_FFFF;
+
+#[cfg(target_pointer_width = "64")]
+const MODE_SHIFT: usize = 60;
+#[cfg(target_pointer_width = "64")]
+const VMID_SHIFT: usize = 44;
+#[cfg(target_pointer_width = "64")]
+const VMID_MASK: usize = 0x3FFF;
+#[cfg(target_pointer_width = "64")]
+const PPN_MASK: usize = 0xFFF_FFFF_FFFF;
+
+/// hgatp register
+#[derive(Clone, Copy, Debug)]
+pub struct Hgatp {
+    bits: usize,
+}
+
+impl From<usize> for Hgatp {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Hgatp {
+    /// Creates a new [`Hgatp`] value from a G-stage translation mode, virtual machine id and
+    /// physical page number of the root page table.
+    ///
+    /// Returns an error if the virtual machine id or the physical page number do not fit in their fields,
+    /// or if the physical page number is not aligned to the 16 KiB root page table.
+    #[inline]
+    pub fn new(mode: Mode, vmid: usize, ppn: usize) -> Result<Self> {
+        if vmid & !VMID_MASK != 0 {
+            Err(Error::InvalidFieldValue {
+                field: "vmid",
+                value: vmid,
+                bitmask: VMID_MASK,
+            })
+        } else if ppn & !(PPN_MASK & !0b11) != 0 {
+            Err(Error::InvalidFieldValue {
+                field: "ppn",
+                value: ppn,
+                bitmask: PPN_MASK & !0b11,
+            })
+        } else {
+            Ok(Self {
+                bits: ((mode as usize) << MODE_SHIFT) | (vmid << VMID_SHIFT) | ppn,
+            })
+        }
+    }
+
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Current G-stage address-translation scheme
+    ///
+    /// **WARNING**: panics if the field has an invalid variant.
+    #[inline]
+    pub fn mode(&self) -> Mode {
+        self.try_mode().unwrap()
+    }
+
+    /// Attempts to get the current G-stage address-translation scheme.
+    #[inline]
+    pub fn try_mode(&self) -> Result<Mode> {
+        ((self.bits >> MODE_SHIFT) as u8).try_into()
+    }
+
+    /// Virtual machine identifier
+    #[inline]
+    pub fn vmid(&self) -> usize {
+        (self.bits >> VMID_SHIFT) & VMID_MASK
+    }
+
+    /// Physical page number of the root page table
+    #[inline]
+    pub fn ppn(&self) -> usize {
+        self.bits & PPN_MASK
+    }
+}
+
+/// 32-bit hgatp mode
+#[cfg(target_pointer_width = "32")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mode {
+    /// No translation or protection
+    Bare = 0,
+    /// Page-based 34-bit guest physical addressing
+    Sv32x4 = 1,
+}
+
+/// 64-bit hgatp mode
+#[cfg(target_pointer_width = "64")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mode {
+    /// No translation or protection
+    Bare = 0,
+    /// Page-based 41-bit guest physical addressing
+    Sv39x4 = 8,
+    /// Page-based 50-bit guest physical addressing
+    Sv48x4 = 9,
+    /// Page-based 59-bit guest physical addressing
+    Sv57x4 = 10,
+}
+
+#[cfg(target_pointer_width = "32")]
+impl TryFrom<u8> for Mode {
+    type Error = Error;
+
+    fn try_from(val: u8) -> Result<Self> {
+        match val {
+            0 => Ok(Mode::Bare),
+            1 => Ok(Mode::Sv32x4),
+            _ => Err(Error::InvalidFieldVariant {
+                field: "mode",
+                value: val as usize,
+            }),
+        }
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+impl TryFrom<u8> for Mode {
+    type Error = Error;
+
+    fn try_from(val: u8) -> Result<Self> {
+        match val {
+            0 => Ok(Mode::Bare),
+            8 => Ok(Mode::Sv39x4),
+            9 => Ok(Mode::Sv48x4),
+            10 => Ok(Mode::Sv57x4),
+            _ => Err(Error::InvalidFieldVariant {
+                field: "mode",
+                value: val as usize,
+            }),
+        }
+    }
+}
+
+read_csr_as!(Hgatp, 0x680);
+write_csr_as_usize!(0x680);
+
+/// Sets the register to corresponding G-stage translation mode, virtual machine id and physical page number.
+///
+/// Note that the G-stage address-translation caches must be flushed with `HFENCE.GVMA` afterwards
+/// (see [`hfence_gvma`](crate::asm::hfence_gvma)).
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - invalid field values
+#[inline]
+pub unsafe fn set(mode: Mode, vmid: usize, ppn: usize) {
+    try_set(mode, vmid, ppn).unwrap();
+}
+
+/// Attempts to set the register to corresponding G-stage translation mode, virtual machine id and physical page number.
+///
+/// Note that the G-stage address-translation caches must be flushed with `HFENCE.GVMA` afterwards
+/// (see [`hfence_gvma`](crate::asm::hfence_gvma)).
+#[inline]
+pub unsafe fn try_set(mode: Mode, vmid: usize, ppn: usize) -> Result<()> {
+    _try_write(Hgatp::new(mode, vmid, ppn)?.bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hgatp() {
+        let hgatp = Hgatp::new(Mode::Sv39x4, 0x123, 0x8_0004).unwrap();
+        assert_eq!(hgatp.mode(), Mode::Sv39x4);
+        assert_eq!(hgatp.vmid(), 0x123);
+        assert_eq!(hgatp.ppn(), 0x8_0004);
+
+        assert_eq!(
+            Hgatp::new(Mode::Sv48x4, 0x4000, 0).unwrap_err(),
+            Error::InvalidFieldValue {
+                field: "vmid",
+                value: 0x4000,
+                bitmask: 0x3FFF,
+            }
+        );
+        assert_eq!(
+            Hgatp::new(Mode::Sv57x4, 0, 0x8_0001).unwrap_err(),
+            Error::InvalidFieldValue {
+                field: "ppn",
+                value: 0x8_0001,
+                bitmask: 0xFFF_FFFF_FFFC,
+            }
+        );
+        assert_eq!(
+            Hgatp::from(11 << 60).try_mode(),
+            Err(Error::InvalidFieldVariant {
+                field: "mode",
+                value: 11,
+            })
+        );
+    }
+}
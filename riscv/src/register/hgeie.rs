@@ -0,0 +1,94 @@
+//! hgeie register (hypervisor extension)
+//!
+//! Hypervisor guest external interrupt-enable register.
+//! Bit `i` corresponds to guest external interrupt number `i` (i.e., the `VGEIN` value
+//! selecting a guest interrupt file). Bit 0 is read-only zero.
+
+use crate::bits::range_bit_mask;
+use crate::result::Result;
+
+/// hgeie register
+#[derive(Clone, Copy, Debug)]
+pub struct Hgeie {
+    bits: usize,
+}
+
+impl From<usize> for Hgeie {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Hgeie {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Guest External Interrupt Enable for guest interrupt file `vgein`
+    ///
+    /// **WARNING**: panics if `vgein` is not in the range 1 to XLEN-1.
+    #[inline]
+    pub fn guest(&self, vgein: usize) -> bool {
+        self.try_guest(vgein).unwrap()
+    }
+
+    /// Guest External Interrupt Enable for guest interrupt file `vgein`
+    ///
+    /// Returns an error if `vgein` is not in the range 1 to XLEN-1.
+    #[inline]
+    pub fn try_guest(&self, vgein: usize) -> Result<bool> {
+        Ok(self.bits & mask(vgein)? != 0)
+    }
+}
+
+/// Returns the bitmask of guest external interrupt `vgein`
+#[inline]
+fn mask(vgein: usize) -> Result<usize> {
+    range_bit_mask(vgein, 1, usize::BITS as usize - 1).map(|mask| mask as usize)
+}
+
+read_csr_as!(Hgeie, 0x607);
+write_csr_as!(Hgeie, 0x607);
+set!(0x607);
+clear!(0x607);
+
+/// Enables guest external interrupts for guest interrupt file `vgein`
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `vgein` not in the range 1 to XLEN-1
+#[inline]
+pub unsafe fn set_guest(vgein: usize) {
+    try_set_guest(vgein).unwrap();
+}
+
+/// Attempts to enable guest external interrupts for guest interrupt file `vgein`
+///
+/// Returns an error if `vgein` is not in the range 1 to XLEN-1.
+#[inline]
+pub unsafe fn try_set_guest(vgein: usize) -> Result<()> {
+    _try_set(mask(vgein)?)
+}
+
+/// Disables guest external interrupts for guest interrupt file `vgein`
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `vgein` not in the range 1 to XLEN-1
+#[inline]
+pub unsafe fn clear_guest(vgein: usize) {
+    try_clear_guest(vgein).unwrap();
+}
+
+/// Attempts to disable guest external interrupts for guest interrupt file `vgein`
+///
+/// Returns an error if `vgein` is not in the range 1 to XLEN-1.
+#[inline]
+pub unsafe fn try_clear_guest(vgein: usize) -> Result<()> {
+    _try_clear(mask(vgein)?)
+}
@@ -0,0 +1,87 @@
+//! hgeip register (hypervisor extension)
+//!
+//! Hypervisor guest external interrupt-pending register (read-only).
+//! Bit `i` corresponds to guest external interrupt number `i` (i.e., the `VGEIN` value
+//! selecting a guest interrupt file). Bit 0 is read-only zero.
+
+use crate::bits::range_bit_mask;
+use crate::result::Result;
+
+/// hgeip register
+#[derive(Clone, Copy, Debug)]
+pub struct Hgeip {
+    bits: usize,
+}
+
+impl From<usize> for Hgeip {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Hgeip {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Guest External Interrupt Pending for guest interrupt file `vgein`
+    ///
+    /// **WARNING**: panics if `vgein` is not in the range 1 to XLEN-1.
+    #[inline]
+    pub fn guest(&self, vgein: usize) -> bool {
+        self.try_guest(vgein).unwrap()
+    }
+
+    /// Guest External Interrupt Pending for guest interrupt file `vgein`
+    ///
+    /// Returns an error if `vgein` is not in the range 1 to XLEN-1.
+    #[inline]
+    pub fn try_guest(&self, vgein: usize) -> Result<bool> {
+        Ok(self.bits & mask(vgein)? != 0)
+    }
+}
+
+/// Returns the bitmask of guest external interrupt `vgein`
+#[inline]
+fn mask(vgein: usize) -> Result<usize> {
+    range_bit_mask(vgein, 1, usize::BITS as usize - 1).map(|mask| mask as usize)
+}
+
+read_csr_as!(Hgeip, 0xE12);
+
+/// Returns `true` if a guest external interrupt is pending for guest interrupt file `vgein`
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `vgein` not in the range 1 to XLEN-1
+#[inline]
+pub fn is_pending(vgein: usize) -> bool {
+    read().guest(vgein)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::result::Error;
+
+    #[test]
+    fn test_hgeip() {
+        let hgeip = Hgeip::from((1 << 1) | (1 << 5));
+        assert!(hgeip.guest(1));
+        assert!(!hgeip.guest(2));
+        assert!(hgeip.guest(5));
+        assert_eq!(
+            hgeip.try_guest(0),
+            Err(Error::IndexOutOfBounds {
+                index: 0,
+                min: 1,
+                max: usize::BITS as usize - 1,
+            })
+        );
+        assert!(hgeip.try_guest(usize::BITS as usize).is_err());
+    }
+}
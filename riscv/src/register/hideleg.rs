@@ -0,0 +1,229 @@
+//! hideleg register (hypervisor extension)
+//!
+//! Hypervisor interrupt delegation register. Interrupts delegated to HS-mode (see
+//! [`mideleg`](super::mideleg)) are further delegated to VS-mode if their bit is set.
+//!
+//! Only the VS-level interrupts, the local counter-overflow interrupt, and the platform-custom
+//! interrupts (bits 16 to XLEN-1, only with the AIA extension) can be delegated to VS-mode.
+//! Delegated VS-level interrupts appear in `sip` and `sie` of VS-mode as their S-level
+//! counterparts (e.g., `VSTI` appears as `STI`).
+
+use crate::bits::{bf_extract, bit_mask, custom_bit_mask};
+use crate::result::{Error, Result};
+use crate::CoreInterruptNumber;
+
+/// Bits of the standard interrupts that can be delegated to VS-mode
+pub const DELEGABLE: usize = (1 << 2) | (1 << 6) | (1 << 10) | (1 << 13);
+
+/// hideleg register
+#[derive(Clone, Copy, Debug)]
+pub struct Hideleg {
+    bits: usize,
+}
+
+impl From<usize> for Hideleg {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Hideleg {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Virtual Supervisor Software Interrupt Delegate
+    #[inline]
+    pub fn vssoft(&self) -> bool {
+        self.bits & (1 << 2) != 0
+    }
+
+    /// Virtual Supervisor Timer Interrupt Delegate
+    #[inline]
+    pub fn vstimer(&self) -> bool {
+        self.bits & (1 << 6) != 0
+    }
+
+    /// Virtual Supervisor External Interrupt Delegate
+    #[inline]
+    pub fn vsext(&self) -> bool {
+        self.bits & (1 << 10) != 0
+    }
+
+    /// Local Counter Overflow Interrupt Delegate
+    #[inline]
+    pub fn lcofi(&self) -> bool {
+        self.bits & (1 << 13) != 0
+    }
+
+    /// Returns `true` if the given interrupt is delegated to VS-mode
+    #[inline]
+    pub fn is_delegated<I: CoreInterruptNumber>(&self, i: I) -> bool {
+        let index = i.number() as usize;
+        index < usize::BITS as usize && bf_extract(self.bits, index, 1) != 0
+    }
+
+    /// Delegation bit of platform-custom interrupt `index` (bits 16 to XLEN-1)
+    ///
+    /// **WARNING**: panics if `index` is not in the platform-custom range.
+    #[inline]
+    pub fn custom(&self, index: usize) -> bool {
+        self.try_custom(index).unwrap()
+    }
+
+    /// Delegation bit of platform-custom interrupt `index` (bits 16 to XLEN-1)
+    ///
+    /// Returns an error if `index` is not in the platform-custom range.
+    #[inline]
+    pub fn try_custom(&self, index: usize) -> Result<bool> {
+        Ok(self.bits & custom_bit_mask(index)? != 0)
+    }
+}
+
+/// Returns the bit of interrupt number `index`
+///
+/// Returns an error if the interrupt cannot be delegated to VS-mode.
+#[inline]
+fn delegable_mask(index: usize) -> Result<usize> {
+    let mask = bit_mask(index)?;
+    if mask & DELEGABLE != 0 || index >= 16 {
+        Ok(mask)
+    } else {
+        Err(Error::InvalidValue {
+            value: mask,
+            bitmask: DELEGABLE,
+        })
+    }
+}
+
+read_csr_as!(Hideleg, 0x603);
+write_csr!(0x603);
+set!(0x603);
+clear!(0x603);
+
+set_clear_csr!(
+    /// Virtual Supervisor Software Interrupt Delegate
+    , set_vssoft, clear_vssoft, 1 << 2);
+set_clear_csr!(
+    /// Virtual Supervisor Timer Interrupt Delegate
+    , set_vstimer, clear_vstimer, 1 << 6);
+set_clear_csr!(
+    /// Virtual Supervisor External Interrupt Delegate
+    , set_vsext, clear_vsext, 1 << 10);
+set_clear_csr!(
+    /// Local Counter Overflow Interrupt Delegate
+    , set_lcofi, clear_lcofi, 1 << 13);
+
+/// Writes the CSR
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `hideleg` delegating interrupts that cannot be delegated to VS-mode
+#[inline]
+pub unsafe fn write(hideleg: Hideleg) {
+    try_write(hideleg).unwrap();
+}
+
+/// Attempts to write the CSR
+///
+/// Returns an error if `hideleg` delegates standard interrupts that cannot be delegated to VS-mode.
+/// The platform-custom interrupts (bits 16 to XLEN-1) are not checked.
+#[inline]
+pub unsafe fn try_write(hideleg: Hideleg) -> Result<()> {
+    if hideleg.bits & 0xFFFF & !DELEGABLE != 0 {
+        Err(Error::InvalidValue {
+            value: hideleg.bits,
+            bitmask: DELEGABLE,
+        })
+    } else {
+        _try_write(hideleg.bits)
+    }
+}
+
+/// Delegates the given interrupt to VS-mode
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - interrupts that cannot be delegated to VS-mode
+#[inline]
+pub unsafe fn delegate<I: CoreInterruptNumber>(i: I) {
+    try_delegate(i).unwrap();
+}
+
+/// Attempts to delegate the given interrupt to VS-mode
+///
+/// Returns an error if the interrupt cannot be delegated to VS-mode.
+#[inline]
+pub unsafe fn try_delegate<I: CoreInterruptNumber>(i: I) -> Result<()> {
+    _try_set(delegable_mask(i.number() as usize)?)
+}
+
+/// Stops delegating the given interrupt to VS-mode
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - interrupts that cannot be delegated to VS-mode
+#[inline]
+pub unsafe fn undelegate<I: CoreInterruptNumber>(i: I) {
+    try_undelegate(i).unwrap();
+}
+
+/// Attempts to stop delegating the given interrupt to VS-mode
+///
+/// Returns an error if the interrupt cannot be delegated to VS-mode.
+#[inline]
+pub unsafe fn try_undelegate<I: CoreInterruptNumber>(i: I) -> Result<()> {
+    _try_clear(delegable_mask(i.number() as usize)?)
+}
+
+/// Returns `true` if the given interrupt is delegated to VS-mode
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn is_delegated<I: CoreInterruptNumber>(i: I) -> bool {
+    read().is_delegated(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interrupt::Interrupt;
+
+    #[test]
+    fn test_hideleg() {
+        let hideleg = Hideleg::from((1 << 6) | (1 << 13) | (1 << 16));
+        assert!(hideleg.vstimer() && !hideleg.vssoft() && !hideleg.vsext());
+        assert!(hideleg.is_delegated(Interrupt::LocalCounterOverflow));
+        assert!(hideleg.custom(16));
+
+        assert_eq!(delegable_mask(10), Ok(1 << 10));
+        assert_eq!(delegable_mask(20), Ok(1 << 20));
+        assert_eq!(
+            delegable_mask(Interrupt::SupervisorTimer as usize),
+            Err(Error::InvalidValue {
+                value: 1 << 5,
+                bitmask: DELEGABLE,
+            })
+        );
+        assert_eq!(
+            delegable_mask(12),
+            Err(Error::InvalidValue {
+                value: 1 << 12,
+                bitmask: DELEGABLE,
+            })
+        );
+        assert_eq!(
+            unsafe { try_write(Hideleg::from((1 << 5) | (1 << 16))) },
+            Err(Error::InvalidValue {
+                value: (1 << 5) | (1 << 16),
+                bitmask: DELEGABLE,
+            })
+        );
+    }
+}
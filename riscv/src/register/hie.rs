@@ -0,0 +1,66 @@
+//! hie register (hypervisor extension)
+//!
+//! Hypervisor interrupt-enable register.
+
+/// hie register
+#[derive(Clone, Copy, Debug)]
+pub struct Hie {
+    bits: usize,
+}
+
+impl From<usize> for Hie {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Hie {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Virtual Supervisor Software Interrupt Enable
+    #[inline]
+    pub fn vssoft(&self) -> bool {
+        self.bits & (1 << 2) != 0
+    }
+
+    /// Virtual Supervisor Timer Interrupt Enable
+    #[inline]
+    pub fn vstimer(&self) -> bool {
+        self.bits & (1 << 6) != 0
+    }
+
+    /// Virtual Supervisor External Interrupt Enable
+    #[inline]
+    pub fn vsext(&self) -> bool {
+        self.bits & (1 << 10) != 0
+    }
+
+    /// Supervisor Guest External Interrupt Enable
+    #[inline]
+    pub fn sgext(&self) -> bool {
+        self.bits & (1 << 12) != 0
+    }
+}
+
+read_csr_as!(Hie, 0x604);
+write_csr_as!(Hie, 0x604);
+set!(0x604);
+clear!(0x604);
+
+set_clear_csr!(
+    /// Virtual Supervisor Software Interrupt Enable
+    , set_vssoft, clear_vssoft, 1 << 2);
+set_clear_csr!(
+    /// Virtual Supervisor Timer Interrupt Enable
+    , set_vstimer, clear_vstimer, 1 << 6);
+set_clear_csr!(
+    /// Virtual Supervisor External Interrupt Enable
+    , set_vsext, clear_vsext, 1 << 10);
+set_clear_csr!(
+    /// Supervisor Guest External Interrupt Enable
+    , set_sgext, clear_sgext, 1 << 12);
@@ -0,0 +1,56 @@
+//! hip register (hypervisor extension)
+//!
+//! Hypervisor interrupt-pending register. Only `VSSIP` is writable (as an alias of `hvip.VSSIP`).
+
+/// hip register
+#[derive(Clone, Copy, Debug)]
+pub struct Hip {
+    bits: usize,
+}
+
+impl From<usize> for Hip {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Hip {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Virtual Supervisor Software Interrupt Pending
+    #[inline]
+    pub fn vssoft(&self) -> bool {
+        self.bits & (1 << 2) != 0
+    }
+
+    /// Virtual Supervisor Timer Interrupt Pending
+    #[inline]
+    pub fn vstimer(&self) -> bool {
+        self.bits & (1 << 6) != 0
+    }
+
+    /// Virtual Supervisor External Interrupt Pending
+    #[inline]
+    pub fn vsext(&self) -> bool {
+        self.bits & (1 << 10) != 0
+    }
+
+    /// Supervisor Guest External Interrupt Pending
+    #[inline]
+    pub fn sgext(&self) -> bool {
+        self.bits & (1 << 12) != 0
+    }
+}
+
+read_csr_as!(Hip, 0x644);
+set!(0x644);
+clear!(0x644);
+
+set_clear_csr!(
+    /// Virtual Supervisor Software Interrupt Pending
+    , set_vssoft, clear_vssoft, 1 << 2);
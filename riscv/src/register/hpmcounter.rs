@@ -0,0 +1,163 @@
+//! Indexed read access to the `hpmcounter3`-`hpmcounter31` registers
+//!
+//! These are the unprivileged shadows of the `mhpmcounter3`-`mhpmcounter31` registers.
+//! Access from lower privilege modes must be enabled in `mcounteren`/`scounteren`.
+//!
+//! CSR instructions encode the CSR number as an immediate, so each index
+//! dispatches to the dedicated module (e.g., [`hpmcounter3`](super::hpmcounter3)).
+
+use crate::result::{Error, Result};
+
+/// Dispatches a function call to the `hpmcounterX` module selected by `index`.
+macro_rules! dispatch {
+    ($index:expr, $f:ident($($arg:expr),*)) => {
+        match $index {
+            3 => super::hpmcounter3::$f($($arg),*),
+            4 => super::hpmcounter4::$f($($arg),*),
+            5 => super::hpmcounter5::$f($($arg),*),
+            6 => super::hpmcounter6::$f($($arg),*),
+            7 => super::hpmcounter7::$f($($arg),*),
+            8 => super::hpmcounter8::$f($($arg),*),
+            9 => super::hpmcounter9::$f($($arg),*),
+            10 => super::hpmcounter10::$f($($arg),*),
+            11 => super::hpmcounter11::$f($($arg),*),
+            12 => super::hpmcounter12::$f($($arg),*),
+            13 => super::hpmcounter13::$f($($arg),*),
+            14 => super::hpmcounter14::$f($($arg),*),
+            15 => super::hpmcounter15::$f($($arg),*),
+            16 => super::hpmcounter16::$f($($arg),*),
+            17 => super::hpmcounter17::$f($($arg),*),
+            18 => super::hpmcounter18::$f($($arg),*),
+            19 => super::hpmcounter19::$f($($arg),*),
+            20 => super::hpmcounter20::$f($($arg),*),
+            21 => super::hpmcounter21::$f($($arg),*),
+            22 => super::hpmcounter22::$f($($arg),*),
+            23 => super::hpmcounter23::$f($($arg),*),
+            24 => super::hpmcounter24::$f($($arg),*),
+            25 => super::hpmcounter25::$f($($arg),*),
+            26 => super::hpmcounter26::$f($($arg),*),
+            27 => super::hpmcounter27::$f($($arg),*),
+            28 => super::hpmcounter28::$f($($arg),*),
+            29 => super::hpmcounter29::$f($($arg),*),
+            30 => super::hpmcounter30::$f($($arg),*),
+            31 => super::hpmcounter31::$f($($arg),*),
+            index => Err(out_of_bounds(index)),
+        }
+    };
+}
+
+/// Dispatches a function call to the `hpmcounterXh` module selected by `index`.
+#[cfg(target_arch = "riscv32")]
+macro_rules! dispatch_h {
+    ($index:expr, $f:ident($($arg:expr),*)) => {
+        match $index {
+            3 => super::hpmcounter3h::$f($($arg),*),
+            4 => super::hpmcounter4h::$f($($arg),*),
+            5 => super::hpmcounter5h::$f($($arg),*),
+            6 => super::hpmcounter6h::$f($($arg),*),
+            7 => super::hpmcounter7h::$f($($arg),*),
+            8 => super::hpmcounter8h::$f($($arg),*),
+            9 => super::hpmcounter9h::$f($($arg),*),
+            10 => super::hpmcounter10h::$f($($arg),*),
+            11 => super::hpmcounter11h::$f($($arg),*),
+            12 => super::hpmcounter12h::$f($($arg),*),
+            13 => super::hpmcounter13h::$f($($arg),*),
+            14 => super::hpmcounter14h::$f($($arg),*),
+            15 => super::hpmcounter15h::$f($($arg),*),
+            16 => super::hpmcounter16h::$f($($arg),*),
+            17 => super::hpmcounter17h::$f($($arg),*),
+            18 => super::hpmcounter18h::$f($($arg),*),
+            19 => super::hpmcounter19h::$f($($arg),*),
+            20 => super::hpmcounter20h::$f($($arg),*),
+            21 => super::hpmcounter21h::$f($($arg),*),
+            22 => super::hpmcounter22h::$f($($arg),*),
+            23 => super::hpmcounter23h::$f($($arg),*),
+            24 => super::hpmcounter24h::$f($($arg),*),
+            25 => super::hpmcounter25h::$f($($arg),*),
+            26 => super::hpmcounter26h::$f($($arg),*),
+            27 => super::hpmcounter27h::$f($($arg),*),
+            28 => super::hpmcounter28h::$f($($arg),*),
+            29 => super::hpmcounter29h::$f($($arg),*),
+            30 => super::hpmcounter30h::$f($($arg),*),
+            31 => super::hpmcounter31h::$f($($arg),*),
+            index => Err(out_of_bounds(index)),
+        }
+    };
+}
+
+#[inline]
+const fn out_of_bounds(index: usize) -> Error {
+    Error::IndexOutOfBounds {
+        index,
+        min: 3,
+        max: 31,
+    }
+}
+
+/// Reads the `hpmcounter[index]` CSR.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` not in the range 3 to 31
+#[inline]
+pub fn read(index: usize) -> usize {
+    try_read(index).unwrap()
+}
+
+/// Attempts to read the `hpmcounter[index]` CSR.
+///
+/// Returns an error if `index` is not in the range 3 to 31.
+#[inline]
+pub fn try_read(index: usize) -> Result<usize> {
+    dispatch!(index, try_read())
+}
+
+/// Reads the `hpmcounter[index]` CSR as a 64-bit value.
+///
+/// On RV32, the high half is re-read to detect an overflow of the low half between reads.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` not in the range 3 to 31
+#[inline]
+pub fn read64(index: usize) -> u64 {
+    try_read64(index).unwrap()
+}
+
+/// Attempts to read the `hpmcounter[index]` CSR as a 64-bit value.
+///
+/// Returns an error if `index` is not in the range 3 to 31.
+#[inline]
+pub fn try_read64(index: usize) -> Result<u64> {
+    match () {
+        #[cfg(target_arch = "riscv32")]
+        () => loop {
+            let hi = dispatch_h!(index, try_read())?;
+            let lo = try_read(index)?;
+            if hi == dispatch_h!(index, try_read())? {
+                return Ok(((hi as u64) << 32) | lo as u64);
+            }
+        },
+
+        #[cfg(not(target_arch = "riscv32"))]
+        () => try_read(index).map(|lo| lo as u64),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hpmcounter() {
+        for index in [0, 1, 2, 32] {
+            assert_eq!(try_read(index), Err(out_of_bounds(index)));
+            assert_eq!(try_read64(index), Err(out_of_bounds(index)));
+        }
+        for index in 3..32 {
+            assert_eq!(try_read(index), Err(Error::Unimplemented));
+        }
+    }
+}
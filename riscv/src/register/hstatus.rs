@@ -0,0 +1,393 @@
+//! hstatus register (hypervisor extension)
+//!
+//! Hypervisor status register. It holds the state of the virtualization mode before a trap
+//! into HS-mode (e.g., `SPV` and `SPVP`), and controls the traps of VS-mode operations.
+
+pub use super::menvcfg::Pmm;
+pub use super::misa::XLEN;
+pub use super::mstatus::Endianness;
+pub use super::sstatus::SPP;
+use crate::bits::{bf_extract, bf_insert};
+use crate::result::{Error, Result};
+
+/// Mask of the `VGEIN` field
+const VGEIN_MASK: usize = 0x3F;
+
+/// hstatus register
+#[derive(Clone, Copy, Debug)]
+pub struct Hstatus {
+    bits: usize,
+}
+
+impl From<usize> for Hstatus {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Hstatus {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// VS-mode non-instruction-fetch memory endianness
+    #[inline]
+    pub fn vsbe(&self) -> Endianness {
+        Endianness::from(bf_extract(self.bits, 5, 1) != 0)
+    }
+
+    /// Update VS-mode non-instruction-fetch memory endianness
+    ///
+    /// Note this updates a previously read [`Hstatus`] value, but does not
+    /// affect the hstatus CSR itself. See [`set_vsbe`] to directly update the
+    /// CSR.
+    #[inline]
+    pub fn set_vsbe(&mut self, endianness: Endianness) {
+        self.bits = bf_insert(self.bits, 5, 1, endianness as usize);
+    }
+
+    /// Guest Virtual Address
+    ///
+    /// Set on traps into HS-mode if `stval` holds a guest virtual address.
+    #[inline]
+    pub fn gva(&self) -> bool {
+        bf_extract(self.bits, 6, 1) != 0
+    }
+
+    /// Update Guest Virtual Address
+    ///
+    /// Note this updates a previously read [`Hstatus`] value, but does not
+    /// affect the hstatus CSR itself. See [`set_gva`]/[`clear_gva`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_gva(&mut self, gva: bool) {
+        self.bits = bf_insert(self.bits, 6, 1, gva as usize);
+    }
+
+    /// Supervisor Previous Virtualization mode
+    ///
+    /// Set on traps into HS-mode if the trap was taken from VS-mode or VU-mode.
+    /// `sret` returns to the virtualization mode held by this bit.
+    #[inline]
+    pub fn spv(&self) -> bool {
+        bf_extract(self.bits, 7, 1) != 0
+    }
+
+    /// Update Supervisor Previous Virtualization mode
+    ///
+    /// Note this updates a previously read [`Hstatus`] value, but does not
+    /// affect the hstatus CSR itself. See [`set_spv`]/[`clear_spv`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_spv(&mut self, spv: bool) {
+        self.bits = bf_insert(self.bits, 7, 1, spv as usize);
+    }
+
+    /// Supervisor Previous Virtual Privilege
+    ///
+    /// Privilege mode of the virtual machine before the trap, and privilege mode of the
+    /// explicit memory accesses of the virtual-machine load/store instructions.
+    #[inline]
+    pub fn spvp(&self) -> SPP {
+        match bf_extract(self.bits, 8, 1) != 0 {
+            true => SPP::Supervisor,
+            false => SPP::User,
+        }
+    }
+
+    /// Update Supervisor Previous Virtual Privilege
+    ///
+    /// Note this updates a previously read [`Hstatus`] value, but does not
+    /// affect the hstatus CSR itself. See [`set_spvp`] to directly update the
+    /// CSR.
+    #[inline]
+    pub fn set_spvp(&mut self, spvp: SPP) {
+        self.bits = bf_insert(self.bits, 8, 1, spvp as usize);
+    }
+
+    /// Hypervisor in U-mode
+    ///
+    /// When set, the virtual-machine load/store instructions can be executed in U-mode.
+    #[inline]
+    pub fn hu(&self) -> bool {
+        bf_extract(self.bits, 9, 1) != 0
+    }
+
+    /// Update Hypervisor in U-mode
+    ///
+    /// Note this updates a previously read [`Hstatus`] value, but does not
+    /// affect the hstatus CSR itself. See [`set_hu`]/[`clear_hu`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_hu(&mut self, hu: bool) {
+        self.bits = bf_insert(self.bits, 9, 1, hu as usize);
+    }
+
+    /// Virtual Guest External Interrupt Number
+    ///
+    /// Selects the guest external interrupt source for VS-level external interrupts
+    /// (0 means no source).
+    #[inline]
+    pub fn vgein(&self) -> usize {
+        bf_extract(self.bits, 12, 6)
+    }
+
+    /// Update Virtual Guest External Interrupt Number
+    ///
+    /// Note this updates a previously read [`Hstatus`] value, but does not
+    /// affect the hstatus CSR itself. See [`set_vgein`] to directly update the
+    /// CSR.
+    ///
+    /// **WARNING**: panics if `vgein` does not fit in the field (i.e., it is greater than 63).
+    #[inline]
+    pub fn set_vgein(&mut self, vgein: usize) {
+        self.try_set_vgein(vgein).unwrap();
+    }
+
+    /// Attempts to update Virtual Guest External Interrupt Number
+    ///
+    /// Note this updates a previously read [`Hstatus`] value, but does not
+    /// affect the hstatus CSR itself. See [`try_set_vgein`] to directly update the
+    /// CSR.
+    #[inline]
+    pub fn try_set_vgein(&mut self, vgein: usize) -> Result<()> {
+        self.bits = bf_insert(self.bits, 12, 6, check_vgein(vgein)?);
+        Ok(())
+    }
+
+    /// Virtual Trap Virtual Memory
+    ///
+    /// When set, `sfence.vma`, `sinval.vma`, and accesses to `satp` in VS-mode raise
+    /// virtual-instruction exceptions.
+    #[inline]
+    pub fn vtvm(&self) -> bool {
+        bf_extract(self.bits, 20, 1) != 0
+    }
+
+    /// Update Virtual Trap Virtual Memory
+    ///
+    /// Note this updates a previously read [`Hstatus`] value, but does not
+    /// affect the hstatus CSR itself. See [`set_vtvm`]/[`clear_vtvm`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_vtvm(&mut self, vtvm: bool) {
+        self.bits = bf_insert(self.bits, 20, 1, vtvm as usize);
+    }
+
+    /// Virtual Timeout Wait
+    ///
+    /// When set, `wfi` in VS-mode raises a virtual-instruction exception if it does not
+    /// complete within an implementation-specific time limit.
+    #[inline]
+    pub fn vtw(&self) -> bool {
+        bf_extract(self.bits, 21, 1) != 0
+    }
+
+    /// Update Virtual Timeout Wait
+    ///
+    /// Note this updates a previously read [`Hstatus`] value, but does not
+    /// affect the hstatus CSR itself. See [`set_vtw`]/[`clear_vtw`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_vtw(&mut self, vtw: bool) {
+        self.bits = bf_insert(self.bits, 21, 1, vtw as usize);
+    }
+
+    /// Virtual Trap SRET
+    ///
+    /// When set, `sret` in VS-mode raises a virtual-instruction exception.
+    #[inline]
+    pub fn vtsr(&self) -> bool {
+        bf_extract(self.bits, 22, 1) != 0
+    }
+
+    /// Update Virtual Trap SRET
+    ///
+    /// Note this updates a previously read [`Hstatus`] value, but does not
+    /// affect the hstatus CSR itself. See [`set_vtsr`]/[`clear_vtsr`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_vtsr(&mut self, vtsr: bool) {
+        self.bits = bf_insert(self.bits, 22, 1, vtsr as usize);
+    }
+
+    /// Effective xlen in VS-mode (i.e., `VSXLEN`).
+    ///
+    /// In RISCV-32, VSXL does not exist, and `VSXLEN` is always [`XLEN::XLEN32`].
+    #[inline]
+    pub fn vsxl(&self) -> XLEN {
+        match () {
+            #[cfg(riscv32)]
+            () => XLEN::XLEN32,
+            #[cfg(not(riscv32))]
+            () => XLEN::from(bf_extract(self.bits, 32, 2) as u8),
+        }
+    }
+
+    /// Update effective xlen in VS-mode (i.e., `VSXLEN`).
+    ///
+    /// Note this updates a previously read [`Hstatus`] value, but does not
+    /// affect the hstatus CSR itself.
+    #[inline]
+    #[allow(unused_variables)]
+    pub fn set_vsxl(&mut self, vsxl: XLEN) {
+        #[cfg(not(riscv32))]
+        {
+            self.bits = bf_insert(self.bits, 32, 2, vsxl as usize);
+        }
+    }
+
+    /// Pointer masking mode of VU-mode for the virtual-machine load/store instructions
+    /// executed in U-mode (i.e., `HUPMM`).
+    ///
+    /// **WARNING**: panics if the field has a reserved value.
+    #[inline]
+    pub fn hupmm(&self) -> Pmm {
+        self.try_hupmm().unwrap()
+    }
+
+    /// Attempts to get the pointer masking mode of the virtual-machine load/store instructions
+    /// executed in U-mode (i.e., `HUPMM`).
+    ///
+    /// In RISCV-32, HUPMM does not exist, and it is always [`Pmm::Disabled`].
+    #[inline]
+    pub fn try_hupmm(&self) -> Result<Pmm> {
+        match () {
+            #[cfg(riscv32)]
+            () => Ok(Pmm::Disabled),
+            #[cfg(not(riscv32))]
+            () => Pmm::try_from(bf_extract(self.bits, 48, 2) as u8),
+        }
+    }
+
+    /// Update the pointer masking mode of the virtual-machine load/store instructions
+    /// executed in U-mode (i.e., `HUPMM`).
+    ///
+    /// Note this updates a previously read [`Hstatus`] value, but does not
+    /// affect the hstatus CSR itself.
+    #[inline]
+    #[allow(unused_variables)]
+    pub fn set_hupmm(&mut self, hupmm: Pmm) {
+        #[cfg(not(riscv32))]
+        {
+            self.bits = bf_insert(self.bits, 48, 2, hupmm as usize);
+        }
+    }
+}
+
+/// Checks that `vgein` fits in the `VGEIN` field
+#[inline]
+fn check_vgein(vgein: usize) -> Result<usize> {
+    if vgein & !VGEIN_MASK == 0 {
+        Ok(vgein)
+    } else {
+        Err(Error::InvalidFieldValue {
+            field: "vgein",
+            value: vgein,
+            bitmask: VGEIN_MASK,
+        })
+    }
+}
+
+read_csr_as!(Hstatus, 0x600);
+write_csr_as!(Hstatus, 0x600);
+modify_csr_as!(Hstatus);
+set!(0x600);
+clear!(0x600);
+
+set_clear_csr!(
+    /// Guest Virtual Address
+    , set_gva, clear_gva, 1 << 6);
+set_clear_csr!(
+    /// Supervisor Previous Virtualization mode
+    , set_spv, clear_spv, 1 << 7);
+set_clear_csr!(
+    /// Hypervisor in U-mode
+    , set_hu, clear_hu, 1 << 9);
+set_clear_csr!(
+    /// Virtual Trap Virtual Memory
+    , set_vtvm, clear_vtvm, 1 << 20);
+set_clear_csr!(
+    /// Virtual Timeout Wait
+    , set_vtw, clear_vtw, 1 << 21);
+set_clear_csr!(
+    /// Virtual Trap SRET
+    , set_vtsr, clear_vtsr, 1 << 22);
+
+/// Set VS-mode non-instruction-fetch memory endianness
+#[inline]
+pub unsafe fn set_vsbe(endianness: Endianness) {
+    match endianness {
+        Endianness::BigEndian => _set(1 << 5),
+        Endianness::LittleEndian => _clear(1 << 5),
+    }
+}
+
+/// Supervisor Previous Virtual Privilege
+#[inline]
+pub unsafe fn set_spvp(spvp: SPP) {
+    match spvp {
+        SPP::Supervisor => _set(1 << 8),
+        SPP::User => _clear(1 << 8),
+    }
+}
+
+/// Virtual Guest External Interrupt Number
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `vgein` greater than 63
+#[inline]
+pub unsafe fn set_vgein(vgein: usize) {
+    try_set_vgein(vgein).unwrap();
+}
+
+/// Attempts to set the Virtual Guest External Interrupt Number
+#[inline]
+pub unsafe fn try_set_vgein(vgein: usize) -> Result<()> {
+    let vgein = check_vgein(vgein)?;
+    let mut value = _try_read()?;
+    value &= !(VGEIN_MASK << 12); // clear previous value
+    value |= vgein << 12;
+    _try_write(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hstatus() {
+        let mut hstatus = Hstatus::from(0);
+
+        hstatus.set_spv(true);
+        hstatus.set_spvp(SPP::Supervisor);
+        hstatus.set_gva(true);
+        hstatus.set_vtsr(true);
+        assert!(hstatus.spv() && hstatus.gva() && hstatus.vtsr());
+        assert_eq!(hstatus.spvp(), SPP::Supervisor);
+        assert_eq!(hstatus.bits(), (1 << 22) | (1 << 8) | (1 << 7) | (1 << 6));
+
+        hstatus.set_vgein(63);
+        assert_eq!(hstatus.vgein(), 63);
+        assert_eq!(
+            hstatus.try_set_vgein(64),
+            Err(Error::InvalidFieldValue {
+                field: "vgein",
+                value: 64,
+                bitmask: VGEIN_MASK,
+            })
+        );
+        assert_eq!(hstatus.vgein(), 63);
+
+        hstatus.set_vsxl(XLEN::XLEN64);
+        assert_eq!(hstatus.vsxl(), XLEN::XLEN64);
+        hstatus.set_hupmm(Pmm::PmLen16);
+        assert_eq!(hstatus.hupmm(), Pmm::PmLen16);
+        assert!(Hstatus::from(1 << 48).try_hupmm().is_err());
+    }
+}
@@ -0,0 +1,40 @@
+//! htimedelta register (hypervisor extension)
+//!
+//! Hypervisor time delta register. When V=1, the `time` CSR returns the sum of the host time
+//! and this delta, so each virtual machine can have its own time base. On RV32, the upper
+//! 32 bits are held by [`htimedeltah`](super::htimedeltah), and [`read64`]/[`write64`]
+//! access the full 64-bit register.
+
+read_csr_as_usize!(0x605);
+write_csr_as_usize!(0x605);
+read_composite_csr!(super::htimedeltah::read(), read());
+write_composite_csr!(super::htimedeltah::write, write);
+
+/// Returns the delta that makes the guest time equal `guest_time` when the host time is `host_time`.
+///
+/// The delta wraps around, so the guest time can be before the host time.
+#[inline]
+pub const fn delta_for(host_time: u64, guest_time: u64) -> u64 {
+    guest_time.wrapping_sub(host_time)
+}
+
+/// Sets the delta so that the guest time is now `guest_time` (e.g., 0 for a guest epoch that
+/// starts when the virtual machine boots).
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn set_guest_time(guest_time: u64) {
+    write64(delta_for(super::time::read64(), guest_time));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delta_for() {
+        assert_eq!(delta_for(1_000, 1_500), 500);
+        assert_eq!(delta_for(1_000, 0), 1_000u64.wrapping_neg());
+        assert_eq!(1_000u64.wrapping_add(delta_for(1_000, 0)), 0);
+    }
+}
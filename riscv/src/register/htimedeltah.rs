@@ -0,0 +1,8 @@
+//! htimedeltah register (hypervisor extension, RV32 only)
+//!
+//! Upper 32 bits of the hypervisor time delta register.
+//! Use [`htimedelta::read64`](super::htimedelta::read64) and
+//! [`htimedelta::write64`](super::htimedelta::write64) to access the full 64-bit register.
+
+read_csr_as_usize_rv32!(0x615);
+write_csr_as_usize_rv32!(0x615);
@@ -0,0 +1,189 @@
+//! hvictl register (hypervisor extension, AIA)
+//!
+//! Hypervisor virtual interrupt control register. It injects a virtual interrupt into VS-mode
+//! (or forces a virtual timer interrupt trap with `VTI`) with an explicit identity and priority,
+//! for guests without a hardware guest interrupt file.
+
+use crate::result::{Error, Result};
+
+/// Mask of the `IID` field
+const IID_MASK: usize = 0xFFF;
+
+/// hvictl register
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Hvictl {
+    bits: usize,
+}
+
+impl From<usize> for Hvictl {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Hvictl {
+    /// Creates a [`Hvictl`] value that injects interrupt `iid` with priority `iprio` into VS-mode.
+    ///
+    /// If `dpr` is set, the interrupt uses the default priority order of `iid` when comparing
+    /// it with interrupts of the same priority number.
+    ///
+    /// Returns an error if `iid` does not fit in the field (i.e., it is greater than 4095).
+    #[inline]
+    pub fn inject(iid: usize, iprio: u8, dpr: bool) -> Result<Self> {
+        let mut hvictl = Self { bits: 0 };
+        hvictl.try_set_iid(iid)?;
+        hvictl.set_ipriom(true);
+        hvictl.set_iprio(iprio);
+        hvictl.set_dpr(dpr);
+        Ok(hvictl)
+    }
+
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Virtual Trap Interrupt control
+    ///
+    /// When set, attempts of VS-mode to enable supervisor-level interrupts raise
+    /// virtual-instruction exceptions.
+    #[inline]
+    pub fn vti(&self) -> bool {
+        self.bits & (1 << 30) != 0
+    }
+
+    /// Update Virtual Trap Interrupt control
+    ///
+    /// Note this updates a previously read [`Hvictl`] value, but does not
+    /// affect the hvictl CSR itself. See [`set_vti`]/[`clear_vti`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_vti(&mut self, vti: bool) {
+        self.bits = (self.bits & !(1 << 30)) | ((vti as usize) << 30);
+    }
+
+    /// Interrupt Identity of the injected interrupt
+    ///
+    /// The value 9 (supervisor external interrupt) selects the interrupt held by
+    /// `hvip.VSEIP` or the guest interrupt file.
+    #[inline]
+    pub fn iid(&self) -> usize {
+        (self.bits >> 16) & IID_MASK
+    }
+
+    /// Update Interrupt Identity of the injected interrupt
+    ///
+    /// Note this updates a previously read [`Hvictl`] value, but does not
+    /// affect the hvictl CSR itself.
+    ///
+    /// **WARNING**: panics if `iid` does not fit in the field (i.e., it is greater than 4095).
+    #[inline]
+    pub fn set_iid(&mut self, iid: usize) {
+        self.try_set_iid(iid).unwrap();
+    }
+
+    /// Attempts to update Interrupt Identity of the injected interrupt
+    ///
+    /// Note this updates a previously read [`Hvictl`] value, but does not
+    /// affect the hvictl CSR itself.
+    #[inline]
+    pub fn try_set_iid(&mut self, iid: usize) -> Result<()> {
+        if iid & !IID_MASK != 0 {
+            return Err(Error::InvalidFieldValue {
+                field: "iid",
+                value: iid,
+                bitmask: IID_MASK,
+            });
+        }
+        self.bits = (self.bits & !(IID_MASK << 16)) | (iid << 16);
+        Ok(())
+    }
+
+    /// Default Priority Rank
+    ///
+    /// Selects whether the injected interrupt is ordered before (clear) or after (set)
+    /// the interrupts of the same priority number.
+    #[inline]
+    pub fn dpr(&self) -> bool {
+        self.bits & (1 << 9) != 0
+    }
+
+    /// Update Default Priority Rank
+    ///
+    /// Note this updates a previously read [`Hvictl`] value, but does not
+    /// affect the hvictl CSR itself.
+    #[inline]
+    pub fn set_dpr(&mut self, dpr: bool) {
+        self.bits = (self.bits & !(1 << 9)) | ((dpr as usize) << 9);
+    }
+
+    /// Interrupt Priority Mode
+    ///
+    /// When set, the injected interrupt has the priority number [`iprio`](Self::iprio).
+    /// Otherwise, it has the default priority of `iid`.
+    #[inline]
+    pub fn ipriom(&self) -> bool {
+        self.bits & (1 << 8) != 0
+    }
+
+    /// Update Interrupt Priority Mode
+    ///
+    /// Note this updates a previously read [`Hvictl`] value, but does not
+    /// affect the hvictl CSR itself.
+    #[inline]
+    pub fn set_ipriom(&mut self, ipriom: bool) {
+        self.bits = (self.bits & !(1 << 8)) | ((ipriom as usize) << 8);
+    }
+
+    /// Interrupt Priority number of the injected interrupt
+    #[inline]
+    pub fn iprio(&self) -> u8 {
+        self.bits as u8
+    }
+
+    /// Update Interrupt Priority number of the injected interrupt
+    ///
+    /// Note this updates a previously read [`Hvictl`] value, but does not
+    /// affect the hvictl CSR itself.
+    #[inline]
+    pub fn set_iprio(&mut self, iprio: u8) {
+        self.bits = (self.bits & !0xFF) | iprio as usize;
+    }
+}
+
+read_csr_as!(Hvictl, 0x609);
+write_csr_as!(Hvictl, 0x609);
+set!(0x609);
+clear!(0x609);
+
+set_clear_csr!(
+    /// Virtual Trap Interrupt control
+    , set_vti, clear_vti, 1 << 30);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hvictl() {
+        let mut hvictl = Hvictl::inject(9, 0x80, true).unwrap();
+        assert_eq!(hvictl.iid(), 9);
+        assert_eq!(hvictl.iprio(), 0x80);
+        assert!(hvictl.ipriom() && hvictl.dpr() && !hvictl.vti());
+        assert_eq!(hvictl.bits(), (9 << 16) | (1 << 9) | (1 << 8) | 0x80);
+
+        hvictl.set_vti(true);
+        assert!(hvictl.vti());
+        assert_eq!(
+            hvictl.try_set_iid(0x1000),
+            Err(Error::InvalidFieldValue {
+                field: "iid",
+                value: 0x1000,
+                bitmask: IID_MASK,
+            })
+        );
+        assert_eq!(hvictl.iid(), 9);
+    }
+}
@@ -0,0 +1,190 @@
+//! hvien register (hypervisor extension, AIA)
+//!
+//! Hypervisor virtual interrupt-enable register. Setting bit `i` (13 to 63) makes the bit `i`
+//! of `hvip` a virtual interrupt for VS-mode that is not backed by a real interrupt (i.e., it is
+//! only injected by software). On RV32, the upper 32 bits are held by [`hvienh`](super::hvienh).
+
+use crate::bits::range_bit_mask;
+use crate::result::Result;
+
+/// hvien register
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Hvien {
+    bits: u64,
+}
+
+impl From<u64> for Hvien {
+    #[inline]
+    fn from(bits: u64) -> Self {
+        Self { bits }
+    }
+}
+
+impl Hvien {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    /// Returns `true` if the virtual interrupt `index` (13 to 63) is enabled
+    ///
+    /// **WARNING**: panics if `index` is out of bounds.
+    #[inline]
+    pub fn is_enabled(&self, index: usize) -> bool {
+        self.try_is_enabled(index).unwrap()
+    }
+
+    /// Attempts to check if the virtual interrupt `index` (13 to 63) is enabled
+    ///
+    /// Returns an error if `index` is out of bounds.
+    #[inline]
+    pub fn try_is_enabled(&self, index: usize) -> Result<bool> {
+        Ok(self.bits & mask(index)? != 0)
+    }
+
+    /// Enables or disables the virtual interrupt `index` (13 to 63)
+    ///
+    /// Note this updates a previously read [`Hvien`] value, but does not
+    /// affect the hvien CSR itself. See [`enable`]/[`disable`] to directly
+    /// update the CSR.
+    ///
+    /// **WARNING**: panics if `index` is out of bounds.
+    #[inline]
+    pub fn set_enabled(&mut self, index: usize, enabled: bool) {
+        self.try_set_enabled(index, enabled).unwrap();
+    }
+
+    /// Attempts to enable or disable the virtual interrupt `index` (13 to 63)
+    ///
+    /// Note this updates a previously read [`Hvien`] value, but does not
+    /// affect the hvien CSR itself.
+    #[inline]
+    pub fn try_set_enabled(&mut self, index: usize, enabled: bool) -> Result<()> {
+        let mask = mask(index)?;
+        match enabled {
+            true => self.bits |= mask,
+            false => self.bits &= !mask,
+        }
+        Ok(())
+    }
+}
+
+/// Returns the bit of virtual interrupt `index`
+#[inline]
+fn mask(index: usize) -> Result<u64> {
+    range_bit_mask(index, 13, 63)
+}
+
+read_csr!(0x608);
+write_csr!(0x608);
+
+/// Reads the CSR
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn read() -> Hvien {
+    try_read().unwrap()
+}
+
+/// Attempts to read the CSR
+///
+/// On RV32, the upper half is read from the `hvienh` CSR.
+#[inline]
+pub fn try_read() -> Result<Hvien> {
+    let lo = unsafe { _try_read()? } as u64;
+    match () {
+        #[cfg(target_pointer_width = "32")]
+        () => Ok(Hvien::from(
+            ((super::hvienh::try_read()? as u64) << 32) | lo,
+        )),
+        #[cfg(not(target_pointer_width = "32"))]
+        () => Ok(Hvien::from(lo)),
+    }
+}
+
+/// Writes the CSR
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub unsafe fn write(hvien: Hvien) {
+    try_write(hvien).unwrap();
+}
+
+/// Attempts to write the CSR
+///
+/// On RV32, the upper half is written to the `hvienh` CSR first.
+#[inline]
+pub unsafe fn try_write(hvien: Hvien) -> Result<()> {
+    #[cfg(target_pointer_width = "32")]
+    super::hvienh::try_write((hvien.bits >> 32) as usize)?;
+    _try_write(hvien.bits as usize)
+}
+
+/// Enables the virtual interrupt `index` (13 to 63)
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` out of bounds
+#[inline]
+pub unsafe fn enable(index: usize) {
+    try_enable(index).unwrap();
+}
+
+/// Attempts to enable the virtual interrupt `index` (13 to 63)
+///
+/// Returns an error if `index` is out of bounds.
+#[inline]
+pub unsafe fn try_enable(index: usize) -> Result<()> {
+    let mut hvien = try_read()?;
+    hvien.try_set_enabled(index, true)?;
+    try_write(hvien)
+}
+
+/// Disables the virtual interrupt `index` (13 to 63)
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` out of bounds
+#[inline]
+pub unsafe fn disable(index: usize) {
+    try_disable(index).unwrap();
+}
+
+/// Attempts to disable the virtual interrupt `index` (13 to 63)
+///
+/// Returns an error if `index` is out of bounds.
+#[inline]
+pub unsafe fn try_disable(index: usize) -> Result<()> {
+    let mut hvien = try_read()?;
+    hvien.try_set_enabled(index, false)?;
+    try_write(hvien)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::result::Error;
+
+    #[test]
+    fn test_hvien() {
+        let mut hvien = Hvien::from(0);
+        hvien.set_enabled(13, true);
+        hvien.set_enabled(63, true);
+        assert_eq!(hvien.bits(), (1 << 13) | (1 << 63));
+        assert!(hvien.is_enabled(63) && !hvien.is_enabled(14));
+        hvien.set_enabled(63, false);
+        assert_eq!(hvien.bits(), 1 << 13);
+        assert_eq!(
+            hvien.try_set_enabled(12, true),
+            Err(Error::IndexOutOfBounds {
+                index: 12,
+                min: 13,
+                max: 63,
+            })
+        );
+        assert_eq!(unsafe { try_enable(13) }, Err(Error::Unimplemented));
+    }
+}
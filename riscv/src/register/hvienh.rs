@@ -0,0 +1,8 @@
+//! hvienh register (hypervisor extension, AIA, RV32 only)
+//!
+//! Upper 32 bits of the hypervisor virtual interrupt-enable register.
+//! Use [`hvien::read`](super::hvien::read) and [`hvien::write`](super::hvien::write)
+//! to access the full 64-bit register.
+
+read_csr_as_usize_rv32!(0x618);
+write_csr_as_usize_rv32!(0x618);
@@ -0,0 +1,146 @@
+//! hviprio1 register (hypervisor extension, AIA)
+//!
+//! Hypervisor virtual interrupt priorities register 1. It holds the 8-bit priority numbers of
+//! the VS-level interrupts listed in [`INTERRUPTS`], one per byte. The remaining interrupts are
+//! in [`hviprio2`](super::hviprio2). On RV32, the upper 32 bits are held by
+//! [`hviprio1h`](super::hviprio1h).
+
+use crate::result::{Error, Result};
+
+/// Interrupt numbers whose priority is held by each byte of the register
+///
+/// The first byte is read-only zero, so interrupt 0 is not a valid argument.
+pub const INTERRUPTS: [usize; 8] = [0, 1, 4, 5, 8, 13, 14, 15];
+
+/// hviprio1 register
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Hviprio1 {
+    bits: u64,
+}
+
+impl From<u64> for Hviprio1 {
+    #[inline]
+    fn from(bits: u64) -> Self {
+        Self { bits }
+    }
+}
+
+impl Hviprio1 {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    /// Priority number of `interrupt`
+    ///
+    /// **WARNING**: panics if the register does not hold the priority of `interrupt`.
+    #[inline]
+    pub fn priority(&self, interrupt: usize) -> u8 {
+        self.try_priority(interrupt).unwrap()
+    }
+
+    /// Attempts to get the priority number of `interrupt`
+    ///
+    /// Returns an error if the register does not hold the priority of `interrupt`.
+    #[inline]
+    pub fn try_priority(&self, interrupt: usize) -> Result<u8> {
+        Ok((self.bits >> shift(&INTERRUPTS, interrupt)?) as u8)
+    }
+
+    /// Update priority number of `interrupt`
+    ///
+    /// Note this updates a previously read [`Hviprio1`] value, but does not
+    /// affect the hviprio1 CSR itself. See [`write()`] to directly update the CSR.
+    ///
+    /// **WARNING**: panics if the register does not hold the priority of `interrupt`.
+    #[inline]
+    pub fn set_priority(&mut self, interrupt: usize, priority: u8) {
+        self.try_set_priority(interrupt, priority).unwrap();
+    }
+
+    /// Attempts to update priority number of `interrupt`
+    ///
+    /// Note this updates a previously read [`Hviprio1`] value, but does not
+    /// affect the hviprio1 CSR itself. See [`try_write`] to directly update the CSR.
+    #[inline]
+    pub fn try_set_priority(&mut self, interrupt: usize, priority: u8) -> Result<()> {
+        let shift = shift(&INTERRUPTS, interrupt)?;
+        self.bits = (self.bits & !(0xFF << shift)) | ((priority as u64) << shift);
+        Ok(())
+    }
+}
+
+/// Returns the position of the priority of `interrupt` in a register that holds `interrupts`
+#[inline]
+pub(super) fn shift(interrupts: &[usize; 8], interrupt: usize) -> Result<u32> {
+    match interrupts.iter().position(|&i| i == interrupt) {
+        Some(index) if interrupt != 0 => Ok(8 * index as u32),
+        _ => Err(Error::InvalidVariant(interrupt)),
+    }
+}
+
+read_csr!(0x646);
+write_csr!(0x646);
+
+/// Reads the CSR
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn read() -> Hviprio1 {
+    try_read().unwrap()
+}
+
+/// Attempts to read the CSR
+///
+/// On RV32, the upper half is read from the `hviprio1h` CSR.
+#[inline]
+pub fn try_read() -> Result<Hviprio1> {
+    let lo = unsafe { _try_read()? } as u64;
+    match () {
+        #[cfg(target_pointer_width = "32")]
+        () => Ok(Hviprio1::from(
+            ((super::hviprio1h::try_read()? as u64) << 32) | lo,
+        )),
+        #[cfg(not(target_pointer_width = "32"))]
+        () => Ok(Hviprio1::from(lo)),
+    }
+}
+
+/// Writes the CSR
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub unsafe fn write(hviprio1: Hviprio1) {
+    try_write(hviprio1).unwrap();
+}
+
+/// Attempts to write the CSR
+///
+/// On RV32, the upper half is written to the `hviprio1h` CSR first.
+#[inline]
+pub unsafe fn try_write(hviprio1: Hviprio1) -> Result<()> {
+    #[cfg(target_pointer_width = "32")]
+    super::hviprio1h::try_write((hviprio1.bits >> 32) as usize)?;
+    _try_write(hviprio1.bits as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hviprio1() {
+        let mut hviprio1 = Hviprio1::from(0);
+        hviprio1.set_priority(1, 0x11);
+        hviprio1.set_priority(15, 0xFF);
+        assert_eq!(hviprio1.bits(), 0xFF00_0000_0000_1100);
+        assert_eq!(hviprio1.priority(15), 0xFF);
+        assert_eq!(hviprio1.priority(5), 0);
+        assert_eq!(hviprio1.try_priority(0), Err(Error::InvalidVariant(0)));
+        assert_eq!(
+            hviprio1.try_set_priority(9, 1),
+            Err(Error::InvalidVariant(9))
+        );
+    }
+}
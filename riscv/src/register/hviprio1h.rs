@@ -0,0 +1,8 @@
+//! hviprio1h register (hypervisor extension, AIA, RV32 only)
+//!
+//! Upper 32 bits of the hypervisor virtual interrupt priorities register 1.
+//! Use [`hviprio1::read`](super::hviprio1::read) and [`hviprio1::write`](super::hviprio1::write)
+//! to access the full 64-bit register.
+
+read_csr_as_usize_rv32!(0x656);
+write_csr_as_usize_rv32!(0x656);
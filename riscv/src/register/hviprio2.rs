@@ -0,0 +1,132 @@
+//! hviprio2 register (hypervisor extension, AIA)
+//!
+//! Hypervisor virtual interrupt priorities register 2. It holds the 8-bit priority numbers of
+//! the VS-level interrupts listed in [`INTERRUPTS`], one per byte. The other interrupts are
+//! in [`hviprio1`](super::hviprio1). On RV32, the upper 32 bits are held by
+//! [`hviprio2h`](super::hviprio2h).
+
+use super::hviprio1::shift;
+use crate::result::Result;
+
+/// Interrupt numbers whose priority is held by each byte of the register
+pub const INTERRUPTS: [usize; 8] = [16, 17, 18, 19, 20, 21, 22, 23];
+
+/// hviprio2 register
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Hviprio2 {
+    bits: u64,
+}
+
+impl From<u64> for Hviprio2 {
+    #[inline]
+    fn from(bits: u64) -> Self {
+        Self { bits }
+    }
+}
+
+impl Hviprio2 {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    /// Priority number of `interrupt`
+    ///
+    /// **WARNING**: panics if the register does not hold the priority of `interrupt`.
+    #[inline]
+    pub fn priority(&self, interrupt: usize) -> u8 {
+        self.try_priority(interrupt).unwrap()
+    }
+
+    /// Attempts to get the priority number of `interrupt`
+    ///
+    /// Returns an error if the register does not hold the priority of `interrupt`.
+    #[inline]
+    pub fn try_priority(&self, interrupt: usize) -> Result<u8> {
+        Ok((self.bits >> shift(&INTERRUPTS, interrupt)?) as u8)
+    }
+
+    /// Update priority number of `interrupt`
+    ///
+    /// Note this updates a previously read [`Hviprio2`] value, but does not
+    /// affect the hviprio2 CSR itself. See [`write()`] to directly update the CSR.
+    ///
+    /// **WARNING**: panics if the register does not hold the priority of `interrupt`.
+    #[inline]
+    pub fn set_priority(&mut self, interrupt: usize, priority: u8) {
+        self.try_set_priority(interrupt, priority).unwrap();
+    }
+
+    /// Attempts to update priority number of `interrupt`
+    ///
+    /// Note this updates a previously read [`Hviprio2`] value, but does not
+    /// affect the hviprio2 CSR itself. See [`try_write`] to directly update the CSR.
+    #[inline]
+    pub fn try_set_priority(&mut self, interrupt: usize, priority: u8) -> Result<()> {
+        let shift = shift(&INTERRUPTS, interrupt)?;
+        self.bits = (self.bits & !(0xFF << shift)) | ((priority as u64) << shift);
+        Ok(())
+    }
+}
+
+read_csr!(0x647);
+write_csr!(0x647);
+
+/// Reads the CSR
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn read() -> Hviprio2 {
+    try_read().unwrap()
+}
+
+/// Attempts to read the CSR
+///
+/// On RV32, the upper half is read from the `hviprio2h` CSR.
+#[inline]
+pub fn try_read() -> Result<Hviprio2> {
+    let lo = unsafe { _try_read()? } as u64;
+    match () {
+        #[cfg(target_pointer_width = "32")]
+        () => Ok(Hviprio2::from(
+            ((super::hviprio2h::try_read()? as u64) << 32) | lo,
+        )),
+        #[cfg(not(target_pointer_width = "32"))]
+        () => Ok(Hviprio2::from(lo)),
+    }
+}
+
+/// Writes the CSR
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub unsafe fn write(hviprio2: Hviprio2) {
+    try_write(hviprio2).unwrap();
+}
+
+/// Attempts to write the CSR
+///
+/// On RV32, the upper half is written to the `hviprio2h` CSR first.
+#[inline]
+pub unsafe fn try_write(hviprio2: Hviprio2) -> Result<()> {
+    #[cfg(target_pointer_width = "32")]
+    super::hviprio2h::try_write((hviprio2.bits >> 32) as usize)?;
+    _try_write(hviprio2.bits as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::result::Error;
+
+    #[test]
+    fn test_hviprio2() {
+        let mut hviprio2 = Hviprio2::from(0);
+        hviprio2.set_priority(16, 0x10);
+        hviprio2.set_priority(23, 0x17);
+        assert_eq!(hviprio2.bits(), 0x1700_0000_0000_0010);
+        assert_eq!(hviprio2.priority(23), 0x17);
+        assert_eq!(hviprio2.try_priority(15), Err(Error::InvalidVariant(15)));
+    }
+}
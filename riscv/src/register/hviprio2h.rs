@@ -0,0 +1,8 @@
+//! hviprio2h register (hypervisor extension, AIA, RV32 only)
+//!
+//! Upper 32 bits of the hypervisor virtual interrupt priorities register 2.
+//! Use [`hviprio2::read`](super::hviprio2::read) and [`hviprio2::write`](super::hviprio2::write)
+//! to access the full 64-bit register.
+
+read_csr_as_usize_rv32!(0x657);
+write_csr_as_usize_rv32!(0x657);
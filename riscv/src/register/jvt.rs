@@ -0,0 +1,160 @@
+//! jvt register (Zcmt)
+//!
+//! Table jump base vector and control register. It configures the jump vector table
+//! used by the `cm.jt` and `cm.jalt` instructions.
+
+use crate::result::{Error, Result};
+
+/// jvt register
+#[derive(Clone, Copy, Debug)]
+pub struct Jvt {
+    bits: usize,
+}
+
+impl Jvt {
+    /// Creates a new [`Jvt`] value from a jump vector table base-address and mode.
+    ///
+    /// Returns an error if the base-address is not 64-byte aligned.
+    #[inline]
+    pub fn new(base: usize, mode: Mode) -> Result<Self> {
+        let mut jvt = Self { bits: 0 };
+        jvt.set_base(base)?;
+        jvt.set_mode(mode);
+        Ok(jvt)
+    }
+
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns the jump vector table base-address
+    #[inline]
+    pub fn base(&self) -> usize {
+        self.bits & !0x3F
+    }
+
+    /// Updates the jump vector table base-address
+    ///
+    /// Returns an error if the base-address is not 64-byte aligned.
+    ///
+    /// Note this updates a previously read [`Jvt`] value, but does not
+    /// affect the jvt CSR itself. See [`write()`] to directly update the CSR.
+    #[inline]
+    pub fn set_base(&mut self, base: usize) -> Result<()> {
+        if base & 0x3F != 0 {
+            Err(Error::InvalidFieldValue {
+                field: "base",
+                value: base,
+                bitmask: !0x3F,
+            })
+        } else {
+            self.bits = base | (self.bits & 0x3F);
+            Ok(())
+        }
+    }
+
+    /// Returns the jump table mode
+    ///
+    /// **WARNING**: panics if the field has an invalid variant.
+    #[inline]
+    pub fn mode(&self) -> Mode {
+        self.try_mode().unwrap()
+    }
+
+    /// Attempts to get the jump table mode
+    #[inline]
+    pub fn try_mode(&self) -> Result<Mode> {
+        ((self.bits & 0x3F) as u8).try_into()
+    }
+
+    /// Updates the jump table mode
+    ///
+    /// Note this updates a previously read [`Jvt`] value, but does not
+    /// affect the jvt CSR itself. See [`write()`] to directly update the CSR.
+    #[inline]
+    pub fn set_mode(&mut self, mode: Mode) {
+        self.bits = (self.bits & !0x3F) | mode as usize;
+    }
+}
+
+/// Jump table mode
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mode {
+    /// Jump table mode
+    Jump = 0,
+}
+
+impl TryFrom<u8> for Mode {
+    type Error = Error;
+
+    fn try_from(val: u8) -> Result<Self> {
+        match val {
+            0 => Ok(Mode::Jump),
+            _ => Err(Error::InvalidFieldVariant {
+                field: "mode",
+                value: val as usize,
+            }),
+        }
+    }
+}
+
+read_csr_as!(Jvt, 0x017);
+write_csr!(0x017);
+
+/// Writes the CSR
+///
+/// **WARNING**: panics on non-`riscv` targets.
+///
+/// # Safety
+///
+/// The `cm.jt` and `cm.jalt` instructions jump through the table at the new base-address.
+/// The caller must ensure that the table contains valid targets for every index used by
+/// the code running after the write.
+#[inline]
+pub unsafe fn write(jvt: Jvt) {
+    try_write(jvt).unwrap();
+}
+
+/// Attempts to write the CSR
+///
+/// # Safety
+///
+/// See [`write()`].
+#[inline]
+pub unsafe fn try_write(jvt: Jvt) -> Result<()> {
+    _try_write(jvt.bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jvt() {
+        let mut jvt = Jvt::new(0x8000_0040, Mode::Jump).unwrap();
+        assert_eq!(jvt.base(), 0x8000_0040);
+        assert_eq!(jvt.mode(), Mode::Jump);
+        assert_eq!(jvt.bits(), 0x8000_0040);
+
+        assert_eq!(
+            jvt.set_base(0x8000_0020),
+            Err(Error::InvalidFieldValue {
+                field: "base",
+                value: 0x8000_0020,
+                bitmask: !0x3F,
+            })
+        );
+        assert_eq!(jvt.base(), 0x8000_0040);
+
+        jvt.bits |= 0x3;
+        assert_eq!(
+            jvt.try_mode(),
+            Err(Error::InvalidFieldVariant {
+                field: "mode",
+                value: 3,
+            })
+        );
+    }
+}
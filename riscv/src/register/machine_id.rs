@@ -0,0 +1,46 @@
+//! Machine identification
+//!
+//! Snapshot of the machine information registers, e.g., for boot logs and crash reports.
+
+use super::marchid::{self, Arch, Marchid};
+use super::mhartid;
+use super::mimpid::{self, Mimpid};
+use super::mvendorid::{self, Mvendorid};
+
+/// Machine identification registers
+///
+/// Unimplemented registers (i.e., hardwired to zero) are `None`.
+#[derive(Clone, Copy, Debug)]
+pub struct MachineId {
+    /// Vendor ID
+    pub mvendorid: Option<Mvendorid>,
+    /// Architecture ID
+    pub marchid: Option<Marchid>,
+    /// Implementation ID
+    pub mimpid: Option<Mimpid>,
+    /// Hart ID
+    pub mhartid: usize,
+}
+
+impl MachineId {
+    /// Looks up the microarchitecture of well-known cores (see [`Marchid::arch`])
+    ///
+    /// Returns `None` if `marchid` is not implemented.
+    #[inline]
+    pub fn arch(&self) -> Option<Arch> {
+        self.marchid.map(|marchid| marchid.arch(self.mvendorid))
+    }
+}
+
+/// Reads the machine identification registers
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn read() -> MachineId {
+    MachineId {
+        mvendorid: mvendorid::read(),
+        marchid: marchid::read(),
+        mimpid: mimpid::read(),
+        mhartid: mhartid::read(),
+    }
+}
@@ -324,6 +324,39 @@ macro_rules! write_csr_as_usize_rv32 {
     };
 }
 
+/// Convenience macro to atomically swap a [`usize`] value with a CSR register.
+#[macro_export]
+macro_rules! swap_csr_as_usize {
+    ($csr_number:literal) => {
+        /// Atomically swaps the CSR with `bits`, and returns its previous value.
+        ///
+        /// **WARNING**: panics on non-`riscv` targets.
+        #[inline]
+        pub fn swap(bits: usize) -> usize {
+            try_swap(bits).unwrap()
+        }
+
+        /// Attempts to atomically swap the CSR with `bits`, and returns its previous value.
+        #[inline]
+        #[allow(unused_variables)]
+        pub fn try_swap(bits: usize) -> $crate::result::Result<usize> {
+            match () {
+                #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+                () => {
+                    let r: usize;
+                    unsafe {
+                        core::arch::asm!(concat!("csrrw {0}, ", stringify!($csr_number), ", {1}"), out(reg) r, in(reg) bits);
+                    }
+                    Ok(r)
+                }
+
+                #[cfg(not(any(target_arch = "riscv32", target_arch = "riscv64")))]
+                () => Err($crate::result::Error::Unimplemented),
+            }
+        }
+    };
+}
+
 /// Convenience macro around the `csrrs` assembly instruction to set the CSR register.
 ///
 /// This macro is intended for use with the [set_csr](crate::set_csr) or [set_clear_csr](crate::set_clear_csr) macros.
@@ -489,6 +522,36 @@ macro_rules! set_clear_csr {
     }
 }
 
+/// Convenience macro to define read-modify-write functions for a CSR type.
+///
+/// The `register` type must be a defined type in scope of the macro call.
+/// The CSR must also provide read and write functions (e.g., via the [read_csr](crate::read_csr)
+/// and [write_csr](crate::write_csr) macros).
+#[macro_export]
+macro_rules! modify_csr_as {
+    ($register:ident) => {
+        /// Reads the CSR, updates the value with `f`, and writes it back.
+        ///
+        /// The CSR is read and written once, regardless of how many fields `f` updates.
+        /// Note that this operation is not atomic: updates performed by an interrupt handler
+        /// between the read and the write are lost.
+        ///
+        /// **WARNING**: panics on non-`riscv` targets.
+        #[inline]
+        pub unsafe fn modify<F: FnOnce(&mut $register)>(f: F) {
+            try_modify(f).unwrap();
+        }
+
+        /// Attempts to read the CSR, update the value with `f`, and write it back.
+        #[inline]
+        pub unsafe fn try_modify<F: FnOnce(&mut $register)>(f: F) -> $crate::result::Result<()> {
+            let mut value = $register { bits: _try_read()? };
+            f(&mut value);
+            _try_write(value.bits)
+        }
+    };
+}
+
 /// Convenience macro to read a composite value from a CSR register.
 ///
 /// - `RV32`: reads 32-bits from `hi` and 32-bits from `lo` to create a 64-bit value
@@ -497,6 +560,11 @@ macro_rules! set_clear_csr {
 macro_rules! read_composite_csr {
     ($hi:expr, $lo:expr) => {
         /// Reads the CSR as a 64-bit value
+        ///
+        /// On RV32, the high half is re-read to detect a carry from the low half between reads,
+        /// so the result never glitches when the low half wraps around.
+        ///
+        /// **WARNING**: panics on non-`riscv` targets.
         #[inline]
         pub fn read64() -> u64 {
             match () {
@@ -513,6 +581,149 @@ macro_rules! read_composite_csr {
                 () => $lo as u64,
             }
         }
+
+        /// Attempts to read the CSR as a 64-bit value
+        ///
+        /// See [`read64`] for details.
+        #[inline]
+        pub fn try_read64() -> $crate::result::Result<u64> {
+            match () {
+                #[cfg(target_arch = "riscv32")]
+                () => Ok(read64()),
+
+                #[cfg(not(target_arch = "riscv32"))]
+                () => try_read().map(|lo| lo as u64),
+            }
+        }
+    };
+}
+
+/// Convenience macro to write a composite value to a CSR register.
+///
+/// - `RV32`: writes 32-bits into `hi` and 32-bits into `lo` to create a 64-bit value
+/// - `RV64`: writes a 64-bit value into `lo`
+#[macro_export]
+macro_rules! write_composite_csr {
+    ($hi:path, $lo:path) => {
+        /// Writes the CSR as a 64-bit value
+        ///
+        /// On RV32, the low half is first cleared to prevent a carry into the high half
+        /// between the two writes.
+        ///
+        /// **WARNING**: panics on non-`riscv` targets.
+        #[inline]
+        pub fn write64(bits: u64) {
+            match () {
+                #[cfg(target_arch = "riscv32")]
+                () => {
+                    $lo(0);
+                    $hi((bits >> 32) as usize);
+                    $lo(bits as usize);
+                }
+
+                #[cfg(not(target_arch = "riscv32"))]
+                () => $lo(bits as usize),
+            }
+        }
+    };
+}
+
+/// Convenience macro to define field setter and clear functions of the upper half of a 64-bit CSR.
+///
+/// On RV32, the functions forward to the `set_*`/`clear_*` functions of the `$hi` module
+/// (e.g., `menvcfgh`), which must refer to the same field as bit `$bit - 32`.
+macro_rules! set_clear_csr64 {
+    ($(#[$attr:meta])*, $set_field:ident, $clear_field:ident, $hi:ident, $bit:literal) => {
+        $(#[$attr])*
+        #[inline]
+        pub unsafe fn $set_field() {
+            match () {
+                #[cfg(target_pointer_width = "32")]
+                () => super::$hi::$set_field(),
+                #[cfg(not(target_pointer_width = "32"))]
+                () => _set(1 << $bit),
+            }
+        }
+
+        $(#[$attr])*
+        #[inline]
+        pub unsafe fn $clear_field() {
+            match () {
+                #[cfg(target_pointer_width = "32")]
+                () => super::$hi::$clear_field(),
+                #[cfg(not(target_pointer_width = "32"))]
+                () => _clear(1 << $bit),
+            }
+        }
+    };
+}
+
+/// Convenience macro to write a 64-bit configuration value to a CSR register.
+///
+/// Unlike [`write_composite_csr`], the low half is not cleared first on RV32: it would briefly
+/// drop the configuration bits it holds (e.g., the event selector of a `mhpmevent` register).
+macro_rules! write_config_csr {
+    ($hi:path, $lo:path) => {
+        /// Writes the CSR as a 64-bit value
+        ///
+        /// On RV32, the high half is written before the low half.
+        ///
+        /// **WARNING**: panics on non-`riscv` targets.
+        #[inline]
+        pub fn write64(bits: u64) {
+            match () {
+                #[cfg(target_arch = "riscv32")]
+                () => {
+                    $hi((bits >> 32) as usize);
+                    $lo(bits as usize);
+                }
+
+                #[cfg(not(target_arch = "riscv32"))]
+                () => $lo(bits as usize),
+            }
+        }
+    };
+}
+
+/// Convenience macro to define the privilege-mode inhibit bits (`MINH`, `SINH`, `UINH`, `VSINH`, and `VUINH`)
+/// of a 64-bit counter configuration type (e.g., [`Mcyclecfg`](crate::register::mcyclecfg::Mcyclecfg)).
+///
+/// The type must hold its raw value in a `bits: u64` field, and the module must provide a `write64` function.
+macro_rules! inhibit_bits {
+    ($ty:ident, $csr:literal) => {
+        impl $ty {
+            #[inline]
+            const fn bit(&self, index: u32) -> bool {
+                self.bits & (1 << index) != 0
+            }
+
+            #[inline]
+            fn set_bit(&mut self, index: u32, value: bool) {
+                self.bits = (self.bits & !(1 << index)) | ((value as u64) << index);
+            }
+
+            inhibit_bits!(@field $ty, $csr, minh, set_minh, 62, "M-mode (`MINH`)");
+            inhibit_bits!(@field $ty, $csr, sinh, set_sinh, 61, "S/HS-mode (`SINH`)");
+            inhibit_bits!(@field $ty, $csr, uinh, set_uinh, 60, "U-mode (`UINH`)");
+            inhibit_bits!(@field $ty, $csr, vsinh, set_vsinh, 59, "VS-mode (`VSINH`)");
+            inhibit_bits!(@field $ty, $csr, vuinh, set_vuinh, 58, "VU-mode (`VUINH`)");
+        }
+    };
+    (@field $ty:ident, $csr:literal, $field:ident, $set_field:ident, $bit:literal, $mode:literal) => {
+        #[doc = concat!("Inhibit counting in ", $mode)]
+        #[inline]
+        pub const fn $field(&self) -> bool {
+            self.bit($bit)
+        }
+
+        #[doc = concat!("Updates inhibit counting in ", $mode)]
+        ///
+        #[doc = concat!("Note this updates a previously read [`", stringify!($ty), "`] value, but does not")]
+        #[doc = concat!("affect the ", $csr, " CSR itself. See [`write64`] to directly update the CSR.")]
+        #[inline]
+        pub fn $set_field(&mut self, $field: bool) {
+            self.set_bit($bit, $field);
+        }
     };
 }
 
@@ -1,7 +1,11 @@
 //! marchid register
 
+use super::mvendorid::{self, Mvendorid};
 use core::num::NonZeroUsize;
 
+/// Most significant bit of `marchid`, set for commercial (vendor-specific) architecture IDs
+const COMMERCIAL: usize = 1 << (usize::BITS - 1);
+
 /// marchid register
 #[derive(Clone, Copy, Debug)]
 pub struct Marchid {
@@ -14,6 +18,86 @@ impl Marchid {
     pub fn bits(&self) -> usize {
         self.bits.get()
     }
+
+    /// Returns `true` if this is a commercial architecture ID
+    ///
+    /// Commercial architecture IDs have the most significant bit set and are only meaningful
+    /// together with the [`mvendorid`] of the vendor that allocated them.
+    /// Open-source architecture IDs are globally allocated and unique.
+    #[inline]
+    pub fn is_commercial(&self) -> bool {
+        self.bits() & COMMERCIAL != 0
+    }
+
+    /// Looks up the microarchitecture of well-known cores
+    ///
+    /// Commercial architecture IDs are decoded according to `mvendorid` (see [`mvendorid::read`]).
+    /// Unrecognized IDs are returned as [`Arch::Unknown`].
+    #[inline]
+    pub fn arch(&self, mvendorid: Option<Mvendorid>) -> Arch {
+        Arch::decode(
+            self.bits(),
+            mvendorid.map_or(0, |mvendorid| mvendorid.bits()),
+        )
+    }
+}
+
+impl Arch {
+    /// Decodes the raw `marchid` value for the raw `mvendorid` value (0 if not implemented).
+    fn decode(bits: usize, mvendorid: usize) -> Self {
+        if bits & COMMERCIAL != 0 {
+            match mvendorid {
+                mvendorid::SIFIVE => Arch::SiFive(bits & !COMMERCIAL),
+                _ => Arch::Unknown(bits),
+            }
+        } else {
+            match bits {
+                1 => Arch::Rocket,
+                2 => Arch::Boom,
+                3 => Arch::Cva6,
+                4 => Arch::Cv32e40p,
+                5 => Arch::Spike,
+                8 => Arch::Scr1,
+                11 => Arch::SweRvEh1,
+                13 => Arch::BlackParrot,
+                16 => Arch::SweRvEl2,
+                18 => Arch::Serv,
+                19 => Arch::Neorv32,
+                _ => Arch::Unknown(bits),
+            }
+        }
+    }
+}
+
+/// Well-known microarchitectures
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Arch {
+    /// Berkeley Rocket
+    Rocket,
+    /// Berkeley Out-of-Order Machine (BOOM)
+    Boom,
+    /// OpenHW Group CVA6 (formerly Ariane)
+    Cva6,
+    /// OpenHW Group CV32E40P (formerly RI5CY)
+    Cv32e40p,
+    /// Spike ISA simulator
+    Spike,
+    /// Syntacore SCR1
+    Scr1,
+    /// Western Digital SweRV EH1
+    SweRvEh1,
+    /// BlackParrot
+    BlackParrot,
+    /// Western Digital SweRV EL2
+    SweRvEl2,
+    /// SERV bit-serial core
+    Serv,
+    /// NEORV32
+    Neorv32,
+    /// SiFive core of the given series (e.g., 7 for the 7 Series)
+    SiFive(usize),
+    /// Unrecognized architecture ID
+    Unknown(usize),
 }
 
 read_csr!(0xF12);
@@ -26,3 +110,24 @@ pub fn read() -> Option<Marchid> {
     // csr isn't implemented.
     NonZeroUsize::new(r).map(|bits| Marchid { bits })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arch() {
+        assert_eq!(Arch::decode(1, 0), Arch::Rocket);
+        assert_eq!(Arch::decode(2, mvendorid::SIFIVE), Arch::Boom);
+        assert_eq!(Arch::decode(100, 0), Arch::Unknown(100));
+
+        assert_eq!(
+            Arch::decode(COMMERCIAL | 7, mvendorid::SIFIVE),
+            Arch::SiFive(7)
+        );
+        assert_eq!(
+            Arch::decode(COMMERCIAL | 7, 0),
+            Arch::Unknown(COMMERCIAL | 7)
+        );
+    }
+}
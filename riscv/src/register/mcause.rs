@@ -1,5 +1,9 @@
 //! mcause register
 
+pub use crate::interrupt::{Exception, Interrupt, Trap};
+use crate::result::{Error, Result};
+use crate::{CoreInterruptNumber, ExceptionNumber};
+
 /// mcause register
 #[derive(Clone, Copy, Debug)]
 pub struct Mcause {
@@ -13,109 +17,6 @@ impl From<usize> for Mcause {
     }
 }
 
-/// Trap Cause
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub enum Trap {
-    Interrupt(Interrupt),
-    Exception(Exception),
-}
-
-/// Interrupt
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-#[repr(usize)]
-pub enum Interrupt {
-    SupervisorSoft = 1,
-    MachineSoft = 3,
-    SupervisorTimer = 5,
-    MachineTimer = 7,
-    SupervisorExternal = 9,
-    MachineExternal = 11,
-    Unknown,
-}
-
-/// Exception
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-#[repr(usize)]
-pub enum Exception {
-    InstructionMisaligned = 0,
-    InstructionFault = 1,
-    IllegalInstruction = 2,
-    Breakpoint = 3,
-    LoadMisaligned = 4,
-    LoadFault = 5,
-    StoreMisaligned = 6,
-    StoreFault = 7,
-    UserEnvCall = 8,
-    SupervisorEnvCall = 9,
-    MachineEnvCall = 11,
-    InstructionPageFault = 12,
-    LoadPageFault = 13,
-    StorePageFault = 15,
-    Unknown,
-}
-
-impl From<usize> for Interrupt {
-    #[inline]
-    fn from(nr: usize) -> Self {
-        match nr {
-            1 => Self::SupervisorSoft,
-            3 => Self::MachineSoft,
-            5 => Self::SupervisorTimer,
-            7 => Self::MachineTimer,
-            9 => Self::SupervisorExternal,
-            11 => Self::MachineExternal,
-            _ => Self::Unknown,
-        }
-    }
-}
-
-impl TryFrom<Interrupt> for usize {
-    type Error = Interrupt;
-
-    #[inline]
-    fn try_from(value: Interrupt) -> Result<Self, Self::Error> {
-        match value {
-            Interrupt::Unknown => Err(Self::Error::Unknown),
-            _ => Ok(value as Self),
-        }
-    }
-}
-
-impl From<usize> for Exception {
-    #[inline]
-    fn from(nr: usize) -> Self {
-        match nr {
-            0 => Self::InstructionMisaligned,
-            1 => Self::InstructionFault,
-            2 => Self::IllegalInstruction,
-            3 => Self::Breakpoint,
-            4 => Self::LoadMisaligned,
-            5 => Self::LoadFault,
-            6 => Self::StoreMisaligned,
-            7 => Self::StoreFault,
-            8 => Self::UserEnvCall,
-            9 => Self::SupervisorEnvCall,
-            11 => Self::MachineEnvCall,
-            12 => Self::InstructionPageFault,
-            13 => Self::LoadPageFault,
-            15 => Self::StorePageFault,
-            _ => Self::Unknown,
-        }
-    }
-}
-
-impl TryFrom<Exception> for usize {
-    type Error = Exception;
-
-    #[inline]
-    fn try_from(value: Exception) -> Result<Self, Self::Error> {
-        match value {
-            Exception::Unknown => Err(Self::Error::Unknown),
-            _ => Ok(value as Self),
-        }
-    }
-}
-
 impl Mcause {
     /// Returns the contents of the register as raw bits
     #[inline]
@@ -130,13 +31,21 @@ impl Mcause {
     }
 
     /// Trap Cause
+    ///
+    /// The interrupt (`I`) and exception (`E`) types are target-specific.
+    ///
+    /// **WARNING**: panics if the code field does not match any variant of `I` or `E`.
     #[inline]
-    pub fn cause(&self) -> Trap {
-        if self.is_interrupt() {
-            Trap::Interrupt(Interrupt::from(self.code()))
-        } else {
-            Trap::Exception(Exception::from(self.code()))
-        }
+    pub fn cause<I: CoreInterruptNumber, E: ExceptionNumber>(&self) -> Trap<I, E> {
+        self.try_cause().unwrap()
+    }
+
+    /// Attempts to get the trap cause.
+    ///
+    /// Returns an error if the code field does not match any variant of `I` or `E`.
+    #[inline]
+    pub fn try_cause<I: CoreInterruptNumber, E: ExceptionNumber>(&self) -> Result<Trap<I, E>> {
+        Trap::try_from_bits(self.bits)
     }
 
     /// Is trap cause an interrupt.
@@ -152,4 +61,129 @@ impl Mcause {
     }
 }
 
+impl<I: CoreInterruptNumber, E: ExceptionNumber> TryFrom<Mcause> for Trap<I, E> {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(mcause: Mcause) -> Result<Self> {
+        mcause.try_cause()
+    }
+}
+
 read_csr_as!(Mcause, 0x342);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InterruptNumber;
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    enum CustomInterrupt {
+        MachineTimer = 7,
+        Custom = 24,
+    }
+
+    unsafe impl InterruptNumber for CustomInterrupt {
+        const MAX_INTERRUPT_NUMBER: u16 = 24;
+
+        fn number(self) -> u16 {
+            self as u16
+        }
+
+        fn from_number(value: u16) -> Result<Self> {
+            match value {
+                7 => Ok(Self::MachineTimer),
+                24 => Ok(Self::Custom),
+                _ => Err(Error::InvalidVariant(value as usize)),
+            }
+        }
+    }
+
+    unsafe impl CoreInterruptNumber for CustomInterrupt {}
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    enum CustomException {
+        IllegalInstruction = 2,
+        Custom = 24,
+    }
+
+    unsafe impl ExceptionNumber for CustomException {
+        const MAX_EXCEPTION_NUMBER: u16 = 24;
+
+        fn number(self) -> u16 {
+            self as u16
+        }
+
+        fn from_number(value: u16) -> Result<Self> {
+            match value {
+                2 => Ok(Self::IllegalInstruction),
+                24 => Ok(Self::Custom),
+                _ => Err(Error::InvalidVariant(value as usize)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_cause() {
+        let interrupt_bit = 1 << (usize::BITS as usize - 1);
+
+        let mcause = Mcause::from(interrupt_bit | 7);
+        assert_eq!(
+            mcause.cause::<CustomInterrupt, CustomException>(),
+            Trap::Interrupt(CustomInterrupt::MachineTimer)
+        );
+        let mcause = Mcause::from(interrupt_bit | 24);
+        assert_eq!(
+            mcause.cause::<CustomInterrupt, CustomException>(),
+            Trap::Interrupt(CustomInterrupt::Custom)
+        );
+        let mcause = Mcause::from(2);
+        assert_eq!(
+            mcause.cause::<CustomInterrupt, CustomException>(),
+            Trap::Exception(CustomException::IllegalInstruction)
+        );
+        let mcause = Mcause::from(24);
+        assert_eq!(
+            mcause.cause::<CustomInterrupt, CustomException>(),
+            Trap::Exception(CustomException::Custom)
+        );
+
+        let mcause = Mcause::from(interrupt_bit | 2);
+        assert_eq!(
+            mcause.try_cause::<CustomInterrupt, CustomException>(),
+            Err(Error::InvalidVariant(2))
+        );
+        let mcause = Mcause::from(7);
+        assert_eq!(
+            mcause.try_cause::<CustomInterrupt, CustomException>(),
+            Err(Error::InvalidVariant(7))
+        );
+        let mcause = Mcause::from(15);
+        assert_eq!(
+            mcause.cause::<CustomInterrupt, Exception>(),
+            Trap::Exception(Exception::StorePageFault)
+        );
+        let mcause = Mcause::from(interrupt_bit | 13);
+        assert_eq!(
+            mcause.cause::<Interrupt, Exception>(),
+            Trap::Interrupt(Interrupt::LocalCounterOverflow)
+        );
+        let mcause = Mcause::from(14);
+        assert_eq!(
+            mcause.try_cause::<CustomInterrupt, Exception>(),
+            Err(Error::InvalidVariant(14))
+        );
+        let trap = Trap::<Interrupt, Exception>::try_from(Mcause::from(interrupt_bit | 7));
+        assert_eq!(trap, Ok(Trap::Interrupt(Interrupt::MachineTimer)));
+        assert_eq!(trap.map(Trap::into_bits), Ok(interrupt_bit | 7));
+        let trap = Trap::<Interrupt, Exception>::try_from_bits(2).unwrap();
+        assert!(trap.is_exception());
+        assert_eq!(trap.into_bits(), 2);
+
+        let mcause = Mcause::from(usize::MAX >> 1);
+        assert_eq!(
+            mcause.try_cause::<CustomInterrupt, CustomException>(),
+            Err(Error::InvalidVariant(usize::MAX >> 1))
+        );
+    }
+}
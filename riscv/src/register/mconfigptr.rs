@@ -0,0 +1,37 @@
+//! mconfigptr register
+//!
+//! Machine configuration pointer register. It holds the physical address of a
+//! configuration data structure that describes the platform.
+
+use core::num::NonZeroUsize;
+
+/// mconfigptr register
+#[derive(Clone, Copy, Debug)]
+pub struct Mconfigptr {
+    bits: NonZeroUsize,
+}
+
+impl Mconfigptr {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits.get()
+    }
+
+    /// Returns the physical address of the configuration data structure
+    #[inline]
+    pub fn address(&self) -> usize {
+        self.bits()
+    }
+}
+
+read_csr!(0xF15);
+
+/// Reads the CSR
+#[inline]
+pub fn read() -> Option<Mconfigptr> {
+    let r = unsafe { _read() };
+    // When mconfigptr is zero it means that the configuration
+    // data structure does not exist.
+    NonZeroUsize::new(r).map(|bits| Mconfigptr { bits })
+}
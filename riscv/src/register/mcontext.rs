@@ -0,0 +1,100 @@
+//! mcontext register (Sdtrig)
+//!
+//! Machine context register. Triggers can be configured to only fire when the
+//! `HCONTEXT` field matches a given value (e.g., to tag virtual machines or harts).
+//!
+//! The width of the field depends on XLEN: 6 bits on RV32 and 13 bits on RV64.
+
+use crate::result::{Error, Result};
+
+/// Mask of the `HCONTEXT` field
+#[cfg(target_pointer_width = "32")]
+pub const HCONTEXT_MASK: usize = 0x3F;
+/// Mask of the `HCONTEXT` field
+#[cfg(not(target_pointer_width = "32"))]
+pub const HCONTEXT_MASK: usize = 0x1FFF;
+
+/// mcontext register
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Mcontext {
+    pub(super) bits: usize,
+}
+
+impl From<usize> for Mcontext {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mcontext {
+    /// Creates a new [`Mcontext`] value from a context value.
+    ///
+    /// Returns an error if `hcontext` does not fit in the field (see [`HCONTEXT_MASK`]).
+    #[inline]
+    pub fn new(hcontext: usize) -> Result<Self> {
+        if hcontext & !HCONTEXT_MASK != 0 {
+            Err(Error::InvalidFieldValue {
+                field: "hcontext",
+                value: hcontext,
+                bitmask: HCONTEXT_MASK,
+            })
+        } else {
+            Ok(Self { bits: hcontext })
+        }
+    }
+
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Context value
+    #[inline]
+    pub fn hcontext(&self) -> usize {
+        self.bits & HCONTEXT_MASK
+    }
+}
+
+read_csr_as!(Mcontext, 0x7A8);
+write_csr_as!(Mcontext, 0x7A8);
+
+/// Sets the context value
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `hcontext` does not fit in the field
+#[inline]
+pub unsafe fn set(hcontext: usize) {
+    try_set(hcontext).unwrap();
+}
+
+/// Attempts to set the context value
+///
+/// Returns an error if `hcontext` does not fit in the field (see [`HCONTEXT_MASK`]).
+#[inline]
+pub unsafe fn try_set(hcontext: usize) -> Result<()> {
+    try_write(Mcontext::new(hcontext)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mcontext() {
+        let mcontext = Mcontext::new(0x2A).unwrap();
+        assert_eq!(mcontext.hcontext(), 0x2A);
+        assert_eq!(Mcontext::from(!0).hcontext(), HCONTEXT_MASK);
+        assert_eq!(
+            Mcontext::new(HCONTEXT_MASK + 1),
+            Err(Error::InvalidFieldValue {
+                field: "hcontext",
+                value: HCONTEXT_MASK + 1,
+                bitmask: HCONTEXT_MASK,
+            })
+        );
+    }
+}
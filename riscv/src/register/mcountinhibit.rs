@@ -9,7 +9,23 @@ pub struct Mcountinhibit {
     bits: usize,
 }
 
+impl From<usize> for Mcountinhibit {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
 impl Mcountinhibit {
+    /// Mask of all the inhibit bits (CY, IR, and HPM3-HPM31)
+    const ALL: usize = 0xFFFF_FFFD;
+
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
     /// Machine "cycle\[h\]" Disable
     #[inline]
     pub fn cy(&self) -> bool {
@@ -141,6 +157,54 @@ pub unsafe fn try_clear_hpm(index: usize) -> Result<()> {
     }
 }
 
+/// Inhibits all the counters selected in `counters` with a single CSR access.
+///
+/// Counters not selected in `counters` are left untouched.
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub unsafe fn set_counters(counters: Mcountinhibit) {
+    try_set_counters(counters).unwrap();
+}
+
+/// Attempts to inhibit all the counters selected in `counters` with a single CSR access.
+///
+/// Counters not selected in `counters` are left untouched.
+#[inline]
+pub unsafe fn try_set_counters(counters: Mcountinhibit) -> Result<()> {
+    _try_set(counters.bits & Mcountinhibit::ALL)
+}
+
+/// Resumes all the counters selected in `counters` with a single CSR access.
+///
+/// Counters not selected in `counters` are left untouched.
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub unsafe fn clear_counters(counters: Mcountinhibit) {
+    try_clear_counters(counters).unwrap();
+}
+
+/// Attempts to resume all the counters selected in `counters` with a single CSR access.
+///
+/// Counters not selected in `counters` are left untouched.
+#[inline]
+pub unsafe fn try_clear_counters(counters: Mcountinhibit) -> Result<()> {
+    _try_clear(counters.bits & Mcountinhibit::ALL)
+}
+
+/// Inhibits all the counters (CY, IR, and HPM3-HPM31) with a single CSR access.
+#[inline]
+pub unsafe fn set_all() {
+    _set(Mcountinhibit::ALL);
+}
+
+/// Resumes all the counters (CY, IR, and HPM3-HPM31) with a single CSR access.
+#[inline]
+pub unsafe fn clear_all() {
+    _clear(Mcountinhibit::ALL);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
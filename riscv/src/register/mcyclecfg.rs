@@ -0,0 +1,51 @@
+//! mcyclecfg register (Smcntrpmf extension)
+//!
+//! Privilege-mode filtering of the `mcycle` counter.
+//! On RV32, the filtering bits live in the [`mcyclecfgh`](super::mcyclecfgh) register.
+//! Use [`read64`] and [`write64`] together with the typed [`Mcyclecfg`] value to access them.
+
+/// 64-bit mcyclecfg register
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Mcyclecfg {
+    bits: u64,
+}
+
+impl From<u64> for Mcyclecfg {
+    #[inline]
+    fn from(bits: u64) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mcyclecfg {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub const fn bits(&self) -> u64 {
+        self.bits
+    }
+}
+
+inhibit_bits!(Mcyclecfg, "mcyclecfg");
+
+read_csr_as_usize!(0x321);
+write_csr_as_usize!(0x321);
+read_composite_csr!(super::mcyclecfgh::read(), read());
+write_config_csr!(super::mcyclecfgh::write, write);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mcyclecfg() {
+        let mut cfg = Mcyclecfg::default();
+        cfg.set_minh(true);
+        cfg.set_vuinh(true);
+        assert_eq!(cfg.bits(), (1 << 62) | (1 << 58));
+        assert!(cfg.minh() && cfg.vuinh());
+        assert!(!cfg.sinh() && !cfg.uinh() && !cfg.vsinh());
+
+        cfg.set_minh(false);
+        assert_eq!(cfg.bits(), 1 << 58);
+    }
+}
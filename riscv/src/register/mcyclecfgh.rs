@@ -0,0 +1,4 @@
+//! mcyclecfgh register (Smcntrpmf extension, RV32 only)
+
+read_csr_as_usize_rv32!(0x721);
+write_csr_as_usize_rv32!(0x721);
@@ -1,3 +1,4 @@
 //! mcycleh register
 
 read_csr_as_usize_rv32!(0xB80);
+write_csr_as_usize_rv32!(0xB80);
@@ -1,11 +1,22 @@
 //! medeleg register
 
+use crate::bits::{bf_extract, bit_mask};
+use crate::result::Result;
+use crate::ExceptionNumber;
+
 /// medeleg register
 #[derive(Clone, Copy, Debug)]
 pub struct Medeleg {
     bits: usize,
 }
 
+impl From<usize> for Medeleg {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
 impl Medeleg {
     /// Returns the contents of the register as raw bits
     #[inline]
@@ -90,6 +101,13 @@ impl Medeleg {
     pub fn store_page_fault(&self) -> bool {
         self.bits & (1 << 15) != 0
     }
+
+    /// Returns `true` if the given exception is delegated to S-mode
+    #[inline]
+    pub fn is_delegated<E: ExceptionNumber>(&self, e: E) -> bool {
+        let index = e.number() as usize;
+        index < usize::BITS as usize && bf_extract(self.bits, index, 1) != 0
+    }
 }
 
 read_csr_as!(Medeleg, 0x302);
@@ -135,3 +153,73 @@ set_clear_csr!(
 set_clear_csr!(
     /// Store/AMO Page Fault Delegate
     , set_store_page_fault, clear_store_page_fault, 1 << 15);
+
+/// Delegates the given exception to S-mode
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - exception numbers that do not fit in the register
+#[inline]
+pub unsafe fn delegate<E: ExceptionNumber>(e: E) {
+    try_delegate(e).unwrap();
+}
+
+/// Attempts to delegate the given exception to S-mode
+///
+/// Returns an error if the exception number does not fit in the register.
+#[inline]
+pub unsafe fn try_delegate<E: ExceptionNumber>(e: E) -> Result<()> {
+    _try_set(bit_mask(e.number() as usize)?)
+}
+
+/// Stops delegating the given exception to S-mode
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - exception numbers that do not fit in the register
+#[inline]
+pub unsafe fn undelegate<E: ExceptionNumber>(e: E) {
+    try_undelegate(e).unwrap();
+}
+
+/// Attempts to stop delegating the given exception to S-mode
+///
+/// Returns an error if the exception number does not fit in the register.
+#[inline]
+pub unsafe fn try_undelegate<E: ExceptionNumber>(e: E) -> Result<()> {
+    _try_clear(bit_mask(e.number() as usize)?)
+}
+
+/// Returns `true` if the given exception is delegated to S-mode
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn is_delegated<E: ExceptionNumber>(e: E) -> bool {
+    read().is_delegated(e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interrupt::Exception;
+    use crate::result::Error;
+
+    #[test]
+    fn test_medeleg() {
+        let medeleg = Medeleg::from((1 << 8) | (1 << 15));
+        assert!(medeleg.is_delegated(Exception::UserEnvCall));
+        assert!(medeleg.is_delegated(Exception::StorePageFault));
+        assert!(!medeleg.is_delegated(Exception::SupervisorEnvCall));
+        assert_eq!(bit_mask(Exception::LoadPageFault as usize), Ok(1 << 13));
+        assert_eq!(
+            bit_mask(usize::BITS as usize),
+            Err(Error::IndexOutOfBounds {
+                index: usize::BITS as usize,
+                min: 0,
+                max: usize::BITS as usize - 1,
+            })
+        );
+    }
+}
@@ -0,0 +1,490 @@
+//! menvcfg register
+//!
+//! Machine environment configuration register.
+//! It controls the execution environment of the privilege modes less privileged than M-mode.
+//!
+//! The register is 64 bits wide on all targets. On RV32, the upper half lives in the
+//! [`menvcfgh`](super::menvcfgh) register, and [`read`] and [`write()`] access both halves.
+
+use crate::result::{Error, Result};
+
+/// Pointer masking mode (Smmpm, Smnpm, and Ssnpm extensions)
+///
+/// Pointer masking is only available on RV64.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Pmm {
+    /// Pointer masking is disabled
+    Disabled = 0,
+    /// Pointer masking is enabled with `PMLEN = 7` (57-bit effective addresses)
+    PmLen7 = 2,
+    /// Pointer masking is enabled with `PMLEN = 16` (48-bit effective addresses)
+    PmLen16 = 3,
+}
+
+impl Pmm {
+    /// Number of masked upper bits of the effective addresses (`PMLEN`)
+    #[inline]
+    pub const fn pmlen(self) -> u32 {
+        match self {
+            Self::Disabled => 0,
+            Self::PmLen7 => 7,
+            Self::PmLen16 => 16,
+        }
+    }
+}
+
+impl TryFrom<u8> for Pmm {
+    type Error = Error;
+
+    fn try_from(val: u8) -> Result<Self> {
+        match val {
+            0 => Ok(Self::Disabled),
+            2 => Ok(Self::PmLen7),
+            3 => Ok(Self::PmLen16),
+            _ => Err(Error::InvalidFieldVariant {
+                field: "pmm",
+                value: val as usize,
+            }),
+        }
+    }
+}
+
+/// Cache block invalidate instruction behavior (Zicbom)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Cbie {
+    /// `CBO.INVAL` raises an illegal-instruction exception
+    IllegalInstruction = 0,
+    /// `CBO.INVAL` performs a flush operation
+    Flush = 1,
+    /// `CBO.INVAL` performs an invalidate operation
+    Invalidate = 3,
+}
+
+impl TryFrom<u8> for Cbie {
+    type Error = Error;
+
+    fn try_from(val: u8) -> Result<Self> {
+        match val {
+            0 => Ok(Self::IllegalInstruction),
+            1 => Ok(Self::Flush),
+            3 => Ok(Self::Invalidate),
+            _ => Err(Error::InvalidFieldVariant {
+                field: "cbie",
+                value: val as usize,
+            }),
+        }
+    }
+}
+
+/// menvcfg register
+#[derive(Clone, Copy, Debug)]
+pub struct Menvcfg {
+    bits: u64,
+}
+
+impl From<u64> for Menvcfg {
+    #[inline]
+    fn from(bits: u64) -> Self {
+        Self { bits }
+    }
+}
+
+impl Menvcfg {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    #[inline]
+    fn bit(&self, index: usize) -> bool {
+        self.bits & (1 << index) != 0
+    }
+
+    #[inline]
+    fn set_bit(&mut self, index: usize, value: bool) {
+        self.bits = (self.bits & !(1 << index)) | ((value as u64) << index);
+    }
+
+    /// Fence of I/O implies Memory (`FIOM`)
+    ///
+    /// When set, `FENCE` instructions ordering I/O accesses of the less privileged modes also order memory accesses.
+    #[inline]
+    pub fn fiom(&self) -> bool {
+        self.bit(0)
+    }
+
+    /// Update Fence of I/O implies Memory (`FIOM`)
+    ///
+    /// Note this updates a previously read [`Menvcfg`] value, but does not
+    /// affect the menvcfg CSR itself. See [`set_fiom`]/[`clear_fiom`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_fiom(&mut self, fiom: bool) {
+        self.set_bit(0, fiom);
+    }
+
+    /// Landing Pad Enable (`LPE`, Zicfilp)
+    #[inline]
+    pub fn lpe(&self) -> bool {
+        self.bit(2)
+    }
+
+    /// Update Landing Pad Enable (`LPE`, Zicfilp)
+    ///
+    /// Note this updates a previously read [`Menvcfg`] value, but does not
+    /// affect the menvcfg CSR itself. See [`set_lpe`]/[`clear_lpe`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_lpe(&mut self, lpe: bool) {
+        self.set_bit(2, lpe);
+    }
+
+    /// Shadow Stack Enable (`SSE`, Zicfiss)
+    #[inline]
+    pub fn sse(&self) -> bool {
+        self.bit(3)
+    }
+
+    /// Update Shadow Stack Enable (`SSE`, Zicfiss)
+    ///
+    /// Note this updates a previously read [`Menvcfg`] value, but does not
+    /// affect the menvcfg CSR itself. See [`set_sse`]/[`clear_sse`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_sse(&mut self, sse: bool) {
+        self.set_bit(3, sse);
+    }
+
+    /// Cache Block Invalidate instruction Enable (`CBIE`, Zicbom)
+    ///
+    /// **WARNING**: panics if the field has an invalid variant.
+    #[inline]
+    pub fn cbie(&self) -> Cbie {
+        self.try_cbie().unwrap()
+    }
+
+    /// Attempts to get the cache block invalidate instruction enable (`CBIE`, Zicbom)
+    #[inline]
+    pub fn try_cbie(&self) -> Result<Cbie> {
+        (((self.bits >> 4) & 0b11) as u8).try_into()
+    }
+
+    /// Update Cache Block Invalidate instruction Enable (`CBIE`, Zicbom)
+    ///
+    /// Note this updates a previously read [`Menvcfg`] value, but does not
+    /// affect the menvcfg CSR itself. See [`set_cbie`] to directly update the CSR.
+    #[inline]
+    pub fn set_cbie(&mut self, cbie: Cbie) {
+        self.bits = (self.bits & !(0b11 << 4)) | ((cbie as u64) << 4);
+    }
+
+    /// Cache Block Clean and Flush Enable (`CBCFE`, Zicbom)
+    #[inline]
+    pub fn cbcfe(&self) -> bool {
+        self.bit(6)
+    }
+
+    /// Update Cache Block Clean and Flush Enable (`CBCFE`, Zicbom)
+    ///
+    /// Note this updates a previously read [`Menvcfg`] value, but does not
+    /// affect the menvcfg CSR itself. See [`set_cbcfe`]/[`clear_cbcfe`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_cbcfe(&mut self, cbcfe: bool) {
+        self.set_bit(6, cbcfe);
+    }
+
+    /// Cache Block Zero Enable (`CBZE`, Zicboz)
+    #[inline]
+    pub fn cbze(&self) -> bool {
+        self.bit(7)
+    }
+
+    /// Update Cache Block Zero Enable (`CBZE`, Zicboz)
+    ///
+    /// Note this updates a previously read [`Menvcfg`] value, but does not
+    /// affect the menvcfg CSR itself. See [`set_cbze`]/[`clear_cbze`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_cbze(&mut self, cbze: bool) {
+        self.set_bit(7, cbze);
+    }
+
+    /// Pointer masking mode of the next less privileged mode (Smnpm)
+    ///
+    /// It applies to S-mode (HS-mode with the hypervisor extension), or to U-mode if S-mode is not implemented.
+    ///
+    /// **WARNING**: panics if the field has an invalid variant.
+    #[inline]
+    pub fn pmm(&self) -> Pmm {
+        self.try_pmm().unwrap()
+    }
+
+    /// Attempts to get the pointer masking mode of the next less privileged mode (Smnpm)
+    #[inline]
+    pub fn try_pmm(&self) -> Result<Pmm> {
+        (((self.bits >> 32) & 0b11) as u8).try_into()
+    }
+
+    /// Updates the pointer masking mode of the next less privileged mode (Smnpm)
+    ///
+    /// Note this updates a previously read [`Menvcfg`] value, but does not
+    /// affect the menvcfg CSR itself.
+    #[cfg_attr(
+        target_pointer_width = "64",
+        doc = "See [`set_pmm`] to directly update the CSR."
+    )]
+    #[inline]
+    pub fn set_pmm(&mut self, pmm: Pmm) {
+        self.bits = (self.bits & !(0b11 << 32)) | ((pmm as u64) << 32);
+    }
+
+    /// Double Trap Enable (`DTE`, Ssdbltrp)
+    #[inline]
+    pub fn dte(&self) -> bool {
+        self.bit(59)
+    }
+
+    /// Update Double Trap Enable (`DTE`, Ssdbltrp)
+    ///
+    /// Note this updates a previously read [`Menvcfg`] value, but does not
+    /// affect the menvcfg CSR itself. See [`set_dte`]/[`clear_dte`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_dte(&mut self, dte: bool) {
+        self.set_bit(59, dte);
+    }
+
+    /// Counter Delegation Enable (`CDE`, Smcdeleg)
+    #[inline]
+    pub fn cde(&self) -> bool {
+        self.bit(60)
+    }
+
+    /// Update Counter Delegation Enable (`CDE`, Smcdeleg)
+    ///
+    /// Note this updates a previously read [`Menvcfg`] value, but does not
+    /// affect the menvcfg CSR itself. See [`set_cde`]/[`clear_cde`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_cde(&mut self, cde: bool) {
+        self.set_bit(60, cde);
+    }
+
+    /// Hardware A/D bit Update Enable (`ADUE`, Svadu)
+    ///
+    /// When set, the hardware updates the `A` and `D` bits of the page-table entries.
+    #[inline]
+    pub fn adue(&self) -> bool {
+        self.bit(61)
+    }
+
+    /// Update Hardware A/D bit Update Enable (`ADUE`, Svadu)
+    ///
+    /// Note this updates a previously read [`Menvcfg`] value, but does not
+    /// affect the menvcfg CSR itself. See [`set_adue`]/[`clear_adue`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_adue(&mut self, adue: bool) {
+        self.set_bit(61, adue);
+    }
+
+    /// Page-Based Memory Types Enable (`PBMTE`, Svpbmt)
+    #[inline]
+    pub fn pbmte(&self) -> bool {
+        self.bit(62)
+    }
+
+    /// Update Page-Based Memory Types Enable (`PBMTE`, Svpbmt)
+    ///
+    /// Note this updates a previously read [`Menvcfg`] value, but does not
+    /// affect the menvcfg CSR itself. See [`set_pbmte`]/[`clear_pbmte`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_pbmte(&mut self, pbmte: bool) {
+        self.set_bit(62, pbmte);
+    }
+
+    /// STimecmp Enable (`STCE`, Sstc)
+    ///
+    /// When set, the `stimecmp` CSR and the supervisor timer interrupt are available.
+    #[inline]
+    pub fn stce(&self) -> bool {
+        self.bit(63)
+    }
+
+    /// Update STimecmp Enable (`STCE`, Sstc)
+    ///
+    /// Note this updates a previously read [`Menvcfg`] value, but does not
+    /// affect the menvcfg CSR itself. See [`set_stce`]/[`clear_stce`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_stce(&mut self, stce: bool) {
+        self.set_bit(63, stce);
+    }
+}
+
+read_csr!(0x30A);
+write_csr!(0x30A);
+set!(0x30A);
+clear!(0x30A);
+
+/// Reads the CSR
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn read() -> Menvcfg {
+    try_read().unwrap()
+}
+
+/// Attempts to read the CSR
+///
+/// On RV32, the upper half is read from the `menvcfgh` CSR.
+#[inline]
+pub fn try_read() -> Result<Menvcfg> {
+    let lo = unsafe { _try_read()? } as u64;
+    match () {
+        #[cfg(target_pointer_width = "32")]
+        () => Ok(Menvcfg::from(
+            ((super::menvcfgh::try_read()? as u64) << 32) | lo,
+        )),
+        #[cfg(not(target_pointer_width = "32"))]
+        () => Ok(Menvcfg::from(lo)),
+    }
+}
+
+/// Writes the CSR
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub unsafe fn write(menvcfg: Menvcfg) {
+    try_write(menvcfg).unwrap();
+}
+
+/// Attempts to write the CSR
+///
+/// On RV32, the upper half is written to the `menvcfgh` CSR first.
+#[inline]
+pub unsafe fn try_write(menvcfg: Menvcfg) -> Result<()> {
+    #[cfg(target_pointer_width = "32")]
+    super::menvcfgh::try_write((menvcfg.bits >> 32) as usize)?;
+    _try_write(menvcfg.bits as usize)
+}
+
+set_clear_csr!(
+    /// Fence of I/O implies Memory (`FIOM`)
+    , set_fiom, clear_fiom, 1 << 0);
+set_clear_csr!(
+    /// Landing Pad Enable (`LPE`, Zicfilp)
+    , set_lpe, clear_lpe, 1 << 2);
+set_clear_csr!(
+    /// Shadow Stack Enable (`SSE`, Zicfiss)
+    , set_sse, clear_sse, 1 << 3);
+set_clear_csr!(
+    /// Cache Block Clean and Flush Enable (`CBCFE`, Zicbom)
+    , set_cbcfe, clear_cbcfe, 1 << 6);
+set_clear_csr!(
+    /// Cache Block Zero Enable (`CBZE`, Zicboz)
+    , set_cbze, clear_cbze, 1 << 7);
+set_clear_csr64!(
+    /// Double Trap Enable (`DTE`, Ssdbltrp)
+    , set_dte, clear_dte, menvcfgh, 59);
+set_clear_csr64!(
+    /// Counter Delegation Enable (`CDE`, Smcdeleg)
+    , set_cde, clear_cde, menvcfgh, 60);
+set_clear_csr64!(
+    /// Hardware A/D bit Update Enable (`ADUE`, Svadu)
+    , set_adue, clear_adue, menvcfgh, 61);
+set_clear_csr64!(
+    /// Page-Based Memory Types Enable (`PBMTE`, Svpbmt)
+    , set_pbmte, clear_pbmte, menvcfgh, 62);
+set_clear_csr64!(
+    /// STimecmp Enable (`STCE`, Sstc)
+    , set_stce, clear_stce, menvcfgh, 63);
+
+/// Sets the cache block invalidate instruction enable (`CBIE`, Zicbom)
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub unsafe fn set_cbie(cbie: Cbie) {
+    try_set_cbie(cbie).unwrap();
+}
+
+/// Attempts to set the cache block invalidate instruction enable (`CBIE`, Zicbom)
+#[inline]
+pub unsafe fn try_set_cbie(cbie: Cbie) -> Result<()> {
+    let mut menvcfg = try_read()?;
+    menvcfg.set_cbie(cbie);
+    try_write(menvcfg)
+}
+
+/// Sets the pointer masking mode of the next less privileged mode (Smnpm, RV64 only)
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[cfg(target_pointer_width = "64")]
+#[inline]
+pub unsafe fn set_pmm(pmm: Pmm) {
+    try_set_pmm(pmm).unwrap();
+}
+
+/// Attempts to set the pointer masking mode of the next less privileged mode (Smnpm, RV64 only)
+#[cfg(target_pointer_width = "64")]
+#[inline]
+pub unsafe fn try_set_pmm(pmm: Pmm) -> Result<()> {
+    let mut menvcfg = try_read()?;
+    menvcfg.set_pmm(pmm);
+    try_write(menvcfg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_menvcfg() {
+        let mut menvcfg = Menvcfg::from(0);
+        menvcfg.set_fiom(true);
+        menvcfg.set_cbze(true);
+        menvcfg.set_cbie(Cbie::Invalidate);
+        menvcfg.set_stce(true);
+        menvcfg.set_pbmte(true);
+        assert_eq!(menvcfg.bits(), (0b11 << 62) | 0b1011_0001);
+        assert!(menvcfg.fiom() && menvcfg.cbze() && menvcfg.stce() && menvcfg.pbmte());
+        assert!(!menvcfg.cbcfe() && !menvcfg.adue() && !menvcfg.dte());
+        assert_eq!(menvcfg.cbie(), Cbie::Invalidate);
+
+        menvcfg.set_stce(false);
+        menvcfg.set_cbie(Cbie::Flush);
+        assert_eq!(menvcfg.bits(), (1 << 62) | 0b1001_0001);
+        assert_eq!(
+            Menvcfg::from(0b10 << 4).try_cbie(),
+            Err(Error::InvalidFieldVariant {
+                field: "cbie",
+                value: 0b10,
+            })
+        );
+    }
+
+    #[test]
+    fn test_pmm() {
+        let mut menvcfg = Menvcfg::from(0);
+        assert_eq!(menvcfg.pmm(), Pmm::Disabled);
+
+        menvcfg.set_pmm(Pmm::PmLen16);
+        assert_eq!(menvcfg.bits(), 0b11 << 32);
+        assert_eq!(menvcfg.pmm().pmlen(), 16);
+
+        menvcfg.set_pmm(Pmm::PmLen7);
+        assert_eq!(menvcfg.pmm(), Pmm::PmLen7);
+        assert_eq!(menvcfg.pmm().pmlen(), 7);
+
+        assert_eq!(
+            Menvcfg::from(1 << 32).try_pmm(),
+            Err(Error::InvalidFieldVariant {
+                field: "pmm",
+                value: 1,
+            })
+        );
+    }
+}
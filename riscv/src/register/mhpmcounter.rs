@@ -0,0 +1,180 @@
+//! Indexed access to the `mhpmcounter3`-`mhpmcounter31` registers
+//!
+//! CSR instructions encode the CSR number as an immediate, so each index
+//! dispatches to the dedicated module (e.g., [`mhpmcounter3`](super::mhpmcounter3)).
+
+use crate::result::{Error, Result};
+
+/// Dispatches a function call to the `mhpmcounterX` module selected by `index`.
+macro_rules! dispatch {
+    ($index:expr, $f:ident($($arg:expr),*)) => {
+        match $index {
+            3 => super::mhpmcounter3::$f($($arg),*),
+            4 => super::mhpmcounter4::$f($($arg),*),
+            5 => super::mhpmcounter5::$f($($arg),*),
+            6 => super::mhpmcounter6::$f($($arg),*),
+            7 => super::mhpmcounter7::$f($($arg),*),
+            8 => super::mhpmcounter8::$f($($arg),*),
+            9 => super::mhpmcounter9::$f($($arg),*),
+            10 => super::mhpmcounter10::$f($($arg),*),
+            11 => super::mhpmcounter11::$f($($arg),*),
+            12 => super::mhpmcounter12::$f($($arg),*),
+            13 => super::mhpmcounter13::$f($($arg),*),
+            14 => super::mhpmcounter14::$f($($arg),*),
+            15 => super::mhpmcounter15::$f($($arg),*),
+            16 => super::mhpmcounter16::$f($($arg),*),
+            17 => super::mhpmcounter17::$f($($arg),*),
+            18 => super::mhpmcounter18::$f($($arg),*),
+            19 => super::mhpmcounter19::$f($($arg),*),
+            20 => super::mhpmcounter20::$f($($arg),*),
+            21 => super::mhpmcounter21::$f($($arg),*),
+            22 => super::mhpmcounter22::$f($($arg),*),
+            23 => super::mhpmcounter23::$f($($arg),*),
+            24 => super::mhpmcounter24::$f($($arg),*),
+            25 => super::mhpmcounter25::$f($($arg),*),
+            26 => super::mhpmcounter26::$f($($arg),*),
+            27 => super::mhpmcounter27::$f($($arg),*),
+            28 => super::mhpmcounter28::$f($($arg),*),
+            29 => super::mhpmcounter29::$f($($arg),*),
+            30 => super::mhpmcounter30::$f($($arg),*),
+            31 => super::mhpmcounter31::$f($($arg),*),
+            index => Err(out_of_bounds(index)),
+        }
+    };
+}
+
+/// Dispatches a function call to the `mhpmcounterXh` module selected by `index`.
+#[cfg(target_arch = "riscv32")]
+macro_rules! dispatch_h {
+    ($index:expr, $f:ident($($arg:expr),*)) => {
+        match $index {
+            3 => super::mhpmcounter3h::$f($($arg),*),
+            4 => super::mhpmcounter4h::$f($($arg),*),
+            5 => super::mhpmcounter5h::$f($($arg),*),
+            6 => super::mhpmcounter6h::$f($($arg),*),
+            7 => super::mhpmcounter7h::$f($($arg),*),
+            8 => super::mhpmcounter8h::$f($($arg),*),
+            9 => super::mhpmcounter9h::$f($($arg),*),
+            10 => super::mhpmcounter10h::$f($($arg),*),
+            11 => super::mhpmcounter11h::$f($($arg),*),
+            12 => super::mhpmcounter12h::$f($($arg),*),
+            13 => super::mhpmcounter13h::$f($($arg),*),
+            14 => super::mhpmcounter14h::$f($($arg),*),
+            15 => super::mhpmcounter15h::$f($($arg),*),
+            16 => super::mhpmcounter16h::$f($($arg),*),
+            17 => super::mhpmcounter17h::$f($($arg),*),
+            18 => super::mhpmcounter18h::$f($($arg),*),
+            19 => super::mhpmcounter19h::$f($($arg),*),
+            20 => super::mhpmcounter20h::$f($($arg),*),
+            21 => super::mhpmcounter21h::$f($($arg),*),
+            22 => super::mhpmcounter22h::$f($($arg),*),
+            23 => super::mhpmcounter23h::$f($($arg),*),
+            24 => super::mhpmcounter24h::$f($($arg),*),
+            25 => super::mhpmcounter25h::$f($($arg),*),
+            26 => super::mhpmcounter26h::$f($($arg),*),
+            27 => super::mhpmcounter27h::$f($($arg),*),
+            28 => super::mhpmcounter28h::$f($($arg),*),
+            29 => super::mhpmcounter29h::$f($($arg),*),
+            30 => super::mhpmcounter30h::$f($($arg),*),
+            31 => super::mhpmcounter31h::$f($($arg),*),
+            index => Err(out_of_bounds(index)),
+        }
+    };
+}
+
+#[inline]
+const fn out_of_bounds(index: usize) -> Error {
+    Error::IndexOutOfBounds {
+        index,
+        min: 3,
+        max: 31,
+    }
+}
+
+/// Reads the `mhpmcounter[index]` CSR.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` not in the range 3 to 31
+#[inline]
+pub fn read(index: usize) -> usize {
+    try_read(index).unwrap()
+}
+
+/// Attempts to read the `mhpmcounter[index]` CSR.
+///
+/// Returns an error if `index` is not in the range 3 to 31.
+#[inline]
+pub fn try_read(index: usize) -> Result<usize> {
+    dispatch!(index, try_read())
+}
+
+/// Writes the `mhpmcounter[index]` CSR.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` not in the range 3 to 31
+#[inline]
+pub fn write(index: usize, bits: usize) {
+    try_write(index, bits).unwrap()
+}
+
+/// Attempts to write the `mhpmcounter[index]` CSR.
+///
+/// Returns an error if `index` is not in the range 3 to 31.
+#[inline]
+pub fn try_write(index: usize, bits: usize) -> Result<()> {
+    dispatch!(index, try_write(bits))
+}
+
+/// Reads the `mhpmcounter[index]` CSR as a 64-bit value.
+///
+/// On RV32, the high half is re-read to detect an overflow of the low half between reads.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` not in the range 3 to 31
+#[inline]
+pub fn read64(index: usize) -> u64 {
+    try_read64(index).unwrap()
+}
+
+/// Attempts to read the `mhpmcounter[index]` CSR as a 64-bit value.
+///
+/// Returns an error if `index` is not in the range 3 to 31.
+#[inline]
+pub fn try_read64(index: usize) -> Result<u64> {
+    match () {
+        #[cfg(target_arch = "riscv32")]
+        () => loop {
+            let hi = dispatch_h!(index, try_read())?;
+            let lo = try_read(index)?;
+            if hi == dispatch_h!(index, try_read())? {
+                return Ok(((hi as u64) << 32) | lo as u64);
+            }
+        },
+
+        #[cfg(not(target_arch = "riscv32"))]
+        () => try_read(index).map(|lo| lo as u64),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mhpmcounter() {
+        for index in [0, 1, 2, 32] {
+            assert_eq!(try_read(index), Err(out_of_bounds(index)));
+            assert_eq!(try_write(index, 0), Err(out_of_bounds(index)));
+            assert_eq!(try_read64(index), Err(out_of_bounds(index)));
+        }
+        for index in 3..32 {
+            assert_eq!(try_read(index), Err(Error::Unimplemented));
+        }
+    }
+}
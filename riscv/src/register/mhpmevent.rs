@@ -0,0 +1,288 @@
+//! Indexed access to the `mhpmevent3`-`mhpmevent31` registers
+//!
+//! CSR instructions encode the CSR number as an immediate, so each index
+//! dispatches to the dedicated module (e.g., [`mhpmevent3`](super::mhpmevent3)).
+//!
+//! With the Sscofpmf extension, the upper bits of the event selectors hold counter overflow
+//! and mode-filtering control bits. On RV32, these bits live in the `mhpmeventXh` registers.
+//! Use [`read64`] and [`write64`] together with the typed [`Mhpmevent`] value to access them.
+
+use crate::result::{Error, Result};
+
+/// Dispatches a function call to the `mhpmeventX` module selected by `index`.
+macro_rules! dispatch {
+    ($index:expr, $f:ident($($arg:expr),*)) => {
+        match $index {
+            3 => super::mhpmevent3::$f($($arg),*),
+            4 => super::mhpmevent4::$f($($arg),*),
+            5 => super::mhpmevent5::$f($($arg),*),
+            6 => super::mhpmevent6::$f($($arg),*),
+            7 => super::mhpmevent7::$f($($arg),*),
+            8 => super::mhpmevent8::$f($($arg),*),
+            9 => super::mhpmevent9::$f($($arg),*),
+            10 => super::mhpmevent10::$f($($arg),*),
+            11 => super::mhpmevent11::$f($($arg),*),
+            12 => super::mhpmevent12::$f($($arg),*),
+            13 => super::mhpmevent13::$f($($arg),*),
+            14 => super::mhpmevent14::$f($($arg),*),
+            15 => super::mhpmevent15::$f($($arg),*),
+            16 => super::mhpmevent16::$f($($arg),*),
+            17 => super::mhpmevent17::$f($($arg),*),
+            18 => super::mhpmevent18::$f($($arg),*),
+            19 => super::mhpmevent19::$f($($arg),*),
+            20 => super::mhpmevent20::$f($($arg),*),
+            21 => super::mhpmevent21::$f($($arg),*),
+            22 => super::mhpmevent22::$f($($arg),*),
+            23 => super::mhpmevent23::$f($($arg),*),
+            24 => super::mhpmevent24::$f($($arg),*),
+            25 => super::mhpmevent25::$f($($arg),*),
+            26 => super::mhpmevent26::$f($($arg),*),
+            27 => super::mhpmevent27::$f($($arg),*),
+            28 => super::mhpmevent28::$f($($arg),*),
+            29 => super::mhpmevent29::$f($($arg),*),
+            30 => super::mhpmevent30::$f($($arg),*),
+            31 => super::mhpmevent31::$f($($arg),*),
+            index => Err(out_of_bounds(index)),
+        }
+    };
+}
+
+/// Dispatches a function call to the `mhpmeventXh` module selected by `index`.
+#[cfg(target_arch = "riscv32")]
+macro_rules! dispatch_h {
+    ($index:expr, $f:ident($($arg:expr),*)) => {
+        match $index {
+            3 => super::mhpmevent3h::$f($($arg),*),
+            4 => super::mhpmevent4h::$f($($arg),*),
+            5 => super::mhpmevent5h::$f($($arg),*),
+            6 => super::mhpmevent6h::$f($($arg),*),
+            7 => super::mhpmevent7h::$f($($arg),*),
+            8 => super::mhpmevent8h::$f($($arg),*),
+            9 => super::mhpmevent9h::$f($($arg),*),
+            10 => super::mhpmevent10h::$f($($arg),*),
+            11 => super::mhpmevent11h::$f($($arg),*),
+            12 => super::mhpmevent12h::$f($($arg),*),
+            13 => super::mhpmevent13h::$f($($arg),*),
+            14 => super::mhpmevent14h::$f($($arg),*),
+            15 => super::mhpmevent15h::$f($($arg),*),
+            16 => super::mhpmevent16h::$f($($arg),*),
+            17 => super::mhpmevent17h::$f($($arg),*),
+            18 => super::mhpmevent18h::$f($($arg),*),
+            19 => super::mhpmevent19h::$f($($arg),*),
+            20 => super::mhpmevent20h::$f($($arg),*),
+            21 => super::mhpmevent21h::$f($($arg),*),
+            22 => super::mhpmevent22h::$f($($arg),*),
+            23 => super::mhpmevent23h::$f($($arg),*),
+            24 => super::mhpmevent24h::$f($($arg),*),
+            25 => super::mhpmevent25h::$f($($arg),*),
+            26 => super::mhpmevent26h::$f($($arg),*),
+            27 => super::mhpmevent27h::$f($($arg),*),
+            28 => super::mhpmevent28h::$f($($arg),*),
+            29 => super::mhpmevent29h::$f($($arg),*),
+            30 => super::mhpmevent30h::$f($($arg),*),
+            31 => super::mhpmevent31h::$f($($arg),*),
+            index => Err(out_of_bounds(index)),
+        }
+    };
+}
+
+#[inline]
+const fn out_of_bounds(index: usize) -> Error {
+    Error::IndexOutOfBounds {
+        index,
+        min: 3,
+        max: 31,
+    }
+}
+
+/// 64-bit machine performance-monitoring event selector
+///
+/// Bits 58-63 hold the Sscofpmf control bits, and the remaining bits select the event.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Mhpmevent {
+    bits: u64,
+}
+
+impl From<u64> for Mhpmevent {
+    #[inline]
+    fn from(bits: u64) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mhpmevent {
+    /// Mask of the event selector field
+    const EVENT_MASK: u64 = (1 << 58) - 1;
+
+    /// Creates a new event selector for the given event, with all control bits cleared
+    #[inline]
+    pub const fn new(event: u64) -> Self {
+        Self {
+            bits: event & Self::EVENT_MASK,
+        }
+    }
+
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub const fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    /// Returns the event selector field
+    #[inline]
+    pub const fn event(&self) -> u64 {
+        self.bits & Self::EVENT_MASK
+    }
+
+    /// Updates the event selector field
+    ///
+    /// Note this updates a previously read [`Mhpmevent`] value, but does not
+    /// affect the mhpmevent CSR itself. See [`write64`] to directly update the CSR.
+    #[inline]
+    pub fn set_event(&mut self, event: u64) {
+        self.bits = (self.bits & !Self::EVENT_MASK) | (event & Self::EVENT_MASK);
+    }
+
+    /// Overflow status and interrupt disable (`OF`)
+    #[inline]
+    pub const fn of(&self) -> bool {
+        self.bit(63)
+    }
+
+    /// Updates overflow status and interrupt disable (`OF`)
+    ///
+    /// Note this updates a previously read [`Mhpmevent`] value, but does not
+    /// affect the mhpmevent CSR itself. See [`write64`] to directly update the CSR.
+    #[inline]
+    pub fn set_of(&mut self, of: bool) {
+        self.set_bit(63, of);
+    }
+}
+
+inhibit_bits!(Mhpmevent, "mhpmevent");
+
+/// Reads the `mhpmevent[index]` CSR.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` not in the range 3 to 31
+#[inline]
+pub fn read(index: usize) -> usize {
+    try_read(index).unwrap()
+}
+
+/// Attempts to read the `mhpmevent[index]` CSR.
+///
+/// Returns an error if `index` is not in the range 3 to 31.
+#[inline]
+pub fn try_read(index: usize) -> Result<usize> {
+    dispatch!(index, try_read())
+}
+
+/// Writes the `mhpmevent[index]` CSR.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` not in the range 3 to 31
+#[inline]
+pub fn write(index: usize, bits: usize) {
+    try_write(index, bits).unwrap()
+}
+
+/// Attempts to write the `mhpmevent[index]` CSR.
+///
+/// Returns an error if `index` is not in the range 3 to 31.
+#[inline]
+pub fn try_write(index: usize, bits: usize) -> Result<()> {
+    dispatch!(index, try_write(bits))
+}
+
+/// Reads the `mhpmevent[index]` CSR as a 64-bit value.
+///
+/// On RV32, the upper 32 bits are read from `mhpmevent[index]h` (Sscofpmf extension).
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` not in the range 3 to 31
+#[inline]
+pub fn read64(index: usize) -> u64 {
+    try_read64(index).unwrap()
+}
+
+/// Attempts to read the `mhpmevent[index]` CSR as a 64-bit value.
+///
+/// Returns an error if `index` is not in the range 3 to 31.
+#[inline]
+pub fn try_read64(index: usize) -> Result<u64> {
+    match () {
+        #[cfg(target_arch = "riscv32")]
+        () => {
+            let hi = dispatch_h!(index, try_read())?;
+            let lo = try_read(index)?;
+            Ok(((hi as u64) << 32) | lo as u64)
+        }
+
+        #[cfg(not(target_arch = "riscv32"))]
+        () => try_read(index).map(|lo| lo as u64),
+    }
+}
+
+/// Writes the `mhpmevent[index]` CSR as a 64-bit value.
+///
+/// On RV32, the upper 32 bits are written to `mhpmevent[index]h` (Sscofpmf extension).
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` not in the range 3 to 31
+#[inline]
+pub fn write64(index: usize, bits: u64) {
+    try_write64(index, bits).unwrap()
+}
+
+/// Attempts to write the `mhpmevent[index]` CSR as a 64-bit value.
+///
+/// Returns an error if `index` is not in the range 3 to 31.
+#[inline]
+pub fn try_write64(index: usize, bits: u64) -> Result<()> {
+    match () {
+        #[cfg(target_arch = "riscv32")]
+        () => {
+            dispatch_h!(index, try_write((bits >> 32) as usize))?;
+            try_write(index, bits as usize)
+        }
+
+        #[cfg(not(target_arch = "riscv32"))]
+        () => try_write(index, bits as usize),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mhpmevent() {
+        let mut event = Mhpmevent::new(0x42);
+        assert_eq!(event.event(), 0x42);
+        assert!(!event.of());
+
+        event.set_of(true);
+        event.set_minh(true);
+        assert_eq!(event.bits(), (1 << 63) | (1 << 62) | 0x42);
+
+        event.set_event(u64::MAX);
+        assert_eq!(event.event(), (1 << 58) - 1);
+        assert!(event.of() && event.minh());
+        assert!(!event.sinh() && !event.uinh() && !event.vsinh() && !event.vuinh());
+
+        event.set_minh(false);
+        assert!(!event.minh());
+
+        assert_eq!(try_write64(32, 0), Err(out_of_bounds(32)));
+        assert_eq!(try_read(2), Err(out_of_bounds(2)));
+    }
+}
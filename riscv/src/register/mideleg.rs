@@ -1,11 +1,22 @@
 //! mideleg register
 
+use crate::bits::{bf_extract, bit_mask, custom_bit_mask};
+use crate::result::Result;
+use crate::CoreInterruptNumber;
+
 /// mideleg register
 #[derive(Clone, Copy, Debug)]
 pub struct Mideleg {
     bits: usize,
 }
 
+impl From<usize> for Mideleg {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
 impl Mideleg {
     /// Returns the contents of the register as raw bits
     #[inline]
@@ -30,6 +41,29 @@ impl Mideleg {
     pub fn sext(&self) -> bool {
         self.bits & (1 << 9) != 0
     }
+
+    /// Returns `true` if the given interrupt is delegated to S-mode
+    #[inline]
+    pub fn is_delegated<I: CoreInterruptNumber>(&self, i: I) -> bool {
+        let index = i.number() as usize;
+        index < usize::BITS as usize && bf_extract(self.bits, index, 1) != 0
+    }
+
+    /// Delegation bit of platform-custom interrupt `index` (bits 16 to XLEN-1)
+    ///
+    /// **WARNING**: panics if `index` is not in the platform-custom range.
+    #[inline]
+    pub fn custom(&self, index: usize) -> bool {
+        self.try_custom(index).unwrap()
+    }
+
+    /// Delegation bit of platform-custom interrupt `index` (bits 16 to XLEN-1)
+    ///
+    /// Returns an error if `index` is not in the platform-custom range.
+    #[inline]
+    pub fn try_custom(&self, index: usize) -> Result<bool> {
+        Ok(self.bits & custom_bit_mask(index)? != 0)
+    }
 }
 
 read_csr_as!(Mideleg, 0x303);
@@ -45,3 +79,87 @@ set_clear_csr!(
 set_clear_csr!(
     /// Supervisor External Interrupt Delegate
     , set_sext, clear_sext, 1 << 9);
+
+/// Delegates the given interrupt to S-mode
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - interrupt numbers that do not fit in the register
+#[inline]
+pub unsafe fn delegate<I: CoreInterruptNumber>(i: I) {
+    try_delegate(i).unwrap();
+}
+
+/// Attempts to delegate the given interrupt to S-mode
+///
+/// Returns an error if the interrupt number does not fit in the register.
+#[inline]
+pub unsafe fn try_delegate<I: CoreInterruptNumber>(i: I) -> Result<()> {
+    _try_set(bit_mask(i.number() as usize)?)
+}
+
+/// Stops delegating the given interrupt to S-mode
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - interrupt numbers that do not fit in the register
+#[inline]
+pub unsafe fn undelegate<I: CoreInterruptNumber>(i: I) {
+    try_undelegate(i).unwrap();
+}
+
+/// Attempts to stop delegating the given interrupt to S-mode
+///
+/// Returns an error if the interrupt number does not fit in the register.
+#[inline]
+pub unsafe fn try_undelegate<I: CoreInterruptNumber>(i: I) -> Result<()> {
+    _try_clear(bit_mask(i.number() as usize)?)
+}
+
+/// Returns `true` if the given interrupt is delegated to S-mode
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn is_delegated<I: CoreInterruptNumber>(i: I) -> bool {
+    read().is_delegated(i)
+}
+
+/// Delegate platform-custom interrupt `index` (bits 16 to XLEN-1)
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` not in the platform-custom range
+#[inline]
+pub unsafe fn set_custom(index: usize) {
+    try_set_custom(index).unwrap();
+}
+
+/// Attempts to delegate platform-custom interrupt `index` (bits 16 to XLEN-1)
+///
+/// Returns an error if `index` is not in the platform-custom range.
+#[inline]
+pub unsafe fn try_set_custom(index: usize) -> Result<()> {
+    _try_set(custom_bit_mask(index)?)
+}
+
+/// Stop delegating platform-custom interrupt `index` (bits 16 to XLEN-1)
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` not in the platform-custom range
+#[inline]
+pub unsafe fn clear_custom(index: usize) {
+    try_clear_custom(index).unwrap();
+}
+
+/// Attempts to stop delegating platform-custom interrupt `index` (bits 16 to XLEN-1)
+///
+/// Returns an error if `index` is not in the platform-custom range.
+#[inline]
+pub unsafe fn try_clear_custom(index: usize) -> Result<()> {
+    _try_clear(custom_bit_mask(index)?)
+}
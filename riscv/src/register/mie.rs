@@ -1,11 +1,22 @@
 //! mie register
 
+use crate::bits::{bf_extract, bit_mask, custom_bit_mask};
+use crate::result::Result;
+use crate::CoreInterruptNumber;
+
 /// mie register
 #[derive(Clone, Copy, Debug)]
 pub struct Mie {
     bits: usize,
 }
 
+impl From<usize> for Mie {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
 impl Mie {
     /// Returns the contents of the register as raw bits
     #[inline]
@@ -48,6 +59,35 @@ impl Mie {
     pub fn mext(&self) -> bool {
         self.bits & (1 << 11) != 0
     }
+
+    /// Local Counter Overflow Interrupt Enable (Sscofpmf extension)
+    #[inline]
+    pub fn lcofi(&self) -> bool {
+        self.bits & (1 << 13) != 0
+    }
+
+    /// Returns `true` if the given interrupt source is enabled
+    #[inline]
+    pub fn is_enabled<I: CoreInterruptNumber>(&self, interrupt: I) -> bool {
+        let index = interrupt.number() as usize;
+        index < usize::BITS as usize && bf_extract(self.bits, index, 1) != 0
+    }
+
+    /// Enable bit of platform-custom interrupt `index` (bits 16 to XLEN-1)
+    ///
+    /// **WARNING**: panics if `index` is not in the platform-custom range.
+    #[inline]
+    pub fn custom(&self, index: usize) -> bool {
+        self.try_custom(index).unwrap()
+    }
+
+    /// Enable bit of platform-custom interrupt `index` (bits 16 to XLEN-1)
+    ///
+    /// Returns an error if `index` is not in the platform-custom range.
+    #[inline]
+    pub fn try_custom(&self, index: usize) -> Result<bool> {
+        Ok(self.bits & custom_bit_mask(index)? != 0)
+    }
 }
 
 read_csr_as!(Mie, 0x304);
@@ -72,3 +112,118 @@ set_clear_csr!(
 set_clear_csr!(
     /// Machine External Interrupt Enable
     , set_mext, clear_mext, 1 << 11);
+set_clear_csr!(
+    /// Local Counter Overflow Interrupt Enable (Sscofpmf extension)
+    , set_lcofi, clear_lcofi, 1 << 13);
+
+/// Enables the given interrupt source
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - interrupt numbers that do not fit in the register
+#[inline]
+pub unsafe fn enable<I: CoreInterruptNumber>(interrupt: I) {
+    try_enable(interrupt).unwrap();
+}
+
+/// Attempts to enable the given interrupt source
+///
+/// Returns an error if the interrupt number does not fit in the register.
+#[inline]
+pub unsafe fn try_enable<I: CoreInterruptNumber>(interrupt: I) -> Result<()> {
+    _try_set(bit_mask(interrupt.number() as usize)?)
+}
+
+/// Disables the given interrupt source
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - interrupt numbers that do not fit in the register
+#[inline]
+pub unsafe fn disable<I: CoreInterruptNumber>(interrupt: I) {
+    try_disable(interrupt).unwrap();
+}
+
+/// Attempts to disable the given interrupt source
+///
+/// Returns an error if the interrupt number does not fit in the register.
+#[inline]
+pub unsafe fn try_disable<I: CoreInterruptNumber>(interrupt: I) -> Result<()> {
+    _try_clear(bit_mask(interrupt.number() as usize)?)
+}
+
+/// Returns `true` if the given interrupt source is enabled
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn is_enabled<I: CoreInterruptNumber>(interrupt: I) -> bool {
+    read().is_enabled(interrupt)
+}
+
+/// Enable platform-custom interrupt `index` (bits 16 to XLEN-1)
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` not in the platform-custom range
+#[inline]
+pub unsafe fn set_custom(index: usize) {
+    try_set_custom(index).unwrap();
+}
+
+/// Attempts to enable platform-custom interrupt `index` (bits 16 to XLEN-1)
+///
+/// Returns an error if `index` is not in the platform-custom range.
+#[inline]
+pub unsafe fn try_set_custom(index: usize) -> Result<()> {
+    _try_set(custom_bit_mask(index)?)
+}
+
+/// Disable platform-custom interrupt `index` (bits 16 to XLEN-1)
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` not in the platform-custom range
+#[inline]
+pub unsafe fn clear_custom(index: usize) {
+    try_clear_custom(index).unwrap();
+}
+
+/// Attempts to disable platform-custom interrupt `index` (bits 16 to XLEN-1)
+///
+/// Returns an error if `index` is not in the platform-custom range.
+#[inline]
+pub unsafe fn try_clear_custom(index: usize) -> Result<()> {
+    _try_clear(custom_bit_mask(index)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interrupt::Interrupt;
+    use crate::result::Error;
+
+    #[test]
+    fn test_mie() {
+        let mie = Mie::from((1 << 7) | (1 << 13));
+        assert!(mie.is_enabled(Interrupt::MachineTimer));
+        assert!(mie.is_enabled(Interrupt::LocalCounterOverflow));
+        assert!(!mie.is_enabled(Interrupt::MachineExternal));
+
+        let mie = Mie::from(1 << 16);
+        assert!(mie.custom(16));
+        assert_eq!(mie.try_custom(17), Ok(false));
+        assert_eq!(
+            mie.try_custom(15),
+            Err(Error::IndexOutOfBounds {
+                index: 15,
+                min: 16,
+                max: usize::BITS as usize - 1,
+            })
+        );
+        assert!(mie.try_custom(usize::BITS as usize).is_err());
+    }
+}
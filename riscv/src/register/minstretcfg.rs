@@ -0,0 +1,33 @@
+//! minstretcfg register (Smcntrpmf extension)
+//!
+//! Privilege-mode filtering of the `minstret` counter.
+//! On RV32, the filtering bits live in the [`minstretcfgh`](super::minstretcfgh) register.
+//! Use [`read64`] and [`write64`] together with the typed [`Minstretcfg`] value to access them.
+
+/// 64-bit minstretcfg register
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Minstretcfg {
+    bits: u64,
+}
+
+impl From<u64> for Minstretcfg {
+    #[inline]
+    fn from(bits: u64) -> Self {
+        Self { bits }
+    }
+}
+
+impl Minstretcfg {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub const fn bits(&self) -> u64 {
+        self.bits
+    }
+}
+
+inhibit_bits!(Minstretcfg, "minstretcfg");
+
+read_csr_as_usize!(0x322);
+write_csr_as_usize!(0x322);
+read_composite_csr!(super::minstretcfgh::read(), read());
+write_config_csr!(super::minstretcfgh::write, write);
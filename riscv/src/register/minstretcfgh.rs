@@ -0,0 +1,4 @@
+//! minstretcfgh register (Smcntrpmf extension, RV32 only)
+
+read_csr_as_usize_rv32!(0x722);
+write_csr_as_usize_rv32!(0x722);
@@ -0,0 +1,58 @@
+//! mintstatus register (CLIC)
+//!
+//! Machine interrupt status register. It holds the interrupt level of the interrupt
+//! being serviced in each privilege mode.
+
+/// mintstatus register
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Mintstatus {
+    bits: usize,
+}
+
+impl From<usize> for Mintstatus {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mintstatus {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Interrupt level of the M-mode interrupt being serviced
+    #[inline]
+    pub fn mil(&self) -> u8 {
+        (self.bits >> 24) as u8
+    }
+
+    /// Interrupt level of the S-mode interrupt being serviced
+    #[inline]
+    pub fn sil(&self) -> u8 {
+        (self.bits >> 8) as u8
+    }
+
+    /// Interrupt level of the U-mode interrupt being serviced
+    #[inline]
+    pub fn uil(&self) -> u8 {
+        self.bits as u8
+    }
+}
+
+read_csr_as!(Mintstatus, 0xFB1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mintstatus() {
+        let mintstatus = Mintstatus::from(0xC000_8040);
+        assert_eq!(mintstatus.mil(), 0xC0);
+        assert_eq!(mintstatus.sil(), 0x80);
+        assert_eq!(mintstatus.uil(), 0x40);
+    }
+}
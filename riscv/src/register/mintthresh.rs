@@ -0,0 +1,91 @@
+//! mintthresh register (CLIC)
+//!
+//! Machine interrupt-level threshold register. Only M-mode interrupts with a level
+//! greater than the threshold (and the current interrupt level) are taken.
+
+/// mintthresh register
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Mintthresh {
+    bits: usize,
+}
+
+impl From<usize> for Mintthresh {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mintthresh {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Interrupt-level threshold
+    #[inline]
+    pub fn th(&self) -> u8 {
+        self.bits as u8
+    }
+
+    /// Sets the interrupt-level threshold
+    ///
+    /// Note this updates a previously read [`Mintthresh`] value, but does not affect the
+    /// mintthresh CSR itself. See [`set_th`] to directly update the CSR.
+    #[inline]
+    pub fn set_th(&mut self, th: u8) {
+        self.bits = (self.bits & !0xFF) | th as usize;
+    }
+}
+
+read_csr_as!(Mintthresh, 0x347);
+write_csr!(0x347);
+
+/// Writes the CSR.
+///
+/// **WARNING**: panics on non-`riscv` targets.
+///
+/// # Safety
+///
+/// Lowering the threshold can break threshold-based critical sections.
+#[inline]
+pub unsafe fn write(value: Mintthresh) {
+    _write(value.bits)
+}
+
+/// Attempts to write the CSR.
+///
+/// # Safety
+///
+/// See [`write()`].
+#[inline]
+pub unsafe fn try_write(value: Mintthresh) -> crate::result::Result<()> {
+    _try_write(value.bits)
+}
+
+/// Sets the interrupt-level threshold.
+///
+/// **WARNING**: panics on non-`riscv` targets.
+///
+/// # Safety
+///
+/// See [`write()`].
+#[inline]
+pub unsafe fn set_th(th: u8) {
+    _write(th as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mintthresh() {
+        let mut mintthresh = Mintthresh::from(0);
+        assert_eq!(mintthresh.th(), 0);
+        mintthresh.set_th(0xBF);
+        assert_eq!(mintthresh.th(), 0xBF);
+        assert_eq!(mintthresh.bits(), 0xBF);
+    }
+}
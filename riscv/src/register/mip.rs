@@ -1,11 +1,22 @@
 //! mip register
 
+use crate::bits::{bf_extract, bit_mask, custom_bit_mask};
+use crate::result::Result;
+use crate::CoreInterruptNumber;
+
 /// mip register
 #[derive(Clone, Copy, Debug)]
 pub struct Mip {
     bits: usize,
 }
 
+impl From<usize> for Mip {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
 impl Mip {
     /// Returns the contents of the register as raw bits
     #[inline]
@@ -48,6 +59,35 @@ impl Mip {
     pub fn mext(&self) -> bool {
         self.bits & (1 << 11) != 0
     }
+
+    /// Local Counter Overflow Interrupt Pending (Sscofpmf extension)
+    #[inline]
+    pub fn lcofi(&self) -> bool {
+        self.bits & (1 << 13) != 0
+    }
+
+    /// Returns `true` if the given interrupt source is pending
+    #[inline]
+    pub fn is_pending<I: CoreInterruptNumber>(&self, interrupt: I) -> bool {
+        let index = interrupt.number() as usize;
+        index < usize::BITS as usize && bf_extract(self.bits, index, 1) != 0
+    }
+
+    /// Pending bit of platform-custom interrupt `index` (bits 16 to XLEN-1)
+    ///
+    /// **WARNING**: panics if `index` is not in the platform-custom range.
+    #[inline]
+    pub fn is_custom_pending(&self, index: usize) -> bool {
+        self.try_is_custom_pending(index).unwrap()
+    }
+
+    /// Pending bit of platform-custom interrupt `index` (bits 16 to XLEN-1)
+    ///
+    /// Returns an error if `index` is not in the platform-custom range.
+    #[inline]
+    pub fn try_is_custom_pending(&self, index: usize) -> Result<bool> {
+        Ok(self.bits & custom_bit_mask(index)? != 0)
+    }
 }
 
 read_csr_as!(Mip, 0x344);
@@ -63,3 +103,87 @@ set_clear_csr!(
 set_clear_csr!(
     /// Supervisor External Interrupt Pending
     , set_sext, clear_sext, 1 << 9);
+set_clear_csr!(
+    /// Local Counter Overflow Interrupt Pending (Sscofpmf extension)
+    , set_lcofi, clear_lcofi, 1 << 13);
+
+/// Returns `true` if the given interrupt source is pending
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn is_pending<I: CoreInterruptNumber>(interrupt: I) -> bool {
+    read().is_pending(interrupt)
+}
+
+/// Clears the pending bit of the given interrupt source
+///
+/// Only writable pending bits (e.g., `SSIP`, `STIP`, `SEIP`, or `LCOFIP`) can be cleared.
+/// Read-only pending bits (e.g., `MSIP`, `MTIP`, or `MEIP`) must be cleared at the interrupt source,
+/// and writes to them are silently ignored.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - interrupt numbers that do not fit in the register
+#[inline]
+pub unsafe fn clear<I: CoreInterruptNumber>(interrupt: I) {
+    try_clear(interrupt).unwrap();
+}
+
+/// Attempts to clear the pending bit of the given interrupt source
+///
+/// Returns an error if the interrupt number does not fit in the register.
+/// See [`clear()`] for details on which pending bits are writable.
+#[inline]
+pub unsafe fn try_clear<I: CoreInterruptNumber>(interrupt: I) -> Result<()> {
+    _try_clear(bit_mask(interrupt.number() as usize)?)
+}
+
+/// Set the pending bit of platform-custom interrupt `index` (bits 16 to XLEN-1)
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` not in the platform-custom range
+#[inline]
+pub unsafe fn set_custom(index: usize) {
+    try_set_custom(index).unwrap();
+}
+
+/// Attempts to set the pending bit of platform-custom interrupt `index` (bits 16 to XLEN-1)
+///
+/// Returns an error if `index` is not in the platform-custom range.
+#[inline]
+pub unsafe fn try_set_custom(index: usize) -> Result<()> {
+    _try_set(custom_bit_mask(index)?)
+}
+
+/// Clear the pending bit of platform-custom interrupt `index` (bits 16 to XLEN-1)
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` not in the platform-custom range
+#[inline]
+pub unsafe fn clear_custom(index: usize) {
+    try_clear_custom(index).unwrap();
+}
+
+/// Attempts to clear the pending bit of platform-custom interrupt `index` (bits 16 to XLEN-1)
+///
+/// Returns an error if `index` is not in the platform-custom range.
+#[inline]
+pub unsafe fn try_clear_custom(index: usize) -> Result<()> {
+    _try_clear(custom_bit_mask(index)?)
+}
+
+/// Returns `true` if platform-custom interrupt `index` (bits 16 to XLEN-1) is pending
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `index` not in the platform-custom range
+#[inline]
+pub fn is_custom_pending(index: usize) -> bool {
+    read().is_custom_pending(index)
+}
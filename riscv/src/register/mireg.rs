@@ -0,0 +1,29 @@
+//! mireg register (Smaia)
+//!
+//! Machine indirect register alias. It accesses the register selected by [`miselect`](super::miselect).
+//! See [`aia::machine`](crate::aia::machine) for accesses that are protected against trap handlers.
+
+read_csr_as_usize!(0x351);
+write_csr!(0x351);
+
+/// Writes the register selected by [`miselect`](super::miselect).
+///
+/// **WARNING**: panics on non-`riscv` targets.
+///
+/// # Safety
+///
+/// Writing the selected register may change the interrupt configuration of the hart.
+#[inline]
+pub unsafe fn write(bits: usize) {
+    _write(bits)
+}
+
+/// Attempts to write the register selected by [`miselect`](super::miselect).
+///
+/// # Safety
+///
+/// See [`write()`].
+#[inline]
+pub unsafe fn try_write(bits: usize) -> crate::result::Result<()> {
+    _try_write(bits)
+}
@@ -16,6 +16,9 @@ pub enum XLEN {
     XLEN128 = 3,
 }
 
+/// Machine XLEN field (`MXL`) of the [`Misa`] register
+pub type Mxl = XLEN;
+
 impl XLEN {
     /// Converts a number into an ISA width
     pub(crate) fn from(value: u8) -> Self {
@@ -52,11 +55,25 @@ impl Misa {
     /// ```
     #[inline]
     pub fn has_extension(&self, extension: char) -> bool {
-        let bit = extension as u8 - 65;
-        if bit > 25 {
-            return false;
-        }
-        self.bits() & (1 << bit) == (1 << bit)
+        crate::isa::letter_bit(extension).map_or(false, |bit| self.bits() & bit as usize != 0)
+    }
+
+    /// Returns an iterator over the implemented extensions, in alphabetical order.
+    ///
+    /// Extensions are represented by their (uppercase) letter (e.g., `'M'` or `'A'`).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// if let Some(misa) = riscv::register::misa::read() {
+    ///     for extension in misa.extensions() {
+    ///         // e.g., print the extension letter in a boot banner
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    pub fn extensions(&self) -> impl Iterator<Item = char> {
+        crate::isa::letters(self.bits() as u32)
     }
 }
 
@@ -70,3 +87,21 @@ pub fn read() -> Option<Misa> {
     // isn't implemented.
     NonZeroUsize::new(r).map(|bits| Misa { bits })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_misa() {
+        // RV64IMAC, with the S and U extensions
+        let bits = (2 << (usize::BITS - 2)) | 0b1_0100_0001_0001_0000_0101;
+        let misa = Misa {
+            bits: NonZeroUsize::new(bits).unwrap(),
+        };
+        assert_eq!(misa.mxl(), Mxl::XLEN64);
+        assert!(misa.has_extension('I') && misa.has_extension('m'));
+        assert!(!misa.has_extension('F') && !misa.has_extension('1'));
+        assert!(misa.extensions().eq("ACIMSU".chars()));
+    }
+}
@@ -0,0 +1,11 @@
+//! miselect register (Smaia)
+//!
+//! Machine indirect register select. It selects the register accessed through [`mireg`](super::mireg),
+//! e.g., the major interrupt priorities or the registers of the machine-level IMSIC interrupt file.
+//!
+//! A trap handler that uses the window between the selection and the access changes the selection.
+//! See [`aia::machine`](crate::aia::machine) for accesses that are protected against this.
+
+read_csr_as_usize!(0x350);
+write_csr_as_usize!(0x350);
+swap_csr_as_usize!(0x350);
@@ -0,0 +1,42 @@
+//! mncause register (Smrnmi)
+//!
+//! Resumable NMI cause register.
+
+/// mncause register
+#[derive(Clone, Copy, Debug)]
+pub struct Mncause {
+    bits: usize,
+}
+
+impl From<usize> for Mncause {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mncause {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns the implementation-defined cause of the NMI
+    #[inline]
+    pub fn code(&self) -> usize {
+        self.bits & !(1 << (usize::BITS as usize - 1))
+    }
+
+    /// Returns true if the NMI was caused by an interrupt
+    ///
+    /// NMIs are always reported as interrupts, so this bit always reads 1. Exceptions raised
+    /// while NMIs are disabled (i.e., in the NMI handler) are reported in `mcause` instead.
+    #[inline]
+    pub fn is_interrupt(&self) -> bool {
+        self.bits & (1 << (usize::BITS as usize - 1)) != 0
+    }
+}
+
+read_csr_as!(Mncause, 0x742);
+write_csr_as_usize!(0x742);
@@ -0,0 +1,7 @@
+//! mnepc register (Smrnmi)
+//!
+//! Resumable NMI program counter register. It holds the address of the instruction that was
+//! interrupted by the NMI, where [`mnret`](crate::asm::mnret) resumes execution.
+
+read_csr_as_usize!(0x741);
+write_csr_as_usize!(0x741);
@@ -0,0 +1,6 @@
+//! mnscratch register (Smrnmi)
+//!
+//! Resumable NMI scratch register.
+
+read_csr_as_usize!(0x740);
+write_csr_as_usize!(0x740);
@@ -0,0 +1,142 @@
+//! mnstatus register (Smrnmi)
+//!
+//! Resumable NMI status register.
+
+pub use super::mstatus::MPP;
+use crate::bits::{bf_extract, bf_insert};
+use crate::result::{Error, Result};
+
+/// mnstatus register
+#[derive(Clone, Copy, Debug)]
+pub struct Mnstatus {
+    bits: usize,
+}
+
+impl From<usize> for Mnstatus {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mnstatus {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// NMI enable
+    ///
+    /// It is cleared when entering the NMI handler, and set again by [`mnret`](crate::asm::mnret).
+    #[inline]
+    pub fn nmie(&self) -> bool {
+        self.bits & (1 << 3) != 0
+    }
+
+    /// Update NMI enable
+    ///
+    /// Note this updates a previously read [`Mnstatus`] value, but does not
+    /// affect the mnstatus CSR itself. See [`set_nmie`] to directly update the CSR.
+    #[inline]
+    pub fn set_nmie(&mut self, nmie: bool) {
+        self.bits = bf_insert(self.bits, 3, 1, nmie as usize);
+    }
+
+    /// Previous virtualization mode
+    #[inline]
+    pub fn mnpv(&self) -> bool {
+        self.bits & (1 << 7) != 0
+    }
+
+    /// Update previous virtualization mode
+    ///
+    /// Note this updates a previously read [`Mnstatus`] value, but does not
+    /// affect the mnstatus CSR itself. See [`set_mnpv`]/[`clear_mnpv`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_mnpv(&mut self, mnpv: bool) {
+        self.bits = bf_insert(self.bits, 7, 1, mnpv as usize);
+    }
+
+    /// Previous privilege mode
+    ///
+    /// **WARNING**: panics if the field has an invalid variant.
+    #[inline]
+    pub fn mnpp(&self) -> MPP {
+        self.try_mnpp().unwrap()
+    }
+
+    /// Attempts to get the previous privilege mode
+    #[inline]
+    pub fn try_mnpp(&self) -> Result<MPP> {
+        match bf_extract(self.bits, 11, 2) {
+            0b00 => Ok(MPP::User),
+            0b01 => Ok(MPP::Supervisor),
+            0b11 => Ok(MPP::Machine),
+            value => Err(Error::InvalidFieldVariant {
+                field: "mnpp",
+                value,
+            }),
+        }
+    }
+
+    /// Update previous privilege mode
+    ///
+    /// Note this updates a previously read [`Mnstatus`] value, but does not
+    /// affect the mnstatus CSR itself. See [`set_mnpp`] to directly update the CSR.
+    #[inline]
+    pub fn set_mnpp(&mut self, mnpp: MPP) {
+        self.bits = bf_insert(self.bits, 11, 2, mnpp as usize);
+    }
+}
+
+read_csr_as!(Mnstatus, 0x744);
+write_csr_as!(Mnstatus, 0x744);
+set!(0x744);
+clear!(0x744);
+
+set_csr!(
+    /// NMI enable
+    ///
+    /// Once set, it can only be cleared by taking an NMI.
+    , set_nmie, 1 << 3);
+set_clear_csr!(
+    /// Previous virtualization mode
+    , set_mnpv, clear_mnpv, 1 << 7);
+
+/// Previous privilege mode
+#[inline]
+pub unsafe fn set_mnpp(mnpp: MPP) {
+    let mut value = _read();
+    value &= !(0x3 << 11); // clear previous value
+    value |= (mnpp as usize) << 11;
+    _write(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mnstatus() {
+        let mut mnstatus = Mnstatus::from(0);
+        assert!(!mnstatus.nmie() && !mnstatus.mnpv());
+        assert_eq!(mnstatus.mnpp(), MPP::User);
+
+        mnstatus.set_nmie(true);
+        mnstatus.set_mnpv(true);
+        mnstatus.set_mnpp(MPP::Machine);
+        assert!(mnstatus.nmie() && mnstatus.mnpv());
+        assert_eq!(mnstatus.mnpp(), MPP::Machine);
+        assert_eq!(mnstatus.bits(), 0b1_1000_1000_1000);
+
+        assert_eq!(
+            Mnstatus::from(0b10 << 11).try_mnpp(),
+            Err(Error::InvalidFieldVariant {
+                field: "mnpp",
+                value: 0b10,
+            })
+        );
+    }
+}
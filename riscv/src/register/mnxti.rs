@@ -0,0 +1,149 @@
+//! mnxti register (CLIC)
+//!
+//! Machine next interrupt handler address and interrupt-enable register. It lets an M-mode
+//! trap handler service the following pending horizontal interrupts (i.e., of a level greater
+//! than the interrupted context) without returning from the trap.
+//!
+//! When accessed with a write (i.e., `csrrsi` or `csrrci`), the write applies to `mstatus`,
+//! and, if an interrupt is pending, the access claims it: the exception code of `mcause` and
+//! the interrupt level of `mintstatus` are updated. Plain reads have no side effects.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use riscv::register::mnxti;
+//!
+//! // at the end of an M-mode CLIC interrupt handler
+//! while let Some(entry) = unsafe { mnxti::claim_and_enable() } {
+//!     // SAFETY: `entry` points to an entry of the `mtvt` table
+//!     let handler: extern "C" fn() = unsafe { core::mem::transmute(*(entry as *const usize)) };
+//!     handler();
+//!     // disable interrupts before the next claim
+//!     unsafe { riscv::register::mstatus::clear_mie() };
+//! }
+//! ```
+
+#[cfg(not(riscv))]
+use crate::result::Error;
+use crate::result::Result;
+
+/// Reads the CSR without side effects.
+///
+/// Returns the address of the `mtvt` entry of the highest-priority pending horizontal interrupt,
+/// or `None` if no such interrupt is pending.
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn read() -> Option<usize> {
+    try_read().unwrap()
+}
+
+/// Attempts to read the CSR without side effects.
+#[inline]
+pub fn try_read() -> Result<Option<usize>> {
+    match () {
+        #[cfg(riscv)]
+        () => {
+            let r: usize;
+            unsafe { core::arch::asm!("csrrs {0}, 0x345, x0", out(reg) r) };
+            if r != 0 {
+                Ok(Some(r))
+            } else {
+                Ok(None)
+            }
+        }
+
+        #[cfg(not(riscv))]
+        () => Err(Error::Unimplemented),
+    }
+}
+
+/// Sets `mstatus.MIE` and claims the highest-priority pending horizontal interrupt, atomically.
+///
+/// Returns the address of the `mtvt` entry of the claimed interrupt, or `None` if no such
+/// interrupt is pending (interrupts are enabled anyway).
+///
+/// **WARNING**: panics on non-`riscv` targets.
+///
+/// # Safety
+///
+/// It must be called from an M-mode CLIC interrupt handler, and the claimed interrupt must be serviced.
+/// Enabling interrupts can break mask-based critical sections.
+#[inline]
+pub unsafe fn claim_and_enable() -> Option<usize> {
+    try_claim_and_enable().unwrap()
+}
+
+/// Attempts to set `mstatus.MIE` and claim the highest-priority pending horizontal interrupt, atomically.
+///
+/// # Safety
+///
+/// See [`claim_and_enable`].
+#[inline]
+pub unsafe fn try_claim_and_enable() -> Result<Option<usize>> {
+    match () {
+        #[cfg(riscv)]
+        () => {
+            let r: usize;
+            core::arch::asm!("csrrsi {0}, 0x345, 8", out(reg) r);
+            if r != 0 {
+                Ok(Some(r))
+            } else {
+                Ok(None)
+            }
+        }
+
+        #[cfg(not(riscv))]
+        () => Err(Error::Unimplemented),
+    }
+}
+
+/// Clears `mstatus.MIE` and claims the highest-priority pending horizontal interrupt, atomically.
+///
+/// Returns the address of the `mtvt` entry of the claimed interrupt, or `None` if no such
+/// interrupt is pending (interrupts are disabled anyway).
+///
+/// **WARNING**: panics on non-`riscv` targets.
+///
+/// # Safety
+///
+/// It must be called from an M-mode CLIC interrupt handler, and the claimed interrupt must be serviced.
+#[inline]
+pub unsafe fn claim_and_disable() -> Option<usize> {
+    try_claim_and_disable().unwrap()
+}
+
+/// Attempts to clear `mstatus.MIE` and claim the highest-priority pending horizontal interrupt, atomically.
+///
+/// # Safety
+///
+/// See [`claim_and_disable`].
+#[inline]
+pub unsafe fn try_claim_and_disable() -> Result<Option<usize>> {
+    match () {
+        #[cfg(riscv)]
+        () => {
+            let r: usize;
+            core::arch::asm!("csrrci {0}, 0x345, 8", out(reg) r);
+            if r != 0 {
+                Ok(Some(r))
+            } else {
+                Ok(None)
+            }
+        }
+
+        #[cfg(not(riscv))]
+        () => Err(Error::Unimplemented),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mnxti() {
+        assert_eq!(try_read(), Err(Error::Unimplemented));
+        assert_eq!(unsafe { try_claim_and_enable() }, Err(Error::Unimplemented));
+    }
+}
@@ -1,4 +1,9 @@
 //! mscratch register
+//!
+//! Scratch register for M-mode trap handlers. It usually holds a pointer to the
+//! hart-local context (e.g., the trap handler stack), which the trap entry exchanges
+//! with `sp` using `csrrw sp, mscratch, sp`. The same exchange is available as [`swap`].
 
 read_csr_as_usize!(0x340);
 write_csr_as_usize!(0x340);
+swap_csr_as_usize!(0x340);
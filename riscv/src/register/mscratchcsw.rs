@@ -0,0 +1,8 @@
+//! mscratchcsw register (CLIC)
+//!
+//! Conditional scratch swap register for M-mode trap handlers. `csrrw sp, mscratchcsw, sp`
+//! exchanges `sp` and `mscratch` only if the trap came from a less privileged mode (i.e.,
+//! `mstatus.MPP` is not M-mode), so handlers can switch to the M-mode stack without branches.
+//! The same exchange is available as [`swap`].
+
+swap_csr_as_usize!(0x348);
@@ -0,0 +1,8 @@
+//! mscratchcswl register (CLIC)
+//!
+//! Conditional scratch swap register on interrupt level changes. `csrrw sp, mscratchcswl, sp`
+//! exchanges `sp` and `mscratch` only if exactly one of the interrupted context (`mcause.MPIL`)
+//! and the current handler (`mintstatus.MIL`) runs at interrupt level 0, so interrupt handlers can
+//! switch to an interrupt stack without branches. The same exchange is available as [`swap`].
+
+swap_csr_as_usize!(0x349);
@@ -0,0 +1,270 @@
+//! mseccfg register (Smepmp)
+//!
+//! Machine security configuration register. It controls the enhanced PMP (ePMP) features.
+//! Note that the `MML` and `MMWP` bits are sticky: once set, they can only be cleared by a reset.
+//!
+//! The register is 64 bits wide on all targets. On RV32, the upper half lives in the
+//! [`mseccfgh`](super::mseccfgh) register, and [`read`] and [`write()`] access both halves.
+
+pub use super::menvcfg::Pmm;
+use crate::result::Result;
+
+/// mseccfg register
+#[derive(Clone, Copy, Debug)]
+pub struct Mseccfg {
+    bits: u64,
+}
+
+impl From<u64> for Mseccfg {
+    #[inline]
+    fn from(bits: u64) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mseccfg {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    #[inline]
+    fn bit(&self, index: usize) -> bool {
+        self.bits & (1 << index) != 0
+    }
+
+    #[inline]
+    fn set_bit(&mut self, index: usize, value: bool) {
+        self.bits = (self.bits & !(1 << index)) | ((value as u64) << index);
+    }
+
+    /// Machine Mode Lockdown
+    ///
+    /// When set, locked PMP entries are enforced on M-mode only, unlocked entries are
+    /// enforced on S/U-modes only, and the shared-region encodings become available
+    /// (see [`MmlPermission`](crate::pmp::MmlPermission)).
+    #[inline]
+    pub fn mml(&self) -> bool {
+        self.bit(0)
+    }
+
+    /// Update Machine Mode Lockdown
+    ///
+    /// Note this updates a previously read [`Mseccfg`] value, but does not
+    /// affect the mseccfg CSR itself. See [`set_mml`] to directly update the CSR.
+    #[inline]
+    pub fn set_mml(&mut self, mml: bool) {
+        self.set_bit(0, mml);
+    }
+
+    /// Machine Mode Whitelist Policy
+    ///
+    /// When set, M-mode accesses that do not match any PMP entry are denied.
+    #[inline]
+    pub fn mmwp(&self) -> bool {
+        self.bit(1)
+    }
+
+    /// Update Machine Mode Whitelist Policy
+    ///
+    /// Note this updates a previously read [`Mseccfg`] value, but does not
+    /// affect the mseccfg CSR itself. See [`set_mmwp`] to directly update the CSR.
+    #[inline]
+    pub fn set_mmwp(&mut self, mmwp: bool) {
+        self.set_bit(1, mmwp);
+    }
+
+    /// Rule Locking Bypass
+    ///
+    /// When set, locked PMP entries can be modified or removed.
+    #[inline]
+    pub fn rlb(&self) -> bool {
+        self.bit(2)
+    }
+
+    /// Update Rule Locking Bypass
+    ///
+    /// Note this updates a previously read [`Mseccfg`] value, but does not
+    /// affect the mseccfg CSR itself. See [`set_rlb`]/[`clear_rlb`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_rlb(&mut self, rlb: bool) {
+        self.set_bit(2, rlb);
+    }
+
+    /// U-mode `seed` access (Zkr)
+    ///
+    /// When set, U-mode can access the [`seed`](super::seed) CSR.
+    #[inline]
+    pub fn useed(&self) -> bool {
+        self.bit(8)
+    }
+
+    /// Update U-mode `seed` access (Zkr)
+    ///
+    /// Note this updates a previously read [`Mseccfg`] value, but does not
+    /// affect the mseccfg CSR itself. See [`set_useed`]/[`clear_useed`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_useed(&mut self, useed: bool) {
+        self.set_bit(8, useed);
+    }
+
+    /// S-mode `seed` access (Zkr)
+    ///
+    /// When set, S-mode can access the [`seed`](super::seed) CSR.
+    #[inline]
+    pub fn sseed(&self) -> bool {
+        self.bit(9)
+    }
+
+    /// Update S-mode `seed` access (Zkr)
+    ///
+    /// Note this updates a previously read [`Mseccfg`] value, but does not
+    /// affect the mseccfg CSR itself. See [`set_sseed`]/[`clear_sseed`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_sseed(&mut self, sseed: bool) {
+        self.set_bit(9, sseed);
+    }
+
+    /// Pointer masking mode of M-mode (Smmpm)
+    ///
+    /// **WARNING**: panics if the field has an invalid variant.
+    #[inline]
+    pub fn pmm(&self) -> Pmm {
+        self.try_pmm().unwrap()
+    }
+
+    /// Attempts to get the pointer masking mode of M-mode (Smmpm)
+    #[inline]
+    pub fn try_pmm(&self) -> Result<Pmm> {
+        (((self.bits >> 32) & 0b11) as u8).try_into()
+    }
+
+    /// Updates the pointer masking mode of M-mode (Smmpm)
+    ///
+    /// Note this updates a previously read [`Mseccfg`] value, but does not
+    /// affect the mseccfg CSR itself.
+    #[cfg_attr(
+        target_pointer_width = "64",
+        doc = "See [`set_pmm`] to directly update the CSR."
+    )]
+    #[inline]
+    pub fn set_pmm(&mut self, pmm: Pmm) {
+        self.bits = (self.bits & !(0b11 << 32)) | ((pmm as u64) << 32);
+    }
+}
+
+read_csr!(0x747);
+write_csr!(0x747);
+set!(0x747);
+clear!(0x747);
+
+/// Reads the CSR
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn read() -> Mseccfg {
+    try_read().unwrap()
+}
+
+/// Attempts to read the CSR
+///
+/// On RV32, the upper half is read from the `mseccfgh` CSR.
+#[inline]
+pub fn try_read() -> Result<Mseccfg> {
+    let lo = unsafe { _try_read()? } as u64;
+    match () {
+        #[cfg(riscv32)]
+        () => Ok(Mseccfg::from(
+            ((super::mseccfgh::try_read()? as u64) << 32) | lo,
+        )),
+        #[cfg(not(riscv32))]
+        () => Ok(Mseccfg::from(lo)),
+    }
+}
+
+/// Writes the CSR
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub unsafe fn write(mseccfg: Mseccfg) {
+    try_write(mseccfg).unwrap();
+}
+
+/// Attempts to write the CSR
+///
+/// On RV32, the upper half is written to the `mseccfgh` CSR first.
+#[inline]
+pub unsafe fn try_write(mseccfg: Mseccfg) -> Result<()> {
+    #[cfg(riscv32)]
+    super::mseccfgh::try_write((mseccfg.bits >> 32) as usize)?;
+    _try_write(mseccfg.bits as usize)
+}
+
+/// Sets the pointer masking mode of M-mode (Smmpm, RV64 only)
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[cfg(target_pointer_width = "64")]
+#[inline]
+pub unsafe fn set_pmm(pmm: Pmm) {
+    try_set_pmm(pmm).unwrap();
+}
+
+/// Attempts to set the pointer masking mode of M-mode (Smmpm, RV64 only)
+#[cfg(target_pointer_width = "64")]
+#[inline]
+pub unsafe fn try_set_pmm(pmm: Pmm) -> Result<()> {
+    let mut mseccfg = try_read()?;
+    mseccfg.set_pmm(pmm);
+    try_write(mseccfg)
+}
+
+set_csr!(
+    /// Machine Mode Lockdown (sticky until reset)
+    , set_mml, 1 << 0);
+set_csr!(
+    /// Machine Mode Whitelist Policy (sticky until reset)
+    , set_mmwp, 1 << 1);
+set_clear_csr!(
+    /// Rule Locking Bypass
+    ///
+    /// Once cleared, it cannot be set again while any PMP entry is locked.
+    , set_rlb, clear_rlb, 1 << 2);
+set_clear_csr!(
+    /// U-mode `seed` access (Zkr)
+    , set_useed, clear_useed, 1 << 8);
+set_clear_csr!(
+    /// S-mode `seed` access (Zkr)
+    , set_sseed, clear_sseed, 1 << 9);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mseccfg() {
+        let mut mseccfg = Mseccfg::from(0);
+        assert!(!mseccfg.mml() && !mseccfg.mmwp() && !mseccfg.rlb());
+
+        mseccfg.set_mml(true);
+        assert!(mseccfg.mml());
+        mseccfg.set_mmwp(true);
+        assert!(mseccfg.mmwp());
+        mseccfg.set_rlb(true);
+        assert!(mseccfg.rlb());
+        assert_eq!(mseccfg.bits(), 0b111);
+
+        mseccfg.set_mml(false);
+        assert_eq!(mseccfg.bits(), 0b110);
+
+        mseccfg.set_useed(true);
+        mseccfg.set_sseed(true);
+        assert!(mseccfg.useed() && mseccfg.sseed());
+        assert_eq!(mseccfg.bits(), 0b11_0000_0110);
+        mseccfg.set_useed(false);
+        assert!(!mseccfg.useed() && mseccfg.sseed());
+    }
+}
@@ -0,0 +1,8 @@
+//! mseccfgh register (Smepmp, RV32 only)
+//!
+//! Upper 32 bits of the machine security configuration register.
+//! Use [`mseccfg::read`](super::mseccfg::read) and [`mseccfg::write`](super::mseccfg::write)
+//! to access the full 64-bit register.
+
+read_csr_as_usize_rv32!(0x757);
+write_csr_as_usize_rv32!(0x757);
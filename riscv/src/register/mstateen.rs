@@ -0,0 +1,255 @@
+//! mstateen registers (Smstateen)
+//!
+//! Machine state enable registers. They control the access of the less privileged modes
+//! to extension state that is not covered by other mechanisms (e.g., `mstatus.FS`).
+//! Only `mstateen0` defines access-control bits other than `SE`.
+//!
+//! The registers are 64 bits wide on all targets. On RV32, the upper halves live in the
+//! `mstateenXh` registers. Use the `read64` and `write64` functions of the `mstateenX` modules
+//! (e.g., [`mstateen0::read64`](super::mstateen0::read64)) together with the typed [`Mstateen`] value.
+
+/// mstateen register
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Mstateen {
+    bits: u64,
+}
+
+impl From<u64> for Mstateen {
+    #[inline]
+    fn from(bits: u64) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mstateen {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub const fn bits(&self) -> u64 {
+        self.bits
+    }
+
+    #[inline]
+    const fn bit(&self, index: u32) -> bool {
+        self.bits & (1 << index) != 0
+    }
+
+    #[inline]
+    fn set_bit(&mut self, index: u32, value: bool) {
+        self.bits = (self.bits & !(1 << index)) | ((value as u64) << index);
+    }
+
+    /// Custom state enable (`C`)
+    ///
+    /// Grants access to the custom (non-standard) state of the less privileged modes.
+    #[inline]
+    pub const fn c(&self) -> bool {
+        self.bit(0)
+    }
+
+    /// Update Custom state enable (`C`)
+    ///
+    /// Note this updates a previously read [`Mstateen`] value, but does not
+    /// affect the mstateenX CSRs themselves.
+    #[inline]
+    pub fn set_c(&mut self, c: bool) {
+        self.set_bit(0, c);
+    }
+
+    /// Floating-point CSR enable (`FCSR`, Zfinx)
+    ///
+    /// Grants access to `fcsr` when the floating-point registers live in the integer registers.
+    #[inline]
+    pub const fn fcsr(&self) -> bool {
+        self.bit(1)
+    }
+
+    /// Update Floating-point CSR enable (`FCSR`, Zfinx)
+    ///
+    /// Note this updates a previously read [`Mstateen`] value, but does not
+    /// affect the mstateenX CSRs themselves.
+    #[inline]
+    pub fn set_fcsr(&mut self, fcsr: bool) {
+        self.set_bit(1, fcsr);
+    }
+
+    /// Jump vector table CSR enable (`JVT`, Zcmt)
+    #[inline]
+    pub const fn jvt(&self) -> bool {
+        self.bit(2)
+    }
+
+    /// Update Jump vector table CSR enable (`JVT`, Zcmt)
+    ///
+    /// Note this updates a previously read [`Mstateen`] value, but does not
+    /// affect the mstateenX CSRs themselves.
+    #[inline]
+    pub fn set_jvt(&mut self, jvt: bool) {
+        self.set_bit(2, jvt);
+    }
+
+    /// Control transfer records enable (`CTR`, Smctr/Ssctr)
+    #[inline]
+    pub const fn ctr(&self) -> bool {
+        self.bit(54)
+    }
+
+    /// Update Control transfer records enable (`CTR`, Smctr/Ssctr)
+    ///
+    /// Note this updates a previously read [`Mstateen`] value, but does not
+    /// affect the mstateenX CSRs themselves.
+    #[inline]
+    pub fn set_ctr(&mut self, ctr: bool) {
+        self.set_bit(54, ctr);
+    }
+
+    /// Resource management configuration CSR enable (`SRMCFG`, Ssqosid)
+    #[inline]
+    pub const fn srmcfg(&self) -> bool {
+        self.bit(55)
+    }
+
+    /// Update Resource management configuration CSR enable (`SRMCFG`, Ssqosid)
+    ///
+    /// Note this updates a previously read [`Mstateen`] value, but does not
+    /// affect the mstateenX CSRs themselves.
+    #[inline]
+    pub fn set_srmcfg(&mut self, srmcfg: bool) {
+        self.set_bit(55, srmcfg);
+    }
+
+    /// Privileged spec 1.13 state enable (`P1P13`)
+    ///
+    /// Grants access to the `hedelegh` CSR.
+    #[inline]
+    pub const fn p1p13(&self) -> bool {
+        self.bit(56)
+    }
+
+    /// Update Privileged spec 1.13 state enable (`P1P13`)
+    ///
+    /// Note this updates a previously read [`Mstateen`] value, but does not
+    /// affect the mstateenX CSRs themselves.
+    #[inline]
+    pub fn set_p1p13(&mut self, p1p13: bool) {
+        self.set_bit(56, p1p13);
+    }
+
+    /// Context CSRs enable (`CONTEXT`, Sdtrig)
+    ///
+    /// Grants access to the `scontext` and `hcontext` CSRs.
+    #[inline]
+    pub const fn context(&self) -> bool {
+        self.bit(57)
+    }
+
+    /// Update Context CSRs enable (`CONTEXT`, Sdtrig)
+    ///
+    /// Note this updates a previously read [`Mstateen`] value, but does not
+    /// affect the mstateenX CSRs themselves.
+    #[inline]
+    pub fn set_context(&mut self, context: bool) {
+        self.set_bit(57, context);
+    }
+
+    /// IMSIC state enable (`IMSIC`, Ssaia)
+    #[inline]
+    pub const fn imsic(&self) -> bool {
+        self.bit(58)
+    }
+
+    /// Update IMSIC state enable (`IMSIC`, Ssaia)
+    ///
+    /// Note this updates a previously read [`Mstateen`] value, but does not
+    /// affect the mstateenX CSRs themselves.
+    #[inline]
+    pub fn set_imsic(&mut self, imsic: bool) {
+        self.set_bit(58, imsic);
+    }
+
+    /// AIA state enable (`AIA`, Ssaia)
+    ///
+    /// Grants access to the AIA state not covered by other bits (e.g., `siph` or `sieh`).
+    #[inline]
+    pub const fn aia(&self) -> bool {
+        self.bit(59)
+    }
+
+    /// Update AIA state enable (`AIA`, Ssaia)
+    ///
+    /// Note this updates a previously read [`Mstateen`] value, but does not
+    /// affect the mstateenX CSRs themselves.
+    #[inline]
+    pub fn set_aia(&mut self, aia: bool) {
+        self.set_bit(59, aia);
+    }
+
+    /// Indirect CSR access enable (`CSRIND`, Sscsrind)
+    ///
+    /// Grants access to the `siselect` and `sireg*` CSRs.
+    #[inline]
+    pub const fn csrind(&self) -> bool {
+        self.bit(60)
+    }
+
+    /// Update Indirect CSR access enable (`CSRIND`, Sscsrind)
+    ///
+    /// Note this updates a previously read [`Mstateen`] value, but does not
+    /// affect the mstateenX CSRs themselves.
+    #[inline]
+    pub fn set_csrind(&mut self, csrind: bool) {
+        self.set_bit(60, csrind);
+    }
+
+    /// Environment configuration CSR enable (`ENVCFG`)
+    ///
+    /// Grants access to the `senvcfg` and `henvcfg` CSRs.
+    #[inline]
+    pub const fn envcfg(&self) -> bool {
+        self.bit(62)
+    }
+
+    /// Update Environment configuration CSR enable (`ENVCFG`)
+    ///
+    /// Note this updates a previously read [`Mstateen`] value, but does not
+    /// affect the mstateenX CSRs themselves.
+    #[inline]
+    pub fn set_envcfg(&mut self, envcfg: bool) {
+        self.set_bit(62, envcfg);
+    }
+
+    /// State enable (`SE`)
+    ///
+    /// Grants access to the `hstateenX`/`sstateenX` CSRs of the same index.
+    #[inline]
+    pub const fn se(&self) -> bool {
+        self.bit(63)
+    }
+
+    /// Update State enable (`SE`)
+    ///
+    /// Note this updates a previously read [`Mstateen`] value, but does not
+    /// affect the mstateenX CSRs themselves.
+    #[inline]
+    pub fn set_se(&mut self, se: bool) {
+        self.set_bit(63, se);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mstateen() {
+        let mut mstateen = Mstateen::default();
+        mstateen.set_se(true);
+        mstateen.set_envcfg(true);
+        mstateen.set_fcsr(true);
+        assert_eq!(mstateen.bits(), (1 << 63) | (1 << 62) | (1 << 1));
+        assert!(mstateen.se() && mstateen.envcfg() && mstateen.fcsr());
+        assert!(!mstateen.aia() && !mstateen.imsic() && !mstateen.csrind() && !mstateen.c());
+
+        mstateen.set_se(false);
+        assert_eq!(mstateen.bits(), (1 << 62) | (1 << 1));
+    }
+}
@@ -65,6 +65,14 @@ pub enum SPP {
     User = 0,
 }
 
+/// Privilege level
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PrivilegeLevel {
+    Machine = 3,
+    Supervisor = 1,
+    User = 0,
+}
+
 /// Non-instruction-fetch memory endianness
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Endianness {
@@ -258,6 +266,22 @@ impl Mstatus {
         self.bits = bf_insert(self.bits, 9, 2, vs as usize);
     }
 
+    /// Returns `true` if the floating-point extension state is [`FS::Dirty`]
+    ///
+    /// Lazy context switching code must save the floating-point state when this is `true`.
+    #[inline]
+    pub fn is_fpu_dirty(&self) -> bool {
+        self.fs() == FS::Dirty
+    }
+
+    /// Returns `true` if the vector extension state is [`VS::Dirty`]
+    ///
+    /// Lazy context switching code must save the vector state when this is `true`.
+    #[inline]
+    pub fn is_vector_dirty(&self) -> bool {
+        self.vs() == VS::Dirty
+    }
+
     /// Additional extension state
     ///
     /// Encodes the status of additional user-mode extensions and associated
@@ -336,6 +360,10 @@ impl Mstatus {
     /// If this bit is set, reads or writes to `satp` CSR or execute `sfence.vma`
     /// instruction when in S-mode will raise an illegal instruction exception.
     ///
+    /// With the hypervisor extension, TVM also traps HS-mode accesses to the `hgatp` CSR
+    /// and `hfence.gvma` instructions. TVM does not affect VS-mode, which is controlled
+    /// by the VTVM bit of the `hstatus` CSR instead.
+    ///
     /// TVM is hard-wired to 0 when S-mode is not supported.
     #[inline]
     pub fn tvm(&self) -> bool {
@@ -356,9 +384,13 @@ impl Mstatus {
     ///
     /// Indicates that if WFI instruction should be intercepted.
     ///
-    /// If this bit is set, when WFI is executed in S-mode, and it does not complete
-    /// within an implementation specific, bounded time limit, the WFI instruction will cause
-    /// an illegal instruction trap; or could always cause trap then the time limit is zero.
+    /// If this bit is set, when WFI is executed in any less-privileged mode, and it does not
+    /// complete within an implementation specific, bounded time limit, the WFI instruction will
+    /// cause an illegal instruction trap; or could always cause trap then the time limit is zero.
+    ///
+    /// With the hypervisor extension, TW takes precedence over the VTW bit of the `hstatus` CSR:
+    /// WFI in VS-mode or VU-mode raises an illegal instruction exception if TW is set, and
+    /// a virtual instruction exception if only VTW is set.
     ///
     /// TW is hard-wired to 0 when S-mode is not supported.
     #[inline]
@@ -381,6 +413,9 @@ impl Mstatus {
     /// Indicates that if SRET instruction should be trapped to raise illegal
     /// instruction exception.
     ///
+    /// With the hypervisor extension, TSR only affects HS-mode. SRET in VS-mode is
+    /// controlled by the VTSR bit of the `hstatus` CSR instead.
+    ///
     /// If S-mode is not supported, TSR bit is hard-wired to 0.
     #[inline]
     pub fn tsr(&self) -> bool {
@@ -515,6 +550,7 @@ impl Mstatus {
 
 read_csr_as!(Mstatus, 0x300);
 write_csr_as!(Mstatus, 0x300);
+modify_csr_as!(Mstatus);
 set!(0x300);
 clear!(0x300);
 
@@ -547,12 +583,19 @@ set_clear_csr!(
     , set_mxr, clear_mxr, 1 << 19);
 set_clear_csr!(
     /// Trap Virtual Memory
+    ///
+    /// Traps S-mode accesses to `satp` and `sfence.vma` (and, with the hypervisor
+    /// extension, HS-mode accesses to `hgatp` and `hfence.gvma`).
     , set_tvm, clear_tvm, 1 << 20);
 set_clear_csr!(
     /// Timeout Wait
+    ///
+    /// Traps WFI in less-privileged modes (including VS-mode and VU-mode).
     , set_tw, clear_tw, 1 << 21);
 set_clear_csr!(
     /// Trap SRET
+    ///
+    /// Traps SRET in S-mode (HS-mode with the hypervisor extension).
     , set_tsr, clear_tsr, 1 << 22);
 
 /// Set U-mode non-instruction-fetch memory endianness
@@ -600,6 +643,46 @@ pub unsafe fn set_vs(vs: VS) {
     _write(value);
 }
 
+/// Marks the floating-point extension state as [`FS::Dirty`]
+#[inline]
+pub unsafe fn mark_fpu_dirty() {
+    _set(0x3 << 13);
+}
+
+/// Marks the floating-point extension state as [`FS::Clean`]
+///
+/// This is usually done after saving the floating-point state during a context switch.
+#[inline]
+pub unsafe fn mark_fpu_clean() {
+    set_fs(FS::Clean);
+}
+
+/// Returns `true` if the floating-point extension state is [`FS::Dirty`]
+#[inline]
+pub fn is_fpu_dirty() -> bool {
+    read().is_fpu_dirty()
+}
+
+/// Marks the vector extension state as [`VS::Dirty`]
+#[inline]
+pub unsafe fn mark_vector_dirty() {
+    _set(0x3 << 9);
+}
+
+/// Marks the vector extension state as [`VS::Clean`]
+///
+/// This is usually done after saving the vector state during a context switch.
+#[inline]
+pub unsafe fn mark_vector_clean() {
+    set_vs(VS::Clean);
+}
+
+/// Returns `true` if the vector extension state is [`VS::Dirty`]
+#[inline]
+pub fn is_vector_dirty() -> bool {
+    read().is_vector_dirty()
+}
+
 /// Set S-mode non-instruction-fetch memory endianness
 ///
 /// # Note
@@ -636,6 +719,98 @@ pub unsafe fn set_mbe(endianness: Endianness) {
     }
 }
 
+/// Set the non-instruction-fetch memory endianness of a given privilege level
+///
+/// # Note
+///
+/// In RISCV-32, the M-mode and S-mode endianness are set in the [`crate::register::mstatush`] register.
+#[inline]
+pub unsafe fn set_data_endianness(level: PrivilegeLevel, endianness: Endianness) {
+    match level {
+        PrivilegeLevel::Machine => set_mbe(endianness),
+        PrivilegeLevel::Supervisor => set_sbe(endianness),
+        PrivilegeLevel::User => set_ube(endianness),
+    }
+}
+
+/// Scope guard for a temporary MPRV window
+///
+/// Created by [`mprv_guard`]. Restores the previous MPRV and MPP fields of `mstatus` when dropped.
+#[must_use = "the previous MPRV and MPP values are restored when the guard is dropped"]
+pub struct MprvGuard {
+    mprv: bool,
+    mpp: MPP,
+}
+
+impl Drop for MprvGuard {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            if !self.mprv {
+                clear_mprv();
+            }
+            set_mpp(self.mpp);
+        }
+    }
+}
+
+/// Scope guard for a temporarily set `mstatus` bit
+///
+/// Created by [`sum_guard`] or [`mxr_guard`]. Restores the previous value of the bit when dropped.
+#[must_use = "the previous value of the bit is restored when the guard is dropped"]
+pub struct BitGuard {
+    mask: usize,
+    set: bool,
+}
+
+impl Drop for BitGuard {
+    #[inline]
+    fn drop(&mut self) {
+        if !self.set {
+            unsafe { _clear(self.mask) };
+        }
+    }
+}
+
+/// Sets MPRV with the given MPP until the returned guard is dropped
+///
+/// While the guard is alive, M-mode loads and stores are translated and protected
+/// as though the current privilege mode were `mpp`.
+///
+/// # Safety
+///
+/// Traps taken into M-mode overwrite MPP. Interrupts should be disabled while the guard is alive.
+#[inline]
+pub unsafe fn mprv_guard(mpp: MPP) -> MprvGuard {
+    let mstatus = read();
+    set_mpp(mpp);
+    set_mprv();
+    MprvGuard {
+        mprv: mstatus.mprv(),
+        mpp: mstatus.mpp(),
+    }
+}
+
+/// Sets SUM until the returned guard is dropped
+///
+/// While the guard is alive, S-mode memory accesses to pages accessible by U-mode are permitted.
+#[inline]
+pub unsafe fn sum_guard() -> BitGuard {
+    let set = read().sum();
+    set_sum();
+    BitGuard { mask: 1 << 18, set }
+}
+
+/// Sets MXR until the returned guard is dropped
+///
+/// While the guard is alive, loads from pages marked either readable or executable succeed.
+#[inline]
+pub unsafe fn mxr_guard() -> BitGuard {
+    let set = read().mxr();
+    set_mxr();
+    BitGuard { mask: 1 << 19, set }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -650,4 +825,62 @@ mod test {
         mstatus.set_mpp(MPP::Supervisor);
         assert_eq!(mstatus.mpp(), MPP::Supervisor);
     }
+
+    #[test]
+    fn test_trap_control() {
+        let mut mstatus = Mstatus { bits: 0 };
+        mstatus.set_tvm(true);
+        mstatus.set_tw(true);
+        mstatus.set_tsr(true);
+        assert!(mstatus.tvm() && mstatus.tw() && mstatus.tsr());
+        assert_eq!(mstatus.bits(), 0b111 << 20);
+        mstatus.set_tw(false);
+        assert!(mstatus.tvm() && !mstatus.tw() && mstatus.tsr());
+        mstatus.set_tvm(false);
+        mstatus.set_tsr(false);
+        assert_eq!(mstatus.bits(), 0);
+    }
+
+    #[test]
+    fn test_endianness() {
+        let mut mstatus = Mstatus { bits: 0 };
+        assert_eq!(mstatus.ube(), Endianness::LittleEndian);
+        mstatus.set_ube(Endianness::BigEndian);
+        assert_eq!(mstatus.ube(), Endianness::BigEndian);
+        assert_eq!(mstatus.bits(), 1 << 6);
+        mstatus.set_ube(Endianness::LittleEndian);
+        assert_eq!(mstatus.ube(), Endianness::LittleEndian);
+        assert_eq!(mstatus.bits(), 0);
+    }
+
+    #[test]
+    fn test_dirty_state() {
+        let mut mstatus = Mstatus { bits: 0 };
+        assert!(!mstatus.is_fpu_dirty());
+        assert!(!mstatus.is_vector_dirty());
+
+        for fs in [FS::Off, FS::Initial, FS::Clean, FS::Dirty] {
+            mstatus.set_fs(fs);
+            assert_eq!(mstatus.fs(), fs);
+            assert_eq!(mstatus.is_fpu_dirty(), fs == FS::Dirty);
+        }
+
+        for vs in [VS::Off, VS::Initial, VS::Clean, VS::Dirty] {
+            mstatus.set_vs(vs);
+            assert_eq!(mstatus.vs(), vs);
+            assert_eq!(mstatus.is_vector_dirty(), vs == VS::Dirty);
+        }
+
+        for xs in [
+            XS::AllOff,
+            XS::NoneDirtyOrClean,
+            XS::NoneDirtySomeClean,
+            XS::SomeDirty,
+        ] {
+            mstatus.set_xs(xs);
+            assert_eq!(mstatus.xs(), xs);
+        }
+        assert_eq!(mstatus.fs(), FS::Dirty);
+        assert_eq!(mstatus.vs(), VS::Dirty);
+    }
 }
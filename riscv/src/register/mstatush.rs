@@ -1,32 +1,111 @@
 //! mstatush register (RISCV-32 only)
 
 pub use super::mstatus::Endianness;
+use crate::bits::{bf_extract, bf_insert};
 
-/// mstatus register
+/// mstatush register
 #[derive(Clone, Copy, Debug)]
 pub struct Mstatush {
     bits: usize,
 }
 
+impl From<usize> for Mstatush {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
 impl Mstatush {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
     /// S-mode non-instruction-fetch memory endianness
     #[inline]
     pub fn sbe(&self) -> Endianness {
-        Endianness::from(self.bits & (1 << 4) != 0)
+        Endianness::from(bf_extract(self.bits, 4, 1) != 0)
+    }
+
+    /// Update S-mode non-instruction-fetch memory endianness
+    ///
+    /// Note this updates a previously read [`Mstatush`] value, but does not
+    /// affect the mstatush CSR itself. See [`set_sbe`] to directly update the
+    /// CSR.
+    #[inline]
+    pub fn set_sbe(&mut self, endianness: Endianness) {
+        self.bits = bf_insert(self.bits, 4, 1, endianness as usize);
     }
 
     /// M-mode non-instruction-fetch memory endianness
     #[inline]
     pub fn mbe(&self) -> Endianness {
-        Endianness::from(self.bits & (1 << 5) != 0)
+        Endianness::from(bf_extract(self.bits, 5, 1) != 0)
+    }
+
+    /// Update M-mode non-instruction-fetch memory endianness
+    ///
+    /// Note this updates a previously read [`Mstatush`] value, but does not
+    /// affect the mstatush CSR itself. See [`set_mbe`] to directly update the
+    /// CSR.
+    #[inline]
+    pub fn set_mbe(&mut self, endianness: Endianness) {
+        self.bits = bf_insert(self.bits, 5, 1, endianness as usize);
+    }
+
+    /// Guest Virtual Address
+    ///
+    /// Set when a trap into M-mode writes a guest virtual address to `mtval`.
+    /// Only implemented with the hypervisor extension.
+    #[inline]
+    pub fn gva(&self) -> bool {
+        bf_extract(self.bits, 6, 1) != 0
+    }
+
+    /// Update Guest Virtual Address
+    ///
+    /// Note this updates a previously read [`Mstatush`] value, but does not
+    /// affect the mstatush CSR itself. See [`set_gva`]/[`clear_gva`] to
+    /// directly update the CSR.
+    #[inline]
+    pub fn set_gva(&mut self, gva: bool) {
+        self.bits = bf_insert(self.bits, 6, 1, gva as usize);
+    }
+
+    /// Machine Previous Virtualization Mode
+    ///
+    /// Only implemented with the hypervisor extension.
+    #[inline]
+    pub fn mpv(&self) -> bool {
+        bf_extract(self.bits, 7, 1) != 0
+    }
+
+    /// Update Machine Previous Virtualization Mode
+    ///
+    /// Note this updates a previously read [`Mstatush`] value, but does not
+    /// affect the mstatush CSR itself. See [`set_mpv`]/[`clear_mpv`] to
+    /// directly update the CSR.
+    #[inline]
+    pub fn set_mpv(&mut self, mpv: bool) {
+        self.bits = bf_insert(self.bits, 7, 1, mpv as usize);
     }
 }
 
 read_csr_as_rv32!(Mstatush, 0x310);
-write_csr_rv32!(0x310);
+write_csr_as_rv32!(Mstatush, 0x310);
+modify_csr_as!(Mstatush);
 set_rv32!(0x310);
 clear_rv32!(0x310);
 
+set_clear_csr!(
+    /// Guest Virtual Address
+    , set_gva, clear_gva, 1 << 6);
+set_clear_csr!(
+    /// Machine Previous Virtualization Mode
+    , set_mpv, clear_mpv, 1 << 7);
+
 /// Set S-mode non-instruction-fetch memory endianness
 #[inline]
 pub unsafe fn set_sbe(endianness: Endianness) {
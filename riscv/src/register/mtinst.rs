@@ -0,0 +1,138 @@
+//! mtinst register (hypervisor extension)
+//!
+//! On a trap into M-mode, `mtinst` may hold a transformed version of the trapping
+//! instruction, a pseudoinstruction describing an implicit memory access performed
+//! during VS-stage address translation, or zero if no information is provided.
+
+use crate::bits::bf_extract;
+
+/// Pseudoinstructions reported for guest-page faults on implicit VS-stage memory accesses
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Pseudoinstruction {
+    /// 32-bit read for VS-stage address translation (RV32)
+    Read32 = 0x0000_2000,
+    /// 32-bit write for VS-stage address translation (RV32)
+    Write32 = 0x0000_2020,
+    /// 64-bit read for VS-stage address translation (RV64)
+    Read64 = 0x0000_3000,
+    /// 64-bit write for VS-stage address translation (RV64)
+    Write64 = 0x0000_3020,
+}
+
+impl Pseudoinstruction {
+    /// Attempts to decode a pseudoinstruction from its raw encoding
+    #[inline]
+    pub const fn from_bits(bits: usize) -> Option<Self> {
+        match bits {
+            0x0000_2000 => Some(Self::Read32),
+            0x0000_2020 => Some(Self::Write32),
+            0x0000_3000 => Some(Self::Read64),
+            0x0000_3020 => Some(Self::Write64),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the implicit access is a write
+    #[inline]
+    pub const fn is_write(&self) -> bool {
+        matches!(self, Self::Write32 | Self::Write64)
+    }
+}
+
+/// mtinst register
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Mtinst {
+    bits: usize,
+}
+
+impl From<usize> for Mtinst {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mtinst {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns `true` if the register holds a transformed standard instruction
+    #[inline]
+    pub fn is_transformed(&self) -> bool {
+        bf_extract(self.bits, 0, 1) != 0
+    }
+
+    /// Returns `true` if the trapping instruction was a 16-bit compressed instruction
+    ///
+    /// Only meaningful if [`Mtinst::is_transformed`] returns `true`.
+    #[inline]
+    pub fn is_compressed(&self) -> bool {
+        self.is_transformed() && bf_extract(self.bits, 1, 1) == 0
+    }
+
+    /// Returns the transformed instruction in its 32-bit (uncompressed) encoding
+    ///
+    /// Returns `None` if the register does not hold a transformed standard instruction.
+    #[inline]
+    pub fn transformed_instruction(&self) -> Option<u32> {
+        match self.is_transformed() {
+            true => Some(self.bits as u32 | 0b11),
+            false => None,
+        }
+    }
+
+    /// Returns the address offset field of a transformed load/store instruction
+    ///
+    /// This field replaces `rs1` and holds the offset of the faulting address
+    /// from the original virtual address of a misaligned access.
+    /// It is zero when the transformed instruction does not hold a load or store.
+    #[inline]
+    pub fn address_offset(&self) -> usize {
+        bf_extract(self.bits, 15, 5)
+    }
+
+    /// Returns the pseudoinstruction for implicit VS-stage memory accesses
+    ///
+    /// Returns `None` if the register does not hold a known pseudoinstruction.
+    #[inline]
+    pub fn pseudoinstruction(&self) -> Option<Pseudoinstruction> {
+        Pseudoinstruction::from_bits(self.bits)
+    }
+}
+
+read_csr_as!(Mtinst, 0x34A);
+write_csr_as!(Mtinst, 0x34A);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mtinst() {
+        // transformed `lw a0, 0(a1)` with the rs1 field holding an address offset of 2
+        let mtinst = Mtinst::from(0x0001_2503);
+        assert!(mtinst.is_transformed());
+        assert!(!mtinst.is_compressed());
+        assert_eq!(mtinst.transformed_instruction(), Some(0x0001_2503));
+        assert_eq!(mtinst.address_offset(), 2);
+        assert_eq!(mtinst.pseudoinstruction(), None);
+
+        // transformed `c.lw`
+        let mtinst = Mtinst::from(0x0000_2501);
+        assert!(mtinst.is_compressed());
+        assert_eq!(mtinst.transformed_instruction(), Some(0x0000_2503));
+
+        let mtinst = Mtinst::from(0x0000_3020);
+        assert!(!mtinst.is_transformed());
+        assert_eq!(mtinst.transformed_instruction(), None);
+        assert_eq!(mtinst.pseudoinstruction(), Some(Pseudoinstruction::Write64));
+        assert!(Pseudoinstruction::Write64.is_write());
+
+        let mtinst = Mtinst::from(0);
+        assert!(!mtinst.is_transformed());
+        assert_eq!(mtinst.pseudoinstruction(), None);
+    }
+}
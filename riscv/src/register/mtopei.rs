@@ -0,0 +1,103 @@
+//! mtopei register (Smaia)
+//!
+//! Machine top external interrupt register. It reports the highest-priority interrupt of the
+//! machine-level IMSIC interrupt file that is pending and enabled, and above the `eithreshold`.
+//!
+//! Writing the register claims the reported interrupt, i.e., clears its pending bit.
+//! Use [`claim`] to read and claim the interrupt atomically.
+
+#[cfg(not(riscv))]
+use crate::result::Error;
+use crate::result::Result;
+
+/// mtopei register
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Mtopei {
+    bits: usize,
+}
+
+impl From<usize> for Mtopei {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mtopei {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// External interrupt identity of the top interrupt, or 0 if no interrupt is pending
+    #[inline]
+    pub fn identity(&self) -> usize {
+        (self.bits >> 16) & 0x7FF
+    }
+
+    /// Priority of the top interrupt, which is equal to its identity
+    #[inline]
+    pub fn priority(&self) -> usize {
+        self.bits & 0x7FF
+    }
+
+    /// Returns `true` if an interrupt is pending
+    #[inline]
+    pub fn is_pending(&self) -> bool {
+        self.identity() != 0
+    }
+}
+
+read_csr_as!(Mtopei, 0x35C);
+
+/// Claims the top external interrupt.
+///
+/// Returns `None` if no interrupt is pending.
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn claim() -> Option<Mtopei> {
+    try_claim().unwrap()
+}
+
+/// Attempts to claim the top external interrupt.
+///
+/// Returns `Ok(None)` if no interrupt is pending.
+#[inline]
+pub fn try_claim() -> Result<Option<Mtopei>> {
+    match () {
+        #[cfg(riscv)]
+        () => {
+            let r: usize;
+            // the read and the claim must be a single access, or a higher-priority interrupt
+            // could be claimed without being reported
+            unsafe { core::arch::asm!("csrrw {0}, 0x35C, x0", out(reg) r) };
+            let mtopei = Mtopei::from(r);
+            if mtopei.is_pending() {
+                Ok(Some(mtopei))
+            } else {
+                Ok(None)
+            }
+        }
+
+        #[cfg(not(riscv))]
+        () => Err(Error::Unimplemented),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mtopei() {
+        let mtopei = Mtopei::from((0x25 << 16) | 0x25);
+        assert_eq!(mtopei.identity(), 0x25);
+        assert_eq!(mtopei.priority(), 0x25);
+        assert!(mtopei.is_pending());
+        assert!(!Mtopei::from(0).is_pending());
+
+        assert_eq!(try_claim(), Err(Error::Unimplemented));
+    }
+}
@@ -0,0 +1,59 @@
+//! mtopi register (Smaia)
+//!
+//! Machine top interrupt register. It reports the highest-priority interrupt that is pending and
+//! enabled for M-mode, regardless of `mstatus.MIE`.
+
+/// mtopi register
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Mtopi {
+    bits: usize,
+}
+
+impl From<usize> for Mtopi {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mtopi {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Major interrupt number of the top interrupt, or 0 if no interrupt is pending
+    #[inline]
+    pub fn iid(&self) -> usize {
+        (self.bits >> 16) & 0xFFF
+    }
+
+    /// Priority of the top interrupt (lower values are higher priorities)
+    #[inline]
+    pub fn iprio(&self) -> usize {
+        self.bits & 0xFF
+    }
+
+    /// Returns `true` if an interrupt is pending
+    #[inline]
+    pub fn is_pending(&self) -> bool {
+        self.bits != 0
+    }
+}
+
+read_csr_as!(Mtopi, 0xFB0);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mtopi() {
+        let mtopi = Mtopi::from((11 << 16) | 0x42);
+        assert_eq!(mtopi.iid(), 11);
+        assert_eq!(mtopi.iprio(), 0x42);
+        assert!(mtopi.is_pending());
+        assert!(!Mtopi::from(0).is_pending());
+    }
+}
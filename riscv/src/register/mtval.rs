@@ -1,3 +1,112 @@
 //! mtval register
+//!
+//! Depending on the trap cause, `mtval` holds the faulting virtual address,
+//! the faulting instruction bits, or zero.
+//! Use [`read_with_cause`] to get a typed [`Mtval`] value that selects the correct interpretation.
+
+pub use crate::interrupt::Exception;
+use crate::result::Result;
+
+/// mtval register, interpreted according to the trap cause
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Mtval {
+    bits: usize,
+    cause: Exception,
+}
+
+impl Mtval {
+    /// Creates a new [`Mtval`] value from the raw register bits and the exception that caused the trap.
+    #[inline]
+    pub const fn new(bits: usize, cause: Exception) -> Self {
+        Self { bits, cause }
+    }
+
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub const fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns the exception that caused the trap
+    #[inline]
+    pub const fn cause(&self) -> Exception {
+        self.cause
+    }
+
+    /// Returns the faulting virtual address
+    ///
+    /// Returns `None` if the trap cause does not report a faulting address.
+    /// For guest-page faults, this is the guest virtual address (see `mtval2` for the guest physical address).
+    #[inline]
+    pub const fn faulting_address(&self) -> Option<usize> {
+        match self.cause {
+            Exception::InstructionMisaligned
+            | Exception::InstructionFault
+            | Exception::Breakpoint
+            | Exception::LoadMisaligned
+            | Exception::LoadFault
+            | Exception::StoreMisaligned
+            | Exception::StoreFault
+            | Exception::InstructionPageFault
+            | Exception::LoadPageFault
+            | Exception::StorePageFault
+            | Exception::InstructionGuestPageFault
+            | Exception::LoadGuestPageFault
+            | Exception::StoreGuestPageFault => Some(self.bits),
+            _ => None,
+        }
+    }
+
+    /// Returns the faulting instruction bits
+    ///
+    /// Returns `None` if the trap cause does not report the faulting instruction,
+    /// or if the implementation does not provide it (i.e., the register reads as zero).
+    #[inline]
+    pub const fn faulting_instruction(&self) -> Option<usize> {
+        match self.cause {
+            Exception::IllegalInstruction | Exception::VirtualInstruction if self.bits != 0 => {
+                Some(self.bits)
+            }
+            _ => None,
+        }
+    }
+}
 
 read_csr_as_usize!(0x343);
+
+/// Reads the CSR and interprets it according to the exception that caused the trap.
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn read_with_cause(cause: Exception) -> Mtval {
+    try_read_with_cause(cause).unwrap()
+}
+
+/// Attempts to read the CSR and interpret it according to the exception that caused the trap.
+#[inline]
+pub fn try_read_with_cause(cause: Exception) -> Result<Mtval> {
+    Ok(Mtval::new(try_read()?, cause))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mtval() {
+        let mtval = Mtval::new(0x8000_0000, Exception::LoadPageFault);
+        assert_eq!(mtval.faulting_address(), Some(0x8000_0000));
+        assert_eq!(mtval.faulting_instruction(), None);
+
+        let mtval = Mtval::new(0x0000_0073, Exception::IllegalInstruction);
+        assert_eq!(mtval.faulting_address(), None);
+        assert_eq!(mtval.faulting_instruction(), Some(0x0000_0073));
+
+        let mtval = Mtval::new(0, Exception::IllegalInstruction);
+        assert_eq!(mtval.faulting_instruction(), None);
+
+        let mtval = Mtval::new(0x8000_0000, Exception::MachineEnvCall);
+        assert_eq!(mtval.faulting_address(), None);
+        assert_eq!(mtval.faulting_instruction(), None);
+    }
+}
@@ -0,0 +1,37 @@
+//! mtval2 register (hypervisor extension)
+//!
+//! On a guest-page fault taken into M-mode, `mtval2` holds the faulting guest physical address
+//! shifted right by 2 bits. Otherwise, it is written with zero.
+
+/// mtval2 register
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Mtval2 {
+    bits: usize,
+}
+
+impl From<usize> for Mtval2 {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Mtval2 {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub const fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns the faulting guest physical address
+    ///
+    /// The two least significant bits are not reported and are always zero.
+    /// Note that, in RV32, guest physical addresses can be up to 34 bits wide.
+    #[inline]
+    pub const fn guest_physical_address(&self) -> u64 {
+        (self.bits as u64) << 2
+    }
+}
+
+read_csr_as!(Mtval2, 0x34B);
+write_csr_as!(Mtval2, 0x34B);
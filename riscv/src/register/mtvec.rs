@@ -1,5 +1,8 @@
 //! mtvec register
 
+use crate::register::trap_vector::{self, TrapVector};
+use crate::result::{Error, Result};
+
 /// mtvec register
 #[derive(Clone, Copy, Debug)]
 pub struct Mtvec {
@@ -11,9 +14,22 @@ pub struct Mtvec {
 pub enum TrapMode {
     Direct = 0,
     Vectored = 1,
+    /// Core-Local Interrupt Controller (CLIC) mode
+    ///
+    /// The trap-vector base-address must be 64-byte aligned.
+    Clic = 3,
 }
 
 impl Mtvec {
+    /// Creates a new [`Mtvec`] value from a trap-vector base-address and a trap mode.
+    ///
+    /// Returns an error if the base-address is not 4-byte aligned
+    /// (64-byte aligned in [`TrapMode::Clic`] mode).
+    #[inline]
+    pub fn new(address: usize, mode: TrapMode) -> Result<Self> {
+        TrapVector::new(address, mode).try_into()
+    }
+
     /// Returns the contents of the register as raw bits
     #[inline]
     pub fn bits(&self) -> usize {
@@ -23,28 +39,90 @@ impl Mtvec {
     /// Returns the trap-vector base-address
     #[inline]
     pub fn address(&self) -> usize {
-        self.bits - (self.bits & 0b11)
+        trap_vector::address(self.bits)
+    }
+
+    /// Updates the trap-vector base-address
+    ///
+    /// Returns an error if the base-address is not 4-byte aligned
+    /// (64-byte aligned in [`TrapMode::Clic`] mode).
+    ///
+    /// Note this updates a previously read [`Mtvec`] value, but does not
+    /// affect the mtvec CSR itself. See [`write()`] to directly update the CSR.
+    #[inline]
+    pub fn set_address(&mut self, address: usize) -> Result<()> {
+        trap_vector::validate_address(address, self.raw_mode())?;
+        self.bits = address | self.raw_mode();
+        Ok(())
+    }
+
+    /// Returns the raw trap-vector mode bits
+    ///
+    /// Unlike [`Mtvec::trap_mode`], this also exposes reserved and vendor-specific encodings.
+    #[inline]
+    pub fn raw_mode(&self) -> usize {
+        trap_vector::raw_mode(self.bits)
     }
 
     /// Returns the trap-vector mode
+    ///
+    /// Returns `None` if the mode bits hold a reserved or vendor-specific encoding.
+    /// Use [`Mtvec::raw_mode`] to inspect them.
     #[inline]
     pub fn trap_mode(&self) -> Option<TrapMode> {
-        let mode = self.bits & 0b11;
-        match mode {
-            0 => Some(TrapMode::Direct),
-            1 => Some(TrapMode::Vectored),
-            _ => None,
-        }
+        trap_vector::trap_mode(self.bits)
+    }
+
+    /// Updates the trap-vector mode
+    ///
+    /// Note this updates a previously read [`Mtvec`] value, but does not
+    /// affect the mtvec CSR itself. See [`write()`] to directly update the CSR.
+    #[inline]
+    pub fn set_trap_mode(&mut self, mode: TrapMode) {
+        self.bits = self.address() | mode as usize;
     }
 }
 
-read_csr_as!(Mtvec, 0x305);
+impl TryFrom<Mtvec> for TrapVector {
+    type Error = Error;
 
+    #[inline]
+    fn try_from(mtvec: Mtvec) -> Result<Self> {
+        Self::try_from_bits(mtvec.bits)
+    }
+}
+
+impl TryFrom<TrapVector> for Mtvec {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(vector: TrapVector) -> Result<Self> {
+        Ok(Self {
+            bits: vector.try_into_bits()?,
+        })
+    }
+}
+
+read_csr_as!(Mtvec, 0x305);
 write_csr!(0x305);
 
 /// Writes the CSR
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - misaligned trap-vector base-address for the current trap mode
+#[inline]
+pub unsafe fn write(mtvec: Mtvec) {
+    try_write(mtvec).unwrap();
+}
+
+/// Attempts to write the CSR
+///
+/// Returns an error if the trap-vector base-address is not 4-byte aligned
+/// (64-byte aligned in [`TrapMode::Clic`] mode).
 #[inline]
-pub unsafe fn write(addr: usize, mode: TrapMode) {
-    let bits = addr + mode as usize;
-    _write(bits);
+pub unsafe fn try_write(mtvec: Mtvec) -> Result<()> {
+    trap_vector::validate_bits(mtvec.bits)?;
+    _try_write(mtvec.bits)
 }
@@ -0,0 +1,78 @@
+//! mtvt register (CLIC)
+//!
+//! Machine trap-handler vector table base address register.
+
+use crate::result::{Error, Result};
+
+/// mtvt register
+#[derive(Clone, Copy, Debug)]
+pub struct Mtvt {
+    bits: usize,
+}
+
+impl Mtvt {
+    /// Creates a new [`Mtvt`] value from a vector table base-address.
+    ///
+    /// Returns an error if the base-address is not 64-byte aligned.
+    #[inline]
+    pub fn new(address: usize) -> Result<Self> {
+        let mut mtvt = Self { bits: 0 };
+        mtvt.set_address(address)?;
+        Ok(mtvt)
+    }
+
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns the vector table base-address
+    #[inline]
+    pub fn address(&self) -> usize {
+        self.bits & !0x3F
+    }
+
+    /// Updates the vector table base-address
+    ///
+    /// Returns an error if the base-address is not 64-byte aligned.
+    ///
+    /// Note this updates a previously read [`Mtvt`] value, but does not
+    /// affect the mtvt CSR itself. See [`write()`] to directly update the CSR.
+    #[inline]
+    pub fn set_address(&mut self, address: usize) -> Result<()> {
+        if address & 0x3F != 0 {
+            Err(Error::InvalidFieldValue {
+                field: "address",
+                value: address,
+                bitmask: !0x3F,
+            })
+        } else {
+            self.bits = address;
+            Ok(())
+        }
+    }
+}
+
+read_csr_as!(Mtvt, 0x307);
+write_csr!(0x307);
+
+/// Writes the CSR
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - vector table base-address not 64-byte aligned
+#[inline]
+pub unsafe fn write(mtvt: Mtvt) {
+    try_write(mtvt).unwrap();
+}
+
+/// Attempts to write the CSR
+///
+/// Returns an error if the vector table base-address is not 64-byte aligned.
+#[inline]
+pub unsafe fn try_write(mtvt: Mtvt) -> Result<()> {
+    Mtvt::new(mtvt.bits)?;
+    _try_write(mtvt.bits)
+}
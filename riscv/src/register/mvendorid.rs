@@ -2,6 +2,9 @@
 
 use core::num::NonZeroUsize;
 
+/// JEDEC manufacturer ID of SiFive
+pub const SIFIVE: usize = 0x489;
+
 /// mvendorid register
 #[derive(Clone, Copy, Debug)]
 pub struct Mvendorid {
@@ -16,10 +19,23 @@ impl Mvendorid {
     }
 
     /// Returns the JEDEC manufacturer ID
+    ///
+    /// The register splits the JEDEC JEP106 encoding in two fields: this one is the bank of
+    /// the manufacturer ID (i.e., the number of `0x7F` continuation codes, so bank 1 is reported
+    /// as 0), and [`offset`](Self::offset) is the final byte within the bank.
     #[inline]
     pub fn jedec_manufacturer(&self) -> usize {
         self.bits() >> 7
     }
+
+    /// Returns the JEDEC offset of the manufacturer ID within its bank
+    /// (see [`jedec_manufacturer`](Self::jedec_manufacturer))
+    ///
+    /// This is the final byte of the JEDEC JEP106 encoding, without its parity bit.
+    #[inline]
+    pub fn offset(&self) -> u8 {
+        (self.bits() & 0x7F) as u8
+    }
 }
 
 read_csr!(0xF11);
@@ -32,3 +48,17 @@ pub fn read() -> Option<Mvendorid> {
     // csr isn't implemented.
     NonZeroUsize::new(r).map(|bits| Mvendorid { bits })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mvendorid() {
+        let mvendorid = Mvendorid {
+            bits: NonZeroUsize::new(SIFIVE).unwrap(),
+        };
+        assert_eq!(mvendorid.jedec_manufacturer(), 9);
+        assert_eq!(mvendorid.offset(), 0x09);
+    }
+}
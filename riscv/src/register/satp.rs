@@ -2,13 +2,62 @@
 
 use crate::result::{Error, Result};
 
+#[cfg(target_pointer_width = "32")]
+const MODE_SHIFT: usize = 31;
+#[cfg(target_pointer_width = "32")]
+const ASID_SHIFT: usize = 22;
+#[cfg(target_pointer_width = "32")]
+const ASID_MASK: usize = 0x1FF;
+#[cfg(target_pointer_width = "32")]
+const PPN_MASK: usize = 0x3F_FFFF;
+
+#[cfg(target_pointer_width = "64")]
+const MODE_SHIFT: usize = 60;
+#[cfg(target_pointer_width = "64")]
+const ASID_SHIFT: usize = 44;
+#[cfg(target_pointer_width = "64")]
+const ASID_MASK: usize = 0xFFFF;
+#[cfg(target_pointer_width = "64")]
+const PPN_MASK: usize = 0xFFF_FFFF_FFFF;
+
 /// satp register
 #[derive(Clone, Copy, Debug)]
 pub struct Satp {
-    bits: usize,
+    pub(super) bits: usize,
+}
+
+impl From<usize> for Satp {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
 }
 
 impl Satp {
+    /// Creates a new [`Satp`] value from a page table mode, address space id and physical page number.
+    ///
+    /// Returns an error if the address space id or the physical page number do not fit in their fields.
+    #[inline]
+    pub fn new(mode: Mode, asid: usize, ppn: usize) -> Result<Self> {
+        if asid & !ASID_MASK != 0 {
+            Err(Error::InvalidFieldValue {
+                field: "asid",
+                value: asid,
+                bitmask: ASID_MASK,
+            })
+        } else if ppn & !PPN_MASK != 0 {
+            Err(Error::InvalidFieldValue {
+                field: "ppn",
+                value: ppn,
+                bitmask: PPN_MASK,
+            })
+        } else {
+            Ok(Self {
+                bits: ((mode as usize) << MODE_SHIFT) | (asid << ASID_SHIFT) | ppn,
+            })
+        }
+    }
+
     /// Returns the contents of the register as raw bits
     #[inline]
     pub fn bits(&self) -> usize {
@@ -142,68 +191,58 @@ write_csr_as_usize!(0x180);
 
 /// Sets the register to corresponding page table mode, physical page number and address space id.
 ///
+/// If `sfence` is `true`, an `SFENCE.VMA` instruction is executed after the write
+/// to flush the stale address-translation caches of all address spaces.
+///
 /// **WARNING**: panics on:
 ///
 /// - non-`riscv` targets
 /// - invalid field values
 #[inline]
-#[cfg(target_pointer_width = "32")]
-pub unsafe fn set(mode: Mode, asid: usize, ppn: usize) {
-    try_set(mode, asid, ppn).unwrap();
+pub unsafe fn set(mode: Mode, asid: usize, ppn: usize, sfence: bool) {
+    try_set(mode, asid, ppn, sfence).unwrap();
 }
 
 /// Attempts to set the register to corresponding page table mode, physical page number and address space id.
-#[inline]
-#[cfg(target_pointer_width = "32")]
-pub unsafe fn try_set(mode: Mode, asid: usize, ppn: usize) -> Result<()> {
-    if asid != asid & 0x1FF {
-        Err(Error::InvalidFieldValue {
-            field: "asid",
-            value: asid,
-            bitmask: 0x1FF,
-        })
-    } else if ppn != ppn & 0x3F_FFFF {
-        Err(Error::InvalidFieldValue {
-            field: "ppn",
-            value: ppn,
-            bitmask: 0x3F_FFFF,
-        })
-    } else {
-        let bits = (mode as usize) << 31 | (asid << 22) | ppn;
-        _try_write(bits)
-    }
-}
-
-/// Sets the register to corresponding page table mode, physical page number and address space id.
 ///
-/// **WARNING**: panics on:
-///
-/// - non-`riscv` targets
-/// - invalid field values
+/// If `sfence` is `true`, an `SFENCE.VMA` instruction is executed after the write
+/// to flush the stale address-translation caches of all address spaces.
 #[inline]
-#[cfg(target_pointer_width = "64")]
-pub unsafe fn set(mode: Mode, asid: usize, ppn: usize) {
-    try_set(mode, asid, ppn).unwrap()
+pub unsafe fn try_set(mode: Mode, asid: usize, ppn: usize, sfence: bool) -> Result<()> {
+    _try_write(Satp::new(mode, asid, ppn)?.bits)?;
+    if sfence {
+        crate::asm::sfence_vma_all();
+    }
+    Ok(())
 }
 
-/// Attempts to set the register to corresponding page table mode, physical page number and address space id.
-#[inline]
-#[cfg(target_pointer_width = "64")]
-pub unsafe fn try_set(mode: Mode, asid: usize, ppn: usize) -> Result<()> {
-    if asid != asid & 0xFFFF {
-        Err(Error::InvalidFieldValue {
-            field: "asid",
-            value: asid,
-            bitmask: 0xFFFF,
-        })
-    } else if ppn != ppn & 0xFFF_FFFF_FFFF {
-        Err(Error::InvalidFieldValue {
-            field: "ppn",
-            value: ppn,
-            bitmask: 0xFFF_FFFF_FFFF,
-        })
-    } else {
-        let bits = (mode as usize) << 60 | (asid << 44) | ppn;
-        _try_write(bits)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_satp() {
+        let satp = Satp::new(Mode::Sv39, 0x1234, 0x8_0000).unwrap();
+        assert_eq!(satp.mode(), Mode::Sv39);
+        assert_eq!(satp.asid(), 0x1234);
+        assert_eq!(satp.ppn(), 0x8_0000);
+        assert_eq!(satp.bits(), (8 << 60) | (0x1234 << 44) | 0x8_0000);
+
+        assert_eq!(
+            Satp::new(Mode::Sv48, 0x1_0000, 0).unwrap_err(),
+            Error::InvalidFieldValue {
+                field: "asid",
+                value: 0x1_0000,
+                bitmask: 0xFFFF,
+            }
+        );
+        assert_eq!(
+            Satp::new(Mode::Sv57, 0, 1 << 44).unwrap_err(),
+            Error::InvalidFieldValue {
+                field: "ppn",
+                value: 1 << 44,
+                bitmask: 0xFFF_FFFF_FFFF,
+            }
+        );
     }
 }
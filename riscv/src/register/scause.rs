@@ -1,103 +1,19 @@
 //! scause register
 
+pub use crate::interrupt::{Exception, Interrupt, Trap};
+use crate::result::{Error, Result};
+use crate::{CoreInterruptNumber, ExceptionNumber};
+
 /// scause register
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub struct Scause {
     bits: usize,
 }
 
-/// Trap Cause
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub enum Trap {
-    Interrupt(Interrupt),
-    Exception(Exception),
-}
-
-/// Interrupt
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-#[repr(usize)]
-pub enum Interrupt {
-    SupervisorSoft = 1,
-    SupervisorTimer = 5,
-    SupervisorExternal = 9,
-    Unknown,
-}
-
-/// Exception
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-#[repr(usize)]
-pub enum Exception {
-    InstructionMisaligned = 0,
-    InstructionFault = 1,
-    IllegalInstruction = 2,
-    Breakpoint = 3,
-    LoadMisaligned = 4,
-    LoadFault = 5,
-    StoreMisaligned = 6,
-    StoreFault = 7,
-    UserEnvCall = 8,
-    SupervisorEnvCall = 9,
-    InstructionPageFault = 12,
-    LoadPageFault = 13,
-    StorePageFault = 15,
-    Unknown,
-}
-
-impl From<usize> for Interrupt {
+impl From<usize> for Scause {
     #[inline]
-    fn from(nr: usize) -> Self {
-        match nr {
-            1 => Self::SupervisorSoft,
-            5 => Self::SupervisorTimer,
-            9 => Self::SupervisorExternal,
-            _ => Self::Unknown,
-        }
-    }
-}
-
-impl TryFrom<Interrupt> for usize {
-    type Error = Interrupt;
-
-    #[inline]
-    fn try_from(value: Interrupt) -> Result<Self, Self::Error> {
-        match value {
-            Interrupt::Unknown => Err(Self::Error::Unknown),
-            _ => Ok(value as Self),
-        }
-    }
-}
-
-impl From<usize> for Exception {
-    #[inline]
-    fn from(nr: usize) -> Self {
-        match nr {
-            0 => Self::InstructionMisaligned,
-            1 => Self::InstructionFault,
-            2 => Self::IllegalInstruction,
-            3 => Self::Breakpoint,
-            4 => Self::LoadMisaligned,
-            5 => Self::LoadFault,
-            6 => Self::StoreMisaligned,
-            7 => Self::StoreFault,
-            8 => Self::UserEnvCall,
-            9 => Self::SupervisorEnvCall,
-            12 => Self::InstructionPageFault,
-            13 => Self::LoadPageFault,
-            15 => Self::StorePageFault,
-            _ => Self::Unknown,
-        }
-    }
-}
-
-impl TryFrom<Exception> for usize {
-    type Error = Exception;
-
-    #[inline]
-    fn try_from(value: Exception) -> Result<Self, Self::Error> {
-        match value {
-            Exception::Unknown => Err(Self::Error::Unknown),
-            _ => Ok(value as Self),
-        }
+    fn from(bits: usize) -> Self {
+        Self { bits }
     }
 }
 
@@ -115,13 +31,21 @@ impl Scause {
     }
 
     /// Trap Cause
+    ///
+    /// The interrupt (`I`) and exception (`E`) types are target-specific.
+    ///
+    /// **WARNING**: panics if the code field does not match any variant of `I` or `E`.
+    #[inline]
+    pub fn cause<I: CoreInterruptNumber, E: ExceptionNumber>(&self) -> Trap<I, E> {
+        self.try_cause().unwrap()
+    }
+
+    /// Attempts to get the trap cause.
+    ///
+    /// Returns an error if the code field does not match any variant of `I` or `E`.
     #[inline]
-    pub fn cause(&self) -> Trap {
-        if self.is_interrupt() {
-            Trap::Interrupt(Interrupt::from(self.code()))
-        } else {
-            Trap::Exception(Exception::from(self.code()))
-        }
+    pub fn try_cause<I: CoreInterruptNumber, E: ExceptionNumber>(&self) -> Result<Trap<I, E>> {
+        Trap::try_from_bits(self.bits)
     }
 
     /// Is trap cause an interrupt.
@@ -137,6 +61,15 @@ impl Scause {
     }
 }
 
+impl<I: CoreInterruptNumber, E: ExceptionNumber> TryFrom<Scause> for Trap<I, E> {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(scause: Scause) -> Result<Self> {
+        scause.try_cause()
+    }
+}
+
 read_csr_as!(Scause, 0x142);
 write_csr!(0x142);
 
@@ -148,13 +81,6 @@ pub unsafe fn write(bits: usize) {
 
 /// Set supervisor cause register to corresponding cause.
 #[inline]
-pub unsafe fn set(cause: Trap) {
-    let bits = match cause {
-        Trap::Interrupt(i) => {
-            let i = usize::try_from(i).expect("unknown interrupt");
-            i | (1 << (usize::BITS as usize - 1)) // interrupt bit is 1
-        }
-        Trap::Exception(e) => usize::try_from(e).expect("unknown exception"),
-    };
-    _write(bits);
+pub unsafe fn set<I: CoreInterruptNumber, E: ExceptionNumber>(cause: Trap<I, E>) {
+    _write(cause.into_bits());
 }
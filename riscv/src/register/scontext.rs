@@ -0,0 +1,99 @@
+//! scontext register (Sdtrig)
+//!
+//! Supervisor context register. Triggers can be configured to only fire when the
+//! `DATA` field matches a given value (e.g., to tag processes).
+//!
+//! The width of the field depends on XLEN: 16 bits on RV32 and 32 bits on RV64.
+
+use crate::result::{Error, Result};
+
+/// Mask of the `DATA` field
+#[cfg(target_pointer_width = "32")]
+pub const DATA_MASK: usize = 0xFFFF;
+/// Mask of the `DATA` field
+#[cfg(not(target_pointer_width = "32"))]
+pub const DATA_MASK: usize = 0xFFFF_FFFF;
+
+/// scontext register
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Scontext {
+    bits: usize,
+}
+
+impl From<usize> for Scontext {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Scontext {
+    /// Creates a new [`Scontext`] value from a context value.
+    ///
+    /// Returns an error if `data` does not fit in the field (see [`DATA_MASK`]).
+    #[inline]
+    pub fn new(data: usize) -> Result<Self> {
+        if data & !DATA_MASK != 0 {
+            Err(Error::InvalidFieldValue {
+                field: "data",
+                value: data,
+                bitmask: DATA_MASK,
+            })
+        } else {
+            Ok(Self { bits: data })
+        }
+    }
+
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Context value
+    #[inline]
+    pub fn data(&self) -> usize {
+        self.bits & DATA_MASK
+    }
+}
+
+read_csr_as!(Scontext, 0x5A8);
+write_csr_as!(Scontext, 0x5A8);
+
+/// Sets the context value
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `data` does not fit in the field
+#[inline]
+pub unsafe fn set(data: usize) {
+    try_set(data).unwrap();
+}
+
+/// Attempts to set the context value
+///
+/// Returns an error if `data` does not fit in the field (see [`DATA_MASK`]).
+#[inline]
+pub unsafe fn try_set(data: usize) -> Result<()> {
+    try_write(Scontext::new(data)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scontext() {
+        assert_eq!(Scontext::new(0x1234).unwrap().data(), 0x1234);
+        assert_eq!(Scontext::from(!0).data(), DATA_MASK);
+        assert_eq!(
+            Scontext::new(DATA_MASK + 1),
+            Err(Error::InvalidFieldValue {
+                field: "data",
+                value: DATA_MASK + 1,
+                bitmask: DATA_MASK,
+            })
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! scounteren register
 
+use crate::bits::bf_insert;
 use crate::result::{Error, Result};
 
 /// scounteren register
@@ -8,25 +9,62 @@ pub struct Scounteren {
     bits: usize,
 }
 
+impl From<usize> for Scounteren {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
 impl Scounteren {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
     /// User "cycle\[h\]" Enable
     #[inline]
     pub fn cy(&self) -> bool {
         self.bits & (1 << 0) != 0
     }
 
+    /// Sets whether to enable the "cycle\[h\]" counter.
+    ///
+    /// Only updates the in-memory value, does not modify the `scounteren` register.
+    #[inline]
+    pub fn set_cy(&mut self, cy: bool) {
+        self.bits = bf_insert(self.bits, 0, 1, cy as usize);
+    }
+
     /// User "time\[h\]" Enable
     #[inline]
     pub fn tm(&self) -> bool {
         self.bits & (1 << 1) != 0
     }
 
+    /// Sets whether to enable "time\[h\]".
+    ///
+    /// Only updates the in-memory value, does not modify the `scounteren` register.
+    #[inline]
+    pub fn set_tm(&mut self, tm: bool) {
+        self.bits = bf_insert(self.bits, 1, 1, tm as usize);
+    }
+
     /// User "instret\[h]\" Enable
     #[inline]
     pub fn ir(&self) -> bool {
         self.bits & (1 << 2) != 0
     }
 
+    /// Sets whether to enable the "instret\[h\]" counter.
+    ///
+    /// Only updates the in-memory value, does not modify the `scounteren` register.
+    #[inline]
+    pub fn set_ir(&mut self, ir: bool) {
+        self.bits = bf_insert(self.bits, 2, 1, ir as usize);
+    }
+
     /// User "hpm\[x\]" Enable (bits 3-31)
     #[inline]
     pub fn hpm(&self, index: usize) -> bool {
@@ -49,10 +87,39 @@ impl Scounteren {
             })
         }
     }
+
+    /// Sets whether to enable the "hpm\[X\]" counter.
+    ///
+    /// Only updates the in-memory value, does not modify the `scounteren` register.
+    ///
+    /// **WARNING**: panics on `index` out-of-bounds
+    #[inline]
+    pub fn set_hpm(&mut self, index: usize, hpm: bool) {
+        self.try_set_hpm(index, hpm).unwrap()
+    }
+
+    /// Sets whether to enable the "hpm\[X\]" counter.
+    ///
+    /// Only updates the in-memory value, does not modify the `scounteren` register.
+    ///
+    /// Attempts to update the "hpm\[x\]" value, and returns an error if the `index` is invalid.
+    #[inline]
+    pub fn try_set_hpm(&mut self, index: usize, hpm: bool) -> Result<()> {
+        if (3..32).contains(&index) {
+            self.bits = bf_insert(self.bits, index, 1, hpm as usize);
+            Ok(())
+        } else {
+            Err(Error::IndexOutOfBounds {
+                index,
+                min: 3,
+                max: 31,
+            })
+        }
+    }
 }
 
 read_csr_as!(Scounteren, 0x106);
-write_csr!(0x106);
+write_csr_as!(Scounteren, 0x106);
 set!(0x106);
 clear!(0x106);
 
@@ -0,0 +1,83 @@
+//! scountovf register (Sscofpmf extension)
+//!
+//! Read-only shadow copy of the `OF` bits of the `mhpmevent3`-`mhpmevent31` CSRs.
+//! It allows supervisor-mode overflow interrupt handlers to identify which counters overflowed.
+
+use crate::bits::bf_extract;
+use crate::result::{Error, Result};
+
+/// scountovf register
+#[derive(Clone, Copy, Debug)]
+pub struct Scountovf {
+    bits: usize,
+}
+
+impl From<usize> for Scountovf {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Scountovf {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// "hpm\[x\]" overflow flag (bits 3-31)
+    ///
+    /// **WARNING**: panics if the index is out of range.
+    #[inline]
+    pub fn hpm(&self, index: usize) -> bool {
+        self.try_hpm(index).unwrap()
+    }
+
+    /// "hpm\[x\]" overflow flag (bits 3-31)
+    ///
+    /// Attempts to read the "hpm\[x\]" value, and returns an error if the index is invalid.
+    #[inline]
+    pub fn try_hpm(&self, index: usize) -> Result<bool> {
+        if (3..32).contains(&index) {
+            Ok(bf_extract(self.bits, index, 1) != 0)
+        } else {
+            Err(Error::IndexOutOfBounds {
+                index,
+                min: 3,
+                max: 31,
+            })
+        }
+    }
+
+    /// Returns an iterator over the indices of the counters that overflowed
+    #[inline]
+    pub fn overflowed(&self) -> impl Iterator<Item = usize> {
+        let bits = self.bits;
+        (3..32).filter(move |&index| bf_extract(bits, index, 1) != 0)
+    }
+}
+
+read_csr_as!(Scountovf, 0xDA0);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scountovf() {
+        let scountovf = Scountovf::from((1 << 3) | (1 << 31) | 0b111);
+        assert!(scountovf.hpm(3));
+        assert!(!scountovf.hpm(4));
+        assert!(scountovf.hpm(31));
+        assert_eq!(
+            scountovf.try_hpm(2),
+            Err(Error::IndexOutOfBounds {
+                index: 2,
+                min: 3,
+                max: 31,
+            })
+        );
+        assert!(scountovf.overflowed().eq([3, 31]));
+    }
+}
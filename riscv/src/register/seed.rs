@@ -0,0 +1,170 @@
+//! seed register (Zkr)
+//!
+//! Entropy source register. Each read returns the state of the entropy source and, when ready,
+//! 16 bits of entropy. The register must be accessed with a read-write instruction (`csrrw`),
+//! which is what this module does.
+//!
+//! S-mode and U-mode accesses must be enabled by M-mode with the `SSEED` and `USEED` bits
+//! of `mseccfg`, respectively (see [`mseccfg::set_sseed`](super::mseccfg::set_sseed)
+//! and [`mseccfg::set_useed`](super::mseccfg::set_useed)).
+//!
+//! Note that the entropy must be conditioned (e.g., with a cryptographic hash function)
+//! before being used as random numbers.
+
+use crate::result::{Error, Result};
+
+/// seed register
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Seed {
+    bits: usize,
+}
+
+impl From<usize> for Seed {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Seed {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Status of the entropy source
+    #[inline]
+    pub fn opst(&self) -> Opst {
+        match (self.bits >> 30) & 0b11 {
+            0b00 => Opst::Bist,
+            0b01 => Opst::Wait,
+            0b10 => Opst::Es16,
+            _ => Opst::Dead,
+        }
+    }
+
+    /// 16 bits of entropy, only valid if [`opst`](Self::opst) is [`Opst::Es16`]
+    #[inline]
+    pub fn entropy(&self) -> u16 {
+        self.bits as u16
+    }
+}
+
+/// Status of the entropy source
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Opst {
+    /// Built-in self test in progress: retry later
+    Bist = 0,
+    /// Not enough entropy available yet: retry later
+    Wait = 1,
+    /// 16 bits of entropy available
+    Es16 = 2,
+    /// Unrecoverable self-test failure
+    Dead = 3,
+}
+
+/// Reads the CSR.
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn read() -> Seed {
+    try_read().unwrap()
+}
+
+/// Attempts to read the CSR.
+#[inline]
+pub fn try_read() -> Result<Seed> {
+    match () {
+        #[cfg(riscv)]
+        () => {
+            let r: usize;
+            // a read-only access (i.e., `csrrs`) raises an illegal-instruction exception
+            unsafe { core::arch::asm!("csrrw {0}, 0x015, x0", out(reg) r) };
+            Ok(Seed::from(r))
+        }
+
+        #[cfg(not(riscv))]
+        () => Err(Error::Unimplemented),
+    }
+}
+
+/// Polls the entropy source once.
+///
+/// Returns `Some(entropy)` with 16 bits of entropy, or `None` if the entropy source is not
+/// ready yet ([`Opst::Bist`] or [`Opst::Wait`]).
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - the entropy source is [`Opst::Dead`]
+#[inline]
+pub fn poll_entropy() -> Option<u16> {
+    try_poll_entropy().unwrap()
+}
+
+/// Attempts to poll the entropy source once.
+///
+/// Returns `Ok(Some(entropy))` with 16 bits of entropy, or `Ok(None)` if the entropy source is not
+/// ready yet ([`Opst::Bist`] or [`Opst::Wait`]). Returns [`Error::InvalidFieldVariant`] if the
+/// entropy source is [`Opst::Dead`].
+#[inline]
+pub fn try_poll_entropy() -> Result<Option<u16>> {
+    let seed = try_read()?;
+    match seed.opst() {
+        Opst::Es16 => Ok(Some(seed.entropy())),
+        Opst::Bist | Opst::Wait => Ok(None),
+        Opst::Dead => Err(Error::InvalidFieldVariant {
+            field: "opst",
+            value: Opst::Dead as usize,
+        }),
+    }
+}
+
+/// Fills `buf` with entropy, waiting for the entropy source if necessary.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - the entropy source is [`Opst::Dead`]
+#[inline]
+pub fn read_entropy(buf: &mut [u8]) {
+    try_read_entropy(buf).unwrap();
+}
+
+/// Attempts to fill `buf` with entropy, waiting for the entropy source if necessary.
+///
+/// Returns [`Error::InvalidFieldVariant`] if the entropy source is [`Opst::Dead`].
+/// In this case, `buf` may be partially filled.
+#[inline]
+pub fn try_read_entropy(buf: &mut [u8]) -> Result<()> {
+    for chunk in buf.chunks_mut(2) {
+        let entropy = loop {
+            if let Some(entropy) = try_poll_entropy()? {
+                break entropy;
+            }
+            core::hint::spin_loop();
+        };
+        chunk.copy_from_slice(&entropy.to_ne_bytes()[..chunk.len()]);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seed() {
+        let seed = Seed::from((0b10 << 30) | 0xBEEF);
+        assert_eq!(seed.opst(), Opst::Es16);
+        assert_eq!(seed.entropy(), 0xBEEF);
+        assert_eq!(Seed::from(0).opst(), Opst::Bist);
+        assert_eq!(Seed::from(0b01 << 30).opst(), Opst::Wait);
+        assert_eq!(Seed::from(0b11 << 30).opst(), Opst::Dead);
+
+        assert_eq!(try_poll_entropy(), Err(Error::Unimplemented));
+        assert_eq!(try_read_entropy(&mut [0; 3]), Err(Error::Unimplemented));
+        assert_eq!(try_read_entropy(&mut []), Ok(()));
+    }
+}
@@ -0,0 +1,224 @@
+//! senvcfg register
+//!
+//! Supervisor environment configuration register.
+//! It controls the execution environment of U-mode.
+
+pub use super::menvcfg::{Cbie, Pmm};
+use crate::result::Result;
+
+/// senvcfg register
+#[derive(Clone, Copy, Debug)]
+pub struct Senvcfg {
+    bits: usize,
+}
+
+impl From<usize> for Senvcfg {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Senvcfg {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    #[inline]
+    fn bit(&self, index: usize) -> bool {
+        self.bits & (1 << index) != 0
+    }
+
+    #[inline]
+    fn set_bit(&mut self, index: usize, value: bool) {
+        self.bits = (self.bits & !(1 << index)) | ((value as usize) << index);
+    }
+
+    /// Fence of I/O implies Memory (`FIOM`)
+    ///
+    /// When set, `FENCE` instructions ordering I/O accesses of the less privileged modes also order memory accesses.
+    #[inline]
+    pub fn fiom(&self) -> bool {
+        self.bit(0)
+    }
+
+    /// Update Fence of I/O implies Memory (`FIOM`)
+    ///
+    /// Note this updates a previously read [`Senvcfg`] value, but does not
+    /// affect the senvcfg CSR itself. See [`set_fiom`]/[`clear_fiom`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_fiom(&mut self, fiom: bool) {
+        self.set_bit(0, fiom);
+    }
+
+    /// Landing Pad Enable (`LPE`, Zicfilp)
+    #[inline]
+    pub fn lpe(&self) -> bool {
+        self.bit(2)
+    }
+
+    /// Update Landing Pad Enable (`LPE`, Zicfilp)
+    ///
+    /// Note this updates a previously read [`Senvcfg`] value, but does not
+    /// affect the senvcfg CSR itself. See [`set_lpe`]/[`clear_lpe`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_lpe(&mut self, lpe: bool) {
+        self.set_bit(2, lpe);
+    }
+
+    /// Shadow Stack Enable (`SSE`, Zicfiss)
+    #[inline]
+    pub fn sse(&self) -> bool {
+        self.bit(3)
+    }
+
+    /// Update Shadow Stack Enable (`SSE`, Zicfiss)
+    ///
+    /// Note this updates a previously read [`Senvcfg`] value, but does not
+    /// affect the senvcfg CSR itself. See [`set_sse`]/[`clear_sse`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_sse(&mut self, sse: bool) {
+        self.set_bit(3, sse);
+    }
+
+    /// Cache Block Invalidate instruction Enable (`CBIE`, Zicbom)
+    ///
+    /// **WARNING**: panics if the field has an invalid variant.
+    #[inline]
+    pub fn cbie(&self) -> Cbie {
+        self.try_cbie().unwrap()
+    }
+
+    /// Attempts to get the cache block invalidate instruction enable (`CBIE`, Zicbom)
+    #[inline]
+    pub fn try_cbie(&self) -> Result<Cbie> {
+        (((self.bits >> 4) & 0b11) as u8).try_into()
+    }
+
+    /// Update Cache Block Invalidate instruction Enable (`CBIE`, Zicbom)
+    ///
+    /// Note this updates a previously read [`Senvcfg`] value, but does not
+    /// affect the senvcfg CSR itself. See [`set_cbie`] to directly update the CSR.
+    #[inline]
+    pub fn set_cbie(&mut self, cbie: Cbie) {
+        self.bits = (self.bits & !(0b11 << 4)) | ((cbie as usize) << 4);
+    }
+
+    /// Cache Block Clean and Flush Enable (`CBCFE`, Zicbom)
+    #[inline]
+    pub fn cbcfe(&self) -> bool {
+        self.bit(6)
+    }
+
+    /// Update Cache Block Clean and Flush Enable (`CBCFE`, Zicbom)
+    ///
+    /// Note this updates a previously read [`Senvcfg`] value, but does not
+    /// affect the senvcfg CSR itself. See [`set_cbcfe`]/[`clear_cbcfe`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_cbcfe(&mut self, cbcfe: bool) {
+        self.set_bit(6, cbcfe);
+    }
+
+    /// Cache Block Zero Enable (`CBZE`, Zicboz)
+    #[inline]
+    pub fn cbze(&self) -> bool {
+        self.bit(7)
+    }
+
+    /// Update Cache Block Zero Enable (`CBZE`, Zicboz)
+    ///
+    /// Note this updates a previously read [`Senvcfg`] value, but does not
+    /// affect the senvcfg CSR itself. See [`set_cbze`]/[`clear_cbze`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_cbze(&mut self, cbze: bool) {
+        self.set_bit(7, cbze);
+    }
+
+    /// Pointer masking mode of U-mode (Ssnpm, RV64 only)
+    ///
+    /// **WARNING**: panics if the field has an invalid variant.
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn pmm(&self) -> Pmm {
+        self.try_pmm().unwrap()
+    }
+
+    /// Attempts to get the pointer masking mode of U-mode (Ssnpm, RV64 only)
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn try_pmm(&self) -> Result<Pmm> {
+        (((self.bits >> 32) & 0b11) as u8).try_into()
+    }
+
+    /// Updates the pointer masking mode of U-mode (Ssnpm, RV64 only)
+    ///
+    /// Note this updates a previously read [`Senvcfg`] value, but does not
+    /// affect the senvcfg CSR itself. See [`set_pmm`] to directly update the CSR.
+    #[cfg(target_pointer_width = "64")]
+    #[inline]
+    pub fn set_pmm(&mut self, pmm: Pmm) {
+        self.bits = (self.bits & !(0b11 << 32)) | ((pmm as usize) << 32);
+    }
+}
+
+read_csr_as!(Senvcfg, 0x10A);
+write_csr_as!(Senvcfg, 0x10A);
+set!(0x10A);
+clear!(0x10A);
+
+set_clear_csr!(
+    /// Fence of I/O implies Memory (`FIOM`)
+    , set_fiom, clear_fiom, 1 << 0);
+set_clear_csr!(
+    /// Landing Pad Enable (`LPE`, Zicfilp)
+    , set_lpe, clear_lpe, 1 << 2);
+set_clear_csr!(
+    /// Shadow Stack Enable (`SSE`, Zicfiss)
+    , set_sse, clear_sse, 1 << 3);
+set_clear_csr!(
+    /// Cache Block Clean and Flush Enable (`CBCFE`, Zicbom)
+    , set_cbcfe, clear_cbcfe, 1 << 6);
+set_clear_csr!(
+    /// Cache Block Zero Enable (`CBZE`, Zicboz)
+    , set_cbze, clear_cbze, 1 << 7);
+
+/// Sets the cache block invalidate instruction enable (`CBIE`, Zicbom)
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub unsafe fn set_cbie(cbie: Cbie) {
+    try_set_cbie(cbie).unwrap();
+}
+
+/// Attempts to set the cache block invalidate instruction enable (`CBIE`, Zicbom)
+#[inline]
+pub unsafe fn try_set_cbie(cbie: Cbie) -> Result<()> {
+    let mut senvcfg = try_read()?;
+    senvcfg.set_cbie(cbie);
+    try_write(senvcfg)
+}
+
+/// Sets the pointer masking mode of U-mode (Ssnpm, RV64 only)
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[cfg(target_pointer_width = "64")]
+#[inline]
+pub unsafe fn set_pmm(pmm: Pmm) {
+    try_set_pmm(pmm).unwrap();
+}
+
+/// Attempts to set the pointer masking mode of U-mode (Ssnpm, RV64 only)
+#[cfg(target_pointer_width = "64")]
+#[inline]
+pub unsafe fn try_set_pmm(pmm: Pmm) -> Result<()> {
+    let mut senvcfg = try_read()?;
+    senvcfg.set_pmm(pmm);
+    try_write(senvcfg)
+}
@@ -1,11 +1,22 @@
 //! sie register
 
+use crate::bits::{bf_extract, bit_mask};
+use crate::result::Result;
+use crate::CoreInterruptNumber;
+
 /// sie register
 #[derive(Clone, Copy, Debug)]
 pub struct Sie {
     bits: usize,
 }
 
+impl From<usize> for Sie {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
 impl Sie {
     /// Returns the contents of the register as raw bits
     #[inline]
@@ -30,6 +41,19 @@ impl Sie {
     pub fn sext(&self) -> bool {
         self.bits & (1 << 9) != 0
     }
+
+    /// Local Counter Overflow Interrupt Enable (Sscofpmf extension)
+    #[inline]
+    pub fn lcofi(&self) -> bool {
+        self.bits & (1 << 13) != 0
+    }
+
+    /// Returns `true` if the given interrupt source is enabled
+    #[inline]
+    pub fn is_enabled<I: CoreInterruptNumber>(&self, interrupt: I) -> bool {
+        let index = interrupt.number() as usize;
+        index < usize::BITS as usize && bf_extract(self.bits, index, 1) != 0
+    }
 }
 
 read_csr_as!(Sie, 0x104);
@@ -45,3 +69,52 @@ set_clear_csr!(
 set_clear_csr!(
     /// Supervisor External Interrupt Enable
     , set_sext, clear_sext, 1 << 9);
+set_clear_csr!(
+    /// Local Counter Overflow Interrupt Enable (Sscofpmf extension)
+    , set_lcofi, clear_lcofi, 1 << 13);
+
+/// Enables the given interrupt source
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - interrupt numbers that do not fit in the register
+#[inline]
+pub unsafe fn enable<I: CoreInterruptNumber>(interrupt: I) {
+    try_enable(interrupt).unwrap();
+}
+
+/// Attempts to enable the given interrupt source
+///
+/// Returns an error if the interrupt number does not fit in the register.
+#[inline]
+pub unsafe fn try_enable<I: CoreInterruptNumber>(interrupt: I) -> Result<()> {
+    _try_set(bit_mask(interrupt.number() as usize)?)
+}
+
+/// Disables the given interrupt source
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - interrupt numbers that do not fit in the register
+#[inline]
+pub unsafe fn disable<I: CoreInterruptNumber>(interrupt: I) {
+    try_disable(interrupt).unwrap();
+}
+
+/// Attempts to disable the given interrupt source
+///
+/// Returns an error if the interrupt number does not fit in the register.
+#[inline]
+pub unsafe fn try_disable<I: CoreInterruptNumber>(interrupt: I) -> Result<()> {
+    _try_clear(bit_mask(interrupt.number() as usize)?)
+}
+
+/// Returns `true` if the given interrupt source is enabled
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn is_enabled<I: CoreInterruptNumber>(interrupt: I) -> bool {
+    read().is_enabled(interrupt)
+}
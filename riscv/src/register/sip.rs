@@ -1,11 +1,22 @@
 //! sip register
 
+use crate::bits::{bf_extract, bit_mask};
+use crate::result::Result;
+use crate::CoreInterruptNumber;
+
 /// sip register
 #[derive(Clone, Copy, Debug)]
 pub struct Sip {
     bits: usize,
 }
 
+impl From<usize> for Sip {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
 impl Sip {
     /// Returns the contents of the register as raw bits
     #[inline]
@@ -30,6 +41,19 @@ impl Sip {
     pub fn sext(&self) -> bool {
         self.bits & (1 << 9) != 0
     }
+
+    /// Local Counter Overflow Interrupt Pending (Sscofpmf extension)
+    #[inline]
+    pub fn lcofi(&self) -> bool {
+        self.bits & (1 << 13) != 0
+    }
+
+    /// Returns `true` if the given interrupt source is pending
+    #[inline]
+    pub fn is_pending<I: CoreInterruptNumber>(&self, interrupt: I) -> bool {
+        let index = interrupt.number() as usize;
+        index < usize::BITS as usize && bf_extract(self.bits, index, 1) != 0
+    }
 }
 
 read_csr_as!(Sip, 0x144);
@@ -39,3 +63,39 @@ clear!(0x144);
 set_clear_csr!(
     /// Supervisor Software Interrupt Pending
     , set_ssoft, clear_ssoft, 1 << 1);
+set_clear_csr!(
+    /// Local Counter Overflow Interrupt Pending (Sscofpmf extension)
+    , set_lcofi, clear_lcofi, 1 << 13);
+
+/// Returns `true` if the given interrupt source is pending
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn is_pending<I: CoreInterruptNumber>(interrupt: I) -> bool {
+    read().is_pending(interrupt)
+}
+
+/// Clears the pending bit of the given interrupt source
+///
+/// Only writable pending bits (e.g., `SSIP` or `LCOFIP`) can be cleared.
+/// This is typically used to acknowledge supervisor software self-interrupts.
+/// Read-only pending bits (e.g., `STIP` or `SEIP`) must be cleared at the interrupt source,
+/// and writes to them are silently ignored.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - interrupt numbers that do not fit in the register
+#[inline]
+pub unsafe fn clear<I: CoreInterruptNumber>(interrupt: I) {
+    try_clear(interrupt).unwrap();
+}
+
+/// Attempts to clear the pending bit of the given interrupt source
+///
+/// Returns an error if the interrupt number does not fit in the register.
+/// See [`clear()`] for details on which pending bits are writable.
+#[inline]
+pub unsafe fn try_clear<I: CoreInterruptNumber>(interrupt: I) -> Result<()> {
+    _try_clear(bit_mask(interrupt.number() as usize)?)
+}
@@ -0,0 +1,29 @@
+//! sireg register (Ssaia)
+//!
+//! Supervisor indirect register alias. It accesses the register selected by [`siselect`](super::siselect).
+//! See [`aia::supervisor`](crate::aia::supervisor) for accesses that are protected against trap handlers.
+
+read_csr_as_usize!(0x151);
+write_csr!(0x151);
+
+/// Writes the register selected by [`siselect`](super::siselect).
+///
+/// **WARNING**: panics on non-`riscv` targets.
+///
+/// # Safety
+///
+/// Writing the selected register may change the interrupt configuration of the hart.
+#[inline]
+pub unsafe fn write(bits: usize) {
+    _write(bits)
+}
+
+/// Attempts to write the register selected by [`siselect`](super::siselect).
+///
+/// # Safety
+///
+/// See [`write()`].
+#[inline]
+pub unsafe fn try_write(bits: usize) -> crate::result::Result<()> {
+    _try_write(bits)
+}
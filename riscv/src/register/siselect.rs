@@ -0,0 +1,11 @@
+//! siselect register (Ssaia)
+//!
+//! Supervisor indirect register select. It selects the register accessed through [`sireg`](super::sireg),
+//! e.g., the major interrupt priorities or the registers of the supervisor-level IMSIC interrupt file.
+//!
+//! A trap handler that uses the window between the selection and the access changes the selection.
+//! See [`aia::supervisor`](crate::aia::supervisor) for accesses that are protected against this.
+
+read_csr_as_usize!(0x150);
+write_csr_as_usize!(0x150);
+swap_csr_as_usize!(0x150);
@@ -0,0 +1,138 @@
+//! CSR snapshots
+//!
+//! Snapshot of the M-mode CSRs for post-mortem logging (e.g., from panic or trap handlers).
+//!
+//! Only CSRs that every M-mode implementation provides are captured, so that taking a snapshot
+//! never raises an illegal-instruction exception.
+
+use super::machine_id::{self, MachineId};
+use super::mcause::{self, Mcause};
+use super::mie::{self, Mie};
+use super::mip::{self, Mip};
+use super::misa::{self, Misa};
+use super::mstatus::{self, Mstatus};
+use super::mtvec::{self, Mtvec};
+use super::{mcycle, mepc, minstret, mscratch, mtval};
+use core::fmt;
+
+/// Snapshot of the M-mode CSRs
+///
+/// The [`Debug`](fmt::Debug) implementation renders the raw contents of the registers in hexadecimal.
+#[derive(Clone, Copy)]
+pub struct MachineSnapshot {
+    /// Machine identification registers
+    pub id: MachineId,
+    /// ISA and extensions (`None` if not implemented)
+    pub misa: Option<Misa>,
+    /// Machine status
+    pub mstatus: Mstatus,
+    /// Machine trap-handler base address
+    pub mtvec: Mtvec,
+    /// Machine interrupt enable
+    pub mie: Mie,
+    /// Machine interrupt pending
+    pub mip: Mip,
+    /// Machine trap cause
+    pub mcause: Mcause,
+    /// Machine exception program counter
+    pub mepc: usize,
+    /// Machine trap value
+    pub mtval: usize,
+    /// Machine scratch
+    pub mscratch: usize,
+    /// Machine cycle counter
+    pub mcycle: u64,
+    /// Machine instructions-retired counter
+    pub minstret: u64,
+}
+
+/// Renders a value in hexadecimal
+struct Hex<T>(T);
+
+impl<T: fmt::LowerHex> fmt::Debug for Hex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:#x}", self.0)
+    }
+}
+
+impl fmt::Debug for MachineSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bits = |value: Option<usize>| Hex(value.unwrap_or(0));
+        f.debug_struct("MachineSnapshot")
+            .field("mhartid", &self.id.mhartid)
+            .field("mvendorid", &bits(self.id.mvendorid.map(|r| r.bits())))
+            .field("marchid", &bits(self.id.marchid.map(|r| r.bits())))
+            .field("mimpid", &bits(self.id.mimpid.map(|r| r.bits())))
+            .field("misa", &bits(self.misa.map(|r| r.bits())))
+            .field("mstatus", &Hex(self.mstatus.bits()))
+            .field("mtvec", &Hex(self.mtvec.bits()))
+            .field("mie", &Hex(self.mie.bits()))
+            .field("mip", &Hex(self.mip.bits()))
+            .field("mcause", &Hex(self.mcause.bits()))
+            .field("mepc", &Hex(self.mepc))
+            .field("mtval", &Hex(self.mtval))
+            .field("mscratch", &Hex(self.mscratch))
+            .field("mcycle", &self.mcycle)
+            .field("minstret", &self.minstret)
+            .finish()
+    }
+}
+
+/// Captures a snapshot of the M-mode CSRs
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn capture_machine() -> MachineSnapshot {
+    MachineSnapshot {
+        id: machine_id::read(),
+        misa: misa::read(),
+        mstatus: mstatus::read(),
+        mtvec: mtvec::read(),
+        mie: mie::read(),
+        mip: mip::read(),
+        mcause: mcause::read(),
+        mepc: mepc::read(),
+        mtval: mtval::read(),
+        mscratch: mscratch::read(),
+        mcycle: mcycle::read64(),
+        minstret: minstret::read64(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::register::mtvec::TrapMode;
+
+    #[test]
+    fn test_debug() {
+        extern crate std;
+        use std::format;
+
+        let snapshot = MachineSnapshot {
+            id: MachineId {
+                mvendorid: None,
+                marchid: None,
+                mimpid: None,
+                mhartid: 1,
+            },
+            misa: None,
+            mstatus: Mstatus::from(0x1800),
+            mtvec: Mtvec::new(0x8000_0000, TrapMode::Direct).unwrap(),
+            mie: Mie::from(0x80),
+            mip: Mip::from(0),
+            mcause: Mcause::from(2),
+            mepc: 0x8000_1234,
+            mtval: 0,
+            mscratch: 0,
+            mcycle: 100,
+            minstret: 50,
+        };
+        assert_eq!(
+            format!("{snapshot:?}"),
+            "MachineSnapshot { mhartid: 1, mvendorid: 0x0, marchid: 0x0, mimpid: 0x0, misa: 0x0, \
+             mstatus: 0x1800, mtvec: 0x80000000, mie: 0x80, mip: 0x0, mcause: 0x2, mepc: 0x80001234, \
+             mtval: 0x0, mscratch: 0x0, mcycle: 100, minstret: 50 }"
+        );
+    }
+}
@@ -1,4 +1,9 @@
 //! sscratch register
+//!
+//! Scratch register for S-mode trap handlers. It usually holds a pointer to the
+//! hart-local context (e.g., the trap handler stack), which the trap entry exchanges
+//! with `sp` using `csrrw sp, sscratch, sp`. The same exchange is available as [`swap`].
 
 read_csr_as_usize!(0x140);
 write_csr_as_usize!(0x140);
+swap_csr_as_usize!(0x140);
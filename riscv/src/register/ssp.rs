@@ -0,0 +1,34 @@
+//! ssp register (Zicfiss)
+//!
+//! Shadow stack pointer. It holds the address of the top of the shadow stack of the current
+//! context, so it must be saved and restored when switching contexts.
+//!
+//! Shadow stacks are enabled for the less privileged modes with the `SSE` bit of
+//! [`menvcfg`](super::menvcfg) and [`senvcfg`](super::senvcfg). The shadow stack instructions
+//! are available in [`asm`](crate::asm) (e.g., `sspush`).
+
+read_csr_as_usize!(0x011);
+write_csr!(0x011);
+
+/// Writes the CSR.
+///
+/// **WARNING**: panics on non-`riscv` targets.
+///
+/// # Safety
+///
+/// `ssp` must point to a shadow stack that matches the return addresses of the current context,
+/// or returns will raise software-check exceptions.
+#[inline]
+pub unsafe fn write(bits: usize) {
+    _write(bits)
+}
+
+/// Attempts to write the CSR.
+///
+/// # Safety
+///
+/// See [`write()`].
+#[inline]
+pub unsafe fn try_write(bits: usize) -> crate::result::Result<()> {
+    _try_write(bits)
+}
@@ -0,0 +1,84 @@
+//! sstateen registers (Ssstateen)
+//!
+//! Supervisor state enable registers. They control the access of U-mode to extension state.
+//! Only `sstateen0` defines access-control bits. The registers are 32 bits wide on all targets.
+
+/// sstateen register
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Sstateen {
+    bits: usize,
+}
+
+impl From<usize> for Sstateen {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Sstateen {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub const fn bits(&self) -> usize {
+        self.bits
+    }
+
+    #[inline]
+    const fn bit(&self, index: u32) -> bool {
+        self.bits & (1 << index) != 0
+    }
+
+    #[inline]
+    fn set_bit(&mut self, index: u32, value: bool) {
+        self.bits = (self.bits & !(1 << index)) | ((value as usize) << index);
+    }
+
+    /// Custom state enable (`C`)
+    ///
+    /// Grants access to the custom (non-standard) state of the less privileged modes.
+    #[inline]
+    pub const fn c(&self) -> bool {
+        self.bit(0)
+    }
+
+    /// Update Custom state enable (`C`)
+    ///
+    /// Note this updates a previously read [`Sstateen`] value, but does not
+    /// affect the sstateenX CSRs themselves.
+    #[inline]
+    pub fn set_c(&mut self, c: bool) {
+        self.set_bit(0, c);
+    }
+
+    /// Floating-point CSR enable (`FCSR`, Zfinx)
+    ///
+    /// Grants access to `fcsr` when the floating-point registers live in the integer registers.
+    #[inline]
+    pub const fn fcsr(&self) -> bool {
+        self.bit(1)
+    }
+
+    /// Update Floating-point CSR enable (`FCSR`, Zfinx)
+    ///
+    /// Note this updates a previously read [`Sstateen`] value, but does not
+    /// affect the sstateenX CSRs themselves.
+    #[inline]
+    pub fn set_fcsr(&mut self, fcsr: bool) {
+        self.set_bit(1, fcsr);
+    }
+
+    /// Jump vector table CSR enable (`JVT`, Zcmt)
+    #[inline]
+    pub const fn jvt(&self) -> bool {
+        self.bit(2)
+    }
+
+    /// Update Jump vector table CSR enable (`JVT`, Zcmt)
+    ///
+    /// Note this updates a previously read [`Sstateen`] value, but does not
+    /// affect the sstateenX CSRs themselves.
+    #[inline]
+    pub fn set_jvt(&mut self, jvt: bool) {
+        self.set_bit(2, jvt);
+    }
+}
@@ -1,7 +1,8 @@
 //! sstatus register
 
 pub use super::misa::XLEN;
-pub use super::mstatus::FS;
+pub use super::mstatus::{Endianness, FS};
+use crate::bits::bf_insert;
 
 /// Supervisor Status Register
 #[derive(Clone, Copy, Debug)]
@@ -16,19 +17,68 @@ pub enum SPP {
     User = 0,
 }
 
+impl From<usize> for Sstatus {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
 impl Sstatus {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
     /// Supervisor Interrupt Enable
     #[inline]
     pub fn sie(&self) -> bool {
         self.bits & (1 << 1) != 0
     }
 
+    /// Update Supervisor Interrupt Enable
+    ///
+    /// Note this updates a previously read [`Sstatus`] value, but does not
+    /// affect the sstatus CSR itself. See [`set_sie`]/[`clear_sie`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_sie(&mut self, sie: bool) {
+        self.bits = bf_insert(self.bits, 1, 1, sie as usize);
+    }
+
     /// Supervisor Previous Interrupt Enable
     #[inline]
     pub fn spie(&self) -> bool {
         self.bits & (1 << 5) != 0
     }
 
+    /// Update Supervisor Previous Interrupt Enable
+    ///
+    /// Note this updates a previously read [`Sstatus`] value, but does not
+    /// affect the sstatus CSR itself. See [`set_spie`] to directly update the
+    /// CSR.
+    #[inline]
+    pub fn set_spie(&mut self, spie: bool) {
+        self.bits = bf_insert(self.bits, 5, 1, spie as usize);
+    }
+
+    /// U-mode non-instruction-fetch memory endianness
+    #[inline]
+    pub fn ube(&self) -> Endianness {
+        Endianness::from(self.bits & (1 << 6) != 0)
+    }
+
+    /// Update U-mode non-instruction-fetch memory endianness
+    ///
+    /// Note this updates a previously read [`Sstatus`] value, but does not
+    /// affect the sstatus CSR itself. See [`set_ube`] to directly update the
+    /// CSR.
+    #[inline]
+    pub fn set_ube(&mut self, endianness: Endianness) {
+        self.bits = bf_insert(self.bits, 6, 1, endianness as usize);
+    }
+
     /// Supervisor Previous Privilege Mode
     #[inline]
     pub fn spp(&self) -> SPP {
@@ -38,6 +88,16 @@ impl Sstatus {
         }
     }
 
+    /// Update Supervisor Previous Privilege Mode
+    ///
+    /// Note this updates a previously read [`Sstatus`] value, but does not
+    /// affect the sstatus CSR itself. See [`set_spp`] to directly update the
+    /// CSR.
+    #[inline]
+    pub fn set_spp(&mut self, spp: SPP) {
+        self.bits = bf_insert(self.bits, 8, 1, spp as usize);
+    }
+
     /// The status of the floating-point unit
     #[inline]
     pub fn fs(&self) -> FS {
@@ -51,6 +111,16 @@ impl Sstatus {
         }
     }
 
+    /// Update the status of the floating-point unit
+    ///
+    /// Note this updates a previously read [`Sstatus`] value, but does not
+    /// affect the sstatus CSR itself. See [`set_fs`] to directly update the
+    /// CSR.
+    #[inline]
+    pub fn set_fs(&mut self, fs: FS) {
+        self.bits = bf_insert(self.bits, 13, 2, fs as usize);
+    }
+
     /// The status of additional user-mode extensions
     /// and associated state
     #[inline]
@@ -71,12 +141,32 @@ impl Sstatus {
         self.bits & (1 << 18) != 0
     }
 
+    /// Update Permit Supervisor User Memory access
+    ///
+    /// Note this updates a previously read [`Sstatus`] value, but does not
+    /// affect the sstatus CSR itself. See [`set_sum`]/[`clear_sum`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_sum(&mut self, sum: bool) {
+        self.bits = bf_insert(self.bits, 18, 1, sum as usize);
+    }
+
     /// Make eXecutable Readable
     #[inline]
     pub fn mxr(&self) -> bool {
         self.bits & (1 << 19) != 0
     }
 
+    /// Update Make eXecutable Readable
+    ///
+    /// Note this updates a previously read [`Sstatus`] value, but does not
+    /// affect the sstatus CSR itself. See [`set_mxr`]/[`clear_mxr`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_mxr(&mut self, mxr: bool) {
+        self.bits = bf_insert(self.bits, 19, 1, mxr as usize);
+    }
+
     /// Effective xlen in U-mode (i.e., `UXLEN`).
     ///
     /// In RISCV-32, UXL does not exist, and `UXLEN` is always [`XLEN::XLEN32`].
@@ -99,7 +189,8 @@ impl Sstatus {
 }
 
 read_csr_as!(Sstatus, 0x100);
-write_csr!(0x100);
+write_csr_as!(Sstatus, 0x100);
+modify_csr_as!(Sstatus);
 set!(0x100);
 clear!(0x100);
 
@@ -122,6 +213,15 @@ set_clear_csr!(
     /// Make eXecutable Readable
     , set_mxr, clear_mxr, 1 << 19);
 
+/// Set U-mode non-instruction-fetch memory endianness
+#[inline]
+pub unsafe fn set_ube(endianness: Endianness) {
+    match endianness {
+        Endianness::BigEndian => _set(1 << 6),
+        Endianness::LittleEndian => _clear(1 << 6),
+    }
+}
+
 /// Supervisor Previous Privilege Mode
 #[inline]
 pub unsafe fn set_spp(spp: SPP) {
@@ -139,3 +239,86 @@ pub unsafe fn set_fs(fs: FS) {
     value |= (fs as usize) << 13;
     _write(value);
 }
+
+/// Scope guard for a temporarily set `sstatus` bit
+///
+/// Created by [`sum_guard`] or [`mxr_guard`]. Restores the previous value of the bit when dropped.
+#[must_use = "the previous value of the bit is restored when the guard is dropped"]
+pub struct BitGuard {
+    mask: usize,
+    set: bool,
+}
+
+impl Drop for BitGuard {
+    #[inline]
+    fn drop(&mut self) {
+        if !self.set {
+            unsafe { _clear(self.mask) };
+        }
+    }
+}
+
+/// Sets SUM until the returned guard is dropped
+///
+/// While the guard is alive, S-mode memory accesses to pages accessible by U-mode are permitted.
+#[inline]
+pub unsafe fn sum_guard() -> BitGuard {
+    let set = read().sum();
+    set_sum();
+    BitGuard { mask: 1 << 18, set }
+}
+
+/// Sets MXR until the returned guard is dropped
+///
+/// While the guard is alive, loads from pages marked either readable or executable succeed.
+#[inline]
+pub unsafe fn mxr_guard() -> BitGuard {
+    let set = read().mxr();
+    set_mxr();
+    BitGuard { mask: 1 << 19, set }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sstatus() {
+        let mut sstatus = Sstatus::from(0);
+
+        sstatus.set_sie(true);
+        assert!(sstatus.sie());
+        sstatus.set_spie(true);
+        assert!(sstatus.spie());
+        sstatus.set_spp(SPP::Supervisor);
+        assert_eq!(sstatus.spp(), SPP::Supervisor);
+        sstatus.set_sum(true);
+        assert!(sstatus.sum());
+        sstatus.set_mxr(true);
+        assert!(sstatus.mxr());
+        sstatus.set_fs(FS::Dirty);
+        assert_eq!(sstatus.fs(), FS::Dirty);
+        sstatus.set_ube(Endianness::BigEndian);
+        assert_eq!(sstatus.ube(), Endianness::BigEndian);
+        assert_eq!(
+            sstatus.bits(),
+            (1 << 1) | (1 << 5) | (1 << 6) | (1 << 8) | (0b11 << 13) | (1 << 18) | (1 << 19)
+        );
+
+        sstatus.set_sie(false);
+        assert!(!sstatus.sie());
+        sstatus.set_spie(false);
+        assert!(!sstatus.spie());
+        sstatus.set_spp(SPP::User);
+        assert_eq!(sstatus.spp(), SPP::User);
+        sstatus.set_sum(false);
+        assert!(!sstatus.sum());
+        sstatus.set_mxr(false);
+        assert!(!sstatus.mxr());
+        sstatus.set_fs(FS::Off);
+        assert_eq!(sstatus.fs(), FS::Off);
+        sstatus.set_ube(Endianness::LittleEndian);
+        assert_eq!(sstatus.ube(), Endianness::LittleEndian);
+        assert_eq!(sstatus.bits(), 0);
+    }
+}
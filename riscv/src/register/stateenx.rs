@@ -0,0 +1,56 @@
+macro_rules! reg {
+    (
+        $addr:expr, $csr:ident, $csrh:ident
+    ) => {
+        /// Machine state enable register
+        ///
+        /// See [`Mstateen`](super::mstateen::Mstateen) for the typed register value.
+        pub mod $csr {
+            read_csr_as_usize!($addr);
+            write_csr_as_usize!($addr);
+            read_composite_csr!(super::$csrh::read(), read());
+            write_config_csr!(super::$csrh::write, write);
+        }
+    };
+}
+
+macro_rules! regh {
+    (
+        $addr:expr, $csrh:ident
+    ) => {
+        /// Upper 32 bits of machine state enable register (RV32 only)
+        pub mod $csrh {
+            read_csr_as_usize_rv32!($addr);
+            write_csr_as_usize_rv32!($addr);
+        }
+    };
+}
+
+macro_rules! sreg {
+    (
+        $addr:expr, $csr:ident
+    ) => {
+        /// Supervisor state enable register
+        ///
+        /// See [`Sstateen`](super::sstateen::Sstateen) for the typed register value.
+        pub mod $csr {
+            read_csr_as_usize!($addr);
+            write_csr_as_usize!($addr);
+        }
+    };
+}
+
+reg!(0x30C, mstateen0, mstateen0h);
+reg!(0x30D, mstateen1, mstateen1h);
+reg!(0x30E, mstateen2, mstateen2h);
+reg!(0x30F, mstateen3, mstateen3h);
+
+regh!(0x31C, mstateen0h);
+regh!(0x31D, mstateen1h);
+regh!(0x31E, mstateen2h);
+regh!(0x31F, mstateen3h);
+
+sreg!(0x10C, sstateen0);
+sreg!(0x10D, sstateen1);
+sreg!(0x10E, sstateen2);
+sreg!(0x10F, sstateen3);
@@ -0,0 +1,103 @@
+//! stopei register (Ssaia)
+//!
+//! Supervisor top external interrupt register. It reports the highest-priority interrupt of the
+//! supervisor-level IMSIC interrupt file that is pending and enabled, and above the `eithreshold`.
+//!
+//! Writing the register claims the reported interrupt, i.e., clears its pending bit.
+//! Use [`claim`] to read and claim the interrupt atomically.
+
+#[cfg(not(riscv))]
+use crate::result::Error;
+use crate::result::Result;
+
+/// stopei register
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Stopei {
+    bits: usize,
+}
+
+impl From<usize> for Stopei {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Stopei {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// External interrupt identity of the top interrupt, or 0 if no interrupt is pending
+    #[inline]
+    pub fn identity(&self) -> usize {
+        (self.bits >> 16) & 0x7FF
+    }
+
+    /// Priority of the top interrupt, which is equal to its identity
+    #[inline]
+    pub fn priority(&self) -> usize {
+        self.bits & 0x7FF
+    }
+
+    /// Returns `true` if an interrupt is pending
+    #[inline]
+    pub fn is_pending(&self) -> bool {
+        self.identity() != 0
+    }
+}
+
+read_csr_as!(Stopei, 0x15C);
+
+/// Claims the top external interrupt.
+///
+/// Returns `None` if no interrupt is pending.
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn claim() -> Option<Stopei> {
+    try_claim().unwrap()
+}
+
+/// Attempts to claim the top external interrupt.
+///
+/// Returns `Ok(None)` if no interrupt is pending.
+#[inline]
+pub fn try_claim() -> Result<Option<Stopei>> {
+    match () {
+        #[cfg(riscv)]
+        () => {
+            let r: usize;
+            // the read and the claim must be a single access, or a higher-priority interrupt
+            // could be claimed without being reported
+            unsafe { core::arch::asm!("csrrw {0}, 0x15C, x0", out(reg) r) };
+            let stopei = Stopei::from(r);
+            if stopei.is_pending() {
+                Ok(Some(stopei))
+            } else {
+                Ok(None)
+            }
+        }
+
+        #[cfg(not(riscv))]
+        () => Err(Error::Unimplemented),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stopei() {
+        let stopei = Stopei::from((0x25 << 16) | 0x25);
+        assert_eq!(stopei.identity(), 0x25);
+        assert_eq!(stopei.priority(), 0x25);
+        assert!(stopei.is_pending());
+        assert!(!Stopei::from(0).is_pending());
+
+        assert_eq!(try_claim(), Err(Error::Unimplemented));
+    }
+}
@@ -0,0 +1,59 @@
+//! stopi register (Ssaia)
+//!
+//! Supervisor top interrupt register. It reports the highest-priority interrupt that is pending and
+//! enabled for S-mode, regardless of `sstatus.SIE`.
+
+/// stopi register
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Stopi {
+    bits: usize,
+}
+
+impl From<usize> for Stopi {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Stopi {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Major interrupt number of the top interrupt, or 0 if no interrupt is pending
+    #[inline]
+    pub fn iid(&self) -> usize {
+        (self.bits >> 16) & 0xFFF
+    }
+
+    /// Priority of the top interrupt (lower values are higher priorities)
+    #[inline]
+    pub fn iprio(&self) -> usize {
+        self.bits & 0xFF
+    }
+
+    /// Returns `true` if an interrupt is pending
+    #[inline]
+    pub fn is_pending(&self) -> bool {
+        self.bits != 0
+    }
+}
+
+read_csr_as!(Stopi, 0xDB0);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stopi() {
+        let stopi = Stopi::from((9 << 16) | 0x42);
+        assert_eq!(stopi.iid(), 9);
+        assert_eq!(stopi.iprio(), 0x42);
+        assert!(stopi.is_pending());
+        assert!(!Stopi::from(0).is_pending());
+    }
+}
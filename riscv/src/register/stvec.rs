@@ -1,6 +1,8 @@
 //! stvec register
 
 pub use crate::register::mtvec::TrapMode;
+use crate::register::trap_vector::{self, TrapVector};
+use crate::result::{Error, Result};
 
 /// stvec register
 #[derive(Clone, Copy, Debug)]
@@ -9,6 +11,15 @@ pub struct Stvec {
 }
 
 impl Stvec {
+    /// Creates a new [`Stvec`] value from a trap-vector base-address and a trap mode.
+    ///
+    /// Returns an error if the base-address is not 4-byte aligned
+    /// (64-byte aligned in [`TrapMode::Clic`] mode).
+    #[inline]
+    pub fn new(address: usize, mode: TrapMode) -> Result<Self> {
+        TrapVector::new(address, mode).try_into()
+    }
+
     /// Returns the contents of the register as raw bits
     #[inline]
     pub fn bits(&self) -> usize {
@@ -18,18 +29,67 @@ impl Stvec {
     /// Returns the trap-vector base-address
     #[inline]
     pub fn address(&self) -> usize {
-        self.bits - (self.bits & 0b11)
+        trap_vector::address(self.bits)
+    }
+
+    /// Updates the trap-vector base-address
+    ///
+    /// Returns an error if the base-address is not 4-byte aligned
+    /// (64-byte aligned in [`TrapMode::Clic`] mode).
+    ///
+    /// Note this updates a previously read [`Stvec`] value, but does not
+    /// affect the stvec CSR itself. See [`write()`] to directly update the CSR.
+    #[inline]
+    pub fn set_address(&mut self, address: usize) -> Result<()> {
+        trap_vector::validate_address(address, self.raw_mode())?;
+        self.bits = address | self.raw_mode();
+        Ok(())
+    }
+
+    /// Returns the raw trap-vector mode bits
+    ///
+    /// Unlike [`Stvec::trap_mode`], this also exposes reserved and vendor-specific encodings.
+    #[inline]
+    pub fn raw_mode(&self) -> usize {
+        trap_vector::raw_mode(self.bits)
     }
 
     /// Returns the trap-vector mode
+    ///
+    /// Returns `None` if the mode bits hold a reserved or vendor-specific encoding.
+    /// Use [`Stvec::raw_mode`] to inspect them.
     #[inline]
     pub fn trap_mode(&self) -> Option<TrapMode> {
-        let mode = self.bits & 0b11;
-        match mode {
-            0 => Some(TrapMode::Direct),
-            1 => Some(TrapMode::Vectored),
-            _ => None,
-        }
+        trap_vector::trap_mode(self.bits)
+    }
+
+    /// Updates the trap-vector mode
+    ///
+    /// Note this updates a previously read [`Stvec`] value, but does not
+    /// affect the stvec CSR itself. See [`write()`] to directly update the CSR.
+    #[inline]
+    pub fn set_trap_mode(&mut self, mode: TrapMode) {
+        self.bits = self.address() | mode as usize;
+    }
+}
+
+impl TryFrom<Stvec> for TrapVector {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(stvec: Stvec) -> Result<Self> {
+        Self::try_from_bits(stvec.bits)
+    }
+}
+
+impl TryFrom<TrapVector> for Stvec {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(vector: TrapVector) -> Result<Self> {
+        Ok(Self {
+            bits: vector.try_into_bits()?,
+        })
     }
 }
 
@@ -37,7 +97,64 @@ read_csr_as!(Stvec, 0x105);
 write_csr!(0x105);
 
 /// Writes the CSR
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - misaligned trap-vector base-address for the current trap mode
+#[inline]
+pub unsafe fn write(stvec: Stvec) {
+    try_write(stvec).unwrap();
+}
+
+/// Attempts to write the CSR
+///
+/// Returns an error if the trap-vector base-address is not 4-byte aligned
+/// (64-byte aligned in [`TrapMode::Clic`] mode).
 #[inline]
-pub unsafe fn write(addr: usize, mode: TrapMode) {
-    _write(addr + mode as usize);
+pub unsafe fn try_write(stvec: Stvec) -> Result<()> {
+    trap_vector::validate_bits(stvec.bits)?;
+    _try_write(stvec.bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stvec() {
+        let mut stvec = Stvec::new(0x8000_0000, TrapMode::Vectored).unwrap();
+        assert_eq!(stvec.address(), 0x8000_0000);
+        assert_eq!(stvec.trap_mode(), Some(TrapMode::Vectored));
+
+        stvec.set_trap_mode(TrapMode::Direct);
+        assert_eq!(stvec.address(), 0x8000_0000);
+        assert_eq!(stvec.trap_mode(), Some(TrapMode::Direct));
+
+        assert_eq!(stvec.set_address(0x8000_0100), Ok(()));
+        assert_eq!(stvec.address(), 0x8000_0100);
+        assert_eq!(stvec.trap_mode(), Some(TrapMode::Direct));
+
+        (1..4).for_each(|offset| {
+            let address = 0x8000_0000 + offset;
+            let err = Error::InvalidFieldValue {
+                field: "address",
+                value: address,
+                bitmask: !0b11,
+            };
+            assert_eq!(stvec.set_address(address), Err(err));
+            assert_eq!(
+                Stvec::new(address, TrapMode::Direct).map(|s| s.bits()),
+                Err(err)
+            );
+        });
+        assert_eq!(stvec.address(), 0x8000_0100);
+
+        let stvec = Stvec {
+            bits: 0x8000_0000 | 0b10,
+        };
+        assert_eq!(stvec.raw_mode(), 0b10);
+        assert_eq!(stvec.trap_mode(), None);
+        assert_eq!(stvec.address(), 0x8000_0000);
+    }
 }
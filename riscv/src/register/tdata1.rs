@@ -0,0 +1,369 @@
+//! tdata1 register (Sdtrig)
+//!
+//! First trigger data register. It holds the type and configuration of the trigger
+//! selected by [`tselect`](super::tselect).
+//!
+//! Match triggers (i.e., `mcontrol` and `mcontrol6` triggers) can be configured with a
+//! [`MatchTrigger`], while the value to match is written to [`tdata2`](super::tdata2).
+
+use crate::result::{Error, Result};
+
+/// Position of the trigger type field
+const TYPE_SHIFT: u32 = usize::BITS - 4;
+/// Position of the `dmode` bit
+const DMODE_SHIFT: u32 = usize::BITS - 5;
+
+/// tdata1 register
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Tdata1 {
+    bits: usize,
+}
+
+impl From<usize> for Tdata1 {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Tdata1 {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Type of the selected trigger
+    ///
+    /// **WARNING**: panics if the field has an invalid variant.
+    #[inline]
+    pub fn trigger_type(&self) -> TriggerType {
+        self.try_trigger_type().unwrap()
+    }
+
+    /// Attempts to get the type of the selected trigger
+    #[inline]
+    pub fn try_trigger_type(&self) -> Result<TriggerType> {
+        ((self.bits >> TYPE_SHIFT) as u8).try_into()
+    }
+
+    /// Returns true if the trigger can only be written from debug mode
+    #[inline]
+    pub fn dmode(&self) -> bool {
+        self.bits & (1 << DMODE_SHIFT) != 0
+    }
+
+    /// Type-specific data of the trigger
+    #[inline]
+    pub fn data(&self) -> usize {
+        self.bits & ((1 << DMODE_SHIFT) - 1)
+    }
+
+    /// Returns true if a match trigger has fired (i.e., its `hit` bit is set)
+    ///
+    /// Returns `false` for other trigger types.
+    #[inline]
+    pub fn hit(&self) -> bool {
+        match self.try_trigger_type() {
+            Ok(TriggerType::Mcontrol) => self.bits & (1 << 20) != 0,
+            Ok(TriggerType::Mcontrol6) => self.bits & ((1 << 25) | (1 << 22)) != 0,
+            _ => false,
+        }
+    }
+}
+
+/// Trigger type
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TriggerType {
+    /// There is no trigger at this `tselect`
+    None = 0,
+    /// Legacy SiFive address match trigger
+    Legacy = 1,
+    /// Address/data match trigger (`mcontrol`)
+    Mcontrol = 2,
+    /// Instruction count trigger (`icount`)
+    Icount = 3,
+    /// Interrupt trigger (`itrigger`)
+    Itrigger = 4,
+    /// Exception trigger (`etrigger`)
+    Etrigger = 5,
+    /// Address/data match trigger (`mcontrol6`)
+    Mcontrol6 = 6,
+    /// External trigger (`tmexttrigger`)
+    Tmexttrigger = 7,
+    /// Custom trigger 0
+    Custom0 = 12,
+    /// Custom trigger 1
+    Custom1 = 13,
+    /// Custom trigger 2
+    Custom2 = 14,
+    /// The trigger exists but is currently disabled
+    Disabled = 15,
+}
+
+impl TryFrom<u8> for TriggerType {
+    type Error = Error;
+
+    fn try_from(val: u8) -> Result<Self> {
+        match val {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Legacy),
+            2 => Ok(Self::Mcontrol),
+            3 => Ok(Self::Icount),
+            4 => Ok(Self::Itrigger),
+            5 => Ok(Self::Etrigger),
+            6 => Ok(Self::Mcontrol6),
+            7 => Ok(Self::Tmexttrigger),
+            12 => Ok(Self::Custom0),
+            13 => Ok(Self::Custom1),
+            14 => Ok(Self::Custom2),
+            15 => Ok(Self::Disabled),
+            _ => Err(Error::InvalidFieldVariant {
+                field: "type",
+                value: val as usize,
+            }),
+        }
+    }
+}
+
+/// Value compared by a match trigger
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MatchKind {
+    /// Virtual address of the access
+    Address = 0,
+    /// Data loaded or stored, or the instruction executed
+    Data = 1,
+}
+
+/// Accesses that fire a match trigger
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccessKind {
+    /// Instruction execution (breakpoint)
+    Execute = 0b100,
+    /// Loads (read watchpoint)
+    Load = 0b001,
+    /// Stores (write watchpoint)
+    Store = 0b010,
+    /// Loads and stores (access watchpoint)
+    LoadStore = 0b011,
+}
+
+/// Condition between the accessed value and `tdata2` that fires a match trigger
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Match {
+    /// The value equals `tdata2`
+    Equal = 0,
+    /// The value matches the naturally aligned power-of-two range encoded in `tdata2`
+    Napot = 1,
+    /// The value is greater than or equal to `tdata2`
+    GreaterOrEqual = 2,
+    /// The value is less than `tdata2`
+    Less = 3,
+    /// The lower half of the value, masked with the upper half of `tdata2`,
+    /// equals the lower half of `tdata2`
+    MaskLow = 4,
+    /// The upper half of the value, masked with the upper half of `tdata2`,
+    /// equals the lower half of `tdata2`
+    MaskHigh = 5,
+    /// Negation of [`Match::Equal`]
+    NotEqual = 8,
+    /// Negation of [`Match::Napot`]
+    NotNapot = 9,
+    /// Negation of [`Match::MaskLow`]
+    NotMaskLow = 12,
+    /// Negation of [`Match::MaskHigh`]
+    NotMaskHigh = 13,
+}
+
+/// Action taken when a trigger fires
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Action {
+    /// Raise a breakpoint exception
+    Breakpoint = 0,
+    /// Enter debug mode (only available if `dmode` is set)
+    DebugMode = 1,
+    /// Start tracing
+    TraceOn = 2,
+    /// Stop tracing
+    TraceOff = 3,
+    /// Emit a trace notification
+    TraceNotify = 4,
+    /// Signal the external trigger output 0
+    External0 = 8,
+    /// Signal the external trigger output 1
+    External1 = 9,
+}
+
+/// Privilege modes in which a trigger can fire
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PrivilegeFilter {
+    /// M-mode
+    pub m: bool,
+    /// S-mode (or HS-mode)
+    pub s: bool,
+    /// U-mode
+    pub u: bool,
+    /// VS-mode (only supported by `mcontrol6` triggers)
+    pub vs: bool,
+    /// VU-mode (only supported by `mcontrol6` triggers)
+    pub vu: bool,
+}
+
+impl PrivilegeFilter {
+    /// All the privilege modes
+    pub const ALL: Self = Self {
+        m: true,
+        s: true,
+        u: true,
+        vs: true,
+        vu: true,
+    };
+
+    /// Only M-mode
+    pub const MACHINE: Self = Self {
+        m: true,
+        s: false,
+        u: false,
+        vs: false,
+        vu: false,
+    };
+
+    /// All the privilege modes except M-mode
+    pub const LOWER: Self = Self {
+        m: false,
+        s: true,
+        u: true,
+        vs: true,
+        vu: true,
+    };
+}
+
+/// Configuration of an address/data match trigger
+///
+/// The value to compare with is written to [`tdata2`](super::tdata2).
+/// The size of the accesses is not filtered (i.e., accesses of any size can match).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MatchTrigger {
+    /// Value compared with `tdata2`
+    pub kind: MatchKind,
+    /// Accesses that fire the trigger
+    pub access: AccessKind,
+    /// Condition that fires the trigger
+    pub condition: Match,
+    /// Action taken when the trigger fires
+    pub action: Action,
+    /// Privilege modes in which the trigger fires
+    pub privilege: PrivilegeFilter,
+    /// When set, the trigger only fires if the next trigger also matches
+    pub chain: bool,
+}
+
+impl MatchTrigger {
+    /// Creates a trigger that raises a breakpoint exception when
+    /// the address of an `access` equals `tdata2` in any privilege mode.
+    #[inline]
+    pub const fn new(access: AccessKind) -> Self {
+        Self {
+            kind: MatchKind::Address,
+            access,
+            condition: Match::Equal,
+            action: Action::Breakpoint,
+            privilege: PrivilegeFilter::ALL,
+            chain: false,
+        }
+    }
+
+    /// Encodes the fields shared by `mcontrol` and `mcontrol6` triggers
+    #[inline]
+    const fn common_bits(&self) -> usize {
+        ((self.action as usize) << 12)
+            | ((self.chain as usize) << 11)
+            | ((self.condition as usize) << 7)
+            | ((self.privilege.m as usize) << 6)
+            | ((self.privilege.s as usize) << 4)
+            | ((self.privilege.u as usize) << 3)
+            | self.access as usize
+    }
+
+    /// Encodes the trigger as an `mcontrol` trigger.
+    ///
+    /// The `vs` and `vu` privilege filters are ignored.
+    #[inline]
+    pub const fn mcontrol(&self) -> Tdata1 {
+        Tdata1 {
+            bits: ((TriggerType::Mcontrol as usize) << TYPE_SHIFT)
+                | ((self.kind as usize) << 19)
+                | self.common_bits(),
+        }
+    }
+
+    /// Encodes the trigger as an `mcontrol6` trigger.
+    #[inline]
+    pub const fn mcontrol6(&self) -> Tdata1 {
+        Tdata1 {
+            bits: ((TriggerType::Mcontrol6 as usize) << TYPE_SHIFT)
+                | ((self.privilege.vs as usize) << 24)
+                | ((self.privilege.vu as usize) << 23)
+                | ((self.kind as usize) << 21)
+                | self.common_bits(),
+        }
+    }
+
+    /// Encodes the trigger for the given match trigger type.
+    ///
+    /// Returns an error if `trigger_type` is not a match trigger type.
+    #[inline]
+    pub fn encode(&self, trigger_type: TriggerType) -> Result<Tdata1> {
+        match trigger_type {
+            TriggerType::Mcontrol => Ok(self.mcontrol()),
+            TriggerType::Mcontrol6 => Ok(self.mcontrol6()),
+            _ => Err(Error::InvalidFieldVariant {
+                field: "type",
+                value: trigger_type as usize,
+            }),
+        }
+    }
+}
+
+read_csr_as!(Tdata1, 0x7A1);
+write_csr_as!(Tdata1, 0x7A1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_trigger() {
+        let mut trigger = MatchTrigger::new(AccessKind::Execute);
+        let tdata1 = trigger.mcontrol6();
+        assert_eq!(tdata1.trigger_type(), TriggerType::Mcontrol6);
+        assert_eq!(tdata1.data(), 0x180_005C);
+        assert!(!tdata1.dmode() && !tdata1.hit());
+
+        trigger.kind = MatchKind::Data;
+        trigger.access = AccessKind::Store;
+        trigger.condition = Match::Napot;
+        trigger.privilege = PrivilegeFilter::MACHINE;
+        trigger.chain = true;
+        let tdata1 = trigger.mcontrol();
+        assert_eq!(tdata1.trigger_type(), TriggerType::Mcontrol);
+        assert_eq!(tdata1.data(), 0x8_08C2);
+        assert_eq!(trigger.encode(TriggerType::Mcontrol), Ok(tdata1));
+
+        assert_eq!(
+            trigger.encode(TriggerType::Icount),
+            Err(Error::InvalidFieldVariant {
+                field: "type",
+                value: 3,
+            })
+        );
+        assert_eq!(
+            Tdata1::from(8 << TYPE_SHIFT).try_trigger_type(),
+            Err(Error::InvalidFieldVariant {
+                field: "type",
+                value: 8,
+            })
+        );
+        assert!(Tdata1::from((2 << TYPE_SHIFT) | (1 << 20)).hit());
+    }
+}
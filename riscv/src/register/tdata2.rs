@@ -0,0 +1,7 @@
+//! tdata2 register (Sdtrig)
+//!
+//! Second trigger data register. For match triggers, it holds the value to compare with
+//! (see [`MatchTrigger`](super::tdata1::MatchTrigger)).
+
+read_csr_as_usize!(0x7A2);
+write_csr_as_usize!(0x7A2);
@@ -0,0 +1,6 @@
+//! tdata3 register (Sdtrig)
+//!
+//! Third trigger data register. It holds additional trigger conditions (e.g., context matching).
+
+read_csr_as_usize!(0x7A3);
+write_csr_as_usize!(0x7A3);
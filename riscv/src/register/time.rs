@@ -1,4 +1,105 @@
 //! time register
 
+use core::time::Duration;
+
 read_csr_as_usize!(0xC01);
 read_composite_csr!(super::timeh::read(), read());
+
+/// Clock source based on the `time` CSR
+///
+/// Converts `time` (or `mtime`) readings into [`Duration`]s given the tick frequency of the timer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Clock {
+    /// The tick frequency of the timer, in Hertz
+    ticks_second: u32,
+}
+
+impl Clock {
+    /// Constructs the clock.
+    /// `ticks_second` should be the tick frequency of the timer, in Hertz.
+    ///
+    /// **WARNING**: panics if `ticks_second` is zero.
+    #[inline]
+    pub const fn new(ticks_second: u32) -> Self {
+        assert!(ticks_second != 0);
+        Self { ticks_second }
+    }
+
+    /// Returns the tick frequency of the timer, in Hertz
+    #[inline]
+    pub const fn ticks_second(&self) -> u32 {
+        self.ticks_second
+    }
+
+    /// Returns the current value of the `time` CSR, in ticks
+    ///
+    /// **WARNING**: panics on non-`riscv` targets.
+    #[inline]
+    pub fn now(&self) -> u64 {
+        read64()
+    }
+
+    /// Converts a number of ticks into a [`Duration`]
+    #[inline]
+    pub const fn ticks_to_duration(&self, ticks: u64) -> Duration {
+        let ticks_second = self.ticks_second as u64;
+        let secs = ticks / ticks_second;
+        let nanos = (ticks % ticks_second) * 1_000_000_000 / ticks_second;
+        Duration::new(secs, nanos as u32)
+    }
+
+    /// Converts a [`Duration`] into a number of ticks, rounding down
+    ///
+    /// The result saturates at [`u64::MAX`].
+    #[inline]
+    pub const fn duration_to_ticks(&self, duration: Duration) -> u64 {
+        let ticks = duration.as_nanos() * self.ticks_second as u128 / 1_000_000_000;
+        if ticks > u64::MAX as u128 {
+            u64::MAX
+        } else {
+            ticks as u64
+        }
+    }
+
+    /// Returns the [`Duration`] between an `earlier` and a `later` reading, in ticks.
+    ///
+    /// The timer is assumed to have wrapped around at most once between both readings.
+    #[inline]
+    pub const fn duration_between(&self, earlier: u64, later: u64) -> Duration {
+        self.ticks_to_duration(later.wrapping_sub(earlier))
+    }
+
+    /// Returns the [`Duration`] elapsed since an `earlier` reading, in ticks.
+    ///
+    /// The timer is assumed to have wrapped around at most once since the `earlier` reading.
+    ///
+    /// **WARNING**: panics on non-`riscv` targets.
+    #[inline]
+    pub fn elapsed_since(&self, earlier: u64) -> Duration {
+        self.duration_between(earlier, self.now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clock() {
+        let clock = Clock::new(32_768);
+        assert_eq!(clock.ticks_to_duration(32_768), Duration::from_secs(1));
+        assert_eq!(clock.ticks_to_duration(16_384), Duration::from_millis(500));
+        assert_eq!(clock.duration_to_ticks(Duration::from_millis(250)), 8_192);
+        assert_eq!(clock.duration_to_ticks(Duration::MAX), u64::MAX);
+
+        let clock = Clock::new(10_000_000);
+        assert_eq!(
+            clock.duration_between(u64::MAX - 4, 5),
+            Duration::from_nanos(1_000)
+        );
+        assert_eq!(
+            clock.duration_between(100, 110),
+            Duration::from_nanos(1_000)
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! tinfo register (Sdtrig)
+//!
+//! Trigger info register. It reports the trigger types supported by the trigger
+//! selected by [`tselect`](super::tselect).
+
+use super::tdata1::TriggerType;
+
+/// tinfo register
+#[derive(Clone, Copy, Debug)]
+pub struct Tinfo {
+    bits: usize,
+}
+
+impl From<usize> for Tinfo {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Tinfo {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns true if the selected trigger supports the trigger type `trigger_type`
+    #[inline]
+    pub fn supports(&self, trigger_type: TriggerType) -> bool {
+        self.bits & (1 << trigger_type as usize) != 0
+    }
+
+    /// Returns true if the selected trigger does not exist
+    ///
+    /// In this case, the register only reports [`TriggerType::None`].
+    #[inline]
+    pub fn is_none(&self) -> bool {
+        self.bits & 0xFFFF == 1 << TriggerType::None as usize
+    }
+
+    /// Version of the Sdtrig extension
+    ///
+    /// It is 0 for version 0.13 and 1 for version 1.0 of the debug specification.
+    #[inline]
+    pub fn version(&self) -> u8 {
+        ((self.bits >> 24) & 0xFF) as u8
+    }
+}
+
+read_csr_as!(Tinfo, 0x7A4);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tinfo() {
+        let tinfo = Tinfo::from((1 << 24) | (1 << 6) | (1 << 2));
+        assert_eq!(tinfo.version(), 1);
+        assert!(tinfo.supports(TriggerType::Mcontrol6));
+        assert!(tinfo.supports(TriggerType::Mcontrol));
+        assert!(!tinfo.supports(TriggerType::Icount));
+        assert!(!tinfo.is_none());
+        assert!(Tinfo::from(1).is_none());
+    }
+}
@@ -0,0 +1,158 @@
+//! Trap-vector configuration shared by the `xtvec` registers
+
+pub use crate::register::mtvec::TrapMode;
+use crate::result::{Error, Result};
+
+/// Trap-vector configuration
+///
+/// Common representation of the `mtvec`, `stvec`, `utvec`, and `vstvec` registers.
+/// The same value can be converted into any of them to program trap vectors identically.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TrapVector {
+    /// Trap-vector base-address
+    pub base: usize,
+    /// Trap-vector mode
+    pub mode: TrapMode,
+}
+
+impl TrapVector {
+    /// Creates a new trap-vector configuration
+    #[inline]
+    pub const fn new(base: usize, mode: TrapMode) -> Self {
+        Self { base, mode }
+    }
+
+    /// Attempts to decode a trap-vector configuration from the raw bits of an `xtvec` register.
+    ///
+    /// Returns an error if the mode bits hold a reserved or vendor-specific encoding.
+    #[inline]
+    pub fn try_from_bits(bits: usize) -> Result<Self> {
+        match trap_mode(bits) {
+            Some(mode) => Ok(Self::new(address(bits), mode)),
+            None => Err(Error::InvalidFieldVariant {
+                field: "mode",
+                value: raw_mode(bits),
+            }),
+        }
+    }
+
+    /// Attempts to encode the trap-vector configuration as the raw bits of an `xtvec` register.
+    ///
+    /// Returns an error if the base-address is not 4-byte aligned
+    /// (64-byte aligned in [`TrapMode::Clic`] mode).
+    #[inline]
+    pub fn try_into_bits(self) -> Result<usize> {
+        validate_address(self.base, self.mode as usize)?;
+        Ok(self.base | self.mode as usize)
+    }
+}
+
+/// Returns the mask of the low bits that do not belong to the trap-vector base-address.
+///
+/// In CLIC mode, bits 2-5 hold the submode field and the base-address is 64-byte aligned.
+#[inline]
+fn low_bits_mask(raw_mode: usize) -> usize {
+    match raw_mode {
+        3 => 0x3F,
+        _ => 0b11,
+    }
+}
+
+/// Extracts the trap-vector base-address from the raw bits of an `xtvec` register.
+#[inline]
+pub(crate) fn address(bits: usize) -> usize {
+    bits & !low_bits_mask(raw_mode(bits))
+}
+
+/// Extracts the raw trap-vector mode from the raw bits of an `xtvec` register.
+#[inline]
+pub(crate) fn raw_mode(bits: usize) -> usize {
+    bits & 0b11
+}
+
+/// Decodes the trap-vector mode from the raw bits of an `xtvec` register.
+#[inline]
+pub(crate) fn trap_mode(bits: usize) -> Option<TrapMode> {
+    match raw_mode(bits) {
+        0 => Some(TrapMode::Direct),
+        1 => Some(TrapMode::Vectored),
+        3 => Some(TrapMode::Clic),
+        _ => None,
+    }
+}
+
+/// Checks that a trap-vector base-address is properly aligned for the given raw trap-vector mode.
+#[inline]
+pub(crate) fn validate_address(address: usize, raw_mode: usize) -> Result<()> {
+    let mask = low_bits_mask(raw_mode);
+    if address & mask != 0 {
+        Err(Error::InvalidFieldValue {
+            field: "address",
+            value: address,
+            bitmask: !mask,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that the raw bits of an `xtvec` register hold a properly aligned trap-vector base-address.
+#[inline]
+pub(crate) fn validate_bits(bits: usize) -> Result<()> {
+    validate_address(bits & !0b11, raw_mode(bits))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trap_vector() {
+        let vector = TrapVector::new(0x8000_0000, TrapMode::Vectored);
+        assert_eq!(vector.try_into_bits(), Ok(0x8000_0001));
+        assert_eq!(TrapVector::try_from_bits(0x8000_0001), Ok(vector));
+
+        let vector = TrapVector::new(0x8000_0100, TrapMode::Direct);
+        assert_eq!(vector.try_into_bits(), Ok(0x8000_0100));
+        assert_eq!(TrapVector::try_from_bits(0x8000_0100), Ok(vector));
+
+        let vector = TrapVector::new(0x8000_0040, TrapMode::Clic);
+        assert_eq!(vector.try_into_bits(), Ok(0x8000_0043));
+        assert_eq!(TrapVector::try_from_bits(0x8000_0043), Ok(vector));
+        assert_eq!(address(0x8000_007F), 0x8000_0040);
+
+        assert_eq!(
+            TrapVector::try_from_bits(0x8000_0002),
+            Err(Error::InvalidFieldVariant {
+                field: "mode",
+                value: 2
+            })
+        );
+
+        (1..4).for_each(|offset| {
+            let base = 0x8000_0000 + offset;
+            assert_eq!(
+                TrapVector::new(base, TrapMode::Direct).try_into_bits(),
+                Err(Error::InvalidFieldValue {
+                    field: "address",
+                    value: base,
+                    bitmask: !0b11,
+                })
+            );
+        });
+
+        (1..16).for_each(|offset| {
+            let base = 0x8000_0000 + 4 * offset;
+            assert_eq!(
+                TrapVector::new(base, TrapMode::Clic).try_into_bits(),
+                Err(Error::InvalidFieldValue {
+                    field: "address",
+                    value: base,
+                    bitmask: !0x3F,
+                })
+            );
+            assert!(validate_bits(base | TrapMode::Direct as usize).is_ok());
+            assert!(validate_bits(base | TrapMode::Clic as usize).is_err());
+        });
+    }
+}
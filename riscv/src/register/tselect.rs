@@ -0,0 +1,11 @@
+//! tselect register (Sdtrig)
+//!
+//! Trigger select register. It selects the trigger accessed through the
+//! [`tdata1`](super::tdata1), [`tdata2`](super::tdata2), [`tdata3`](super::tdata3)
+//! and [`tinfo`](super::tinfo) registers.
+//!
+//! Writing an index of an unimplemented trigger does not select it:
+//! read the register back to check whether the write took effect.
+
+read_csr_as_usize!(0x7A0);
+write_csr_as_usize!(0x7A0);
@@ -0,0 +1,114 @@
+//! ucause register (N extension)
+
+pub use crate::interrupt::{Exception, Trap};
+use crate::result::{Error, Result};
+use crate::{CoreInterruptNumber, ExceptionNumber, InterruptNumber};
+
+/// ucause register
+#[derive(Clone, Copy, Debug)]
+pub struct Ucause {
+    bits: usize,
+}
+
+impl From<usize> for Ucause {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+/// User-level interrupts of the N extension
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u16)]
+pub enum Interrupt {
+    UserSoft = 0,
+    UserTimer = 4,
+    UserExternal = 8,
+}
+
+unsafe impl InterruptNumber for Interrupt {
+    const MAX_INTERRUPT_NUMBER: u16 = Self::UserExternal as u16;
+
+    #[inline]
+    fn number(self) -> u16 {
+        self as u16
+    }
+
+    #[inline]
+    fn from_number(value: u16) -> Result<Self> {
+        match value {
+            0 => Ok(Self::UserSoft),
+            4 => Ok(Self::UserTimer),
+            8 => Ok(Self::UserExternal),
+            _ => Err(Error::InvalidVariant(value as usize)),
+        }
+    }
+}
+
+unsafe impl CoreInterruptNumber for Interrupt {}
+
+impl Ucause {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns the code field
+    #[inline]
+    pub fn code(&self) -> usize {
+        self.bits & !(1 << (usize::BITS as usize - 1))
+    }
+
+    /// Trap Cause
+    ///
+    /// **WARNING**: panics if the code field does not match any variant of `I` or `E`.
+    #[inline]
+    pub fn cause<I: CoreInterruptNumber, E: ExceptionNumber>(&self) -> Trap<I, E> {
+        self.try_cause().unwrap()
+    }
+
+    /// Attempts to get the trap cause.
+    ///
+    /// Returns an error if the code field does not match any variant of `I` or `E`.
+    #[inline]
+    pub fn try_cause<I: CoreInterruptNumber, E: ExceptionNumber>(&self) -> Result<Trap<I, E>> {
+        Trap::try_from_bits(self.bits)
+    }
+
+    /// Is trap cause an interrupt.
+    #[inline]
+    pub fn is_interrupt(&self) -> bool {
+        self.bits & (1 << (usize::BITS as usize - 1)) != 0
+    }
+
+    /// Is trap cause an exception.
+    #[inline]
+    pub fn is_exception(&self) -> bool {
+        !self.is_interrupt()
+    }
+}
+
+impl<I: CoreInterruptNumber, E: ExceptionNumber> TryFrom<Ucause> for Trap<I, E> {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(ucause: Ucause) -> Result<Self> {
+        ucause.try_cause()
+    }
+}
+
+read_csr_as!(Ucause, 0x042);
+write_csr!(0x042);
+
+/// Writes the CSR
+#[inline]
+pub unsafe fn write(bits: usize) {
+    _write(bits)
+}
+
+/// Set user cause register to corresponding cause.
+#[inline]
+pub unsafe fn set<I: CoreInterruptNumber, E: ExceptionNumber>(cause: Trap<I, E>) {
+    _write(cause.into_bits());
+}
@@ -0,0 +1,4 @@
+//! uepc register (N extension)
+
+read_csr_as_usize!(0x041);
+write_csr_as_usize!(0x041);
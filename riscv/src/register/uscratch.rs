@@ -0,0 +1,4 @@
+//! uscratch register (N extension)
+
+read_csr_as_usize!(0x040);
+write_csr_as_usize!(0x040);
@@ -0,0 +1,69 @@
+//! ustatus register (N extension)
+
+use crate::bits::{bf_extract, bf_insert};
+
+/// ustatus register
+#[derive(Clone, Copy, Debug)]
+pub struct Ustatus {
+    bits: usize,
+}
+
+impl From<usize> for Ustatus {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Ustatus {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// User Interrupt Enable
+    #[inline]
+    pub fn uie(&self) -> bool {
+        bf_extract(self.bits, 0, 1) != 0
+    }
+
+    /// Update User Interrupt Enable
+    ///
+    /// Note this updates a previously read [`Ustatus`] value, but does not
+    /// affect the ustatus CSR itself. See [`set_uie`]/[`clear_uie`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_uie(&mut self, uie: bool) {
+        self.bits = bf_insert(self.bits, 0, 1, uie as usize);
+    }
+
+    /// User Previous Interrupt Enable
+    #[inline]
+    pub fn upie(&self) -> bool {
+        bf_extract(self.bits, 4, 1) != 0
+    }
+
+    /// Update User Previous Interrupt Enable
+    ///
+    /// Note this updates a previously read [`Ustatus`] value, but does not
+    /// affect the ustatus CSR itself. See [`set_upie`] to directly update the
+    /// CSR.
+    #[inline]
+    pub fn set_upie(&mut self, upie: bool) {
+        self.bits = bf_insert(self.bits, 4, 1, upie as usize);
+    }
+}
+
+read_csr_as!(Ustatus, 0x000);
+write_csr_as!(Ustatus, 0x000);
+modify_csr_as!(Ustatus);
+set!(0x000);
+clear!(0x000);
+
+set_clear_csr!(
+    /// User Interrupt Enable
+    , set_uie, clear_uie, 1 << 0);
+set_csr!(
+    /// User Previous Interrupt Enable
+    , set_upie, 1 << 4);
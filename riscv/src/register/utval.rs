@@ -0,0 +1,4 @@
+//! utval register (N extension)
+
+read_csr_as_usize!(0x043);
+write_csr_as_usize!(0x043);
@@ -0,0 +1,65 @@
+//! vcsr register (V extension)
+//!
+//! Vector control and status register. It mirrors the [`vxrm`](super::vxrm) and
+//! [`vxsat`](super::vxsat) registers.
+
+pub use super::vxrm::Vxrm;
+
+/// vcsr register
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Vcsr {
+    bits: usize,
+}
+
+impl From<usize> for Vcsr {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Vcsr {
+    /// Creates a new [`Vcsr`] value from a fixed-point rounding mode and saturation flag.
+    #[inline]
+    pub const fn new(vxrm: Vxrm, vxsat: bool) -> Self {
+        Self {
+            bits: ((vxrm as usize) << 1) | vxsat as usize,
+        }
+    }
+
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Fixed-point saturation flag
+    #[inline]
+    pub fn vxsat(&self) -> bool {
+        self.bits & 1 != 0
+    }
+
+    /// Fixed-point rounding mode
+    #[inline]
+    pub fn vxrm(&self) -> Vxrm {
+        Vxrm::from_bits(self.bits >> 1)
+    }
+}
+
+read_csr_as!(Vcsr, 0x00F);
+write_csr_as!(Vcsr, 0x00F);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vcsr() {
+        let vcsr = Vcsr::new(Vxrm::RoundDown, true);
+        assert_eq!(vcsr.bits(), 0b101);
+        assert_eq!(vcsr.vxrm(), Vxrm::RoundDown);
+        assert!(vcsr.vxsat());
+        assert_eq!(Vcsr::from(0b110).vxrm(), Vxrm::RoundToOdd);
+        assert!(!Vcsr::from(0b110).vxsat());
+    }
+}
@@ -0,0 +1,6 @@
+//! vl register (V extension)
+//!
+//! Vector length register. It holds the number of elements updated by vector instructions,
+//! and is only set by the `vsetvl` family of instructions.
+
+read_csr_as_usize!(0xC20);
@@ -0,0 +1,52 @@
+//! vsatp register (hypervisor extension)
+//!
+//! Virtual supervisor address translation and protection register. It substitutes `satp` when V=1.
+//! It shares the [`Satp`] and [`Mode`] types with the `satp` register,
+//! so values can be freely moved between them.
+
+pub use super::satp::{Mode, Satp};
+use crate::result::Result;
+
+read_csr_as!(Satp, 0x280);
+write_csr_as!(Satp, 0x280);
+
+/// Sets the register to corresponding page table mode, physical page number and address space id.
+///
+/// Note that the VS-stage address-translation caches must be flushed with `HFENCE.VVMA` afterwards
+/// (see [`hfence_vvma`](crate::asm::hfence_vvma)).
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - invalid field values
+#[inline]
+pub unsafe fn set(mode: Mode, asid: usize, ppn: usize) {
+    try_set(mode, asid, ppn).unwrap();
+}
+
+/// Attempts to set the register to corresponding page table mode, physical page number and address space id.
+///
+/// Note that the VS-stage address-translation caches must be flushed with `HFENCE.VVMA` afterwards
+/// (see [`hfence_vvma`](crate::asm::hfence_vvma)).
+#[inline]
+pub unsafe fn try_set(mode: Mode, asid: usize, ppn: usize) -> Result<()> {
+    try_write(Satp::new(mode, asid, ppn)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vsatp() {
+        let vsatp = Satp::new(Mode::Bare, 0x1A, 0x8_0000).unwrap();
+        assert_eq!(vsatp.mode(), Mode::Bare);
+        assert_eq!(vsatp.asid(), 0x1A);
+        assert_eq!(vsatp.ppn(), 0x8_0000);
+
+        let vsatp = Satp::from(vsatp.bits());
+        assert_eq!(vsatp.mode(), Mode::Bare);
+        assert_eq!(vsatp.asid(), 0x1A);
+        assert_eq!(vsatp.ppn(), 0x8_0000);
+    }
+}
@@ -0,0 +1,88 @@
+//! vscause register (hypervisor extension)
+//!
+//! Virtual supervisor cause register. It substitutes `scause` when V=1.
+
+pub use crate::interrupt::{Exception, Interrupt, Trap};
+use crate::result::{Error, Result};
+use crate::{CoreInterruptNumber, ExceptionNumber};
+
+/// vscause register
+#[derive(Clone, Copy, Debug)]
+pub struct Vscause {
+    bits: usize,
+}
+
+impl From<usize> for Vscause {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Vscause {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns the code field
+    #[inline]
+    pub fn code(&self) -> usize {
+        self.bits & !(1 << (usize::BITS as usize - 1))
+    }
+
+    /// Trap Cause
+    ///
+    /// The interrupt (`I`) and exception (`E`) types are target-specific.
+    ///
+    /// **WARNING**: panics if the code field does not match any variant of `I` or `E`.
+    #[inline]
+    pub fn cause<I: CoreInterruptNumber, E: ExceptionNumber>(&self) -> Trap<I, E> {
+        self.try_cause().unwrap()
+    }
+
+    /// Attempts to get the trap cause.
+    ///
+    /// Returns an error if the code field does not match any variant of `I` or `E`.
+    #[inline]
+    pub fn try_cause<I: CoreInterruptNumber, E: ExceptionNumber>(&self) -> Result<Trap<I, E>> {
+        Trap::try_from_bits(self.bits)
+    }
+
+    /// Is trap cause an interrupt.
+    #[inline]
+    pub fn is_interrupt(&self) -> bool {
+        self.bits & (1 << (usize::BITS as usize - 1)) != 0
+    }
+
+    /// Is trap cause an exception.
+    #[inline]
+    pub fn is_exception(&self) -> bool {
+        !self.is_interrupt()
+    }
+}
+
+impl<I: CoreInterruptNumber, E: ExceptionNumber> TryFrom<Vscause> for Trap<I, E> {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(vscause: Vscause) -> Result<Self> {
+        vscause.try_cause()
+    }
+}
+
+read_csr_as!(Vscause, 0x242);
+write_csr!(0x242);
+
+/// Writes the CSR
+#[inline]
+pub unsafe fn write(bits: usize) {
+    _write(bits)
+}
+
+/// Set virtual supervisor cause register to corresponding cause.
+#[inline]
+pub unsafe fn set<I: CoreInterruptNumber, E: ExceptionNumber>(cause: Trap<I, E>) {
+    _write(cause.into_bits());
+}
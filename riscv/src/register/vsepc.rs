@@ -0,0 +1,6 @@
+//! vsepc register (hypervisor extension)
+//!
+//! Virtual supervisor exception program counter. It substitutes `sepc` when V=1.
+
+read_csr_as_usize!(0x241);
+write_csr_as_usize!(0x241);
@@ -0,0 +1,124 @@
+//! vsie register (hypervisor extension)
+//!
+//! Virtual supervisor interrupt-enable register. It substitutes `sie` when V=1, so the
+//! VS-level interrupts delegated by [`hideleg`](super::hideleg) appear as S-level interrupts.
+
+use crate::bits::{bf_extract, bit_mask};
+use crate::result::Result;
+use crate::CoreInterruptNumber;
+
+/// vsie register
+#[derive(Clone, Copy, Debug)]
+pub struct Vsie {
+    bits: usize,
+}
+
+impl From<usize> for Vsie {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Vsie {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Supervisor Software Interrupt Enable
+    #[inline]
+    pub fn ssoft(&self) -> bool {
+        self.bits & (1 << 1) != 0
+    }
+
+    /// Supervisor Timer Interrupt Enable
+    #[inline]
+    pub fn stimer(&self) -> bool {
+        self.bits & (1 << 5) != 0
+    }
+
+    /// Supervisor External Interrupt Enable
+    #[inline]
+    pub fn sext(&self) -> bool {
+        self.bits & (1 << 9) != 0
+    }
+
+    /// Local Counter Overflow Interrupt Enable (Sscofpmf extension)
+    #[inline]
+    pub fn lcofi(&self) -> bool {
+        self.bits & (1 << 13) != 0
+    }
+
+    /// Returns `true` if the given interrupt source is enabled
+    #[inline]
+    pub fn is_enabled<I: CoreInterruptNumber>(&self, interrupt: I) -> bool {
+        let index = interrupt.number() as usize;
+        index < usize::BITS as usize && bf_extract(self.bits, index, 1) != 0
+    }
+}
+
+read_csr_as!(Vsie, 0x204);
+write_csr_as!(Vsie, 0x204);
+set!(0x204);
+clear!(0x204);
+
+set_clear_csr!(
+    /// Supervisor Software Interrupt Enable
+    , set_ssoft, clear_ssoft, 1 << 1);
+set_clear_csr!(
+    /// Supervisor Timer Interrupt Enable
+    , set_stimer, clear_stimer, 1 << 5);
+set_clear_csr!(
+    /// Supervisor External Interrupt Enable
+    , set_sext, clear_sext, 1 << 9);
+set_clear_csr!(
+    /// Local Counter Overflow Interrupt Enable (Sscofpmf extension)
+    , set_lcofi, clear_lcofi, 1 << 13);
+
+/// Enables the given interrupt source
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - interrupt numbers that do not fit in the register
+#[inline]
+pub unsafe fn enable<I: CoreInterruptNumber>(interrupt: I) {
+    try_enable(interrupt).unwrap();
+}
+
+/// Attempts to enable the given interrupt source
+///
+/// Returns an error if the interrupt number does not fit in the register.
+#[inline]
+pub unsafe fn try_enable<I: CoreInterruptNumber>(interrupt: I) -> Result<()> {
+    _try_set(bit_mask(interrupt.number() as usize)?)
+}
+
+/// Disables the given interrupt source
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - interrupt numbers that do not fit in the register
+#[inline]
+pub unsafe fn disable<I: CoreInterruptNumber>(interrupt: I) {
+    try_disable(interrupt).unwrap();
+}
+
+/// Attempts to disable the given interrupt source
+///
+/// Returns an error if the interrupt number does not fit in the register.
+#[inline]
+pub unsafe fn try_disable<I: CoreInterruptNumber>(interrupt: I) -> Result<()> {
+    _try_clear(bit_mask(interrupt.number() as usize)?)
+}
+
+/// Returns `true` if the given interrupt source is enabled
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn is_enabled<I: CoreInterruptNumber>(interrupt: I) -> bool {
+    read().is_enabled(interrupt)
+}
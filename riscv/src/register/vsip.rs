@@ -0,0 +1,105 @@
+//! vsip register (hypervisor extension)
+//!
+//! Virtual supervisor interrupt-pending register. It substitutes `sip` when V=1, so the
+//! VS-level interrupts delegated by [`hideleg`](super::hideleg) appear as S-level interrupts.
+
+use crate::bits::{bf_extract, bit_mask};
+use crate::result::Result;
+use crate::CoreInterruptNumber;
+
+/// vsip register
+#[derive(Clone, Copy, Debug)]
+pub struct Vsip {
+    bits: usize,
+}
+
+impl From<usize> for Vsip {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Vsip {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Supervisor Software Interrupt Pending
+    #[inline]
+    pub fn ssoft(&self) -> bool {
+        self.bits & (1 << 1) != 0
+    }
+
+    /// Supervisor Timer Interrupt Pending
+    #[inline]
+    pub fn stimer(&self) -> bool {
+        self.bits & (1 << 5) != 0
+    }
+
+    /// Supervisor External Interrupt Pending
+    #[inline]
+    pub fn sext(&self) -> bool {
+        self.bits & (1 << 9) != 0
+    }
+
+    /// Local Counter Overflow Interrupt Pending (Sscofpmf extension)
+    #[inline]
+    pub fn lcofi(&self) -> bool {
+        self.bits & (1 << 13) != 0
+    }
+
+    /// Returns `true` if the given interrupt source is pending
+    #[inline]
+    pub fn is_pending<I: CoreInterruptNumber>(&self, interrupt: I) -> bool {
+        let index = interrupt.number() as usize;
+        index < usize::BITS as usize && bf_extract(self.bits, index, 1) != 0
+    }
+}
+
+read_csr_as!(Vsip, 0x244);
+write_csr_as!(Vsip, 0x244);
+set!(0x244);
+clear!(0x244);
+
+set_clear_csr!(
+    /// Supervisor Software Interrupt Pending
+    , set_ssoft, clear_ssoft, 1 << 1);
+set_clear_csr!(
+    /// Local Counter Overflow Interrupt Pending (Sscofpmf extension)
+    , set_lcofi, clear_lcofi, 1 << 13);
+
+/// Returns `true` if the given interrupt source is pending
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn is_pending<I: CoreInterruptNumber>(interrupt: I) -> bool {
+    read().is_pending(interrupt)
+}
+
+/// Clears the pending bit of the given interrupt source
+///
+/// Only writable pending bits (e.g., `SSIP` or `LCOFIP`) can be cleared.
+/// This is typically used to acknowledge supervisor software self-interrupts.
+/// Read-only pending bits (e.g., `STIP` or `SEIP`) must be cleared at the interrupt source,
+/// and writes to them are silently ignored.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - interrupt numbers that do not fit in the register
+#[inline]
+pub unsafe fn clear<I: CoreInterruptNumber>(interrupt: I) {
+    try_clear(interrupt).unwrap();
+}
+
+/// Attempts to clear the pending bit of the given interrupt source
+///
+/// Returns an error if the interrupt number does not fit in the register.
+/// See [`clear()`] for details on which pending bits are writable.
+#[inline]
+pub unsafe fn try_clear<I: CoreInterruptNumber>(interrupt: I) -> Result<()> {
+    _try_clear(bit_mask(interrupt.number() as usize)?)
+}
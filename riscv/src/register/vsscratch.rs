@@ -0,0 +1,8 @@
+//! vsscratch register (hypervisor extension)
+//!
+//! Virtual supervisor scratch register. It substitutes `sscratch` when V=1, and the guest
+//! trap entry usually exchanges it with `sp` (see [`swap`]).
+
+read_csr_as_usize!(0x240);
+write_csr_as_usize!(0x240);
+swap_csr_as_usize!(0x240);
@@ -0,0 +1,290 @@
+//! vsstatus register (hypervisor extension)
+//!
+//! Virtual supervisor status register. It substitutes `sstatus` when V=1.
+
+pub use super::misa::XLEN;
+pub use super::mstatus::{Endianness, FS, VS, XS};
+pub use super::sstatus::SPP;
+use crate::bits::{bf_extract, bf_insert};
+
+/// vsstatus register
+#[derive(Clone, Copy, Debug)]
+pub struct Vsstatus {
+    bits: usize,
+}
+
+impl From<usize> for Vsstatus {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Vsstatus {
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Supervisor Interrupt Enable
+    #[inline]
+    pub fn sie(&self) -> bool {
+        bf_extract(self.bits, 1, 1) != 0
+    }
+
+    /// Update Supervisor Interrupt Enable
+    ///
+    /// Note this updates a previously read [`Vsstatus`] value, but does not
+    /// affect the vsstatus CSR itself. See [`set_sie`]/[`clear_sie`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_sie(&mut self, sie: bool) {
+        self.bits = bf_insert(self.bits, 1, 1, sie as usize);
+    }
+
+    /// Supervisor Previous Interrupt Enable
+    #[inline]
+    pub fn spie(&self) -> bool {
+        bf_extract(self.bits, 5, 1) != 0
+    }
+
+    /// Update Supervisor Previous Interrupt Enable
+    ///
+    /// Note this updates a previously read [`Vsstatus`] value, but does not
+    /// affect the vsstatus CSR itself. See [`set_spie`] to directly update the
+    /// CSR.
+    #[inline]
+    pub fn set_spie(&mut self, spie: bool) {
+        self.bits = bf_insert(self.bits, 5, 1, spie as usize);
+    }
+
+    /// VU-mode non-instruction-fetch memory endianness
+    #[inline]
+    pub fn ube(&self) -> Endianness {
+        Endianness::from(bf_extract(self.bits, 6, 1) != 0)
+    }
+
+    /// Update VU-mode non-instruction-fetch memory endianness
+    ///
+    /// Note this updates a previously read [`Vsstatus`] value, but does not
+    /// affect the vsstatus CSR itself. See [`set_ube`] to directly update the
+    /// CSR.
+    #[inline]
+    pub fn set_ube(&mut self, endianness: Endianness) {
+        self.bits = bf_insert(self.bits, 6, 1, endianness as usize);
+    }
+
+    /// Supervisor Previous Privilege Mode
+    #[inline]
+    pub fn spp(&self) -> SPP {
+        match bf_extract(self.bits, 8, 1) != 0 {
+            true => SPP::Supervisor,
+            false => SPP::User,
+        }
+    }
+
+    /// Update Supervisor Previous Privilege Mode
+    ///
+    /// Note this updates a previously read [`Vsstatus`] value, but does not
+    /// affect the vsstatus CSR itself. See [`set_spp`] to directly update the
+    /// CSR.
+    #[inline]
+    pub fn set_spp(&mut self, spp: SPP) {
+        self.bits = bf_insert(self.bits, 8, 1, spp as usize);
+    }
+
+    /// Vector extension state
+    #[inline]
+    pub fn vs(&self) -> VS {
+        match bf_extract(self.bits, 9, 2) {
+            0b00 => VS::Off,
+            0b01 => VS::Initial,
+            0b10 => VS::Clean,
+            0b11 => VS::Dirty,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Update vector extension state
+    ///
+    /// Note this updates a previously read [`Vsstatus`] value, but does not
+    /// affect the vsstatus CSR itself. See [`set_vs`] to directly update the
+    /// CSR.
+    #[inline]
+    pub fn set_vs(&mut self, vs: VS) {
+        self.bits = bf_insert(self.bits, 9, 2, vs as usize);
+    }
+
+    /// Floating-point extension state
+    #[inline]
+    pub fn fs(&self) -> FS {
+        match bf_extract(self.bits, 13, 2) {
+            0b00 => FS::Off,
+            0b01 => FS::Initial,
+            0b10 => FS::Clean,
+            0b11 => FS::Dirty,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Update floating-point extension state
+    ///
+    /// Note this updates a previously read [`Vsstatus`] value, but does not
+    /// affect the vsstatus CSR itself. See [`set_fs`] to directly update the
+    /// CSR.
+    #[inline]
+    pub fn set_fs(&mut self, fs: FS) {
+        self.bits = bf_insert(self.bits, 13, 2, fs as usize);
+    }
+
+    /// Additional extension state
+    #[inline]
+    pub fn xs(&self) -> XS {
+        match bf_extract(self.bits, 15, 2) {
+            0b00 => XS::AllOff,
+            0b01 => XS::NoneDirtyOrClean,
+            0b10 => XS::NoneDirtySomeClean,
+            0b11 => XS::SomeDirty,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Update additional extension state
+    ///
+    /// Note this updates a previously read [`Vsstatus`] value, but does not
+    /// affect the vsstatus CSR itself.
+    #[inline]
+    pub fn set_xs(&mut self, xs: XS) {
+        self.bits = bf_insert(self.bits, 15, 2, xs as usize);
+    }
+
+    /// Permit Supervisor User Memory access
+    #[inline]
+    pub fn sum(&self) -> bool {
+        bf_extract(self.bits, 18, 1) != 0
+    }
+
+    /// Update Permit Supervisor User Memory access
+    ///
+    /// Note this updates a previously read [`Vsstatus`] value, but does not
+    /// affect the vsstatus CSR itself. See [`set_sum`]/[`clear_sum`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_sum(&mut self, sum: bool) {
+        self.bits = bf_insert(self.bits, 18, 1, sum as usize);
+    }
+
+    /// Make eXecutable Readable
+    #[inline]
+    pub fn mxr(&self) -> bool {
+        bf_extract(self.bits, 19, 1) != 0
+    }
+
+    /// Update Make eXecutable Readable
+    ///
+    /// Note this updates a previously read [`Vsstatus`] value, but does not
+    /// affect the vsstatus CSR itself. See [`set_mxr`]/[`clear_mxr`] to directly
+    /// update the CSR.
+    #[inline]
+    pub fn set_mxr(&mut self, mxr: bool) {
+        self.bits = bf_insert(self.bits, 19, 1, mxr as usize);
+    }
+
+    /// Effective xlen in VU-mode (i.e., `VUXLEN`).
+    ///
+    /// In RISCV-32, UXL does not exist, and `VUXLEN` is always [`XLEN::XLEN32`].
+    #[inline]
+    pub fn uxl(&self) -> XLEN {
+        match () {
+            #[cfg(riscv32)]
+            () => XLEN::XLEN32,
+            #[cfg(not(riscv32))]
+            () => XLEN::from(bf_extract(self.bits, 32, 2) as u8),
+        }
+    }
+
+    /// Update effective xlen in VU-mode (i.e., `VUXLEN`).
+    ///
+    /// Note this updates a previously read [`Vsstatus`] value, but does not
+    /// affect the vsstatus CSR itself.
+    #[inline]
+    #[allow(unused_variables)]
+    pub fn set_uxl(&mut self, uxl: XLEN) {
+        #[cfg(not(riscv32))]
+        {
+            self.bits = bf_insert(self.bits, 32, 2, uxl as usize);
+        }
+    }
+
+    /// Whether either the FS, VS, or XS field signals the presence of some dirty state
+    #[inline]
+    pub fn sd(&self) -> bool {
+        bf_extract(self.bits, usize::BITS as usize - 1, 1) != 0
+    }
+
+    /// Update whether either the FS, VS, or XS field signals the presence of
+    /// some dirty state
+    ///
+    /// Note this updates a previously read [`Vsstatus`] value, but does not
+    /// affect the vsstatus CSR itself.
+    #[inline]
+    pub fn set_sd(&mut self, sd: bool) {
+        self.bits = bf_insert(self.bits, usize::BITS as usize - 1, 1, sd as usize);
+    }
+}
+
+read_csr_as!(Vsstatus, 0x200);
+write_csr_as!(Vsstatus, 0x200);
+modify_csr_as!(Vsstatus);
+set!(0x200);
+clear!(0x200);
+
+set_clear_csr!(
+    /// Supervisor Interrupt Enable
+    , set_sie, clear_sie, 1 << 1);
+set_csr!(
+    /// Supervisor Previous Interrupt Enable
+    , set_spie, 1 << 5);
+set_clear_csr!(
+    /// Permit Supervisor User Memory access
+    , set_sum, clear_sum, 1 << 18);
+set_clear_csr!(
+    /// Make eXecutable Readable
+    , set_mxr, clear_mxr, 1 << 19);
+
+/// Set VU-mode non-instruction-fetch memory endianness
+#[inline]
+pub unsafe fn set_ube(endianness: Endianness) {
+    match endianness {
+        Endianness::BigEndian => _set(1 << 6),
+        Endianness::LittleEndian => _clear(1 << 6),
+    }
+}
+
+/// Supervisor Previous Privilege Mode
+#[inline]
+pub unsafe fn set_spp(spp: SPP) {
+    match spp {
+        SPP::Supervisor => _set(1 << 8),
+        SPP::User => _clear(1 << 8),
+    }
+}
+
+/// Vector extension state
+#[inline]
+pub unsafe fn set_vs(vs: VS) {
+    let mut value = _read();
+    value &= !(0x3 << 9); // clear previous value
+    value |= (vs as usize) << 9;
+    _write(value);
+}
+
+/// Floating-point extension state
+#[inline]
+pub unsafe fn set_fs(fs: FS) {
+    let mut value = _read();
+    value &= !(0x3 << 13); // clear previous value
+    value |= (fs as usize) << 13;
+    _write(value);
+}
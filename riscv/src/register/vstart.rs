@@ -0,0 +1,7 @@
+//! vstart register (V extension)
+//!
+//! Vector start index register. It holds the index of the first element to be processed by
+//! a vector instruction, and is reset to zero after each vector instruction.
+
+read_csr_as_usize!(0x008);
+write_csr_as_usize!(0x008);
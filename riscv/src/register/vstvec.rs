@@ -0,0 +1,120 @@
+//! vstvec register (hypervisor extension)
+//!
+//! Virtual supervisor trap vector base address register.
+
+pub use crate::register::mtvec::TrapMode;
+use crate::register::trap_vector::{self, TrapVector};
+use crate::result::{Error, Result};
+
+/// vstvec register
+#[derive(Clone, Copy, Debug)]
+pub struct Vstvec {
+    bits: usize,
+}
+
+impl Vstvec {
+    /// Creates a new [`Vstvec`] value from a trap-vector base-address and a trap mode.
+    ///
+    /// Returns an error if the base-address is not 4-byte aligned
+    /// (64-byte aligned in [`TrapMode::Clic`] mode).
+    #[inline]
+    pub fn new(address: usize, mode: TrapMode) -> Result<Self> {
+        TrapVector::new(address, mode).try_into()
+    }
+
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns the trap-vector base-address
+    #[inline]
+    pub fn address(&self) -> usize {
+        trap_vector::address(self.bits)
+    }
+
+    /// Updates the trap-vector base-address
+    ///
+    /// Returns an error if the base-address is not 4-byte aligned
+    /// (64-byte aligned in [`TrapMode::Clic`] mode).
+    ///
+    /// Note this updates a previously read [`Vstvec`] value, but does not
+    /// affect the vstvec CSR itself. See [`write()`] to directly update the CSR.
+    #[inline]
+    pub fn set_address(&mut self, address: usize) -> Result<()> {
+        trap_vector::validate_address(address, self.raw_mode())?;
+        self.bits = address | self.raw_mode();
+        Ok(())
+    }
+
+    /// Returns the raw trap-vector mode bits
+    ///
+    /// Unlike [`Vstvec::trap_mode`], this also exposes reserved and vendor-specific encodings.
+    #[inline]
+    pub fn raw_mode(&self) -> usize {
+        trap_vector::raw_mode(self.bits)
+    }
+
+    /// Returns the trap-vector mode
+    ///
+    /// Returns `None` if the mode bits hold a reserved or vendor-specific encoding.
+    /// Use [`Vstvec::raw_mode`] to inspect them.
+    #[inline]
+    pub fn trap_mode(&self) -> Option<TrapMode> {
+        trap_vector::trap_mode(self.bits)
+    }
+
+    /// Updates the trap-vector mode
+    ///
+    /// Note this updates a previously read [`Vstvec`] value, but does not
+    /// affect the vstvec CSR itself. See [`write()`] to directly update the CSR.
+    #[inline]
+    pub fn set_trap_mode(&mut self, mode: TrapMode) {
+        self.bits = self.address() | mode as usize;
+    }
+}
+
+impl TryFrom<Vstvec> for TrapVector {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(vstvec: Vstvec) -> Result<Self> {
+        Self::try_from_bits(vstvec.bits)
+    }
+}
+
+impl TryFrom<TrapVector> for Vstvec {
+    type Error = Error;
+
+    #[inline]
+    fn try_from(vector: TrapVector) -> Result<Self> {
+        Ok(Self {
+            bits: vector.try_into_bits()?,
+        })
+    }
+}
+
+read_csr_as!(Vstvec, 0x205);
+write_csr!(0x205);
+
+/// Writes the CSR
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - misaligned trap-vector base-address for the current trap mode
+#[inline]
+pub unsafe fn write(vstvec: Vstvec) {
+    try_write(vstvec).unwrap();
+}
+
+/// Attempts to write the CSR
+///
+/// Returns an error if the trap-vector base-address is not 4-byte aligned
+/// (64-byte aligned in [`TrapMode::Clic`] mode).
+#[inline]
+pub unsafe fn try_write(vstvec: Vstvec) -> Result<()> {
+    trap_vector::validate_bits(vstvec.bits)?;
+    _try_write(vstvec.bits)
+}
@@ -0,0 +1,202 @@
+//! vtype register (V extension)
+//!
+//! Vector data type register. It holds the configuration set by the `vsetvl` family of
+//! instructions (see [`vsetvl`](crate::asm::vsetvl)), and cannot be written directly.
+
+use crate::result::{Error, Result};
+
+/// Position of the `vill` bit
+const VILL_SHIFT: u32 = usize::BITS - 1;
+
+/// vtype register
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Vtype {
+    bits: usize,
+}
+
+impl From<usize> for Vtype {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl Vtype {
+    /// Creates a new [`Vtype`] value from a selected element width, register group multiplier,
+    /// and tail/mask agnostic policies.
+    #[inline]
+    pub const fn new(sew: Sew, lmul: Lmul, ta: bool, ma: bool) -> Self {
+        Self {
+            bits: ((ma as usize) << 7)
+                | ((ta as usize) << 6)
+                | ((sew as usize) << 3)
+                | lmul as usize,
+        }
+    }
+
+    /// Returns the contents of the register as raw bits
+    #[inline]
+    pub const fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Illegal configuration
+    ///
+    /// When set, the last `vsetvl` instruction requested an unsupported configuration,
+    /// and vector instructions that depend on `vtype` raise an illegal-instruction exception.
+    #[inline]
+    pub fn vill(&self) -> bool {
+        self.bits & (1 << VILL_SHIFT) != 0
+    }
+
+    /// Selected element width
+    ///
+    /// **WARNING**: panics if the field has an invalid variant.
+    #[inline]
+    pub fn sew(&self) -> Sew {
+        self.try_sew().unwrap()
+    }
+
+    /// Attempts to get the selected element width
+    #[inline]
+    pub fn try_sew(&self) -> Result<Sew> {
+        (((self.bits >> 3) & 0b111) as u8).try_into()
+    }
+
+    /// Vector register group multiplier
+    ///
+    /// **WARNING**: panics if the field has an invalid variant.
+    #[inline]
+    pub fn lmul(&self) -> Lmul {
+        self.try_lmul().unwrap()
+    }
+
+    /// Attempts to get the vector register group multiplier
+    #[inline]
+    pub fn try_lmul(&self) -> Result<Lmul> {
+        ((self.bits & 0b111) as u8).try_into()
+    }
+
+    /// Tail agnostic
+    #[inline]
+    pub fn vta(&self) -> bool {
+        self.bits & (1 << 6) != 0
+    }
+
+    /// Mask agnostic
+    #[inline]
+    pub fn vma(&self) -> bool {
+        self.bits & (1 << 7) != 0
+    }
+}
+
+/// Selected element width
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Sew {
+    /// 8-bit elements
+    E8 = 0,
+    /// 16-bit elements
+    E16 = 1,
+    /// 32-bit elements
+    E32 = 2,
+    /// 64-bit elements
+    E64 = 3,
+}
+
+impl Sew {
+    /// Returns the element width in bits
+    #[inline]
+    pub const fn bits(self) -> usize {
+        8 << self as usize
+    }
+}
+
+impl TryFrom<u8> for Sew {
+    type Error = Error;
+
+    fn try_from(val: u8) -> Result<Self> {
+        match val {
+            0 => Ok(Self::E8),
+            1 => Ok(Self::E16),
+            2 => Ok(Self::E32),
+            3 => Ok(Self::E64),
+            _ => Err(Error::InvalidFieldVariant {
+                field: "vsew",
+                value: val as usize,
+            }),
+        }
+    }
+}
+
+/// Vector register group multiplier
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Lmul {
+    /// LMUL = 1
+    M1 = 0,
+    /// LMUL = 2
+    M2 = 1,
+    /// LMUL = 4
+    M4 = 2,
+    /// LMUL = 8
+    M8 = 3,
+    /// LMUL = 1/8
+    Mf8 = 5,
+    /// LMUL = 1/4
+    Mf4 = 6,
+    /// LMUL = 1/2
+    Mf2 = 7,
+}
+
+impl TryFrom<u8> for Lmul {
+    type Error = Error;
+
+    fn try_from(val: u8) -> Result<Self> {
+        match val {
+            0 => Ok(Self::M1),
+            1 => Ok(Self::M2),
+            2 => Ok(Self::M4),
+            3 => Ok(Self::M8),
+            5 => Ok(Self::Mf8),
+            6 => Ok(Self::Mf4),
+            7 => Ok(Self::Mf2),
+            _ => Err(Error::InvalidFieldVariant {
+                field: "vlmul",
+                value: val as usize,
+            }),
+        }
+    }
+}
+
+read_csr_as!(Vtype, 0xC21);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vtype() {
+        let vtype = Vtype::new(Sew::E32, Lmul::Mf2, true, false);
+        assert_eq!(vtype.bits(), 0b0101_0111);
+        assert_eq!(vtype.sew(), Sew::E32);
+        assert_eq!(vtype.sew().bits(), 32);
+        assert_eq!(vtype.lmul(), Lmul::Mf2);
+        assert!(vtype.vta() && !vtype.vma() && !vtype.vill());
+
+        let vtype = Vtype::from((1 << VILL_SHIFT) | 0b100_100);
+        assert!(vtype.vill());
+        assert_eq!(
+            vtype.try_sew(),
+            Err(Error::InvalidFieldVariant {
+                field: "vsew",
+                value: 4,
+            })
+        );
+        assert_eq!(
+            vtype.try_lmul(),
+            Err(Error::InvalidFieldVariant {
+                field: "vlmul",
+                value: 4,
+            })
+        );
+    }
+}
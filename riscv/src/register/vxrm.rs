@@ -0,0 +1,60 @@
+//! vxrm register (V extension)
+//!
+//! Vector fixed-point rounding mode register.
+
+/// Fixed-point rounding mode
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Vxrm {
+    /// Round to nearest, ties up (`rnu`)
+    RoundToNearestUp = 0,
+    /// Round to nearest, ties to even (`rne`)
+    RoundToNearestEven = 1,
+    /// Round down, i.e., truncate (`rdn`)
+    RoundDown = 2,
+    /// Round to odd, i.e., jam (`rod`)
+    RoundToOdd = 3,
+}
+
+impl Vxrm {
+    /// Decodes the two least-significant bits of `bits` as a fixed-point rounding mode
+    #[inline]
+    pub const fn from_bits(bits: usize) -> Self {
+        match bits & 0b11 {
+            0 => Self::RoundToNearestUp,
+            1 => Self::RoundToNearestEven,
+            2 => Self::RoundDown,
+            _ => Self::RoundToOdd,
+        }
+    }
+}
+
+read_csr!(0x00A);
+write_csr!(0x00A);
+
+/// Reads the CSR
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn read() -> Vxrm {
+    try_read().unwrap()
+}
+
+/// Attempts to read the CSR
+#[inline]
+pub fn try_read() -> crate::result::Result<Vxrm> {
+    Ok(Vxrm::from_bits(unsafe { _try_read()? }))
+}
+
+/// Writes the CSR
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn write(vxrm: Vxrm) {
+    try_write(vxrm).unwrap();
+}
+
+/// Attempts to write the CSR
+#[inline]
+pub fn try_write(vxrm: Vxrm) -> crate::result::Result<()> {
+    unsafe { _try_write(vxrm as usize) }
+}
@@ -0,0 +1,24 @@
+//! vxsat register (V extension)
+//!
+//! Vector fixed-point saturation flag register.
+
+read_csr!(0x009);
+clear!(0x009);
+
+/// Reads the CSR, and returns true if a fixed-point instruction saturated its result
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn read() -> bool {
+    try_read().unwrap()
+}
+
+/// Attempts to read the CSR
+#[inline]
+pub fn try_read() -> crate::result::Result<bool> {
+    Ok(unsafe { _try_read()? } & 1 != 0)
+}
+
+clear_csr!(
+    /// Clears the saturation flag
+    , clear_vxsat, 1);
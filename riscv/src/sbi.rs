@@ -0,0 +1,111 @@
+//! Supervisor Binary Interface (SBI) bindings
+//!
+//! These bindings are meant for S-mode software running on top of an SBI implementation
+//! (e.g., OpenSBI or RustSBI). All the calls follow the SBI calling convention:
+//! the extension ID goes in `a7`, the function ID in `a6`, the arguments in `a0`-`a5`,
+//! and the implementation returns an error code in `a0` and a value in `a1`.
+
+use core::fmt;
+
+pub mod pmu;
+
+/// SBI error codes
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// Failed (`SBI_ERR_FAILED`)
+    Failed,
+    /// Not supported (`SBI_ERR_NOT_SUPPORTED`)
+    NotSupported,
+    /// Invalid parameter(s) (`SBI_ERR_INVALID_PARAM`)
+    InvalidParam,
+    /// Denied or not allowed (`SBI_ERR_DENIED`)
+    Denied,
+    /// Invalid address(es) (`SBI_ERR_INVALID_ADDRESS`)
+    InvalidAddress,
+    /// Already available (`SBI_ERR_ALREADY_AVAILABLE`)
+    AlreadyAvailable,
+    /// Already started (`SBI_ERR_ALREADY_STARTED`)
+    AlreadyStarted,
+    /// Already stopped (`SBI_ERR_ALREADY_STOPPED`)
+    AlreadyStopped,
+    /// Shared memory not available (`SBI_ERR_NO_SHMEM`)
+    NoShmem,
+    /// Error code not defined by the SBI specification
+    Unknown(isize),
+}
+
+impl Error {
+    /// Decodes an error from a (non-zero) SBI error code
+    #[inline]
+    pub const fn from_code(code: isize) -> Self {
+        match code {
+            -1 => Self::Failed,
+            -2 => Self::NotSupported,
+            -3 => Self::InvalidParam,
+            -4 => Self::Denied,
+            -5 => Self::InvalidAddress,
+            -6 => Self::AlreadyAvailable,
+            -7 => Self::AlreadyStarted,
+            -8 => Self::AlreadyStopped,
+            -9 => Self::NoShmem,
+            code => Self::Unknown(code),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Failed => write!(f, "SBI call failed"),
+            Self::NotSupported => write!(f, "SBI call not supported"),
+            Self::InvalidParam => write!(f, "invalid SBI call parameter"),
+            Self::Denied => write!(f, "SBI call denied"),
+            Self::InvalidAddress => write!(f, "invalid SBI call address"),
+            Self::AlreadyAvailable => write!(f, "already available"),
+            Self::AlreadyStarted => write!(f, "already started"),
+            Self::AlreadyStopped => write!(f, "already stopped"),
+            Self::NoShmem => write!(f, "shared memory not available"),
+            Self::Unknown(code) => write!(f, "unknown SBI error code: {code}"),
+        }
+    }
+}
+
+/// Convenience alias for the [Result](core::result::Result) type for SBI calls.
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// Performs an SBI call.
+///
+/// On non-`riscv` targets, it always returns [`Error::NotSupported`].
+#[inline]
+#[allow(unused_variables)]
+pub(crate) fn ecall(eid: usize, fid: usize, args: [usize; 6]) -> Result<usize> {
+    match () {
+        #[cfg(riscv)]
+        () => {
+            let error: isize;
+            let value: usize;
+            // SAFETY: the SBI calling convention only clobbers a0 and a1
+            unsafe {
+                core::arch::asm!(
+                    "ecall",
+                    inlateout("a0") args[0] => error,
+                    inlateout("a1") args[1] => value,
+                    in("a2") args[2],
+                    in("a3") args[3],
+                    in("a4") args[4],
+                    in("a5") args[5],
+                    in("a6") fid,
+                    in("a7") eid,
+                    options(nostack),
+                );
+            }
+            match error {
+                0 => Ok(value),
+                code => Err(Error::from_code(code)),
+            }
+        }
+
+        #[cfg(not(riscv))]
+        () => Err(Error::NotSupported),
+    }
+}
@@ -0,0 +1,347 @@
+//! Performance Monitoring Unit extension (`PMU`)
+//!
+//! S-mode software cannot program the machine-level hardware performance counters directly.
+//! Instead, the SBI implementation discovers and configures them on its behalf. It also provides
+//! firmware counters that count SBI-specific events (e.g., the number of emulated accesses).
+
+use super::{ecall, Result};
+use core::ops::BitOr;
+
+/// PMU extension ID
+pub const EID: usize = 0x504D55;
+
+const NUM_COUNTERS: usize = 0;
+const COUNTER_GET_INFO: usize = 1;
+const COUNTER_CONFIG_MATCHING: usize = 2;
+const COUNTER_START: usize = 3;
+const COUNTER_STOP: usize = 4;
+const COUNTER_FW_READ: usize = 5;
+#[cfg(riscv32)]
+const COUNTER_FW_READ_HI: usize = 6;
+
+/// Counter information returned by [`counter_get_info`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CounterInfo {
+    bits: usize,
+}
+
+impl From<usize> for CounterInfo {
+    #[inline]
+    fn from(bits: usize) -> Self {
+        Self { bits }
+    }
+}
+
+impl CounterInfo {
+    /// Returns the raw counter information
+    #[inline]
+    pub const fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns `true` if this is a firmware counter
+    #[inline]
+    pub const fn is_firmware(&self) -> bool {
+        self.bits & (1 << (usize::BITS - 1)) != 0
+    }
+
+    /// Returns the CSR number of a hardware counter
+    ///
+    /// Returns `None` for firmware counters.
+    #[inline]
+    pub const fn csr(&self) -> Option<usize> {
+        match self.is_firmware() {
+            true => None,
+            false => Some(self.bits & 0xFFF),
+        }
+    }
+
+    /// Returns the width (in bits) of a hardware counter
+    ///
+    /// Returns `None` for firmware counters.
+    #[inline]
+    pub const fn width(&self) -> Option<usize> {
+        match self.is_firmware() {
+            true => None,
+            false => Some(((self.bits >> 12) & 0x3F) + 1),
+        }
+    }
+}
+
+/// Event types
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventType {
+    /// Hardware general events
+    Hardware = 0x0,
+    /// Hardware cache events
+    Cache = 0x1,
+    /// Hardware raw events
+    Raw = 0x2,
+    /// Firmware events
+    Firmware = 0xF,
+}
+
+/// Event index used to match a counter in [`counter_config_matching`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Event {
+    bits: usize,
+}
+
+impl Event {
+    /// Creates a new event index from an event type and an event code
+    #[inline]
+    pub const fn new(event_type: EventType, code: u16) -> Self {
+        Self {
+            bits: ((event_type as usize) << 16) | code as usize,
+        }
+    }
+
+    /// Returns the raw event index
+    #[inline]
+    pub const fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// Returns the event code
+    #[inline]
+    pub const fn code(&self) -> u16 {
+        self.bits as u16
+    }
+}
+
+macro_rules! flags {
+    ($(#[$attr:meta])* $name:ident { $($(#[$fattr:meta])* $flag:ident = $bit:expr,)* }) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+        pub struct $name {
+            bits: usize,
+        }
+
+        impl $name {
+            /// No flags set
+            pub const NONE: Self = Self { bits: 0 };
+            $(
+                $(#[$fattr])*
+                pub const $flag: Self = Self { bits: 1 << $bit };
+            )*
+
+            /// Returns the raw flags
+            #[inline]
+            pub const fn bits(&self) -> usize {
+                self.bits
+            }
+
+            /// Returns `true` if all the flags in `other` are set
+            #[inline]
+            pub const fn contains(&self, other: Self) -> bool {
+                self.bits & other.bits == other.bits
+            }
+        }
+
+        impl BitOr for $name {
+            type Output = Self;
+
+            #[inline]
+            fn bitor(self, rhs: Self) -> Self {
+                Self {
+                    bits: self.bits | rhs.bits,
+                }
+            }
+        }
+    };
+}
+
+flags!(
+    /// Flags for [`counter_config_matching`]
+    ConfigFlags {
+        /// Skip the counter matching
+        SKIP_MATCH = 0,
+        /// Clear (reset) the counter value in the counter configuration
+        CLEAR_VALUE = 1,
+        /// Start the counter after configuring a matching counter
+        AUTO_START = 2,
+        /// Event counting inhibited in VU-mode
+        SET_VUINH = 3,
+        /// Event counting inhibited in VS-mode
+        SET_VSINH = 4,
+        /// Event counting inhibited in U-mode
+        SET_UINH = 5,
+        /// Event counting inhibited in S-mode
+        SET_SINH = 6,
+        /// Event counting inhibited in M-mode
+        SET_MINH = 7,
+    }
+);
+
+flags!(
+    /// Flags for [`counter_start`]
+    StartFlags {
+        /// Set the value of the counters to the initial value
+        SET_INIT_VALUE = 0,
+        /// Initialize the counters from the snapshot shared memory
+        INIT_SNAPSHOT = 1,
+    }
+);
+
+flags!(
+    /// Flags for [`counter_stop`]
+    StopFlags {
+        /// Reset the counter to event mapping
+        RESET = 0,
+        /// Save a snapshot of the counters in the snapshot shared memory
+        TAKE_SNAPSHOT = 1,
+    }
+);
+
+/// Returns the number of counters (both hardware and firmware).
+#[inline]
+pub fn num_counters() -> Result<usize> {
+    ecall(EID, NUM_COUNTERS, [0; 6])
+}
+
+/// Returns the details of the counter `counter_idx`.
+#[inline]
+pub fn counter_get_info(counter_idx: usize) -> Result<CounterInfo> {
+    ecall(EID, COUNTER_GET_INFO, [counter_idx, 0, 0, 0, 0, 0]).map(CounterInfo::from)
+}
+
+/// Finds and configures a counter, from the set of counters selected by
+/// `counter_idx_base` and `counter_idx_mask`, able to monitor `event`.
+///
+/// `event_data` holds additional event configuration (e.g., the raw event selector).
+/// Returns the index of the configured counter.
+#[inline]
+pub fn counter_config_matching(
+    counter_idx_base: usize,
+    counter_idx_mask: usize,
+    config_flags: ConfigFlags,
+    event: Event,
+    event_data: u64,
+) -> Result<usize> {
+    let (lo, hi) = split(event_data);
+    ecall(
+        EID,
+        COUNTER_CONFIG_MATCHING,
+        [
+            counter_idx_base,
+            counter_idx_mask,
+            config_flags.bits(),
+            event.bits(),
+            lo,
+            hi,
+        ],
+    )
+}
+
+/// Starts or enables the counters selected by `counter_idx_base` and `counter_idx_mask`.
+///
+/// `initial_value` is only used if [`StartFlags::SET_INIT_VALUE`] is set.
+#[inline]
+pub fn counter_start(
+    counter_idx_base: usize,
+    counter_idx_mask: usize,
+    start_flags: StartFlags,
+    initial_value: u64,
+) -> Result<()> {
+    let (lo, hi) = split(initial_value);
+    ecall(
+        EID,
+        COUNTER_START,
+        [
+            counter_idx_base,
+            counter_idx_mask,
+            start_flags.bits(),
+            lo,
+            hi,
+            0,
+        ],
+    )
+    .map(|_| ())
+}
+
+/// Stops or disables the counters selected by `counter_idx_base` and `counter_idx_mask`.
+#[inline]
+pub fn counter_stop(
+    counter_idx_base: usize,
+    counter_idx_mask: usize,
+    stop_flags: StopFlags,
+) -> Result<()> {
+    ecall(
+        EID,
+        COUNTER_STOP,
+        [
+            counter_idx_base,
+            counter_idx_mask,
+            stop_flags.bits(),
+            0,
+            0,
+            0,
+        ],
+    )
+    .map(|_| ())
+}
+
+/// Reads the current value of the firmware counter `counter_idx`.
+///
+/// On RV32, the upper 32 bits are read using `sbi_pmu_counter_fw_read_hi` (SBI v2.0).
+/// They are assumed to be zero if the SBI implementation does not support it.
+#[inline]
+pub fn counter_fw_read(counter_idx: usize) -> Result<u64> {
+    let lo = ecall(EID, COUNTER_FW_READ, [counter_idx, 0, 0, 0, 0, 0])?;
+    match () {
+        #[cfg(riscv32)]
+        () => {
+            let hi = match ecall(EID, COUNTER_FW_READ_HI, [counter_idx, 0, 0, 0, 0, 0]) {
+                Err(super::Error::NotSupported) => 0,
+                hi => hi?,
+            };
+            Ok(((hi as u64) << 32) | lo as u64)
+        }
+
+        #[cfg(not(riscv32))]
+        () => Ok(lo as u64),
+    }
+}
+
+/// Splits a 64-bit argument following the SBI calling convention.
+///
+/// On RV32, 64-bit arguments are passed in two registers (low half first).
+/// Otherwise, they are passed in a single register, and the second one is unused.
+#[inline]
+const fn split(value: u64) -> (usize, usize) {
+    match () {
+        #[cfg(riscv32)]
+        () => (value as usize, (value >> 32) as usize),
+
+        #[cfg(not(riscv32))]
+        () => (value as usize, 0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sbi::Error;
+
+    #[test]
+    fn test_pmu() {
+        assert_eq!(num_counters(), Err(Error::NotSupported));
+
+        let info = CounterInfo::from((63 << 12) | 0xC00);
+        assert!(!info.is_firmware());
+        assert_eq!(info.csr(), Some(0xC00));
+        assert_eq!(info.width(), Some(64));
+        let info = CounterInfo::from(1 << (usize::BITS - 1));
+        assert!(info.is_firmware());
+        assert_eq!(info.csr(), None);
+
+        let event = Event::new(EventType::Firmware, 0x15);
+        assert_eq!(event.bits(), 0xF_0015);
+        assert_eq!(event.code(), 0x15);
+
+        let flags = ConfigFlags::CLEAR_VALUE | ConfigFlags::AUTO_START;
+        assert_eq!(flags.bits(), 0b110);
+        assert!(flags.contains(ConfigFlags::AUTO_START));
+        assert!(!flags.contains(ConfigFlags::SKIP_MATCH));
+    }
+}
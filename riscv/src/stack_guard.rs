@@ -0,0 +1,134 @@
+//! PMP-based stack guard
+//!
+//! A stack guard is a locked, no-access PMP region placed just below the stack.
+//! As locked entries also apply to M-mode, a stack overflow faults deterministically
+//! with an access fault instead of silently corrupting the memory below the stack.
+//!
+//! Note that PMP entries are matched in priority order: the guard only takes effect if
+//! no lower-numbered entry grants access to the guard region.
+
+use crate::pmp::{self, Permission, Range, Region, ENTRIES};
+use crate::result::{Error, Result};
+
+/// Installs a stack guard covering `guard_size` bytes from `guard_base`.
+///
+/// Returns the index of the PMP entry that holds the stack guard.
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - invalid guard region (see [`try_install`])
+/// - no PMP entry available
+#[inline]
+pub unsafe fn install(guard_base: usize, guard_size: usize) -> usize {
+    try_install(guard_base, guard_size).unwrap()
+}
+
+/// Attempts to install a stack guard covering `guard_size` bytes from `guard_base`.
+///
+/// The guard uses the lowest-numbered PMP entry that is disabled, not locked, and not
+/// the base address of a top-of-range entry. As the entry is locked, it cannot be
+/// released until the next reset. Every call consumes a new PMP entry.
+///
+/// Returns the index of the PMP entry that holds the stack guard, or an error if:
+///
+/// - `guard_size` is neither 4 nor a power of two of at least 8 bytes,
+///   or `guard_base` is not aligned to `guard_size`.
+/// - all the PMP entries are in use ([`Error::IndexOutOfBounds`]).
+#[inline]
+pub unsafe fn try_install(guard_base: usize, guard_size: usize) -> Result<usize> {
+    let region = Region {
+        base: guard_base,
+        size: guard_size,
+        permission: Permission::NONE,
+        mode: match guard_size {
+            4 => Range::NA4,
+            _ => Range::NAPOT,
+        },
+        locked: true,
+    };
+    region.pmpaddr()?;
+
+    let index = try_find_entry()?;
+    pmp::try_set_entry(index, region)?;
+    Ok(index)
+}
+
+/// Returns the index of the PMP entry that holds the stack guard covering `guard_size` bytes
+/// from `guard_base`, or `None` if no such stack guard is installed.
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn entry(guard_base: usize, guard_size: usize) -> Option<usize> {
+    try_entry(guard_base, guard_size).unwrap()
+}
+
+/// Attempts to find the index of the PMP entry that holds the stack guard covering `guard_size` bytes
+/// from `guard_base`.
+///
+/// Returns `Ok(None)` if no such stack guard is installed.
+#[inline]
+pub fn try_entry(guard_base: usize, guard_size: usize) -> Result<Option<usize>> {
+    for index in 0..ENTRIES {
+        let region = pmp::try_get_entry(index)?;
+        if matches!(region.mode, Range::NA4 | Range::NAPOT)
+            && region.locked
+            && region.permission == Permission::NONE
+            && region.base == guard_base
+            && region.size == guard_size
+        {
+            return Ok(Some(index));
+        }
+    }
+    Ok(None)
+}
+
+/// Finds the lowest-numbered PMP entry that can be used for a stack guard.
+fn try_find_entry() -> Result<usize> {
+    for index in 0..ENTRIES {
+        // locked entries cannot be reprogrammed, even if they are disabled
+        let region = pmp::try_get_entry(index)?;
+        if region.mode != Range::OFF || region.locked {
+            continue;
+        }
+        if index + 1 < ENTRIES && pmp::try_get_entry(index + 1)?.mode == Range::TOR {
+            continue;
+        }
+        return Ok(index);
+    }
+    Err(Error::IndexOutOfBounds {
+        index: ENTRIES,
+        min: 0,
+        max: ENTRIES - 1,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_install() {
+        assert_eq!(
+            unsafe { try_install(0x8000_1000, 0x1800) },
+            Err(Error::InvalidFieldValue {
+                field: "size",
+                value: 0x1800,
+                bitmask: !0b111,
+            })
+        );
+        assert_eq!(
+            unsafe { try_install(0x8000_0800, 0x1000) },
+            Err(Error::InvalidFieldValue {
+                field: "base",
+                value: 0x8000_0800,
+                bitmask: !0xFFF,
+            })
+        );
+        assert_eq!(
+            unsafe { try_install(0x8000_1000, 0x1000) },
+            Err(Error::Unimplemented)
+        );
+        assert_eq!(try_entry(0x8000_1000, 0x1000), Err(Error::Unimplemented));
+    }
+}
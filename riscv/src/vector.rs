@@ -0,0 +1,241 @@
+//! Vector state save and restore
+//!
+//! Helpers to save and restore the vector register file (`v0`-`v31`) and the vector configuration
+//! CSRs (`vstart`, `vl`, `vtype` and `vcsr`), e.g., to switch the vector context of a thread.
+//!
+//! The state is stored in a byte buffer of [`buffer_size`] bytes: a header with the CSRs,
+//! followed by the vector registers. The layout is not stable across versions of this crate.
+//!
+//! # Example
+//!
+//! ```no_run
+//! let mut state = [0u8; 1024];
+//! assert!(riscv::vector::buffer_size() <= state.len());
+//! unsafe {
+//!     riscv::vector::save(&mut state);
+//!     // ...
+//!     riscv::vector::restore(&state);
+//! }
+//! ```
+
+use crate::register::{vcsr, vl, vlenb, vstart, vtype};
+use crate::result::{Error, Result};
+
+/// Number of CSRs in the header
+const CSRS: usize = 4;
+/// Size of the header in bytes
+const HEADER_SIZE: usize = CSRS * core::mem::size_of::<usize>();
+
+/// Returns the size in bytes of the buffer that holds the vector state
+///
+/// **WARNING**: panics on non-`riscv` targets.
+#[inline]
+pub fn buffer_size() -> usize {
+    try_buffer_size().unwrap()
+}
+
+/// Attempts to get the size in bytes of the buffer that holds the vector state
+#[inline]
+pub fn try_buffer_size() -> Result<usize> {
+    Ok(size_for(vlenb::try_read()?))
+}
+
+/// Returns the size of the buffer for vector registers of `vlenb` bytes
+#[inline]
+const fn size_for(vlenb: usize) -> usize {
+    HEADER_SIZE + 32 * vlenb
+}
+
+/// Checks that `len` bytes can hold the vector state, and returns `vlenb`
+#[inline]
+fn check_buffer(len: usize) -> Result<usize> {
+    let vlenb = vlenb::try_read()?;
+    let size = size_for(vlenb);
+    if len < size {
+        Err(Error::IndexOutOfBounds {
+            index: size - 1,
+            min: 0,
+            max: len.saturating_sub(1),
+        })
+    } else {
+        Ok(vlenb)
+    }
+}
+
+/// Saves the vector state to `buf`
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `buf` smaller than [`buffer_size`]
+///
+/// # Safety
+///
+/// The vector unit must be enabled (i.e., the `VS` field of `mstatus` or `sstatus` must not be off).
+#[inline]
+pub unsafe fn save(buf: &mut [u8]) {
+    try_save(buf).unwrap();
+}
+
+/// Attempts to save the vector state to `buf`
+///
+/// Returns [`Error::IndexOutOfBounds`] if `buf` is smaller than [`buffer_size`].
+///
+/// # Safety
+///
+/// See [`save`].
+#[inline]
+pub unsafe fn try_save(buf: &mut [u8]) -> Result<()> {
+    let vlenb = check_buffer(buf.len())?;
+    let csrs = [
+        vstart::try_read()?,
+        vl::try_read()?,
+        vtype::try_read()?.bits(),
+        vcsr::try_read()?.bits(),
+    ];
+    let (header, regs) = buf.split_at_mut(HEADER_SIZE);
+    for (chunk, csr) in header.chunks_exact_mut(HEADER_SIZE / CSRS).zip(csrs) {
+        chunk.copy_from_slice(&csr.to_ne_bytes());
+    }
+
+    // whole register stores honor vstart
+    vstart::try_write(0)?;
+    store_registers(regs.as_mut_ptr(), 8 * vlenb);
+    vstart::try_write(csrs[0])
+}
+
+/// Restores the vector state from `buf`
+///
+/// **WARNING**: panics on:
+///
+/// - non-`riscv` targets
+/// - `buf` smaller than [`buffer_size`]
+///
+/// # Safety
+///
+/// The vector unit must be enabled, and `buf` must hold a state saved with [`save`] on a hart with
+/// the same vector register length. It overwrites the vector state of the current context.
+#[inline]
+pub unsafe fn restore(buf: &[u8]) {
+    try_restore(buf).unwrap();
+}
+
+/// Attempts to restore the vector state from `buf`
+///
+/// Returns [`Error::IndexOutOfBounds`] if `buf` is smaller than [`buffer_size`].
+///
+/// # Safety
+///
+/// See [`restore`].
+#[inline]
+pub unsafe fn try_restore(buf: &[u8]) -> Result<()> {
+    let vlenb = check_buffer(buf.len())?;
+    let (header, regs) = buf.split_at(HEADER_SIZE);
+    let mut csrs = [0; CSRS];
+    for (csr, chunk) in csrs.iter_mut().zip(header.chunks_exact(HEADER_SIZE / CSRS)) {
+        let mut bytes = [0; HEADER_SIZE / CSRS];
+        bytes.copy_from_slice(chunk);
+        *csr = usize::from_ne_bytes(bytes);
+    }
+    let [vstart, vl, vtype, vcsr] = csrs;
+
+    vstart::try_write(0)?;
+    load_registers(regs.as_ptr(), 8 * vlenb);
+    set_config(vl, vtype);
+    vcsr::try_write(vcsr::Vcsr::from(vcsr))?;
+    // vector instructions reset vstart, so it is restored last
+    vstart::try_write(vstart)
+}
+
+/// Stores `v0`-`v31` to `ptr`, in groups of 8 registers of `stride` bytes
+#[inline]
+#[allow(unused_variables)]
+unsafe fn store_registers(ptr: *mut u8, stride: usize) {
+    match () {
+        // vs8r.v with `.insn`, as assemblers may not enable the extension
+        #[cfg(riscv)]
+        () => core::arch::asm!(
+            ".insn r 0x27, 0, 0x71, x0, {0}, x8",
+            "add {0}, {0}, {1}",
+            ".insn r 0x27, 0, 0x71, x8, {0}, x8",
+            "add {0}, {0}, {1}",
+            ".insn r 0x27, 0, 0x71, x16, {0}, x8",
+            "add {0}, {0}, {1}",
+            ".insn r 0x27, 0, 0x71, x24, {0}, x8",
+            inout(reg) ptr => _,
+            in(reg) stride,
+            options(nostack),
+        ),
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+/// Loads `v0`-`v31` from `ptr`, in groups of 8 registers of `stride` bytes
+///
+/// All the vector registers are declared as clobbered, as the compiler may hold values in them.
+#[inline]
+#[allow(unused_variables)]
+unsafe fn load_registers(ptr: *const u8, stride: usize) {
+    match () {
+        // vl8re8.v with `.insn`, as assemblers may not enable the extension
+        #[cfg(riscv)]
+        () => core::arch::asm!(
+            ".insn r 0x07, 0, 0x71, x0, {0}, x8",
+            "add {0}, {0}, {1}",
+            ".insn r 0x07, 0, 0x71, x8, {0}, x8",
+            "add {0}, {0}, {1}",
+            ".insn r 0x07, 0, 0x71, x16, {0}, x8",
+            "add {0}, {0}, {1}",
+            ".insn r 0x07, 0, 0x71, x24, {0}, x8",
+            inout(reg) ptr => _,
+            in(reg) stride,
+            out("v0") _, out("v1") _, out("v2") _, out("v3") _,
+            out("v4") _, out("v5") _, out("v6") _, out("v7") _,
+            out("v8") _, out("v9") _, out("v10") _, out("v11") _,
+            out("v12") _, out("v13") _, out("v14") _, out("v15") _,
+            out("v16") _, out("v17") _, out("v18") _, out("v19") _,
+            out("v20") _, out("v21") _, out("v22") _, out("v23") _,
+            out("v24") _, out("v25") _, out("v26") _, out("v27") _,
+            out("v28") _, out("v29") _, out("v30") _, out("v31") _,
+            options(nostack, readonly),
+        ),
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+/// Restores `vl` and `vtype` with `vsetvl`
+#[inline]
+#[allow(unused_variables)]
+unsafe fn set_config(vl: usize, vtype: usize) {
+    match () {
+        #[cfg(riscv)]
+        () => core::arch::asm!(
+            ".insn r 0x57, 7, 0x40, x0, {0}, {1}",
+            in(reg) vl,
+            in(reg) vtype,
+            options(nomem, nostack),
+        ),
+
+        #[cfg(not(riscv))]
+        () => unimplemented!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector() {
+        assert_eq!(size_for(16), HEADER_SIZE + 512);
+        assert_eq!(try_buffer_size(), Err(Error::Unimplemented));
+
+        let mut buf = [0u8; 64];
+        assert_eq!(unsafe { try_save(&mut buf) }, Err(Error::Unimplemented));
+        assert_eq!(unsafe { try_restore(&buf) }, Err(Error::Unimplemented));
+    }
+}